@@ -0,0 +1,65 @@
+use ark_bn254::G1Affine as G1Point;
+use ark_serialize::*;
+
+use crate::{InnerProof, OneOutOfManyProof, RangeProof, SigmaAnonProof};
+
+#[derive(Debug, CanonicalDeserialize, CanonicalSerialize)]
+pub struct AnonZeroMTProof {
+    range_proof: RangeProof,
+    inner_proof: InnerProof,
+    y_first: G1Point,
+    e_l: G1Point,
+    e_r: G1Point,
+    one_out_of_many_proof: OneOutOfManyProof,
+    sigma_anon_proof: SigmaAnonProof,
+}
+
+impl AnonZeroMTProof {
+    pub fn new(
+        range_proof: RangeProof,
+        inner_proof: InnerProof,
+        y_first: G1Point,
+        e_l: G1Point,
+        e_r: G1Point,
+        one_out_of_many_proof: OneOutOfManyProof,
+        sigma_anon_proof: SigmaAnonProof,
+    ) -> Self {
+        AnonZeroMTProof {
+            range_proof,
+            inner_proof,
+            y_first,
+            e_l,
+            e_r,
+            one_out_of_many_proof,
+            sigma_anon_proof,
+        }
+    }
+
+    pub fn get_range_proof(&self) -> &RangeProof {
+        &self.range_proof
+    }
+
+    pub fn get_inner_proof(&self) -> &InnerProof {
+        &self.inner_proof
+    }
+
+    pub fn get_y_first(&self) -> &G1Point {
+        &self.y_first
+    }
+
+    pub fn get_e_l(&self) -> &G1Point {
+        &self.e_l
+    }
+
+    pub fn get_e_r(&self) -> &G1Point {
+        &self.e_r
+    }
+
+    pub fn get_one_out_of_many_proof(&self) -> &OneOutOfManyProof {
+        &self.one_out_of_many_proof
+    }
+
+    pub fn get_sigma_anon_proof(&self) -> &SigmaAnonProof {
+        &self.sigma_anon_proof
+    }
+}
@@ -0,0 +1,294 @@
+use crate::errors::proof_error::throw;
+use crate::range::range_link::RangeLinkProver;
+use crate::{
+    AnonZeroMTProof, AnonZeroMTStatement, AnonZeroMTVerifier, AnonZeroMTWitness, InnerProof,
+    InnerProver, LinkedRangeProof, OneOutOfManyProof, OneOutOfManyProver, ProofError, RangeProof,
+    RangeProver, SigmaAnonProof, SigmaAnonProver, TranscriptProtocol, Utils, ZeroMTParams,
+};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{Field, PrimeField};
+use ark_std::rand::Rng;
+use merlin::Transcript;
+use std::io::Error;
+
+/// Anonymous multi-transfer prover. The sender and the recipients are hidden in a ring of
+/// public keys: every ring member receives a ciphertext update C_i = v_i * g + r * y_i, where
+/// v_i is the amount received (zero for decoys) and the sender's update encrypts -sum(a).
+pub struct AnonZeroMTProver<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    g_vec: &'a Vec<G1Point>,
+    h_vec: &'a Vec<G1Point>,
+    u: &'a G1Point,
+    n: usize,
+    y_vec: &'a Vec<G1Point>,
+    c_l_vec: &'a Vec<G1Point>,
+    c_r_vec: &'a Vec<G1Point>,
+    c_vec: &'a Vec<G1Point>,
+    d: &'a G1Point,
+    sender_index: usize,
+    remaining_balance: usize,
+    amounts: &'a Vec<usize>,
+    sk: &'a ScalarField,
+    r: &'a ScalarField,
+}
+
+impl<'a> AnonZeroMTProver<'a> {
+    pub fn new(
+        params: &ZeroMTParams<'a>,
+        statement: &AnonZeroMTStatement<'a>,
+        witness: &AnonZeroMTWitness<'a>,
+    ) -> Self {
+        AnonZeroMTProver {
+            g: params.get_g(),
+            h: params.get_h(),
+            g_vec: params.get_g_vec(),
+            h_vec: params.get_h_vec(),
+            u: params.get_u(),
+            n: params.get_n(),
+            y_vec: statement.get_y_vec(),
+            c_l_vec: statement.get_c_l_vec(),
+            c_r_vec: statement.get_c_r_vec(),
+            c_vec: statement.get_c_vec(),
+            d: statement.get_d(),
+            sender_index: witness.get_sender_index(),
+            remaining_balance: witness.get_remaining_balance(),
+            amounts: witness.get_amounts(),
+            sk: witness.get_sk(),
+            r: witness.get_r(),
+        }
+    }
+
+    pub fn generate_proof<R: Rng>(
+        &mut self,
+        rng: &mut R,
+        transcript: &mut Transcript,
+    ) -> Result<AnonZeroMTProof, Error> {
+        transcript.domain_sep(b"AnonZeroMTProof");
+
+        let statement: AnonZeroMTVerifier = AnonZeroMTVerifier::new(
+            &ZeroMTParams::new(self.g, self.h, self.g_vec, self.h_vec, self.u, self.n),
+            &AnonZeroMTStatement::new(self.y_vec, self.c_l_vec, self.c_r_vec, self.c_vec, self.d),
+        );
+
+        let ring_size: usize = self.y_vec.len();
+        let l: usize = self.sender_index;
+        if !statement.is_valid_ring() || self.amounts.len() != ring_size || l >= ring_size {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+        statement.append_statement(transcript);
+
+        // Range-proven values in ring order, the sender slot holds the remaining balance, so
+        // that value i is weighted by z^(2+i) in s_ab as in the ciphertext updates
+        let values: Vec<usize> = (0..ring_size)
+            .map(|i: usize| {
+                if i == l {
+                    self.remaining_balance
+                } else {
+                    self.amounts[i]
+                }
+            })
+            .collect();
+        let range_amounts: Vec<usize> = values[1..].to_vec();
+
+        let mut range_prover: RangeProver = RangeProver::new(
+            self.g,
+            self.h,
+            values[0],
+            &range_amounts,
+            self.g_vec,
+            self.h_vec,
+            self.n,
+        );
+
+        // Re-randomisations of the sender's key and balance
+        let rho_vec: Vec<ScalarField> = Utils::get_n_random_scalars(3, rng);
+
+        let mut link: AnonRangeLink = AnonRangeLink {
+            prover: self,
+            values: &values,
+            rho_vec: &rho_vec,
+        };
+
+        let linked_proof: LinkedRangeProof<(G1Point, G1Point, G1Point, SigmaAnonProof)> =
+            range_prover.generate_proof_with_link(rng, transcript, &mut link);
+        let range_proof: &RangeProof = linked_proof.get_range_proof();
+        let z_prover: ScalarField = *linked_proof.get_z();
+
+        let (h_first_vec_prover, phu_prover): (Vec<G1Point>, G1Point) = range_prover
+            .get_ipa_arguments(
+                linked_proof.get_x(),
+                linked_proof.get_y(),
+                &z_prover,
+                range_proof.get_mu(),
+                range_proof.get_a(),
+                range_proof.get_s(),
+                self.h,
+                self.g_vec,
+                self.h_vec,
+            );
+
+        let inner_proof: InnerProof = InnerProver::new(
+            self.g_vec,
+            &h_first_vec_prover,
+            &phu_prover,
+            range_proof.get_t_hat(),
+            linked_proof.get_l_poly_vec(),
+            linked_proof.get_r_poly_vec(),
+            self.u,
+        )
+        .generate_proof(transcript)?;
+        let (range_proof, (y_first, e_l, e_r, sigma_anon_proof)): (
+            RangeProof,
+            (G1Point, G1Point, G1Point, SigmaAnonProof),
+        ) = linked_proof.into_proofs();
+
+        let xi: ScalarField = transcript.challenge_scalar(b"xi");
+
+        let ring_commitments: Vec<G1Point> =
+            statement.get_ring_commitments(&z_prover, &xi, &y_first, &e_l, &e_r);
+        let opening: ScalarField = -(rho_vec[0] + (xi * rho_vec[1]) + (xi.square() * rho_vec[2]));
+
+        let one_out_of_many_proof: OneOutOfManyProof =
+            OneOutOfManyProver::new(self.g_vec, self.h, &ring_commitments, l, &opening)
+                .generate_proof(rng, transcript)?;
+
+        Ok(AnonZeroMTProof::new(
+            range_proof,
+            inner_proof,
+            y_first,
+            e_l,
+            e_r,
+            one_out_of_many_proof,
+            sigma_anon_proof,
+        ))
+    }
+}
+
+/// SigmaAnon on the challenges of the range proof, with k_w as its k_ab: then s_w is the s_ab
+/// of the range proof and w = sum_i z^(2+i) * v_i is made of the range-proven values.
+struct AnonRangeLink<'a, 'b> {
+    prover: &'b AnonZeroMTProver<'a>,
+    values: &'b Vec<usize>,
+    rho_vec: &'b Vec<ScalarField>,
+}
+
+/// Messages of SigmaAnon kept between its commitments and its responses.
+struct AnonRangeLinkState {
+    y_first: G1Point,
+    e_l: G1Point,
+    e_r: G1Point,
+    q: G1Point,
+    y_sum: G1Point,
+    w: ScalarField,
+    tau: ScalarField,
+    commitments: Vec<G1Point>,
+    k_vec: Vec<ScalarField>,
+}
+
+impl<'a, 'b> RangeLinkProver for AnonRangeLink<'a, 'b> {
+    type State = AnonRangeLinkState;
+    /// (Y', E_L, E_R, SigmaAnon proof)
+    type Proof = (G1Point, G1Point, G1Point, SigmaAnonProof);
+
+    /// The ring is already appended by `AnonZeroMTVerifier::append_statement`.
+    fn link_statement<T: TranscriptProtocol>(&self, _transcript: &mut T) {}
+
+    fn link_commitments<R: Rng, T: TranscriptProtocol>(
+        &mut self,
+        rng: &mut R,
+        transcript: &mut T,
+        weights: &Vec<ScalarField>,
+        k_ab: &ScalarField,
+    ) -> AnonRangeLinkState {
+        let prover: &AnonZeroMTProver = self.prover;
+        let l: usize = prover.sender_index;
+        let z_l: ScalarField = weights[l];
+
+        // Re-randomised copies of the sender's key and balance
+        let y_first: G1Point =
+            prover.y_vec[l] + prover.h.mul(self.rho_vec[0].into_repr()).into_affine();
+        let e_l: G1Point = prover.c_l_vec[l].mul(z_l.into_repr()).into_affine()
+            + prover.h.mul(self.rho_vec[1].into_repr()).into_affine();
+        let e_r: G1Point = prover.c_r_vec[l].mul(z_l.into_repr()).into_affine()
+            + prover.h.mul(self.rho_vec[2].into_repr()).into_affine();
+
+        let q: G1Point = Utils::inner_product_point_scalar(prover.y_vec, weights).unwrap();
+        let y_sum: G1Point = prover.y_vec.iter().sum::<G1Point>();
+
+        let w: ScalarField = self
+            .values
+            .iter()
+            .zip(weights.iter())
+            .map(|(v_i, z_i): (&usize, &ScalarField)| ScalarField::from(*v_i as i128) * z_i)
+            .sum();
+        let tau: ScalarField = self.rho_vec[1] - (*prover.sk * self.rho_vec[2]);
+
+        let mut k_vec: Vec<ScalarField> = Utils::get_n_random_scalars(5, rng);
+        k_vec[2] = *k_ab;
+
+        let commitments: Vec<G1Point> = SigmaAnonProver::new(
+            prover.g,
+            prover.h,
+            &e_r,
+            &q,
+            &y_sum,
+            prover.sk,
+            &self.rho_vec[0],
+            &w,
+            &tau,
+            prover.r,
+        )
+        .get_commitments(&k_vec);
+
+        let _result = transcript.append_point(b"Y'", &y_first);
+        let _result = transcript.append_point(b"E_L", &e_l);
+        let _result = transcript.append_point(b"E_R", &e_r);
+        let _result = transcript.append_point(b"A_y", &commitments[0]);
+        let _result = transcript.append_point(b"A_p", &commitments[1]);
+        let _result = transcript.append_point(b"A_D", &commitments[2]);
+        let _result = transcript.append_point(b"A_C", &commitments[3]);
+
+        AnonRangeLinkState {
+            y_first,
+            e_l,
+            e_r,
+            q,
+            y_sum,
+            w,
+            tau,
+            commitments,
+            k_vec,
+        }
+    }
+
+    fn link_responses<T: TranscriptProtocol>(
+        &mut self,
+        state: AnonRangeLinkState,
+        transcript: &mut T,
+        c: &ScalarField,
+    ) -> Self::Proof {
+        let sigma_anon_proof: SigmaAnonProof = SigmaAnonProver::new(
+            self.prover.g,
+            self.prover.h,
+            &state.e_r,
+            &state.q,
+            &state.y_sum,
+            self.prover.sk,
+            &self.rho_vec[0],
+            &state.w,
+            &state.tau,
+            self.prover.r,
+        )
+        .get_linked_proof(state.commitments, &state.k_vec, c);
+
+        // s_w is the s_ab already appended by the range proof
+        let _result = transcript.append_scalar(b"s_sk", sigma_anon_proof.get_s_sk());
+        let _result = transcript.append_scalar(b"s_rho", sigma_anon_proof.get_s_rho());
+        let _result = transcript.append_scalar(b"s_tau", sigma_anon_proof.get_s_tau());
+        let _result = transcript.append_scalar(b"s_r", sigma_anon_proof.get_s_r());
+
+        (state.y_first, state.e_l, state.e_r, sigma_anon_proof)
+    }
+}
@@ -0,0 +1,100 @@
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+
+/// Public inputs of an anonymous transfer: the ring of public keys y_vec with the encrypted
+/// balances (C_L, C_R) of its members, the ciphertext updates C_vec and D = r * g
+#[derive(Clone, Copy)]
+pub struct AnonZeroMTStatement<'a> {
+    y_vec: &'a Vec<G1Point>,
+    c_l_vec: &'a Vec<G1Point>,
+    c_r_vec: &'a Vec<G1Point>,
+    c_vec: &'a Vec<G1Point>,
+    d: &'a G1Point,
+}
+
+impl<'a> AnonZeroMTStatement<'a> {
+    pub fn new(
+        y_vec: &'a Vec<G1Point>,
+        c_l_vec: &'a Vec<G1Point>,
+        c_r_vec: &'a Vec<G1Point>,
+        c_vec: &'a Vec<G1Point>,
+        d: &'a G1Point,
+    ) -> Self {
+        AnonZeroMTStatement {
+            y_vec,
+            c_l_vec,
+            c_r_vec,
+            c_vec,
+            d,
+        }
+    }
+
+    pub fn get_y_vec(&self) -> &'a Vec<G1Point> {
+        self.y_vec
+    }
+
+    pub fn get_c_l_vec(&self) -> &'a Vec<G1Point> {
+        self.c_l_vec
+    }
+
+    pub fn get_c_r_vec(&self) -> &'a Vec<G1Point> {
+        self.c_r_vec
+    }
+
+    pub fn get_c_vec(&self) -> &'a Vec<G1Point> {
+        self.c_vec
+    }
+
+    pub fn get_d(&self) -> &'a G1Point {
+        self.d
+    }
+}
+
+/// Secret inputs of the sender: its position in the ring, its remaining balance, the amount
+/// received by every ring member (zero for the sender and the decoys), its private key sk and
+/// the randomness r of the ciphertexts
+#[derive(Clone, Copy)]
+pub struct AnonZeroMTWitness<'a> {
+    sender_index: usize,
+    remaining_balance: usize,
+    amounts: &'a Vec<usize>,
+    sk: &'a ScalarField,
+    r: &'a ScalarField,
+}
+
+impl<'a> AnonZeroMTWitness<'a> {
+    pub fn new(
+        sender_index: usize,
+        remaining_balance: usize,
+        amounts: &'a Vec<usize>,
+        sk: &'a ScalarField,
+        r: &'a ScalarField,
+    ) -> Self {
+        AnonZeroMTWitness {
+            sender_index,
+            remaining_balance,
+            amounts,
+            sk,
+            r,
+        }
+    }
+
+    pub fn get_sender_index(&self) -> usize {
+        self.sender_index
+    }
+
+    pub fn get_remaining_balance(&self) -> usize {
+        self.remaining_balance
+    }
+
+    pub fn get_amounts(&self) -> &'a Vec<usize> {
+        self.amounts
+    }
+
+    pub fn get_sk(&self) -> &'a ScalarField {
+        self.sk
+    }
+
+    pub fn get_r(&self) -> &'a ScalarField {
+        self.r
+    }
+}
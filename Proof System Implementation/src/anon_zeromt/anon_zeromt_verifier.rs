@@ -0,0 +1,228 @@
+use crate::errors::proof_error::throw;
+use crate::range::range_link::RangeLinkVerifier;
+use crate::{
+    AnonZeroMTProof, AnonZeroMTStatement, InnerVerifier, OneOutOfManyVerifier, ProofError,
+    RangeVerifier, SigmaAnonProof, SigmaAnonVerifier, TranscriptProtocol, Utils, ZeroMTParams,
+};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{Field, PrimeField};
+use merlin::Transcript;
+use std::io::Error;
+
+pub struct AnonZeroMTVerifier<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    n: usize,
+    g_vec: &'a Vec<G1Point>,
+    h_vec: &'a Vec<G1Point>,
+    u: &'a G1Point,
+    y_vec: &'a Vec<G1Point>,
+    c_l_vec: &'a Vec<G1Point>,
+    c_r_vec: &'a Vec<G1Point>,
+    c_vec: &'a Vec<G1Point>,
+    d: &'a G1Point,
+}
+
+impl<'a> AnonZeroMTVerifier<'a> {
+    pub const MIN_RING_SIZE: usize = 4;
+    pub const MAX_RING_SIZE: usize = 64;
+
+    pub fn new(params: &ZeroMTParams<'a>, statement: &AnonZeroMTStatement<'a>) -> Self {
+        AnonZeroMTVerifier {
+            g: params.get_g(),
+            h: params.get_h(),
+            n: params.get_n(),
+            g_vec: params.get_g_vec(),
+            h_vec: params.get_h_vec(),
+            u: params.get_u(),
+            y_vec: statement.get_y_vec(),
+            c_l_vec: statement.get_c_l_vec(),
+            c_r_vec: statement.get_c_r_vec(),
+            c_vec: statement.get_c_vec(),
+            d: statement.get_d(),
+        }
+    }
+
+    pub fn verify_proof(
+        &mut self,
+        proof: &AnonZeroMTProof,
+        transcript: &mut Transcript,
+    ) -> Result<(), Error> {
+        transcript.domain_sep(b"AnonZeroMTProof");
+
+        if !self.is_valid_ring() {
+            return Err(throw(ProofError::ProofValidationError));
+        }
+        self.append_statement(transcript);
+
+        let ring_size: usize = self.y_vec.len();
+        let mut range_verifier: RangeVerifier =
+            RangeVerifier::new(self.g, self.h, ring_size, self.n);
+
+        // SigmaAnon runs on the challenges z and c of the range proof and shares its s_ab
+        let (range_proof_result, x_verifier, y_verifier, z_verifier) =
+            range_verifier.verify_proof_with_link(proof.get_range_proof(), proof, self, transcript);
+
+        let (h_first_vec_verifier, phu_verifier): (Vec<G1Point>, G1Point) = range_verifier
+            .get_ipa_arguments(
+                &x_verifier,
+                &y_verifier,
+                &z_verifier,
+                proof.get_range_proof().get_mu(),
+                proof.get_range_proof().get_a(),
+                proof.get_range_proof().get_s(),
+                self.h,
+                self.g_vec,
+                self.h_vec,
+            );
+
+        let inner_result = InnerVerifier::new(
+            self.g_vec,
+            &h_first_vec_verifier,
+            &phu_verifier,
+            proof.get_range_proof().get_t_hat(),
+            self.u,
+        )
+        .verify_proof_multiscalar(proof.get_inner_proof(), transcript);
+
+        let xi: ScalarField = transcript.challenge_scalar(b"xi");
+
+        let ring_commitments: Vec<G1Point> = self.get_ring_commitments(
+            &z_verifier,
+            &xi,
+            proof.get_y_first(),
+            proof.get_e_l(),
+            proof.get_e_r(),
+        );
+
+        let one_out_of_many_result =
+            OneOutOfManyVerifier::new(self.g_vec, self.h, &ring_commitments)
+                .verify_proof(proof.get_one_out_of_many_proof(), transcript);
+
+        let proof_check: bool =
+            range_proof_result.is_ok() && inner_result.is_ok() && one_out_of_many_result.is_ok();
+
+        if proof_check {
+            Ok(())
+        } else {
+            Err(throw(ProofError::ProofValidationError))
+        }
+    }
+
+    /// The ring is a power of two between `MIN_RING_SIZE` and `MAX_RING_SIZE`, with a balance,
+    /// a ciphertext and n generators of `g_vec` and `h_vec` per member.
+    pub(crate) fn is_valid_ring(&self) -> bool {
+        let ring_size: usize = self.y_vec.len();
+
+        ring_size.is_power_of_two()
+            && (Self::MIN_RING_SIZE..=Self::MAX_RING_SIZE).contains(&ring_size)
+            && self.c_l_vec.len() == ring_size
+            && self.c_r_vec.len() == ring_size
+            && self.c_vec.len() == ring_size
+            && self.g_vec.len() == ring_size * self.n
+            && self.h_vec.len() == ring_size * self.n
+    }
+
+    /// Appends the statement (n, g, h, u, g_vec, h_vec, y, C_L, C_R, C, D) to the transcript.
+    pub(crate) fn append_statement(&self, transcript: &mut Transcript) {
+        let _result = transcript.append_scalar(b"n", &ScalarField::from(self.n as u64));
+        let _result = transcript.append_point(b"g", self.g);
+        let _result = transcript.append_point(b"h", self.h);
+        let _result = transcript.append_point(b"u", self.u);
+        for (g_i, h_i) in self.g_vec.iter().zip(self.h_vec.iter()) {
+            let _result = transcript.append_point(b"g_i", g_i);
+            let _result = transcript.append_point(b"h_i", h_i);
+        }
+        for i in 0..self.y_vec.len() {
+            let _result = transcript.append_point(b"y", &self.y_vec[i]);
+            let _result = transcript.append_point(b"C_L", &self.c_l_vec[i]);
+            let _result = transcript.append_point(b"C_R", &self.c_r_vec[i]);
+            let _result = transcript.append_point(b"C", &self.c_vec[i]);
+        }
+        let _result = transcript.append_point(b"D", self.d);
+    }
+
+    /// X_i = (y_i - Y') + xi * (z^(2+i) * C_L,i - E_L) + xi^2 * (z^(2+i) * C_R,i - E_R)
+    pub(crate) fn get_ring_commitments(
+        &self,
+        z: &ScalarField,
+        xi: &ScalarField,
+        y_first: &G1Point,
+        e_l: &G1Point,
+        e_r: &G1Point,
+    ) -> Vec<G1Point> {
+        (0..self.y_vec.len())
+            .map(|i: usize| {
+                let z_i: ScalarField = z.pow([2 + i as u64]);
+                let key: G1Point = self.y_vec[i] + -*y_first;
+                let left: G1Point = self.c_l_vec[i].mul(z_i.into_repr()).into_affine() + -*e_l;
+                let right: G1Point = self.c_r_vec[i].mul(z_i.into_repr()).into_affine() + -*e_r;
+
+                key + left.mul(xi.into_repr()).into_affine()
+                    + right.mul(xi.square().into_repr()).into_affine()
+            })
+            .collect()
+    }
+}
+
+impl<'a> RangeLinkVerifier for AnonZeroMTVerifier<'a> {
+    type Proof = AnonZeroMTProof;
+
+    /// The ring is already appended by `append_statement`, before the range proof.
+    fn link_statement<T: TranscriptProtocol>(&self, _transcript: &mut T) {}
+
+    fn link_commitments<T: TranscriptProtocol>(&self, proof: &AnonZeroMTProof, transcript: &mut T) {
+        let sigma_anon_proof: &SigmaAnonProof = proof.get_sigma_anon_proof();
+
+        let _result = transcript.append_point(b"Y'", proof.get_y_first());
+        let _result = transcript.append_point(b"E_L", proof.get_e_l());
+        let _result = transcript.append_point(b"E_R", proof.get_e_r());
+        let _result = transcript.append_point(b"A_y", sigma_anon_proof.get_a_y());
+        let _result = transcript.append_point(b"A_p", sigma_anon_proof.get_a_p());
+        let _result = transcript.append_point(b"A_D", sigma_anon_proof.get_a_d());
+        let _result = transcript.append_point(b"A_C", sigma_anon_proof.get_a_c());
+    }
+
+    fn link_responses<T: TranscriptProtocol>(&self, proof: &AnonZeroMTProof, transcript: &mut T) {
+        let sigma_anon_proof: &SigmaAnonProof = proof.get_sigma_anon_proof();
+
+        let _result = transcript.append_scalar(b"s_sk", sigma_anon_proof.get_s_sk());
+        let _result = transcript.append_scalar(b"s_rho", sigma_anon_proof.get_s_rho());
+        let _result = transcript.append_scalar(b"s_tau", sigma_anon_proof.get_s_tau());
+        let _result = transcript.append_scalar(b"s_r", sigma_anon_proof.get_s_r());
+    }
+
+    /// P = sum_i z^(2+i) * C_i + E_L opens to w = sum_i z^(2+i) * v_i, with the weights of the
+    /// values of the range proof in ring order, so s_w must be its s_ab.
+    fn verify_link(
+        &mut self,
+        proof: &AnonZeroMTProof,
+        weights: &Vec<ScalarField>,
+        c: &ScalarField,
+        s_ab: &ScalarField,
+    ) -> Result<(), Error> {
+        if proof.get_sigma_anon_proof().get_s_w() != s_ab {
+            return Err(throw(ProofError::ProofValidationError));
+        }
+
+        let p: G1Point =
+            Utils::inner_product_point_scalar(self.c_vec, weights).unwrap() + *proof.get_e_l();
+        let q: G1Point = Utils::inner_product_point_scalar(self.y_vec, weights).unwrap();
+        let y_sum: G1Point = self.y_vec.iter().sum::<G1Point>();
+        let c_sum: G1Point = self.c_vec.iter().sum::<G1Point>();
+
+        SigmaAnonVerifier::new(
+            self.g,
+            self.h,
+            self.d,
+            proof.get_y_first(),
+            proof.get_e_r(),
+            &p,
+            &q,
+            &y_sum,
+            &c_sum,
+        )
+        .verify_with_challenges(proof.get_sigma_anon_proof(), c)
+    }
+}
@@ -0,0 +1,4 @@
+pub(crate) mod anon_zeromt_proof;
+pub(crate) mod anon_zeromt_prover;
+pub(crate) mod anon_zeromt_statement;
+pub(crate) mod anon_zeromt_verifier;
@@ -0,0 +1,55 @@
+use crate::transcript::TranscriptProtocol;
+use ark_bn254::Fr as ScalarField;
+use ark_ff::PrimeField;
+
+/// Public context of the transaction that encloses a proof, e.g. the chain id, the contract
+/// address and the epoch. It is appended to the transcript before the statement, so that a
+/// proof only verifies within the same context.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AssociatedData {
+    entries: Vec<(&'static [u8], Vec<u8>)>,
+}
+
+impl AssociatedData {
+    pub fn new() -> Self {
+        AssociatedData {
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn with_chain_id(self, chain_id: u64) -> Self {
+        self.with_data(b"chain_id", &chain_id.to_le_bytes())
+    }
+
+    pub fn with_contract_address(self, address: &[u8]) -> Self {
+        self.with_data(b"contract_address", address)
+    }
+
+    /// Address of the account the proof is sent from.
+    pub fn with_account_address(self, address: &[u8]) -> Self {
+        self.with_data(b"account_address", address)
+    }
+
+    pub fn with_epoch(self, epoch: u64) -> Self {
+        self.with_data(b"epoch", &epoch.to_le_bytes())
+    }
+
+    /// Any other labelled data, appended in the order of the calls.
+    pub fn with_data(mut self, label: &'static [u8], data: &[u8]) -> Self {
+        self.entries.push((label, data.to_vec()));
+        self
+    }
+
+    /// Appends each entry as its length followed by its bytes, packed in 31-byte chunks so
+    /// that every chunk is a canonical scalar.
+    pub fn append_to_transcript<T: TranscriptProtocol>(&self, transcript: &mut T) {
+        transcript.domain_sep(b"AssociatedData");
+        for (label, data) in self.entries.iter() {
+            let _result = transcript.append_scalar(label, &ScalarField::from(data.len() as u64));
+            for chunk in data.chunks(31) {
+                let _result =
+                    transcript.append_scalar(b"data", &ScalarField::from_le_bytes_mod_order(chunk));
+            }
+        }
+    }
+}
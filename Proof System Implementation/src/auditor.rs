@@ -0,0 +1,104 @@
+use crate::errors::proof_error::throw;
+use crate::ProofError;
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point, G1Projective};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use std::collections::HashMap;
+use std::io::Error;
+
+/// Holder of the auditor private key. It decrypts the amounts each transfer encrypts under
+/// the auditor public key, see `ZeroMTProver::with_auditor`, solving the discrete logarithms
+/// with baby-step giant-step on a table computed once.
+pub struct Auditor {
+    g: G1Point,
+    sk: ScalarField,
+    baby_steps: HashMap<G1Point, u64>,
+    giant_step: G1Projective,
+    baby_steps_count: u64,
+    giant_steps_count: u64,
+}
+
+impl Auditor {
+    /// Largest baby-step table, 2^20 points, so that its memory stays bounded for any n.
+    pub const MAX_TABLE_BITS: usize = 20;
+
+    /// Precomputes the 2^table_bits baby steps j * g, with table_bits at most n and
+    /// `Auditor::MAX_TABLE_BITS`. Decrypting values in [0, 2^n) then takes up to 2^(n - table_bits)
+    /// giant steps, e.g. table_bits = ceil(n / 2) balances the two for small n.
+    pub fn new(g: &G1Point, sk: &ScalarField, n: usize, table_bits: usize) -> Result<Self, Error> {
+        if n == 0 || n > 64 || table_bits == 0 || table_bits > n.min(Auditor::MAX_TABLE_BITS) {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+        let baby_steps_count: u64 = 1 << table_bits;
+        let giant_steps_count: u64 = 1 << (n - table_bits);
+
+        let mut baby_steps_projective: Vec<G1Projective> =
+            Vec::with_capacity(baby_steps_count as usize);
+        let mut current: G1Projective = G1Projective::default();
+        for _ in 0..baby_steps_count {
+            baby_steps_projective.push(current);
+            current.add_assign_mixed(g);
+        }
+        let baby_steps: HashMap<G1Point, u64> =
+            G1Projective::batch_normalization_into_affine(&baby_steps_projective)
+                .into_iter()
+                .zip(0..baby_steps_count)
+                .collect();
+
+        Ok(Auditor {
+            g: *g,
+            sk: *sk,
+            baby_steps,
+            giant_step: -current,
+            baby_steps_count,
+            giant_steps_count,
+        })
+    }
+
+    pub fn get_pub_key(&self) -> G1Point {
+        self.g.mul(self.sk.into_repr()).into_affine()
+    }
+
+    /// Decrypts the amounts C_auditor_i = a_i * g + r * y_auditor of a transfer, with D = r * g.
+    /// The giant steps of all the amounts are normalized together, one inversion each.
+    pub fn decrypt_amounts(
+        &self,
+        d: &G1Point,
+        c_auditor_vec: &Vec<G1Point>,
+    ) -> Result<Vec<usize>, Error> {
+        // a_i * g = C_auditor_i - sk * D
+        let shared_secret: G1Projective = d.mul(self.sk.into_repr());
+        let mut current: Vec<G1Projective> = c_auditor_vec
+            .iter()
+            .map(|c: &G1Point| c.into_projective() - shared_secret)
+            .collect();
+        let mut amounts: Vec<Option<u64>> = vec![None; c_auditor_vec.len()];
+
+        for i in 0..self.giant_steps_count {
+            if amounts.iter().all(|a: &Option<u64>| a.is_some()) {
+                break;
+            }
+            let current_affine: Vec<G1Point> =
+                G1Projective::batch_normalization_into_affine(&current);
+            for (k, point) in current_affine.iter().enumerate() {
+                if amounts[k].is_none() {
+                    amounts[k] = self
+                        .baby_steps
+                        .get(point)
+                        .map(|j: &u64| i * self.baby_steps_count + j);
+                }
+            }
+            for point in current.iter_mut() {
+                *point += self.giant_step;
+            }
+        }
+
+        amounts
+            .into_iter()
+            .map(|a: Option<u64>| {
+                a.map(|a: u64| a as usize)
+                    .ok_or_else(|| throw(ProofError::OutOfBoundsError))
+            })
+            .collect()
+    }
+}
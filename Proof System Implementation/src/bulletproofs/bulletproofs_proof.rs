@@ -0,0 +1,72 @@
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_serialize::*;
+
+use crate::InnerProof;
+
+#[derive(Debug, CanonicalDeserialize, CanonicalSerialize)]
+pub struct BulletproofsProof {
+    a: G1Point,
+    s: G1Point,
+    t_1: G1Point,
+    t_2: G1Point,
+    t_hat: ScalarField,
+    tau_x: ScalarField,
+    mu: ScalarField,
+    inner_proof: InnerProof,
+}
+
+impl BulletproofsProof {
+    pub fn new(
+        a: G1Point,
+        s: G1Point,
+        t_1: G1Point,
+        t_2: G1Point,
+        t_hat: ScalarField,
+        tau_x: ScalarField,
+        mu: ScalarField,
+        inner_proof: InnerProof,
+    ) -> Self {
+        BulletproofsProof {
+            a,
+            s,
+            t_1,
+            t_2,
+            t_hat,
+            tau_x,
+            mu,
+            inner_proof,
+        }
+    }
+
+    pub fn get_a(&self) -> &G1Point {
+        &self.a
+    }
+
+    pub fn get_s(&self) -> &G1Point {
+        &self.s
+    }
+
+    pub fn get_t_1(&self) -> &G1Point {
+        &self.t_1
+    }
+
+    pub fn get_t_2(&self) -> &G1Point {
+        &self.t_2
+    }
+
+    pub fn get_t_hat(&self) -> &ScalarField {
+        &self.t_hat
+    }
+
+    pub fn get_tau_x(&self) -> &ScalarField {
+        &self.tau_x
+    }
+
+    pub fn get_mu(&self) -> &ScalarField {
+        &self.mu
+    }
+
+    pub fn get_inner_proof(&self) -> &InnerProof {
+        &self.inner_proof
+    }
+}
@@ -0,0 +1,191 @@
+use crate::errors::proof_error::throw;
+use crate::range::{poly_coefficients::PolyCoefficients, poly_vector::PolyVector};
+use crate::transcript::TranscriptProtocol;
+use crate::utils::Utils;
+use crate::{InnerProof, InnerProver, ProofError};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{Field, One, PrimeField};
+use ark_std::rand::Rng;
+use std::io::Error;
+
+use super::bulletproofs_proof::BulletproofsProof;
+use super::bulletproofs_verifier::BulletproofsVerifier;
+
+/// Aggregated range proof on Pedersen commitments V_j = v_j * g + gamma_j * h, showing that
+/// every v_j lies in [0, 2^n). Unlike `RangeProver`, it is not tied to the ZeroMT transfer:
+/// it commits to the values itself and includes the inner-product argument.
+pub struct BulletproofsProver<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    g_vec: &'a Vec<G1Point>,
+    h_vec: &'a Vec<G1Point>,
+    u: &'a G1Point,
+    n: usize,
+}
+
+impl<'a> BulletproofsProver<'a> {
+    pub fn new(
+        g: &'a G1Point,
+        h: &'a G1Point,
+        g_vec: &'a Vec<G1Point>,
+        h_vec: &'a Vec<G1Point>,
+        u: &'a G1Point,
+        n: usize,
+    ) -> Self {
+        BulletproofsProver {
+            g,
+            h,
+            g_vec,
+            h_vec,
+            u,
+            n,
+        }
+    }
+
+    /// Commits to the values with the given blindings and proves them in range. `g_vec` and
+    /// `h_vec` must hold at least m * n generators, only the first m * n are used.
+    pub fn prove<R: Rng, T: TranscriptProtocol>(
+        &mut self,
+        values: &Vec<usize>,
+        blindings: &Vec<ScalarField>,
+        rng: &mut R,
+        transcript: &mut T,
+    ) -> Result<(Vec<G1Point>, BulletproofsProof), Error> {
+        let m: usize = values.len();
+        let n: usize = self.n;
+        let total_bits: usize = m * n;
+        if m == 0
+            || blindings.len() != m
+            || n == 0
+            || n > 64
+            || self.g_vec.len() < total_bits
+            || self.h_vec.len() < total_bits
+        {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+        if n < 64 && values.iter().any(|v: &usize| (*v as u64) >> n != 0) {
+            return Err(throw(ProofError::OutOfBoundsError));
+        }
+
+        let g_vec: Vec<G1Point> = self.g_vec[..total_bits].to_vec();
+        let h_vec: Vec<G1Point> = self.h_vec[..total_bits].to_vec();
+
+        let commitments: Vec<G1Point> = values
+            .iter()
+            .zip(blindings.iter())
+            .map(|(v, gamma): (&usize, &ScalarField)| {
+                Utils::pedersen_commitment(&ScalarField::from(*v as u64), self.g, gamma, self.h)
+            })
+            .collect();
+
+        transcript.domain_sep(b"Bulletproofs");
+        BulletproofsVerifier::new(self.g, self.h, self.g_vec, self.h_vec, self.u, self.n)
+            .append_statement(&commitments, &g_vec, &h_vec, transcript);
+
+        let a_l: Vec<ScalarField> = values
+            .iter()
+            .flat_map(|v: &usize| Utils::number_to_be_bits_reversed(*v, n))
+            .map(|bit: u8| ScalarField::from(bit))
+            .collect();
+        let a_r: Vec<ScalarField> = Utils::subtract_scalar(&ScalarField::one(), &a_l);
+
+        let alpha: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let rho: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let s_l: Vec<ScalarField> = Utils::get_n_random_scalars(total_bits, rng);
+        let s_r: Vec<ScalarField> = Utils::get_n_random_scalars(total_bits, rng);
+
+        let a_commitment: G1Point =
+            Utils::pedersen_vector_commitment(&alpha, self.h, &a_l, &g_vec, &a_r, &h_vec)?;
+        let s_commitment: G1Point =
+            Utils::pedersen_vector_commitment(&rho, self.h, &s_l, &g_vec, &s_r, &h_vec)?;
+
+        let _result = transcript.append_point(b"A", &a_commitment);
+        let _result = transcript.append_point(b"S", &s_commitment);
+
+        let y: ScalarField = transcript.challenge_scalar(b"y");
+        let z: ScalarField = transcript.challenge_scalar(b"z");
+
+        // l(X) = a_l - z + s_l * X
+        // r(X) = y^(mn) o (a_r + z + s_r * X) + sum_j z^(1 + j) * (0, ..., 2^n, ..., 0)
+        let y_vec: Vec<ScalarField> = Utils::generate_scalar_exp_vector(total_bits, &y);
+        let two_vec: Vec<ScalarField> = Utils::generate_scalar_exp_vector(n, &ScalarField::from(2));
+        let z_two_vec: Vec<ScalarField> = (1..=m)
+            .flat_map(|j: usize| Utils::product_scalar(&z.pow([1 + (j as u64)]), &two_vec))
+            .collect();
+
+        let l: PolyVector = PolyVector::new(Utils::subtract_scalar(&z, &a_l), s_l);
+        let r: PolyVector = PolyVector::new(
+            Utils::sum_scalar_scalar(
+                &Utils::hadamard_product_scalar_scalar(&y_vec, &Utils::sum_scalar(&z, &a_r))?,
+                &z_two_vec,
+            )?,
+            Utils::hadamard_product_scalar_scalar(&y_vec, &s_r)?,
+        );
+
+        let t_coefficients: PolyCoefficients = PolyCoefficients::new(&l, &r);
+
+        let tau_1: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let tau_2: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+
+        let t_commitment_1: G1Point =
+            Utils::pedersen_commitment(t_coefficients.get_t_1(), self.g, &tau_1, self.h);
+        let t_commitment_2: G1Point =
+            Utils::pedersen_commitment(t_coefficients.get_t_2(), self.g, &tau_2, self.h);
+
+        let _result = transcript.append_point(b"T1", &t_commitment_1);
+        let _result = transcript.append_point(b"T2", &t_commitment_2);
+
+        let x: ScalarField = transcript.challenge_scalar(b"x");
+
+        let l_poly_vec: Vec<ScalarField> = l.evaluate(&x);
+        let r_poly_vec: Vec<ScalarField> = r.evaluate(&x);
+        let t_hat: ScalarField = Utils::inner_product_scalar_scalar(&l_poly_vec, &r_poly_vec)?;
+
+        // tau_x blinds t(x) together with the blindings of the commitments
+        let tau_x: ScalarField = (x * tau_1)
+            + (x * x * tau_2)
+            + (1..=m)
+                .map(|j: usize| z.pow([1 + (j as u64)]) * blindings[j - 1])
+                .sum::<ScalarField>();
+        let mu: ScalarField = alpha + rho * x;
+
+        let _result = transcript.append_scalar(b"t_hat", &t_hat);
+        let _result = transcript.append_scalar(b"tau_x", &tau_x);
+        let _result = transcript.append_scalar(b"mu", &mu);
+
+        // P = <l, g_vec> + <r, h'_vec> with h'_i = y^-i * h_i
+        let h_first_vec: Vec<G1Point> = h_vec
+            .iter()
+            .zip(Utils::batch_inverse(&y_vec).iter())
+            .map(|(h, y_inv): (&G1Point, &ScalarField)| h.mul(y_inv.into_repr()).into_affine())
+            .collect();
+        let p: G1Point = Utils::multiscalar_mul(&g_vec, &l_poly_vec)?
+            + Utils::multiscalar_mul(&h_first_vec, &r_poly_vec)?;
+
+        let inner_proof: InnerProof = InnerProver::new(
+            &g_vec,
+            &h_first_vec,
+            &p,
+            &t_hat,
+            &l_poly_vec,
+            &r_poly_vec,
+            self.u,
+        )
+        .generate_proof(transcript)?;
+
+        Ok((
+            commitments,
+            BulletproofsProof::new(
+                a_commitment,
+                s_commitment,
+                t_commitment_1,
+                t_commitment_2,
+                t_hat,
+                tau_x,
+                mu,
+                inner_proof,
+            ),
+        ))
+    }
+}
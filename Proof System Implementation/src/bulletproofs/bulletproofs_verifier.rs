@@ -0,0 +1,148 @@
+use crate::errors::proof_error::throw;
+use crate::transcript::TranscriptProtocol;
+use crate::utils::Utils;
+use crate::{InnerVerifier, ProofError};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{Field, One, PrimeField, Zero};
+use std::io::Error;
+
+use super::bulletproofs_proof::BulletproofsProof;
+
+pub struct BulletproofsVerifier<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    g_vec: &'a Vec<G1Point>,
+    h_vec: &'a Vec<G1Point>,
+    u: &'a G1Point,
+    n: usize,
+}
+
+impl<'a> BulletproofsVerifier<'a> {
+    pub fn new(
+        g: &'a G1Point,
+        h: &'a G1Point,
+        g_vec: &'a Vec<G1Point>,
+        h_vec: &'a Vec<G1Point>,
+        u: &'a G1Point,
+        n: usize,
+    ) -> Self {
+        BulletproofsVerifier {
+            g,
+            h,
+            g_vec,
+            h_vec,
+            u,
+            n,
+        }
+    }
+
+    /// Checks that every commitment opens to a value in [0, 2^n), range proof and
+    /// inner-product argument included.
+    pub fn verify<T: TranscriptProtocol>(
+        &mut self,
+        commitments: &Vec<G1Point>,
+        proof: &BulletproofsProof,
+        transcript: &mut T,
+    ) -> Result<(), Error> {
+        let m: usize = commitments.len();
+        let n: usize = self.n;
+        let total_bits: usize = m * n;
+        if m == 0
+            || n == 0
+            || n > 64
+            || self.g_vec.len() < total_bits
+            || self.h_vec.len() < total_bits
+        {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+
+        let g_vec: Vec<G1Point> = self.g_vec[..total_bits].to_vec();
+        let h_vec: Vec<G1Point> = self.h_vec[..total_bits].to_vec();
+
+        transcript.domain_sep(b"Bulletproofs");
+        self.append_statement(commitments, &g_vec, &h_vec, transcript);
+
+        let _result = transcript.append_point(b"A", proof.get_a());
+        let _result = transcript.append_point(b"S", proof.get_s());
+
+        let y: ScalarField = transcript.challenge_scalar(b"y");
+        let z: ScalarField = transcript.challenge_scalar(b"z");
+
+        let _result = transcript.append_point(b"T1", proof.get_t_1());
+        let _result = transcript.append_point(b"T2", proof.get_t_2());
+
+        let x: ScalarField = transcript.challenge_scalar(b"x");
+
+        let _result = transcript.append_scalar(b"t_hat", proof.get_t_hat());
+        let _result = transcript.append_scalar(b"tau_x", proof.get_tau_x());
+        let _result = transcript.append_scalar(b"mu", proof.get_mu());
+
+        let y_vec: Vec<ScalarField> = Utils::generate_scalar_exp_vector(total_bits, &y);
+        let z_exp_vec: Vec<ScalarField> = (1..=m).map(|j: usize| z.pow([1 + (j as u64)])).collect();
+        let two_vec: Vec<ScalarField> = Utils::generate_scalar_exp_vector(n, &ScalarField::from(2));
+        let two_sum: ScalarField = two_vec.iter().sum();
+
+        // delta(y, z) = (z - z^2) * <1, y^(mn)> - sum_j z^(2 + j) * <1, 2^n>
+        let delta_y_z: ScalarField = (z - (z * z)) * y_vec.iter().sum::<ScalarField>()
+            - z_exp_vec
+                .iter()
+                .map(|z_j: &ScalarField| z * z_j * two_sum)
+                .sum::<ScalarField>();
+
+        // t_hat * g + tau_x * h = sum_j z^(1 + j) * V_j + delta(y, z) * g + x * T1 + x^2 * T2
+        let mut points: Vec<G1Point> = commitments.clone();
+        let mut scalars: Vec<ScalarField> = z_exp_vec.clone();
+        points.extend([*self.g, *self.h, *proof.get_t_1(), *proof.get_t_2()]);
+        scalars.extend([delta_y_z - proof.get_t_hat(), -*proof.get_tau_x(), x, x * x]);
+        if !Utils::multiscalar_mul(&points, &scalars)?.is_zero() {
+            return Err(throw(ProofError::ProofValidationError));
+        }
+
+        // P = A + x * S - z * <1, g_vec> + <z * y^(mn) + z_two_vec, h'_vec> - mu * h,
+        // with h'_i = y^-i * h_i, so that h_i gets z + y^-i * z_two_vec_i
+        let y_inv_vec: Vec<ScalarField> = Utils::batch_inverse(&y_vec);
+        let h_first_vec: Vec<G1Point> = h_vec
+            .iter()
+            .zip(y_inv_vec.iter())
+            .map(|(h, y_inv): (&G1Point, &ScalarField)| h.mul(y_inv.into_repr()).into_affine())
+            .collect();
+        let h_scalars: Vec<ScalarField> = (0..total_bits)
+            .map(|i: usize| z + y_inv_vec[i] * z_exp_vec[i / n] * two_vec[i % n])
+            .collect();
+
+        let mut points: Vec<G1Point> = vec![*proof.get_a(), *proof.get_s(), *self.h];
+        let mut scalars: Vec<ScalarField> = vec![ScalarField::one(), x, -*proof.get_mu()];
+        points.extend(g_vec.iter());
+        scalars.extend(vec![-z; total_bits]);
+        points.extend(h_vec.iter());
+        scalars.extend(h_scalars);
+        let p: G1Point = Utils::multiscalar_mul(&points, &scalars)?;
+
+        InnerVerifier::new(&g_vec, &h_first_vec, &p, proof.get_t_hat(), self.u)
+            .verify_proof_multiscalar(proof.get_inner_proof(), transcript)
+    }
+
+    /// Appends n, m, the generators g, h, u and the m * n used of `g_vec` and `h_vec`, and the
+    /// commitments.
+    pub(crate) fn append_statement<T: TranscriptProtocol>(
+        &self,
+        commitments: &Vec<G1Point>,
+        g_vec: &Vec<G1Point>,
+        h_vec: &Vec<G1Point>,
+        transcript: &mut T,
+    ) {
+        let _result = transcript.append_scalar(b"n", &ScalarField::from(self.n as u64));
+        let _result = transcript.append_scalar(b"m", &ScalarField::from(commitments.len() as u64));
+        let _result = transcript.append_point(b"g", self.g);
+        let _result = transcript.append_point(b"h", self.h);
+        let _result = transcript.append_point(b"u", self.u);
+        for (g_i, h_i) in g_vec.iter().zip(h_vec.iter()) {
+            let _result = transcript.append_point(b"g_i", g_i);
+            let _result = transcript.append_point(b"h_i", h_i);
+        }
+        for v in commitments.iter() {
+            let _result = transcript.append_point(b"V", v);
+        }
+    }
+}
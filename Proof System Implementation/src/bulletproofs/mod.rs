@@ -0,0 +1,3 @@
+pub(crate) mod bulletproofs_proof;
+pub(crate) mod bulletproofs_prover;
+pub(crate) mod bulletproofs_verifier;
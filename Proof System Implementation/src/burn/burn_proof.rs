@@ -0,0 +1,43 @@
+use ark_serialize::*;
+
+use crate::{InnerProof, RangeProof, SigmaABProof, SigmaSKProof};
+
+#[derive(Debug, CanonicalDeserialize, CanonicalSerialize)]
+pub struct BurnProof {
+    range_proof: RangeProof,
+    inner_proof: InnerProof,
+    sigma_ab_proof: SigmaABProof,
+    sigma_sk_proof: SigmaSKProof,
+}
+
+impl BurnProof {
+    pub fn new(
+        range_proof: RangeProof,
+        inner_proof: InnerProof,
+        sigma_ab_proof: SigmaABProof,
+        sigma_sk_proof: SigmaSKProof,
+    ) -> Self {
+        BurnProof {
+            range_proof,
+            inner_proof,
+            sigma_ab_proof,
+            sigma_sk_proof,
+        }
+    }
+
+    pub fn get_range_proof(&self) -> &RangeProof {
+        &self.range_proof
+    }
+
+    pub fn get_inner_proof(&self) -> &InnerProof {
+        &self.inner_proof
+    }
+
+    pub fn get_sigma_ab_proof(&self) -> &SigmaABProof {
+        &self.sigma_ab_proof
+    }
+
+    pub fn get_sigma_sk_proof(&self) -> &SigmaSKProof {
+        &self.sigma_sk_proof
+    }
+}
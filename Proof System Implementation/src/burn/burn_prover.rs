@@ -0,0 +1,165 @@
+use crate::burn::burn_proof::BurnProof;
+use crate::errors::proof_error::throw;
+use crate::transcript::TranscriptProtocol;
+use crate::{
+    AssociatedData, BurnVerifier, InnerProof, InnerProver, LinkedRangeProof, ProofError,
+    RangeProof, RangeProver, SigmaABProof, SigmaABProver, SigmaSKProof, SigmaSKProver,
+};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_std::rand::Rng;
+use std::io::Error;
+
+/// Proves the withdrawal of the public amount a from the balance (C_L, C_R): the remaining
+/// balance b - a, encrypted in (C_L - a * g, C_R), is in [0, 2^n - 1]. The range proof on the
+/// single remaining balance is linked to the ciphertext by the Sigma-protocol `ab` on the same
+/// challenges.
+pub struct BurnProver<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    g_vec: &'a Vec<G1Point>,
+    h_vec: &'a Vec<G1Point>,
+    u: &'a G1Point,
+    n: usize,
+    c_r: &'a G1Point,
+    balance: usize,
+    amount: usize,
+    sk: &'a ScalarField,
+    associated_data: Option<&'a AssociatedData>,
+}
+
+impl<'a> BurnProver<'a> {
+    /// `g_vec` and `h_vec` must hold exactly n generators.
+    pub fn new(
+        g: &'a G1Point,
+        h: &'a G1Point,
+        g_vec: &'a Vec<G1Point>,
+        h_vec: &'a Vec<G1Point>,
+        u: &'a G1Point,
+        n: usize,
+        c_r: &'a G1Point,
+        balance: usize,
+        amount: usize,
+        sk: &'a ScalarField,
+    ) -> Self {
+        BurnProver {
+            g,
+            h,
+            g_vec,
+            h_vec,
+            u,
+            n,
+            c_r,
+            balance,
+            amount,
+            sk,
+            associated_data: None,
+        }
+    }
+
+    /// Binds the proof to the transaction context, e.g. the chain id and the epoch: the proof
+    /// only verifies with the same associated data.
+    pub fn with_associated_data(mut self, associated_data: &'a AssociatedData) -> Self {
+        self.associated_data = Some(associated_data);
+        self
+    }
+
+    pub fn generate_proof<R: Rng, T: TranscriptProtocol>(
+        &mut self,
+        rng: &mut R,
+        transcript: &mut T,
+    ) -> Result<BurnProof, Error> {
+        let remaining_balance: usize = self
+            .balance
+            .checked_sub(self.amount)
+            .ok_or_else(|| throw(ProofError::OutOfBoundsError))?;
+        if self.g_vec.len() != self.n || self.h_vec.len() != self.n {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+
+        transcript.domain_sep(b"Burn");
+        self.append_statement(transcript);
+
+        let no_amounts: Vec<usize> = Vec::new();
+        let mut range_prover: RangeProver = RangeProver::new(
+            self.g,
+            self.h,
+            remaining_balance,
+            &no_amounts,
+            self.g_vec,
+            self.h_vec,
+            self.n,
+        );
+        let linked_proof: LinkedRangeProof<SigmaABProof> = range_prover.generate_linked_proof(
+            rng,
+            transcript,
+            &mut SigmaABProver::new_single(self.g, self.c_r, remaining_balance, self.sk),
+        );
+
+        let range_proof: &RangeProof = linked_proof.get_range_proof();
+        let (h_first_vec, phu): (Vec<G1Point>, G1Point) = range_prover.get_ipa_arguments(
+            linked_proof.get_x(),
+            linked_proof.get_y(),
+            linked_proof.get_z(),
+            range_proof.get_mu(),
+            range_proof.get_a(),
+            range_proof.get_s(),
+            self.h,
+            self.g_vec,
+            self.h_vec,
+        );
+
+        let inner_proof: InnerProof = InnerProver::new(
+            self.g_vec,
+            &h_first_vec,
+            &phu,
+            range_proof.get_t_hat(),
+            linked_proof.get_l_poly_vec(),
+            linked_proof.get_r_poly_vec(),
+            self.u,
+        )
+        .generate_proof(transcript)?;
+        let (range_proof, sigma_ab_proof): (RangeProof, SigmaABProof) = linked_proof.into_proofs();
+
+        let sigma_sk_proof: SigmaSKProof =
+            SigmaSKProver::new(self.g, self.sk).generate_proof(rng, transcript);
+
+        Ok(BurnProof::new(
+            range_proof,
+            inner_proof,
+            sigma_ab_proof,
+            sigma_sk_proof,
+        ))
+    }
+
+    /// Appends the statement of `BurnVerifier`, with y = sk * g and the balance encrypted
+    /// from the witness: C_L = b * g + sk * C_R.
+    fn append_statement<T: TranscriptProtocol>(&self, transcript: &mut T) {
+        let y: G1Point = self.g.mul(self.sk.into_repr()).into_affine();
+        let c_l: G1Point = self
+            .g
+            .mul(ScalarField::from(self.balance as u64).into_repr())
+            .into_affine()
+            + self.c_r.mul(self.sk.into_repr()).into_affine();
+
+        let burn_verifier: BurnVerifier = BurnVerifier::new(
+            self.g,
+            self.h,
+            self.g_vec,
+            self.h_vec,
+            self.u,
+            self.n,
+            &y,
+            &c_l,
+            self.c_r,
+            self.amount,
+        );
+        match self.associated_data {
+            Some(associated_data) => burn_verifier
+                .with_associated_data(associated_data)
+                .append_statement(transcript),
+            None => burn_verifier.append_statement(transcript),
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use crate::burn::burn_proof::BurnProof;
+use crate::errors::proof_error::throw;
+use crate::transcript::TranscriptProtocol;
+use crate::{
+    AssociatedData, InnerVerifier, ProofError, RangeVerifier, SigmaABVerifier, SigmaSKVerifier,
+};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use std::io::Error;
+
+pub struct BurnVerifier<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    g_vec: &'a Vec<G1Point>,
+    h_vec: &'a Vec<G1Point>,
+    u: &'a G1Point,
+    n: usize,
+    y: &'a G1Point,
+    c_l: &'a G1Point,
+    c_r: &'a G1Point,
+    amount: usize,
+    associated_data: Option<&'a AssociatedData>,
+}
+
+impl<'a> BurnVerifier<'a> {
+    pub fn new(
+        g: &'a G1Point,
+        h: &'a G1Point,
+        g_vec: &'a Vec<G1Point>,
+        h_vec: &'a Vec<G1Point>,
+        u: &'a G1Point,
+        n: usize,
+        y: &'a G1Point,
+        c_l: &'a G1Point,
+        c_r: &'a G1Point,
+        amount: usize,
+    ) -> Self {
+        BurnVerifier {
+            g,
+            h,
+            g_vec,
+            h_vec,
+            u,
+            n,
+            y,
+            c_l,
+            c_r,
+            amount,
+            associated_data: None,
+        }
+    }
+
+    /// Checks the proof within the transaction context of the prover, see
+    /// `BurnProver::with_associated_data`.
+    pub fn with_associated_data(mut self, associated_data: &'a AssociatedData) -> Self {
+        self.associated_data = Some(associated_data);
+        self
+    }
+
+    pub fn verify_proof<T: TranscriptProtocol>(
+        &mut self,
+        proof: &BurnProof,
+        transcript: &mut T,
+    ) -> Result<(), Error> {
+        if self.g_vec.len() != self.n || self.h_vec.len() != self.n {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+        let amount: ScalarField = ScalarField::from(self.amount as u64);
+
+        transcript.domain_sep(b"Burn");
+        self.append_statement(transcript);
+
+        let c_l_remaining: G1Point =
+            (self.c_l.into_projective() - self.g.mul(amount.into_repr())).into_affine();
+        let mut range_verifier: RangeVerifier = RangeVerifier::new(self.g, self.h, 1, self.n);
+        let (range_result, x, y, z) = range_verifier.verify_linked_proof(
+            proof.get_range_proof(),
+            proof.get_sigma_ab_proof(),
+            &mut SigmaABVerifier::new_single(self.g, self.c_r, &c_l_remaining),
+            transcript,
+        );
+
+        let (h_first_vec, phu): (Vec<G1Point>, G1Point) = range_verifier.get_ipa_arguments(
+            &x,
+            &y,
+            &z,
+            proof.get_range_proof().get_mu(),
+            proof.get_range_proof().get_a(),
+            proof.get_range_proof().get_s(),
+            self.h,
+            self.g_vec,
+            self.h_vec,
+        );
+
+        let inner_result: Result<(), Error> = InnerVerifier::new(
+            self.g_vec,
+            &h_first_vec,
+            &phu,
+            proof.get_range_proof().get_t_hat(),
+            self.u,
+        )
+        .verify_proof_multiscalar(proof.get_inner_proof(), transcript);
+
+        let sigma_sk_result: Result<(), Error> = SigmaSKVerifier::new(self.g, self.y)
+            .verify_proof(proof.get_sigma_sk_proof(), transcript);
+
+        if range_result.is_ok() && inner_result.is_ok() && sigma_sk_result.is_ok() {
+            Ok(())
+        } else {
+            Err(throw(ProofError::ProofValidationError))
+        }
+    }
+
+    /// Appends the associated data, if any, the amount and the statement (y, C_L, C_R) with
+    /// the generators of the inner-product argument to the transcript.
+    pub(crate) fn append_statement<T: TranscriptProtocol>(&self, transcript: &mut T) {
+        if let Some(associated_data) = self.associated_data {
+            associated_data.append_to_transcript(transcript);
+        }
+        let _result = transcript.append_scalar(b"amount", &ScalarField::from(self.amount as u64));
+        let _result = transcript.append_point(b"y", self.y);
+        let _result = transcript.append_point(b"C_L", self.c_l);
+        let _result = transcript.append_point(b"C_R", self.c_r);
+        let _result = transcript.append_point(b"u", self.u);
+        for (g_i, h_i) in self.g_vec.iter().zip(self.h_vec.iter()) {
+            let _result = transcript.append_point(b"g_i", g_i);
+            let _result = transcript.append_point(b"h_i", h_i);
+        }
+    }
+}
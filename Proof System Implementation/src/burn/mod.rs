@@ -0,0 +1,3 @@
+pub(crate) mod burn_proof;
+pub(crate) mod burn_prover;
+pub(crate) mod burn_verifier;
@@ -1,5 +1,6 @@
 use csv::Writer;
-use std::fs::File;
+use std::fs::{create_dir_all, File};
+use std::path::Path;
 
 pub struct CsvUtils {
     csv_writer: Writer<File>,
@@ -7,6 +8,9 @@ pub struct CsvUtils {
 
 impl CsvUtils {
     pub fn new(file_path: String, labels: Vec<String>) -> Self {
+        if let Some(parent) = Path::new(&file_path).parent() {
+            create_dir_all(parent).unwrap();
+        }
         let mut csv_writer: Writer<File> = Writer::from_path(file_path).unwrap();
 
         let _result = csv_writer.write_record(&labels);
@@ -22,6 +22,20 @@ impl ElGamal {
         (c, d)
     }
 
+    pub fn elgamal_encrypt_scalar(
+        value: &ScalarField,
+        pub_key: &G1Point,
+        g: &G1Point,
+        r: &ScalarField,
+    ) -> (G1Point, G1Point) {
+        let c: G1Point =
+            g.mul(value.into_repr()).into_affine() + pub_key.mul(r.into_repr()).into_affine();
+
+        let d: G1Point = g.mul(r.into_repr()).into_affine();
+
+        (c, d)
+    }
+
     pub fn elgamal_d(g: &G1Point, r: &ScalarField) -> G1Point {
         g.mul(r.into_repr()).into_affine()
     }
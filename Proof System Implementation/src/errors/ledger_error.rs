@@ -0,0 +1,23 @@
+use std::io::{Error, ErrorKind};
+
+#[derive(Debug)]
+pub enum LedgerError {
+    UnknownAccount,
+    DuplicateAccount,
+    InvalidTransfer,
+}
+
+pub fn throw(event: LedgerError) -> Error {
+    match event {
+        LedgerError::UnknownAccount => {
+            Error::new(ErrorKind::NotFound, "Failure: account not registered")
+        }
+        LedgerError::DuplicateAccount => Error::new(
+            ErrorKind::AlreadyExists,
+            "Failure: account already registered",
+        ),
+        LedgerError::InvalidTransfer => {
+            Error::new(ErrorKind::InvalidInput, "Failure: invalid transfer")
+        }
+    }
+}
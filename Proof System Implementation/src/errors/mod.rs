@@ -1,3 +1,4 @@
+pub(crate) mod ledger_error;
 pub(crate) mod proof_error;
 pub(crate) mod transcript_error;
 pub(crate) mod utils_error;
@@ -3,6 +3,8 @@ use std::io::{Error, ErrorKind};
 #[derive(Debug)]
 pub enum ProofError {
     ProofValidationError,
+    VectorLengthError,
+    OutOfBoundsError,
 }
 
 pub fn throw(event: ProofError) -> Error {
@@ -10,5 +12,11 @@ pub fn throw(event: ProofError) -> Error {
         ProofError::ProofValidationError => {
             Error::new(ErrorKind::Other, "Failure: proof validation error")
         }
+        ProofError::VectorLengthError => {
+            Error::new(ErrorKind::InvalidInput, "Failure: vector length error")
+        }
+        ProofError::OutOfBoundsError => {
+            Error::new(ErrorKind::InvalidInput, "Failure: value out of bounds")
+        }
     }
 }
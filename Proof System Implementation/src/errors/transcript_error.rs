@@ -4,6 +4,7 @@ use std::io::{Error, ErrorKind};
 pub enum TranscriptError {
     PointValidationError,
     PointSerializationError,
+    ChannelError,
 }
 
 pub fn throw(event: TranscriptError) -> Error {
@@ -14,5 +15,8 @@ pub fn throw(event: TranscriptError) -> Error {
         TranscriptError::PointSerializationError => {
             Error::new(ErrorKind::Other, "Failure: G1 point serialization error")
         }
+        TranscriptError::ChannelError => {
+            Error::new(ErrorKind::Other, "Failure: interactive channel error")
+        }
     }
 }
@@ -0,0 +1,131 @@
+use crate::errors::proof_error::throw;
+use crate::transcript::InjectedTranscript;
+use crate::{
+    ProofError, SigmaABProof, SigmaABProver, SigmaABVerifier, SigmaRProof, SigmaRProver,
+    SigmaRVerifier, SigmaSKProof, SigmaSKProver, SigmaSKVerifier,
+};
+use ark_bn254::Fr as ScalarField;
+use ark_ff::Field;
+use ark_std::rand::{rngs::StdRng, SeedableRng};
+use std::io::Error;
+
+/// Rewinding knowledge extractor for the Sigma protocols. The prover is run twice on the same
+/// random coins, so that it sends the same commitment, and it is answered with two different
+/// challenges. The witness is then recovered from the two accepting responses.
+pub struct Extractor;
+
+impl Extractor {
+    /// Extracts sk such that y = sk * g.
+    pub fn extract_sigma_sk(
+        prover: &mut SigmaSKProver,
+        verifier: &mut SigmaSKVerifier,
+        seed: u64,
+        c_first: &ScalarField,
+        c_second: &ScalarField,
+    ) -> Result<ScalarField, Error> {
+        let (first, second): (SigmaSKProof, SigmaSKProof) =
+            Self::rewind(seed, &[*c_first], &[*c_second], |rng, transcript| {
+                prover.generate_proof(rng, transcript)
+            });
+
+        if first.get_a_y() != second.get_a_y()
+            || verifier.verify_with_challenge(&first, c_first).is_err()
+            || verifier.verify_with_challenge(&second, c_second).is_err()
+        {
+            return Err(throw(ProofError::ProofValidationError));
+        }
+
+        Self::special_soundness(first.get_s_sk(), second.get_s_sk(), c_first, c_second)
+    }
+
+    /// Extracts r such that D = r * g.
+    pub fn extract_sigma_r(
+        prover: &mut SigmaRProver,
+        verifier: &mut SigmaRVerifier,
+        seed: u64,
+        c_first: &ScalarField,
+        c_second: &ScalarField,
+    ) -> Result<ScalarField, Error> {
+        let (first, second): (SigmaRProof, SigmaRProof) =
+            Self::rewind(seed, &[*c_first], &[*c_second], |rng, transcript| {
+                prover.generate_proof(rng, transcript)
+            });
+
+        if first.get_a_d() != second.get_a_d()
+            || verifier.verify_with_challenge(&first, c_first).is_err()
+            || verifier.verify_with_challenge(&second, c_second).is_err()
+        {
+            return Err(throw(ProofError::ProofValidationError));
+        }
+
+        Self::special_soundness(first.get_s_r(), second.get_s_r(), c_first, c_second)
+    }
+
+    /// Extracts (sk, b' * z^2 + sum_i a_i * z^(2+i)) for the challenge `z`.
+    pub fn extract_sigma_ab(
+        prover: &mut SigmaABProver,
+        verifier: &mut SigmaABVerifier,
+        seed: u64,
+        z: &ScalarField,
+        c_first: &ScalarField,
+        c_second: &ScalarField,
+    ) -> Result<(ScalarField, ScalarField), Error> {
+        let (first, second): (SigmaABProof, SigmaABProof) = Self::rewind(
+            seed,
+            &[*z, *c_first],
+            &[*z, *c_second],
+            |rng, transcript| prover.generate_proof(rng, transcript),
+        );
+
+        if first.get_a_ab() != second.get_a_ab()
+            || verifier.verify_with_challenges(&first, z, c_first).is_err()
+            || verifier
+                .verify_with_challenges(&second, z, c_second)
+                .is_err()
+        {
+            return Err(throw(ProofError::ProofValidationError));
+        }
+
+        let sk: ScalarField =
+            Self::special_soundness(first.get_s_sk(), second.get_s_sk(), c_first, c_second)?;
+        let ab: ScalarField =
+            Self::special_soundness(first.get_s_ab(), second.get_s_ab(), c_first, c_second)?;
+
+        Ok((sk, ab))
+    }
+
+    fn rewind<P, F>(
+        seed: u64,
+        first_challenges: &[ScalarField],
+        second_challenges: &[ScalarField],
+        mut run: F,
+    ) -> (P, P)
+    where
+        F: FnMut(&mut StdRng, &mut InjectedTranscript) -> P,
+    {
+        let mut first_rng: StdRng = StdRng::seed_from_u64(seed);
+        let mut first_trans: InjectedTranscript =
+            InjectedTranscript::new(b"Extractor", &first_challenges.to_vec());
+        let first: P = run(&mut first_rng, &mut first_trans);
+
+        let mut second_rng: StdRng = StdRng::seed_from_u64(seed);
+        let mut second_trans: InjectedTranscript =
+            InjectedTranscript::new(b"Extractor", &second_challenges.to_vec());
+        let second: P = run(&mut second_rng, &mut second_trans);
+
+        (first, second)
+    }
+
+    /// w = (s - s') / (c - c'), from s = w * c + k and s' = w * c' + k.
+    fn special_soundness(
+        s_first: &ScalarField,
+        s_second: &ScalarField,
+        c_first: &ScalarField,
+        c_second: &ScalarField,
+    ) -> Result<ScalarField, Error> {
+        match (*c_first - c_second).inverse() {
+            Some(c_diff_inv) => Ok((*s_first - s_second) * c_diff_inv),
+            None => Err(throw(ProofError::ProofValidationError)),
+        }
+    }
+}
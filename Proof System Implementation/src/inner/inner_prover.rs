@@ -1,10 +1,12 @@
+use crate::errors::proof_error::throw;
+use crate::inner_product_argument::{InnerProductArgument, IpaStatement, IpaWitness};
 use crate::transcript::TranscriptProtocol;
 use crate::utils::Utils;
-use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use crate::{InnerVerifier, ProofError};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point, G1Projective};
 use ark_ec::{AffineCurve, ProjectiveCurve};
 use ark_ff::{Field, PrimeField};
-
-use merlin::Transcript;
+use std::io::Error;
 
 use super::inner_proof::InnerProof;
 
@@ -39,101 +41,144 @@ impl<'a> InnerProver<'a> {
         }
     }
 
-    pub fn generate_proof(&mut self, transcript: &mut Transcript) -> InnerProof {
+    pub fn generate_proof<T: TranscriptProtocol>(
+        &mut self,
+        transcript: &mut T,
+    ) -> Result<InnerProof, Error> {
+        let n: usize = self.g_vec.len();
+        if n == 0 || self.h_vec.len() != n || self.a_vec.len() != n || self.b_vec.len() != n {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+
         transcript.domain_sep(b"InnerProductArgument");
+        InnerVerifier::new(self.g_vec, self.h_vec, self.p, self.c, self.u)
+            .append_statement(transcript);
         let x: ScalarField = transcript.challenge_scalar(b"x");
         let ux: G1Point = self.u.mul((x).into_repr()).into_affine();
-        let p_first: G1Point = *self.p + ux.mul((self.c).into_repr()).into_affine();
 
-        self.inner_product_argument(
-            self.g_vec, self.h_vec, &ux, &p_first, self.a_vec, self.b_vec, transcript,
-        )
+        // Vectors are padded to the next power of two with zeros and with the NUMS generators
+        // the verifier derives as well, so that the padded entries are bound to zero
+        let padded_n: usize = n.next_power_of_two();
+        let g_vec: Vec<G1Point> = Utils::pad_generators(self.g_vec, b"g_vec", padded_n);
+        let h_vec: Vec<G1Point> = Utils::pad_generators(self.h_vec, b"h_vec", padded_n);
+        let a_vec: Vec<ScalarField> = Utils::pad_scalars(self.a_vec, padded_n);
+        let b_vec: Vec<ScalarField> = Utils::pad_scalars(self.b_vec, padded_n);
+
+        Ok(self.inner_product_argument(&g_vec, &h_vec, &ux, &a_vec, &b_vec, transcript))
     }
 
-    fn inner_product_argument(
+    /// Iterative argument folding the vectors in place: the first half of each buffer holds the
+    /// folded vectors after every round, so that the memory stays linear in n.
+    fn inner_product_argument<T: TranscriptProtocol>(
         &mut self,
         g_vec: &Vec<G1Point>,
         h_vec: &Vec<G1Point>,
         u: &G1Point,
-        p: &G1Point,
         a_vec: &Vec<ScalarField>,
         b_vec: &Vec<ScalarField>,
-        transcript: &mut Transcript,
+        transcript: &mut T,
     ) -> InnerProof {
-        let n: usize = g_vec.len();
-        if n == 1 {
-            let a: ScalarField = a_vec[0];
-            let b: ScalarField = b_vec[0];
-            let _result = transcript.append_scalar(b"a", &a);
-            let _result = transcript.append_scalar(b"b", &b);
-            InnerProof::new(a, b, [].to_vec(), [].to_vec())
-        } else {
-            let n_first = n / 2;
-
-            let a_left: Vec<ScalarField> = a_vec[..n_first].to_vec();
-            let a_right: Vec<ScalarField> = a_vec[n_first..].to_vec();
-            let b_left: Vec<ScalarField> = b_vec[..n_first].to_vec();
-            let b_right: Vec<ScalarField> = b_vec[n_first..].to_vec();
-
-            let g_left: Vec<G1Point> = g_vec[..n_first].to_vec();
-            let g_right: Vec<G1Point> = g_vec[n_first..].to_vec();
-            let h_left: Vec<G1Point> = h_vec[..n_first].to_vec();
-            let h_right: Vec<G1Point> = h_vec[n_first..].to_vec();
-
-            let c_l: ScalarField = Utils::inner_product_scalar_scalar(&a_left, &b_right).unwrap();
-            let c_r: ScalarField = Utils::inner_product_scalar_scalar(&a_right, &b_left).unwrap();
-
-            let l: G1Point =
-                Utils::pedersen_vector_commitment(&c_l, &u, &a_left, &g_right, &b_right, &h_left)
-                    .unwrap();
-
-            let r: G1Point =
-                Utils::pedersen_vector_commitment(&c_r, &u, &a_right, &g_left, &b_left, &h_right)
-                    .unwrap();
-
-            let mut l_vec: Vec<G1Point> = [l].to_vec();
-            let mut r_vec: Vec<G1Point> = [r].to_vec();
+        let mut n: usize = g_vec.len();
+        let rounds: usize = n.trailing_zeros() as usize;
+        let mut l_vec: Vec<G1Point> = Vec::with_capacity(rounds);
+        let mut r_vec: Vec<G1Point> = Vec::with_capacity(rounds);
+
+        let mut a_buf: Vec<ScalarField> = a_vec.clone();
+        let mut b_buf: Vec<ScalarField> = b_vec.clone();
+        let mut g_buf: Vec<G1Projective> = g_vec
+            .iter()
+            .map(|g: &G1Point| g.into_projective())
+            .collect();
+        let mut h_buf: Vec<G1Projective> = h_vec
+            .iter()
+            .map(|h: &G1Point| h.into_projective())
+            .collect();
+        let u: G1Projective = u.into_projective();
+
+        while n > 1 {
+            n /= 2;
+
+            let (a_left, a_right) = a_buf[..2 * n].split_at_mut(n);
+            let (b_left, b_right) = b_buf[..2 * n].split_at_mut(n);
+            let (g_left, g_right) = g_buf[..2 * n].split_at_mut(n);
+            let (h_left, h_right) = h_buf[..2 * n].split_at_mut(n);
+
+            let c_l: ScalarField = a_left.iter().zip(b_right.iter()).map(|(a, b)| *a * b).sum();
+            let c_r: ScalarField = a_right.iter().zip(b_left.iter()).map(|(a, b)| *a * b).sum();
+
+            // l = <a_left, g_right> + <b_right, h_left> + c_l * u
+            let l: G1Point = (Utils::inner_product_projective_scalar(g_right, a_left)
+                + Utils::inner_product_projective_scalar(h_left, b_right)
+                + u.mul(c_l.into_repr()))
+            .into_affine();
+
+            // r = <a_right, g_left> + <b_left, h_right> + c_r * u
+            let r: G1Point = (Utils::inner_product_projective_scalar(g_left, a_right)
+                + Utils::inner_product_projective_scalar(h_right, b_left)
+                + u.mul(c_r.into_repr()))
+            .into_affine();
+
+            l_vec.push(l);
+            r_vec.push(r);
 
             let _result = transcript.append_point(b"l", &l);
             let _result = transcript.append_point(b"r", &r);
             let x: ScalarField = transcript.challenge_scalar(b"x");
-
-            let g_first_left: Vec<G1Point> =
-                Utils::product_scalar_point(&x.inverse().unwrap(), &g_left);
-            let g_first_right: Vec<G1Point> = Utils::product_scalar_point(&x, &g_right);
-            let g_first: Vec<G1Point> =
-                Utils::sum_point_point(&g_first_left, &g_first_right).unwrap();
-
-            let h_first_left: Vec<G1Point> = Utils::product_scalar_point(&x, &h_left);
-            let h_first_right: Vec<G1Point> =
-                Utils::product_scalar_point(&x.inverse().unwrap(), &h_right);
-            let h_first: Vec<G1Point> =
-                Utils::sum_point_point(&h_first_left, &h_first_right).unwrap();
-
-            let p_first: G1Point = l.mul(x.pow([2]).into_repr()).into_affine()
-                + *p
-                + r.mul(x.pow([2]).inverse().unwrap().into_repr())
-                    .into_affine();
-
-            let a_first_left: Vec<ScalarField> = Utils::product_scalar(&x, &a_left);
-            let a_first_right: Vec<ScalarField> =
-                Utils::product_scalar(&x.inverse().unwrap(), &a_right);
-            let a_first: Vec<ScalarField> =
-                Utils::sum_scalar_scalar(&a_first_left, &a_first_right).unwrap();
-
-            let b_first_left: Vec<ScalarField> =
-                Utils::product_scalar(&x.inverse().unwrap(), &b_left);
-            let b_first_right: Vec<ScalarField> = Utils::product_scalar(&x, &b_right);
-            let b_first: Vec<ScalarField> =
-                Utils::sum_scalar_scalar(&b_first_left, &b_first_right).unwrap();
-
-            let rec_proof: InnerProof = self.inner_product_argument(
-                &g_first, &h_first, u, &p_first, &a_first, &b_first, transcript,
-            );
-            l_vec.append(&mut rec_proof.get_l_vec().clone());
-            r_vec.append(&mut rec_proof.get_r_vec().clone());
-
-            InnerProof::new(*rec_proof.get_a(), *rec_proof.get_b(), l_vec, r_vec)
+            let x_inv: ScalarField = x.inverse().unwrap();
+
+            for i in 0..n {
+                a_left[i] = a_left[i] * x + a_right[i] * x_inv;
+                b_left[i] = b_left[i] * x_inv + b_right[i] * x;
+                g_left[i] = g_left[i].mul(x_inv.into_repr()) + g_right[i].mul(x.into_repr());
+                h_left[i] = h_left[i].mul(x.into_repr()) + h_right[i].mul(x_inv.into_repr());
+            }
         }
+
+        let a: ScalarField = a_buf[0];
+        let b: ScalarField = b_buf[0];
+        let _result = transcript.append_scalar(b"a", &a);
+        let _result = transcript.append_scalar(b"b", &b);
+
+        InnerProof::new(a, b, l_vec, r_vec)
+    }
+}
+
+impl<'a> InnerProductArgument for InnerProver<'a> {
+    const ID: u8 = 0;
+
+    type Statement = IpaStatement;
+    type Witness = IpaWitness;
+    type Proof = InnerProof;
+
+    fn prove<T: TranscriptProtocol>(
+        statement: &IpaStatement,
+        witness: &IpaWitness,
+        transcript: &mut T,
+    ) -> Result<InnerProof, Error> {
+        InnerProver::new(
+            statement.get_g_vec(),
+            statement.get_h_vec(),
+            statement.get_p(),
+            statement.get_c(),
+            witness.get_a_vec(),
+            witness.get_b_vec(),
+            statement.get_u(),
+        )
+        .generate_proof(transcript)
+    }
+
+    fn verify<T: TranscriptProtocol>(
+        statement: &IpaStatement,
+        proof: &InnerProof,
+        transcript: &mut T,
+    ) -> Result<(), Error> {
+        InnerVerifier::new(
+            statement.get_g_vec(),
+            statement.get_h_vec(),
+            statement.get_p(),
+            statement.get_c(),
+            statement.get_u(),
+        )
+        .verify_proof_multiscalar(proof, transcript)
     }
 }
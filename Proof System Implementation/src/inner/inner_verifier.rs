@@ -1,10 +1,9 @@
 use crate::errors::proof_error::throw;
 use crate::ProofError;
 use crate::{transcript::TranscriptProtocol, Utils};
-use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point, G1Projective};
 use ark_ec::{AffineCurve, ProjectiveCurve};
-use ark_ff::{Field, PrimeField};
-use merlin::Transcript;
+use ark_ff::{Field, One, PrimeField, Zero};
 use std::io::Error;
 
 use super::inner_proof::InnerProof;
@@ -34,213 +33,188 @@ impl<'a> InnerVerifier<'a> {
         }
     }
 
-    pub fn verify_proof(
+    pub fn verify_proof<T: TranscriptProtocol>(
         &mut self,
         proof: &InnerProof,
-        transcript: &mut Transcript,
+        transcript: &mut T,
     ) -> Result<(), Error> {
+        let (g_vec, h_vec): (Vec<G1Point>, Vec<G1Point>) = self.padded_generators(proof)?;
+
         transcript.domain_sep(b"InnerProductArgument");
+        self.append_statement(transcript);
         let x: ScalarField = transcript.challenge_scalar(b"x");
         let ux: G1Point = self.u.mul((x).into_repr()).into_affine();
         let p_first: G1Point = *self.p + ux.mul((self.c).into_repr()).into_affine();
 
-        self.inner_product_argument(self.g_vec, self.h_vec, &ux, &p_first, proof, transcript)
+        let mut x_vec: Vec<ScalarField> = Vec::with_capacity(proof.get_l_vec().len());
+        for (l, r) in proof.get_l_vec().iter().zip(proof.get_r_vec().iter()) {
+            let _result = transcript.append_point(b"l", l);
+            let _result = transcript.append_point(b"r", r);
+            x_vec.push(transcript.challenge_scalar(b"x"));
+        }
+
+        let _result = transcript.append_scalar(b"a", proof.get_a());
+        let _result = transcript.append_scalar(b"b", proof.get_b());
+
+        self.inner_product_argument(&g_vec, &h_vec, &ux, &p_first, &x_vec, proof)
     }
 
-    pub fn verify_proof_multiscalar(
+    pub fn verify_proof_multiscalar<T: TranscriptProtocol>(
         &mut self,
         proof: &InnerProof,
-        transcript: &mut Transcript,
+        transcript: &mut T,
     ) -> Result<(), Error> {
+        let (g_vec, h_vec): (Vec<G1Point>, Vec<G1Point>) = self.padded_generators(proof)?;
+
         transcript.domain_sep(b"InnerProductArgument");
+        self.append_statement(transcript);
         let x: ScalarField = transcript.challenge_scalar(b"x");
         let ux: G1Point = self.u.mul((x).into_repr()).into_affine();
         let p_first: G1Point = *self.p + ux.mul((self.c).into_repr()).into_affine();
 
-        self.inner_product_argument_multiscalar(
-            self.g_vec,
-            self.h_vec,
-            &ux,
-            &p_first,
-            proof,
-            &mut [].to_vec(),
-            self.g_vec.len(),
-            transcript,
-        )
+        self.inner_product_argument_multiscalar(&g_vec, &h_vec, &ux, &p_first, proof, transcript)
     }
 
-    fn bit_function(&mut self, i: usize, j: usize) -> bool {
-        let bits: Vec<u8> = Utils::number_to_be_bits_reversed(i, usize::BITS as usize);
-
-        if bits[j] == 1 {
-            true
-        } else {
-            false
+    /// Appends n, the generators, u and the statement P, c of the argument. The lengths of
+    /// `g_vec` and `h_vec` are checked beforehand by `padded_generators`.
+    pub(crate) fn append_statement<T: TranscriptProtocol>(&self, transcript: &mut T) {
+        let _result = transcript.append_scalar(b"n", &ScalarField::from(self.g_vec.len() as u64));
+        for (g_i, h_i) in self.g_vec.iter().zip(self.h_vec.iter()) {
+            let _result = transcript.append_point(b"g_i", g_i);
+            let _result = transcript.append_point(b"h_i", h_i);
         }
+        let _result = transcript.append_point(b"u", self.u);
+        let _result = transcript.append_point(b"P", self.p);
+        let _result = transcript.append_scalar(b"c", self.c);
     }
 
-    fn get_s_vector(&mut self, x_vec: &mut Vec<ScalarField>, n: usize) -> Vec<ScalarField> {
-        (0..n)
-            .map(|i: usize| {
-                (0..x_vec.len())
-                    .map(|j| {
-                        if self.bit_function(i, j as usize) {
-                            x_vec[j as usize]
-                        } else {
-                            x_vec[j as usize].inverse().unwrap()
-                        }
-                    })
-                    .reduce(|accum: ScalarField, item: ScalarField| accum * item)
-                    .unwrap()
-            })
-            .collect()
+    /// Generators padded to the next power of two with the NUMS points used by the prover,
+    /// after checking that the statement and the proof agree on the number of rounds.
+    fn padded_generators(&self, proof: &InnerProof) -> Result<(Vec<G1Point>, Vec<G1Point>), Error> {
+        let n: usize = self.g_vec.len();
+        let padded_n: usize = n.next_power_of_two();
+        let rounds: usize = padded_n.trailing_zeros() as usize;
+        if n == 0
+            || self.h_vec.len() != n
+            || proof.get_l_vec().len() != rounds
+            || proof.get_r_vec().len() != rounds
+        {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+
+        Ok((
+            Utils::pad_generators(self.g_vec, b"g_vec", padded_n),
+            Utils::pad_generators(self.h_vec, b"h_vec", padded_n),
+        ))
     }
 
-    fn inner_product_argument_multiscalar(
+    /// Single MSM check of
+    /// a * <s, g_vec> + b * <s^-1, h_vec> + (a * b) * u - sum_j (x_j^2 * L_j + x_j^-2 * R_j) - P = 0
+    fn inner_product_argument_multiscalar<T: TranscriptProtocol>(
         &mut self,
         g_vec: &Vec<G1Point>,
         h_vec: &Vec<G1Point>,
         u: &G1Point,
         p: &G1Point,
         proof: &InnerProof,
-        x_vec: &mut Vec<ScalarField>,
-        n: usize,
-        transcript: &mut Transcript,
+        transcript: &mut T,
     ) -> Result<(), Error> {
-        if n == 1 {
-            let a: ScalarField = *proof.get_a();
-            let b: ScalarField = *proof.get_b();
-
-            let _result = transcript.append_scalar(b"a", &a);
-            let _result = transcript.append_scalar(b"b", &b);
-
-            let c: ScalarField = a * b;
-
-            let s: Vec<ScalarField> = self.get_s_vector(x_vec, g_vec.len());
-            let s_inverse: Vec<ScalarField> = s
-                .iter()
-                .map(|scal: &ScalarField| scal.inverse().unwrap())
-                .collect();
-
-            let g: G1Point = Utils::inner_product_point_scalar(&g_vec, &s).unwrap();
-            let h: G1Point = Utils::inner_product_point_scalar(&h_vec, &s_inverse).unwrap();
+        let n: usize = g_vec.len();
+        let rounds: usize = proof.get_l_vec().len();
 
-            let to_check: G1Point = g.mul(a.into_repr()).into_affine()
-                + h.mul(b.into_repr()).into_affine()
-                + u.mul(c.into_repr()).into_affine();
+        let mut x_vec: Vec<ScalarField> = Vec::with_capacity(rounds);
+        for (l, r) in proof.get_l_vec().iter().zip(proof.get_r_vec().iter()) {
+            let _result = transcript.append_point(b"l", l);
+            let _result = transcript.append_point(b"r", r);
+            x_vec.push(transcript.challenge_scalar(b"x"));
+        }
 
-            if *p == to_check {
-                return Ok(());
-            } else {
-                return Err(throw(ProofError::ProofValidationError));
-            }
+        let a: ScalarField = *proof.get_a();
+        let b: ScalarField = *proof.get_b();
+        let _result = transcript.append_scalar(b"a", &a);
+        let _result = transcript.append_scalar(b"b", &b);
+
+        // g is folded as x^-1 * g_lo + x * g_hi, h as x * h_lo + x^-1 * h_hi,
+        // so the scalars of h_vec are the ones of g_vec in reverse order
+        let x_inv_vec: Vec<ScalarField> = Utils::batch_inverse(&x_vec);
+        let x_sq_vec: Vec<ScalarField> = x_vec.iter().map(|x: &ScalarField| x.square()).collect();
+        let x_inv_sq_vec: Vec<ScalarField> =
+            x_inv_vec.iter().map(|x: &ScalarField| x.square()).collect();
+        let s: Vec<ScalarField> = Utils::get_s_vector(&x_inv_vec.iter().product(), &x_sq_vec, n)?;
+
+        let mut points: Vec<G1Point> = Vec::with_capacity(2 * n + 2 * rounds + 2);
+        let mut scalars: Vec<ScalarField> = Vec::with_capacity(2 * n + 2 * rounds + 2);
+
+        points.extend(g_vec.iter());
+        scalars.extend(s.iter().map(|s_i: &ScalarField| a * s_i));
+        points.extend(h_vec.iter());
+        scalars.extend(s.iter().rev().map(|s_i: &ScalarField| b * s_i));
+        points.push(*u);
+        scalars.push(a * b);
+        points.extend(proof.get_l_vec().iter());
+        scalars.extend(x_sq_vec.iter().map(|x: &ScalarField| -*x));
+        points.extend(proof.get_r_vec().iter());
+        scalars.extend(x_inv_sq_vec.iter().map(|x: &ScalarField| -*x));
+        points.push(*p);
+        scalars.push(-ScalarField::one());
+
+        if Utils::multiscalar_mul(&points, &scalars)?.is_zero() {
+            Ok(())
         } else {
-            let n_first = n / 2;
-
-            let l: G1Point = proof.get_l_vec()[0];
-            let r: G1Point = proof.get_r_vec()[0];
-            let _result = transcript.append_point(b"l", &l);
-            let _result = transcript.append_point(b"r", &r);
-            let x: ScalarField = transcript.challenge_scalar(b"x");
-
-            let mut x_vec_first: Vec<ScalarField> = [x].to_vec();
-            x_vec_first.append(x_vec);
-
-            let p_first: G1Point = l.mul(x.pow([2]).into_repr()).into_affine()
-                + *p
-                + r.mul(x.pow([2]).inverse().unwrap().into_repr())
-                    .into_affine();
-
-            let rec_proof: InnerProof = InnerProof::new(
-                *proof.get_a(),
-                *proof.get_b(),
-                proof.get_l_vec()[1..].to_vec(),
-                proof.get_r_vec()[1..].to_vec(),
-            );
-
-            self.inner_product_argument_multiscalar(
-                g_vec,
-                h_vec,
-                u,
-                &p_first,
-                &rec_proof,
-                &mut x_vec_first,
-                n_first,
-                transcript,
-            )
+            Err(throw(ProofError::ProofValidationError))
         }
     }
 
+    /// Folds the generators in place, round by round, and checks the final commitment.
     fn inner_product_argument(
         &mut self,
         g_vec: &Vec<G1Point>,
         h_vec: &Vec<G1Point>,
         u: &G1Point,
         p: &G1Point,
+        x_vec: &Vec<ScalarField>,
         proof: &InnerProof,
-        transcript: &mut Transcript,
     ) -> Result<(), Error> {
-        let n: usize = g_vec.len();
-        if n == 1 {
-            let a: ScalarField = *proof.get_a();
-            let b: ScalarField = *proof.get_b();
-
-            let _result = transcript.append_scalar(b"a", &a);
-            let _result = transcript.append_scalar(b"b", &b);
+        let mut n: usize = g_vec.len();
+        let mut g_buf: Vec<G1Projective> = g_vec
+            .iter()
+            .map(|g: &G1Point| g.into_projective())
+            .collect();
+        let mut h_buf: Vec<G1Projective> = h_vec
+            .iter()
+            .map(|h: &G1Point| h.into_projective())
+            .collect();
+        let mut p_first: G1Projective = p.into_projective();
+
+        let x_inv_vec: Vec<ScalarField> = Utils::batch_inverse(x_vec);
+
+        for j in 0..x_vec.len() {
+            n /= 2;
+            let x: ScalarField = x_vec[j];
+            let x_inv: ScalarField = x_inv_vec[j];
+
+            let (g_left, g_right) = g_buf[..2 * n].split_at_mut(n);
+            let (h_left, h_right) = h_buf[..2 * n].split_at_mut(n);
+            for i in 0..n {
+                g_left[i] = g_left[i].mul(x_inv.into_repr()) + g_right[i].mul(x.into_repr());
+                h_left[i] = h_left[i].mul(x.into_repr()) + h_right[i].mul(x_inv.into_repr());
+            }
 
-            let c: ScalarField = a * b;
+            p_first += proof.get_l_vec()[j].mul(x.square().into_repr())
+                + proof.get_r_vec()[j].mul(x_inv.square().into_repr());
+        }
 
-            let g: G1Point = g_vec[0];
-            let h: G1Point = h_vec[0];
+        let a: ScalarField = *proof.get_a();
+        let b: ScalarField = *proof.get_b();
+        let c: ScalarField = a * b;
 
-            let to_check: G1Point = g.mul(a.into_repr()).into_affine()
-                + h.mul(b.into_repr()).into_affine()
-                + u.mul(c.into_repr()).into_affine();
+        let to_check: G1Projective =
+            g_buf[0].mul(a.into_repr()) + h_buf[0].mul(b.into_repr()) + u.mul(c.into_repr());
 
-            if *p == to_check {
-                return Ok(());
-            } else {
-                return Err(throw(ProofError::ProofValidationError));
-            }
+        if p_first == to_check {
+            Ok(())
         } else {
-            let n_first = n / 2;
-
-            let g_left: Vec<G1Point> = g_vec[..n_first].to_vec();
-            let g_right: Vec<G1Point> = g_vec[n_first..].to_vec();
-            let h_left: Vec<G1Point> = h_vec[..n_first].to_vec();
-            let h_right: Vec<G1Point> = h_vec[n_first..].to_vec();
-
-            let l: G1Point = proof.get_l_vec()[0];
-            let r: G1Point = proof.get_r_vec()[0];
-            let _result = transcript.append_point(b"l", &l);
-            let _result = transcript.append_point(b"r", &r);
-            let x: ScalarField = transcript.challenge_scalar(b"x");
-
-            let g_first_left: Vec<G1Point> =
-                Utils::product_scalar_point(&x.inverse().unwrap(), &g_left);
-            let g_first_right: Vec<G1Point> = Utils::product_scalar_point(&x, &g_right);
-            let g_first: Vec<G1Point> =
-                Utils::sum_point_point(&g_first_left, &g_first_right).unwrap();
-
-            let h_first_left: Vec<G1Point> = Utils::product_scalar_point(&x, &h_left);
-            let h_first_right: Vec<G1Point> =
-                Utils::product_scalar_point(&x.inverse().unwrap(), &h_right);
-            let h_first: Vec<G1Point> =
-                Utils::sum_point_point(&h_first_left, &h_first_right).unwrap();
-
-            let p_first: G1Point = l.mul(x.pow([2]).into_repr()).into_affine()
-                + *p
-                + r.mul(x.pow([2]).inverse().unwrap().into_repr())
-                    .into_affine();
-
-            let rec_proof: InnerProof = InnerProof::new(
-                *proof.get_a(),
-                *proof.get_b(),
-                proof.get_l_vec()[1..].to_vec(),
-                proof.get_r_vec()[1..].to_vec(),
-            );
-
-            self.inner_product_argument(&g_first, &h_first, u, &p_first, &rec_proof, transcript)
+            Err(throw(ProofError::ProofValidationError))
         }
     }
 }
@@ -0,0 +1,92 @@
+use crate::errors::proof_error::throw;
+use crate::ProofError;
+use crate::{transcript::TranscriptProtocol, Utils};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{Field, One, PrimeField, Zero};
+use std::io::Error;
+
+/// Deferred check of the folded generators of one or more InnerHalo proofs. For each proof,
+/// G = <s, g_vec> with s_i = prod_j m_j^(+1 or -1) according to the bits of i.
+/// Accumulators are merged with random weights, so that a single MSM in `decide` checks
+/// sum_k w_k * G_k = <sum_k w_k * s_k, g_vec>.
+#[derive(Clone, Debug)]
+pub struct HaloAccumulator {
+    challenges: Vec<Vec<ScalarField>>, // round challenges of each deferred proof
+    weights: Vec<ScalarField>,         // weight of each proof in the linear combination
+    g: G1Point,                        // claimed sum_k w_k * G_k
+}
+
+impl HaloAccumulator {
+    pub fn new(challenges: Vec<ScalarField>, g: G1Point) -> Self {
+        HaloAccumulator {
+            challenges: vec![challenges],
+            weights: vec![ScalarField::one()],
+            g,
+        }
+    }
+
+    pub fn get_challenges(&self) -> &Vec<Vec<ScalarField>> {
+        &self.challenges
+    }
+
+    pub fn get_weights(&self) -> &Vec<ScalarField> {
+        &self.weights
+    }
+
+    pub fn get_g(&self) -> &G1Point {
+        &self.g
+    }
+
+    /// self = self + alpha * other, with alpha derived from both accumulators
+    pub fn merge<T: TranscriptProtocol>(&mut self, other: &HaloAccumulator, transcript: &mut T) {
+        transcript.domain_sep(b"HaloAccumulator");
+        for acc in [&*self, other] {
+            let _result = transcript.append_point(b"G", &acc.g);
+            for (challenges, weight) in acc.challenges.iter().zip(acc.weights.iter()) {
+                let _result = transcript.append_scalar(b"w", weight);
+                for m in challenges.iter() {
+                    let _result = transcript.append_scalar(b"m", m);
+                }
+            }
+        }
+        let alpha: ScalarField = transcript.challenge_scalar(b"alpha");
+
+        self.challenges.extend(other.challenges.iter().cloned());
+        self.weights
+            .extend(other.weights.iter().map(|w: &ScalarField| *w * alpha));
+        self.g = self.g + other.g.mul(alpha.into_repr()).into_affine();
+    }
+
+    /// Performs the deferred checks with one MSM over `g_vec`, padded as in the prover.
+    pub fn decide(&self, g_vec: &Vec<G1Point>) -> Result<(), Error> {
+        let n: usize = g_vec.len().next_power_of_two();
+        let rounds: usize = n.trailing_zeros() as usize;
+        let g_vec: Vec<G1Point> = Utils::pad_generators(g_vec, b"g_vec", n);
+
+        let mut s_vec: Vec<ScalarField> = vec![ScalarField::zero(); n];
+        for (challenges, weight) in self.challenges.iter().zip(self.weights.iter()) {
+            if challenges.len() != rounds {
+                return Err(throw(ProofError::ProofValidationError));
+            }
+
+            // G is folded as m^-1 * g_lo + m * g_hi, the first round on the most significant bit
+            let m_sq_vec: Vec<ScalarField> = challenges
+                .iter()
+                .map(|m: &ScalarField| m.square())
+                .collect();
+            let s_k: Vec<ScalarField> = Utils::get_s_vector(
+                &Utils::batch_inverse(challenges).iter().product(),
+                &m_sq_vec,
+                n,
+            )?;
+            s_vec = Utils::sum_scalar_scalar(&s_vec, &Utils::product_scalar(weight, &s_k))?;
+        }
+
+        if Utils::multiscalar_mul(&g_vec, &s_vec)? == self.g {
+            Ok(())
+        } else {
+            Err(throw(ProofError::ProofValidationError))
+        }
+    }
+}
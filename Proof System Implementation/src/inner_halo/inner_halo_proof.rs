@@ -2,18 +2,32 @@ use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
 use ark_serialize::*;
 
 #[derive(Debug, CanonicalDeserialize, CanonicalSerialize)]
-// TODO: MODIFYs
 pub struct InnerHaloProof {
-    l_vec: Vec<G1Point>,
-    r_vec: Vec<G1Point>,
-    r: G1Point,              // Schnorr commitment R
-    z_one: ScalarField,      // Schnorr
-    z_two: ScalarField       // Schnorr
+    l_vec: Vec<G1Point>, // one L per round, log2(d+1) rounds
+    r_vec: Vec<G1Point>, // one R per round
+    g: G1Point,          // folded generator G = <s, g_vec>
+    r: G1Point,          // Schnorr commitment R
+    z_one: ScalarField,  // Schnorr
+    z_two: ScalarField,  // Schnorr
 }
 
 impl InnerHaloProof {
-    pub fn new(l_vec: Vec<G1Point>, r_vec: Vec<G1Point>, r: G1Point, z_one: ScalarField, z_two: ScalarField) -> Self {
-        InnerHaloProof { l_vec, r_vec, r, z_one, z_two }
+    pub fn new(
+        l_vec: Vec<G1Point>,
+        r_vec: Vec<G1Point>,
+        g: G1Point,
+        r: G1Point,
+        z_one: ScalarField,
+        z_two: ScalarField,
+    ) -> Self {
+        InnerHaloProof {
+            l_vec,
+            r_vec,
+            g,
+            r,
+            z_one,
+            z_two,
+        }
     }
 
     pub fn get_l_vec(&self) -> &Vec<G1Point> {
@@ -24,6 +38,10 @@ impl InnerHaloProof {
         return &self.r_vec;
     }
 
+    pub fn get_g(&self) -> &G1Point {
+        return &self.g;
+    }
+
     pub fn get_r(&self) -> &G1Point {
         return &self.r;
     }
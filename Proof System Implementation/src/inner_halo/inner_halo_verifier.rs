@@ -4,18 +4,18 @@ use crate::{transcript::TranscriptProtocol, Utils};
 use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
 use ark_ec::{AffineCurve, ProjectiveCurve};
 use ark_ff::{Field, PrimeField};
-use merlin::Transcript;
 use std::io::Error;
 
+use super::halo_accumulator::HaloAccumulator;
 use super::inner_halo_proof::InnerHaloProof;
 
 pub struct InnerHaloVerifier<'a> {
-    g_vec: &'a Vec<G1Point>,       // G vector of group elements
-    b_vec: &'a Vec<ScalarField>,   // use x challange to derive the b_vec = (1,x,x^2,...,x^d)
-    h: &'a G1Point,                // H single group element (instead of h_vec)
-    t: &'a G1Point,                // T commitment to polynomial t(x) -> t = <t_vec, g_vec> + r * h
-    t_hat: &'a ScalarField,        // the polynomial t(x)=t_hat (evaluates to t_hat at point x)
-    u: &'a G1Point,                // group element become the first verifer challange
+    g_vec: &'a Vec<G1Point>,     // G vector of group elements
+    b_vec: &'a Vec<ScalarField>, // use x challange to derive the b_vec = (1,x,x^2,...,x^d)
+    h: &'a G1Point,              // H single group element (instead of h_vec)
+    t: &'a G1Point,              // T commitment to polynomial t(x) -> t = <t_vec, g_vec> + r * h
+    t_hat: &'a ScalarField,      // the polynomial t(x)=t_hat (evaluates to t_hat at point x)
+    u: &'a G1Point,              // group element become the first verifer challange
 }
 
 impl<'a> InnerHaloVerifier<'a> {
@@ -23,7 +23,7 @@ impl<'a> InnerHaloVerifier<'a> {
         g_vec: &'a Vec<G1Point>,
         b_vec: &'a Vec<ScalarField>,
         h: &'a G1Point,
-        t: &'a G1Point, 
+        t: &'a G1Point,
         t_hat: &'a ScalarField,
         u: &'a G1Point,
     ) -> Self {
@@ -37,69 +37,145 @@ impl<'a> InnerHaloVerifier<'a> {
         }
     }
 
-    pub fn verify_proof(
+    pub fn verify_proof<T: TranscriptProtocol>(
         &mut self,
         proof: &InnerHaloProof,
-        transcript: &mut Transcript,
+        transcript: &mut T,
     ) -> Result<(), Error> {
+        self.check_lengths(proof)?;
+
         transcript.domain_sep(b"InnerProductArgument");
+        self.append_statement(transcript);
 
         let t_first: G1Point = *self.t + self.u.mul((self.t_hat).into_repr()).into_affine();
 
-        self.inner_product_argument(self.g_vec, self.b_vec, self.h, self.u, &t_first, proof, transcript)
+        let _challenges: Vec<ScalarField> = self.inner_product_argument(
+            self.g_vec, self.b_vec, self.h, self.u, &t_first, proof, false, transcript,
+        )?;
+
+        Ok(())
     }
-    
-    fn inner_product_argument(
+
+    /// Checks the proof except for the folded generator G sent by the prover, whose check
+    /// G = <s, g_vec> (a linear-size MSM) is deferred to the returned accumulator.
+    pub fn verify_deferred<T: TranscriptProtocol>(
         &mut self,
-        g_vec: &Vec<G1Point>,
-        b_vec: &'a Vec<ScalarField>,
-        h: &'a G1Point,
-        u: &G1Point,
-        t_first: &G1Point,
         proof: &InnerHaloProof,
-        transcript: &mut Transcript,
-    ) -> Result<(), Error> {
+        transcript: &mut T,
+    ) -> Result<HaloAccumulator, Error> {
+        self.check_lengths(proof)?;
 
-        // ========== RAUND 1 ==========
-        let l_one: G1Point = proof.get_l_vec()[0];
-        let r_one: G1Point = proof.get_r_vec()[0];
+        transcript.domain_sep(b"InnerProductArgument");
+        self.append_statement(transcript);
+
+        let t_first: G1Point = *self.t + self.u.mul((self.t_hat).into_repr()).into_affine();
 
-        let _result = transcript.append_point(b"l_one", &l_one);
-        let _result = transcript.append_point(b"r_one", &r_one);
-        let m_one: ScalarField = transcript.challenge_scalar(b"m_one");
+        let challenges: Vec<ScalarField> = self.inner_product_argument(
+            self.g_vec, self.b_vec, self.h, self.u, &t_first, proof, true, transcript,
+        )?;
 
+        Ok(HaloAccumulator::new(challenges, *proof.get_g()))
+    }
 
-        // ========== RAUND 0 ==========
-        let l_zero: G1Point = proof.get_l_vec()[1];
-        let r_zero: G1Point = proof.get_r_vec()[1];
+    /// Deferred verification followed by the check of G with a single MSM.
+    pub fn verify_proof_multiscalar<T: TranscriptProtocol>(
+        &mut self,
+        proof: &InnerHaloProof,
+        transcript: &mut T,
+    ) -> Result<(), Error> {
+        self.verify_deferred(proof, transcript)?.decide(self.g_vec)
+    }
 
-        let _result = transcript.append_point(b"l_zero", &l_zero);
-        let _result = transcript.append_point(b"r_zero", &r_zero);
-        let m_zero: ScalarField = transcript.challenge_scalar(b"m_zero");
+    /// Appends the number of coefficients, the generators, h, u, the commitment T, the
+    /// evaluation vector b_vec = (1, x, ..., x^d) and the evaluation t_hat. The lengths of
+    /// `g_vec` and `b_vec` are checked beforehand by `check_lengths`.
+    pub(crate) fn append_statement<T: TranscriptProtocol>(&self, transcript: &mut T) {
+        let _result = transcript.append_scalar(b"n", &ScalarField::from(self.g_vec.len() as u64));
+        for (g_i, b_i) in self.g_vec.iter().zip(self.b_vec.iter()) {
+            let _result = transcript.append_point(b"g_i", g_i);
+            let _result = transcript.append_scalar(b"b_i", b_i);
+        }
+        let _result = transcript.append_point(b"h", self.h);
+        let _result = transcript.append_point(b"u", self.u);
+        let _result = transcript.append_point(b"T", self.t);
+        let _result = transcript.append_scalar(b"t_hat", self.t_hat);
+    }
 
+    /// Checks that the statement and the proof agree on the number of rounds: log2(d+1),
+    /// rounded up, as in the prover.
+    fn check_lengths(&self, proof: &InnerHaloProof) -> Result<(), Error> {
+        let rounds: usize = self.b_vec.len().next_power_of_two().trailing_zeros() as usize;
+        if self.b_vec.is_empty()
+            || self.g_vec.len() != self.b_vec.len()
+            || proof.get_l_vec().len() != rounds
+            || proof.get_r_vec().len() != rounds
+        {
+            return Err(throw(ProofError::ProofValidationError));
+        }
+        Ok(())
+    }
 
-        // After final round
-        let t_zero: G1Point = l_zero.mul(m_zero.pow([2]).into_repr()).into_affine()
-                            + l_one.mul(m_one.pow([2]).into_repr()).into_affine()
-                            + *t_first
-                            + r_zero.mul(m_zero.pow([2]).inverse().unwrap().into_repr()).into_affine()
-                            + r_one.mul(m_one.pow([2]).inverse().unwrap().into_repr()).into_affine();
-        
-        // Compute g_zero and b_zero 
-        let s_vec_one: Vec<ScalarField> = vec![m_one.inverse().unwrap(), m_one];
-        let g_vec_one: Vec<G1Point> = g_vec[..2].to_vec();
-        let g_one: G1Point = Utils::inner_product_point_scalar(&g_vec_one, &s_vec_one).unwrap();
-        let s_vec_zero: Vec<ScalarField> = vec![m_zero.inverse().unwrap(), m_zero];
-        let g_vec_zero: Vec<G1Point> = vec![g_one, g_vec[2..][0]];
-        let b_vec_one: Vec<ScalarField> = b_vec[..2].to_vec();
-        let b_one: ScalarField = Utils::inner_product_scalar_scalar(&s_vec_one, &b_vec_one).unwrap();
-        let b_vec_zero: Vec<ScalarField> = vec![b_one, b_vec[2..][0]];
+    fn inner_product_argument<T: TranscriptProtocol>(
+        &mut self,
+        g_vec: &Vec<G1Point>,
+        b_vec: &'a Vec<ScalarField>,
+        h: &'a G1Point,
+        u: &G1Point,
+        t_first: &G1Point,
+        proof: &InnerHaloProof,
+        deferred: bool,
+        transcript: &mut T,
+    ) -> Result<Vec<ScalarField>, Error> {
+        // Same padding as the prover, with the NUMS generators
+        let mut n: usize = b_vec.len().next_power_of_two();
+        let mut g_vec: Vec<G1Point> = Utils::pad_generators(g_vec, b"g_vec", n);
+
+        let mut t_zero: G1Point = *t_first;
+        let mut challenges: Vec<ScalarField> = Vec::new();
+
+        for (l, r) in proof.get_l_vec().iter().zip(proof.get_r_vec().iter()) {
+            n /= 2;
+
+            let _result = transcript.append_point(b"l", l);
+            let _result = transcript.append_point(b"r", r);
+            let m: ScalarField = transcript.challenge_scalar(b"m");
+            let m_inv: ScalarField = m.inverse().unwrap();
+            challenges.push(m);
+
+            t_zero = t_zero
+                + l.mul(m.pow([2]).into_repr()).into_affine()
+                + r.mul(m_inv.pow([2]).into_repr()).into_affine();
+
+            // Compute g_zero by folding, when not deferred
+            if !deferred {
+                g_vec = Utils::sum_point_point(
+                    &Utils::product_scalar_point(&m_inv, &g_vec[..n].to_vec()),
+                    &Utils::product_scalar_point(&m, &g_vec[n..].to_vec()),
+                )
+                .unwrap();
+            }
+        }
 
-        let g_zero: G1Point = Utils::inner_product_point_scalar(&g_vec_zero, &s_vec_zero).unwrap();
-        let b_zero: ScalarField = Utils::inner_product_scalar_scalar(&b_vec_zero, &s_vec_zero).unwrap();
+        // b_zero = <s, b_vec>, the padded entries of b_vec being zero
+        let m_sq_vec: Vec<ScalarField> = challenges
+            .iter()
+            .map(|m: &ScalarField| m.square())
+            .collect();
+        let s_vec: Vec<ScalarField> = Utils::get_s_vector(
+            &Utils::batch_inverse(&challenges).iter().product(),
+            &m_sq_vec,
+            b_vec.len(),
+        )?;
+        let b_zero: ScalarField = Utils::inner_product_scalar_scalar(&s_vec, b_vec)?;
+
+        let g_zero: G1Point = *proof.get_g();
+        if !deferred && g_zero != g_vec[0] {
+            return Err(throw(ProofError::ProofValidationError));
+        }
 
         // SCHNORR
         let r_comm: G1Point = *proof.get_r();
+        let _result = transcript.append_point(b"G", &g_zero);
         let _result = transcript.append_point(b"R", &r_comm);
         let x: ScalarField = transcript.challenge_scalar(b"x");
 
@@ -108,17 +184,18 @@ impl<'a> InnerHaloVerifier<'a> {
 
         let _result = transcript.append_scalar(b"z_one", &z_one);
         let _result = transcript.append_scalar(b"z_two", &z_two);
-        
-        let left_eq: G1Point = t_zero.mul(x.into_repr()).into_affine()
-                             + r_comm;
 
-        let right_eq: G1Point = (g_zero + u.mul(b_zero.into_repr()).into_affine()).mul(z_one.into_repr()).into_affine()
-                              + h.mul(z_two.into_repr()).into_affine();
+        let left_eq: G1Point = t_zero.mul(x.into_repr()).into_affine() + r_comm;
+
+        let right_eq: G1Point = (g_zero + u.mul(b_zero.into_repr()).into_affine())
+            .mul(z_one.into_repr())
+            .into_affine()
+            + h.mul(z_two.into_repr()).into_affine();
 
         if left_eq == right_eq {
-            return Ok(());
-        }  else {
-            return Err(throw(ProofError::ProofValidationError));
+            Ok(challenges)
+        } else {
+            Err(throw(ProofError::ProofValidationError))
         }
     }
 }
@@ -1,3 +1,4 @@
+pub(crate) mod halo_accumulator;
 pub(crate) mod inner_halo_proof;
 pub(crate) mod inner_halo_prover;
 pub(crate) mod inner_halo_verifier;
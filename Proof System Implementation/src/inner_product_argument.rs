@@ -0,0 +1,168 @@
+use crate::transcript::TranscriptProtocol;
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use std::fmt::Debug;
+use std::io::Error;
+
+/// Common interface of the inner-product arguments shipped by the crate. The statement and the
+/// witness are associated types: `InnerProver` and `InnerSigmaProver` prove <a, b> = c for
+/// P = <a, g_vec> + <b, h_vec>, while `InnerHaloProver` proves the evaluation of a committed polynomial.
+pub trait InnerProductArgument {
+    /// Identifier of the argument, recorded in the proofs that embed it
+    const ID: u8;
+
+    type Statement;
+    type Witness;
+    type Proof: CanonicalSerialize + CanonicalDeserialize + Debug;
+
+    fn prove<T: TranscriptProtocol>(
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        transcript: &mut T,
+    ) -> Result<Self::Proof, Error>;
+
+    fn verify<T: TranscriptProtocol>(
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        transcript: &mut T,
+    ) -> Result<(), Error>;
+}
+
+/// P = <a, g_vec> + <b, h_vec> with c = <a, b>, and u the generator used to bind c
+pub struct IpaStatement {
+    g_vec: Vec<G1Point>,
+    h_vec: Vec<G1Point>,
+    p: G1Point,
+    c: ScalarField,
+    u: G1Point,
+}
+
+impl IpaStatement {
+    pub fn new(
+        g_vec: Vec<G1Point>,
+        h_vec: Vec<G1Point>,
+        p: G1Point,
+        c: ScalarField,
+        u: G1Point,
+    ) -> Self {
+        IpaStatement {
+            g_vec,
+            h_vec,
+            p,
+            c,
+            u,
+        }
+    }
+
+    pub fn get_g_vec(&self) -> &Vec<G1Point> {
+        &self.g_vec
+    }
+
+    pub fn get_h_vec(&self) -> &Vec<G1Point> {
+        &self.h_vec
+    }
+
+    pub fn get_p(&self) -> &G1Point {
+        &self.p
+    }
+
+    pub fn get_c(&self) -> &ScalarField {
+        &self.c
+    }
+
+    pub fn get_u(&self) -> &G1Point {
+        &self.u
+    }
+}
+
+pub struct IpaWitness {
+    a_vec: Vec<ScalarField>,
+    b_vec: Vec<ScalarField>,
+}
+
+impl IpaWitness {
+    pub fn new(a_vec: Vec<ScalarField>, b_vec: Vec<ScalarField>) -> Self {
+        IpaWitness { a_vec, b_vec }
+    }
+
+    pub fn get_a_vec(&self) -> &Vec<ScalarField> {
+        &self.a_vec
+    }
+
+    pub fn get_b_vec(&self) -> &Vec<ScalarField> {
+        &self.b_vec
+    }
+}
+
+/// T = <t_vec, g_vec> + r * h with t_hat = <t_vec, b_vec>
+pub struct PolyIpaStatement {
+    g_vec: Vec<G1Point>,
+    b_vec: Vec<ScalarField>,
+    h: G1Point,
+    t: G1Point,
+    t_hat: ScalarField,
+    u: G1Point,
+}
+
+impl PolyIpaStatement {
+    pub fn new(
+        g_vec: Vec<G1Point>,
+        b_vec: Vec<ScalarField>,
+        h: G1Point,
+        t: G1Point,
+        t_hat: ScalarField,
+        u: G1Point,
+    ) -> Self {
+        PolyIpaStatement {
+            g_vec,
+            b_vec,
+            h,
+            t,
+            t_hat,
+            u,
+        }
+    }
+
+    pub fn get_g_vec(&self) -> &Vec<G1Point> {
+        &self.g_vec
+    }
+
+    pub fn get_b_vec(&self) -> &Vec<ScalarField> {
+        &self.b_vec
+    }
+
+    pub fn get_h(&self) -> &G1Point {
+        &self.h
+    }
+
+    pub fn get_t(&self) -> &G1Point {
+        &self.t
+    }
+
+    pub fn get_t_hat(&self) -> &ScalarField {
+        &self.t_hat
+    }
+
+    pub fn get_u(&self) -> &G1Point {
+        &self.u
+    }
+}
+
+pub struct PolyIpaWitness {
+    t_vec: Vec<ScalarField>,
+    r: ScalarField,
+}
+
+impl PolyIpaWitness {
+    pub fn new(t_vec: Vec<ScalarField>, r: ScalarField) -> Self {
+        PolyIpaWitness { t_vec, r }
+    }
+
+    pub fn get_t_vec(&self) -> &Vec<ScalarField> {
+        &self.t_vec
+    }
+
+    pub fn get_r(&self) -> &ScalarField {
+        &self.r
+    }
+}
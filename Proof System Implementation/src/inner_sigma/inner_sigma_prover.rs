@@ -1,10 +1,13 @@
+use crate::errors::proof_error::throw;
+use crate::inner_product_argument::{InnerProductArgument, IpaStatement, IpaWitness};
 use crate::transcript::TranscriptProtocol;
 use crate::utils::Utils;
-use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use crate::{InnerSigmaVerifier, ProofError};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point, G1Projective};
 use ark_ec::{AffineCurve, ProjectiveCurve};
-use ark_ff::{Field, PrimeField};
-
-use merlin::Transcript;
+use ark_ff::PrimeField;
+use ark_std::rand::Rng;
+use std::io::Error;
 
 use super::inner_sigma_proof::InnerSigmaProof;
 
@@ -39,110 +42,226 @@ impl<'a> InnerSigmaProver<'a> {
         }
     }
 
-    pub fn generate_proof(&mut self, transcript: &mut Transcript) -> InnerSigmaProof {
+    pub fn generate_proof<T: TranscriptProtocol>(
+        &mut self,
+        transcript: &mut T,
+    ) -> Result<InnerSigmaProof, Error> {
+        let n: usize = self.g_vec.len();
+        if n == 0 || self.h_vec.len() != n || self.a_vec.len() != n || self.b_vec.len() != n {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+
         transcript.domain_sep(b"InnerProductArgument");
+        InnerSigmaVerifier::new(self.g_vec, self.h_vec, self.t, self.c, self.u)
+            .append_statement(transcript);
         let y: ScalarField = transcript.challenge_scalar(b"y");
         let uy: G1Point = self.u.mul((y).into_repr()).into_affine();
-        let t_first: G1Point = *self.t + uy.mul((self.c).into_repr()).into_affine();
 
-        self.inner_product_argument(
-            self.g_vec, self.h_vec, &uy, &t_first, self.a_vec, self.b_vec, transcript,
-        )
+        // Same padding rule as InnerProver: zeros and NUMS generators up to the next power of two
+        let padded_n: usize = n.next_power_of_two();
+        let g_vec: Vec<G1Point> = Utils::pad_generators(self.g_vec, b"g_vec", padded_n);
+        let h_vec: Vec<G1Point> = Utils::pad_generators(self.h_vec, b"h_vec", padded_n);
+        let a_vec: Vec<ScalarField> = Utils::pad_scalars(self.a_vec, padded_n);
+        let b_vec: Vec<ScalarField> = Utils::pad_scalars(self.b_vec, padded_n);
+
+        Ok(self.inner_product_argument(&g_vec, &h_vec, &uy, &a_vec, &b_vec, transcript))
     }
 
-    fn inner_product_argument(
+    /// Honest-verifier simulator for the blinded case, where the vectors a and b are uniformly
+    /// random, as the l(x) and r(x) vectors of the range proof are. The plain argument is not
+    /// zero-knowledge: for a fixed statement the final scalars are fixed by the witness, so the
+    /// statement (t, c) is sampled together with the proof. Returns t, c and a proof accepting
+    /// for the challenges `y` and `x_vec`.
+    pub fn simulate<R: Rng>(
+        g_vec: &Vec<G1Point>,
+        h_vec: &Vec<G1Point>,
+        u: &G1Point,
+        y: &ScalarField,
+        x_vec: &Vec<ScalarField>,
+        rng: &mut R,
+    ) -> Result<(G1Point, ScalarField, InnerSigmaProof), Error> {
+        let n: usize = g_vec.len();
+        let rounds: usize = x_vec.len();
+        if rounds == 0 || n == 0 || n.next_power_of_two() != 1 << rounds || h_vec.len() != n {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+
+        let a_vec: Vec<ScalarField> = Utils::get_n_random_scalars(n, rng);
+        let b_vec: Vec<ScalarField> = Utils::get_n_random_scalars(n, rng);
+        let c: ScalarField = Utils::inner_product_scalar_scalar(&a_vec, &b_vec)?;
+        let t: G1Point = Utils::inner_product_point_scalar(g_vec, &a_vec)?
+            + Utils::inner_product_point_scalar(h_vec, &b_vec)?;
+
+        let uy: G1Point = u.mul((*y).into_repr()).into_affine();
+        let mut a_first: Vec<ScalarField> = Utils::pad_scalars(&a_vec, 1 << rounds);
+        let mut b_first: Vec<ScalarField> = Utils::pad_scalars(&b_vec, 1 << rounds);
+        let mut g_first: Vec<G1Point> = Utils::pad_generators(g_vec, b"g_vec", 1 << rounds);
+        let mut h_first: Vec<G1Point> = Utils::pad_generators(h_vec, b"h_vec", 1 << rounds);
+        let mut l_vec: Vec<G1Point> = Vec::with_capacity(rounds);
+        let mut r_vec: Vec<G1Point> = Vec::with_capacity(rounds);
+
+        for x in x_vec.iter() {
+            let n_first: usize = g_first.len() / 2;
+            let (a_lo, a_hi) = a_first.split_at(n_first);
+            let (b_lo, b_hi) = b_first.split_at(n_first);
+            let (g_lo, g_hi) = g_first.split_at(n_first);
+            let (h_lo, h_hi) = h_first.split_at(n_first);
+
+            let c_l: ScalarField =
+                Utils::inner_product_scalar_scalar(&a_hi.to_vec(), &b_lo.to_vec())?;
+            let c_r: ScalarField =
+                Utils::inner_product_scalar_scalar(&a_lo.to_vec(), &b_hi.to_vec())?;
+            l_vec.push(
+                Utils::inner_product_point_scalar(&g_lo.to_vec(), &a_hi.to_vec())?
+                    + Utils::inner_product_point_scalar(&h_hi.to_vec(), &b_lo.to_vec())?
+                    + uy.mul(c_l.into_repr()).into_affine(),
+            );
+            r_vec.push(
+                Utils::inner_product_point_scalar(&g_hi.to_vec(), &a_lo.to_vec())?
+                    + Utils::inner_product_point_scalar(&h_lo.to_vec(), &b_hi.to_vec())?
+                    + uy.mul(c_r.into_repr()).into_affine(),
+            );
+
+            let a_next: Vec<ScalarField> = Utils::sum_scalar_scalar(
+                &Utils::product_scalar(x, &a_lo.to_vec()),
+                &a_hi.to_vec(),
+            )?;
+            let b_next: Vec<ScalarField> = Utils::sum_scalar_scalar(
+                &b_lo.to_vec(),
+                &Utils::product_scalar(x, &b_hi.to_vec()),
+            )?;
+            let g_next: Vec<G1Point> = Utils::sum_point_point(
+                &g_lo.to_vec(),
+                &Utils::product_scalar_point(x, &g_hi.to_vec()),
+            )?;
+            let h_next: Vec<G1Point> = Utils::sum_point_point(
+                &Utils::product_scalar_point(x, &h_lo.to_vec()),
+                &h_hi.to_vec(),
+            )?;
+            a_first = a_next;
+            b_first = b_next;
+            g_first = g_next;
+            h_first = h_next;
+        }
+
+        Ok((
+            t,
+            c,
+            InnerSigmaProof::new(a_first[0], b_first[0], l_vec, r_vec),
+        ))
+    }
+
+    /// Iterative argument folding the vectors in place: the first half of each buffer holds the
+    /// folded vectors after every round, so that the memory stays linear in n.
+    fn inner_product_argument<T: TranscriptProtocol>(
         &mut self,
         g_vec: &Vec<G1Point>,
         h_vec: &Vec<G1Point>,
         u: &G1Point,
-        t: &G1Point,
         a_vec: &Vec<ScalarField>,
         b_vec: &Vec<ScalarField>,
-        transcript: &mut Transcript,
+        transcript: &mut T,
     ) -> InnerSigmaProof {
-        let n: usize = g_vec.len();
-        if n == 1 {
-            let a: ScalarField = a_vec[0];
-            let b: ScalarField = b_vec[0];
-            let _result = transcript.append_scalar(b"a", &a);
-            let _result = transcript.append_scalar(b"b", &b);
-            InnerSigmaProof::new(a, b, [].to_vec(), [].to_vec())
-        } else {
-            let n_first = n / 2;
-
-            let a_hi: Vec<ScalarField> = a_vec[n_first..].to_vec(); 
-            let a_lo: Vec<ScalarField> = a_vec[..n_first].to_vec(); 
-            let b_hi: Vec<ScalarField> = b_vec[n_first..].to_vec();
-            let b_lo: Vec<ScalarField> = b_vec[..n_first].to_vec();
-
-            let g_hi: Vec<G1Point> = g_vec[n_first..].to_vec();
-            let g_lo: Vec<G1Point> = g_vec[..n_first].to_vec();
-            let h_hi: Vec<G1Point> = h_vec[n_first..].to_vec();
-            let h_lo: Vec<G1Point> = h_vec[..n_first].to_vec();
-            
-            let c_l: ScalarField = Utils::inner_product_scalar_scalar(&a_hi, &b_lo).unwrap();
-            let c_r: ScalarField = Utils::inner_product_scalar_scalar(&a_lo, &b_hi).unwrap();
-
-            let l: G1Point =
-                Utils::pedersen_vector_commitment(
-                    &c_l, 
-                    &u, 
-                    &a_hi, 
-                    &g_lo, 
-                    &b_lo, 
-                    &h_hi)
-                    .unwrap();
-
-            let r: G1Point =
-                Utils::pedersen_vector_commitment(
-                    &c_r, 
-                    &u, 
-                    &a_lo, 
-                    &g_hi, 
-                    &b_hi, 
-                    &h_lo)
-                    .unwrap();
-
-            let mut l_vec: Vec<G1Point> = [l].to_vec();
-            let mut r_vec: Vec<G1Point> = [r].to_vec();
+        let mut n: usize = g_vec.len();
+        let rounds: usize = n.trailing_zeros() as usize;
+        let mut l_vec: Vec<G1Point> = Vec::with_capacity(rounds);
+        let mut r_vec: Vec<G1Point> = Vec::with_capacity(rounds);
+
+        let mut a_buf: Vec<ScalarField> = a_vec.clone();
+        let mut b_buf: Vec<ScalarField> = b_vec.clone();
+        let mut g_buf: Vec<G1Projective> = g_vec
+            .iter()
+            .map(|g: &G1Point| g.into_projective())
+            .collect();
+        let mut h_buf: Vec<G1Projective> = h_vec
+            .iter()
+            .map(|h: &G1Point| h.into_projective())
+            .collect();
+        let u: G1Projective = u.into_projective();
+
+        while n > 1 {
+            n /= 2;
+
+            let (a_lo, a_hi) = a_buf[..2 * n].split_at_mut(n);
+            let (b_lo, b_hi) = b_buf[..2 * n].split_at_mut(n);
+            let (g_lo, g_hi) = g_buf[..2 * n].split_at_mut(n);
+            let (h_lo, h_hi) = h_buf[..2 * n].split_at_mut(n);
+
+            let c_l: ScalarField = a_hi.iter().zip(b_lo.iter()).map(|(a, b)| *a * b).sum();
+            let c_r: ScalarField = a_lo.iter().zip(b_hi.iter()).map(|(a, b)| *a * b).sum();
+
+            // l = <a_hi, g_lo> + <b_lo, h_hi> + c_l * u
+            let l: G1Point = (Utils::inner_product_projective_scalar(g_lo, a_hi)
+                + Utils::inner_product_projective_scalar(h_hi, b_lo)
+                + u.mul(c_l.into_repr()))
+            .into_affine();
+
+            // r = <a_lo, g_hi> + <b_hi, h_lo> + c_r * u
+            let r: G1Point = (Utils::inner_product_projective_scalar(g_hi, a_lo)
+                + Utils::inner_product_projective_scalar(h_lo, b_hi)
+                + u.mul(c_r.into_repr()))
+            .into_affine();
+
+            l_vec.push(l);
+            r_vec.push(r);
 
             let _result = transcript.append_point(b"l", &l);
             let _result = transcript.append_point(b"r", &r);
             let x: ScalarField = transcript.challenge_scalar(b"x");
 
-            let g_first_hi: Vec<G1Point> = Utils::product_scalar_point(&x, &g_hi);        
-            let g_first: Vec<G1Point> =
-                Utils::sum_point_point(&g_lo, &g_first_hi).unwrap();
-
-            let h_first_lo: Vec<G1Point> = Utils::product_scalar_point(&x, &h_lo);
-            let h_first: Vec<G1Point> = Utils::sum_point_point(&h_first_lo, &h_hi).unwrap();
-
-            let t_first: G1Point = 
-                l 
-                + t.mul(x.into_repr()).into_affine()
-                + r.mul(x.pow([2]).into_repr()).into_affine();
-
-            let a_first_lo: Vec<ScalarField> = Utils::product_scalar(&x, &a_lo);
-            let a_first: Vec<ScalarField> =
-                Utils::sum_scalar_scalar(&a_first_lo, &a_hi).unwrap();
-
-            let b_first_hi: Vec<ScalarField> = Utils::product_scalar(&x, &b_hi);
-            let b_first: Vec<ScalarField> =
-                Utils::sum_scalar_scalar(&b_lo, &b_first_hi).unwrap();
-
-            let rec_proof: InnerSigmaProof = self.inner_product_argument(
-                &g_first, 
-                &h_first, 
-                u, 
-                &t_first, 
-                &a_first, 
-                &b_first, 
-                transcript,
-            );
-            l_vec.append(&mut rec_proof.get_l_vec().clone());
-            r_vec.append(&mut rec_proof.get_r_vec().clone());
-
-            InnerSigmaProof::new(*rec_proof.get_a(), *rec_proof.get_b(), l_vec, r_vec)
+            for i in 0..n {
+                a_lo[i] = a_lo[i] * x + a_hi[i];
+                b_lo[i] += b_hi[i] * x;
+                g_lo[i] += g_hi[i].mul(x.into_repr());
+                h_lo[i] = h_lo[i].mul(x.into_repr()) + h_hi[i];
+            }
         }
+
+        let a: ScalarField = a_buf[0];
+        let b: ScalarField = b_buf[0];
+        let _result = transcript.append_scalar(b"a", &a);
+        let _result = transcript.append_scalar(b"b", &b);
+
+        InnerSigmaProof::new(a, b, l_vec, r_vec)
+    }
+}
+
+impl<'a> InnerProductArgument for InnerSigmaProver<'a> {
+    const ID: u8 = 1;
+
+    type Statement = IpaStatement;
+    type Witness = IpaWitness;
+    type Proof = InnerSigmaProof;
+
+    fn prove<T: TranscriptProtocol>(
+        statement: &IpaStatement,
+        witness: &IpaWitness,
+        transcript: &mut T,
+    ) -> Result<InnerSigmaProof, Error> {
+        InnerSigmaProver::new(
+            statement.get_g_vec(),
+            statement.get_h_vec(),
+            statement.get_p(),
+            statement.get_c(),
+            witness.get_a_vec(),
+            witness.get_b_vec(),
+            statement.get_u(),
+        )
+        .generate_proof(transcript)
+    }
+
+    fn verify<T: TranscriptProtocol>(
+        statement: &IpaStatement,
+        proof: &InnerSigmaProof,
+        transcript: &mut T,
+    ) -> Result<(), Error> {
+        InnerSigmaVerifier::new(
+            statement.get_g_vec(),
+            statement.get_h_vec(),
+            statement.get_p(),
+            statement.get_c(),
+            statement.get_u(),
+        )
+        .verify_proof_multiscalar(proof, transcript)
     }
 }
@@ -1,10 +1,9 @@
 use crate::errors::proof_error::throw;
 use crate::ProofError;
 use crate::{transcript::TranscriptProtocol, Utils};
-use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point, G1Projective};
 use ark_ec::{AffineCurve, ProjectiveCurve};
-use ark_ff::{One, Field, PrimeField};
-use merlin::Transcript;
+use ark_ff::{Field, One, PrimeField, Zero};
 use std::io::Error;
 
 use super::inner_sigma_proof::InnerSigmaProof;
@@ -34,206 +33,209 @@ impl<'a> InnerSigmaVerifier<'a> {
         }
     }
 
-    pub fn verify_proof(
+    pub fn verify_proof<T: TranscriptProtocol>(
         &mut self,
         proof: &InnerSigmaProof,
-        transcript: &mut Transcript,
+        transcript: &mut T,
     ) -> Result<(), Error> {
+        self.check_lengths(proof)?;
+
         transcript.domain_sep(b"InnerProductArgument");
+        self.append_statement(transcript);
         let y: ScalarField = transcript.challenge_scalar(b"y");
-        let uy: G1Point = self.u.mul((y).into_repr()).into_affine();
-        let t_first: G1Point = *self.t + uy.mul((self.c).into_repr()).into_affine();
 
-        self.inner_product_argument(self.g_vec, self.h_vec, &uy, &t_first, proof, transcript)
+        let mut x_vec: Vec<ScalarField> = Vec::with_capacity(proof.get_l_vec().len());
+        for (l, r) in proof.get_l_vec().iter().zip(proof.get_r_vec().iter()) {
+            let _result = transcript.append_point(b"l", l);
+            let _result = transcript.append_point(b"r", r);
+            x_vec.push(transcript.challenge_scalar(b"x"));
+        }
+
+        let _result = transcript.append_scalar(b"a", proof.get_a());
+        let _result = transcript.append_scalar(b"b", proof.get_b());
+
+        self.verify_with_challenges(proof, &y, &x_vec)
     }
 
-    fn inner_product_argument(
+    /// Checks the proof against the challenge `y` and the folding challenges `x_vec`
+    /// instead of deriving them from a transcript.
+    pub fn verify_with_challenges(
         &mut self,
-        g_vec: &Vec<G1Point>,
-        h_vec: &Vec<G1Point>,
-        u: &G1Point,
-        t: &G1Point,
         proof: &InnerSigmaProof,
-        transcript: &mut Transcript,
+        y: &ScalarField,
+        x_vec: &Vec<ScalarField>,
     ) -> Result<(), Error> {
-        let n: usize = g_vec.len();
-        if n == 1 {
-            let a: ScalarField = *proof.get_a();
-            let b: ScalarField = *proof.get_b();
+        let (g_vec, h_vec): (Vec<G1Point>, Vec<G1Point>) = self.padded_generators(proof)?;
+        if x_vec.len() != proof.get_l_vec().len() {
+            return Err(throw(ProofError::VectorLengthError));
+        }
 
-            let _result = transcript.append_scalar(b"a", &a);
-            let _result = transcript.append_scalar(b"b", &b);
+        let uy: G1Point = self.u.mul((*y).into_repr()).into_affine();
+        let mut t_first: G1Projective =
+            (*self.t + uy.mul((self.c).into_repr()).into_affine()).into_projective();
+
+        // Generators folded in place, the first half of each buffer holding the folded vectors
+        let mut n: usize = g_vec.len();
+        let mut g_buf: Vec<G1Projective> = g_vec
+            .iter()
+            .map(|g: &G1Point| g.into_projective())
+            .collect();
+        let mut h_buf: Vec<G1Projective> = h_vec
+            .iter()
+            .map(|h: &G1Point| h.into_projective())
+            .collect();
+
+        for (j, x) in x_vec.iter().enumerate() {
+            n /= 2;
+
+            let (g_lo, g_hi) = g_buf[..2 * n].split_at_mut(n);
+            let (h_lo, h_hi) = h_buf[..2 * n].split_at_mut(n);
+            for i in 0..n {
+                g_lo[i] += g_hi[i].mul(x.into_repr());
+                h_lo[i] = h_lo[i].mul(x.into_repr()) + h_hi[i];
+            }
 
-            let c: ScalarField = a * b;
+            t_first = proof.get_l_vec()[j].into_projective()
+                + t_first.mul(x.into_repr())
+                + proof.get_r_vec()[j].mul(x.square().into_repr());
+        }
 
-            let g: G1Point = g_vec[0];
-            let h: G1Point = h_vec[0];
+        let a: ScalarField = *proof.get_a();
+        let b: ScalarField = *proof.get_b();
 
-            let to_check: G1Point = 
-                g.mul(a.into_repr()).into_affine()
-                + h.mul(b.into_repr()).into_affine()
-                + u.mul(c.into_repr()).into_affine();
+        let to_check: G1Projective =
+            g_buf[0].mul(a.into_repr()) + h_buf[0].mul(b.into_repr()) + uy.mul((a * b).into_repr());
 
-            if *t == to_check {
-                return Ok(());
-            } else {
-                return Err(throw(ProofError::ProofValidationError));
-            }
+        if t_first == to_check {
+            Ok(())
         } else {
-            let n_first = n / 2;
-
-            let g_hi: Vec<G1Point> = g_vec[n_first..].to_vec();
-            let g_lo: Vec<G1Point> = g_vec[..n_first].to_vec();
-            let h_hi: Vec<G1Point> = h_vec[n_first..].to_vec();
-            let h_lo: Vec<G1Point> = h_vec[..n_first].to_vec();
-
-            let l: G1Point = proof.get_l_vec()[0];
-            let r: G1Point = proof.get_r_vec()[0];
-            let _result = transcript.append_point(b"l", &l);
-            let _result = transcript.append_point(b"r", &r);
-            let x: ScalarField = transcript.challenge_scalar(b"x");
-
-            let g_first_hi: Vec<G1Point> = Utils::product_scalar_point(&x, &g_hi);            
-            let g_first: Vec<G1Point> =
-                Utils::sum_point_point(&g_lo, &g_first_hi).unwrap();
-
-            let h_first_lo: Vec<G1Point> = Utils::product_scalar_point(&x, &h_lo);
-            let h_first: Vec<G1Point> = Utils::sum_point_point(&h_first_lo, &h_hi).unwrap();
-
-            let t_first: G1Point = 
-                l 
-                + t.mul(x.into_repr()).into_affine()
-                + r.mul(x.pow([2]).into_repr()).into_affine();
-
-            let rec_proof: InnerSigmaProof = InnerSigmaProof::new(
-                *proof.get_a(),
-                *proof.get_b(),
-                proof.get_l_vec()[1..].to_vec(),
-                proof.get_r_vec()[1..].to_vec(),
-            );
-
-            self.inner_product_argument(&g_first, &h_first, u, &t_first, &rec_proof, transcript)
+            Err(throw(ProofError::ProofValidationError))
         }
     }
 
-    pub fn verify_proof_multiscalar(
+    pub fn verify_proof_multiscalar<T: TranscriptProtocol>(
         &mut self,
         proof: &InnerSigmaProof,
-        transcript: &mut Transcript,
+        transcript: &mut T,
     ) -> Result<(), Error> {
+        let (g_vec, h_vec): (Vec<G1Point>, Vec<G1Point>) = self.padded_generators(proof)?;
+
         transcript.domain_sep(b"InnerProductArgument");
+        self.append_statement(transcript);
         let y: ScalarField = transcript.challenge_scalar(b"y");
         let uy: G1Point = self.u.mul((y).into_repr()).into_affine();
-        let t_first: G1Point = *self.t + uy.mul((self.c).into_repr()).into_affine();
 
         self.inner_product_argument_multiscalar(
-            self.g_vec, 
-            self.h_vec, 
-            &uy, 
-            &t_first, 
-            proof, 
-            &mut [].to_vec(),
-            self.g_vec.len(),
-            transcript,
+            &g_vec, &h_vec, &uy, self.t, self.c, proof, transcript,
         )
     }
 
-    fn bit_function(&mut self, i: usize, j: usize, n: usize) -> bool {
-        let bits: Vec<u8> = Utils::number_to_be_bits_reversed(i, n);
-        
-        if bits[j] == 1 {
-            true
-        } else {
-            false
+    /// Appends n, the generators, u and the statement T, c of the argument. The lengths of
+    /// `g_vec` and `h_vec` are checked beforehand by `check_lengths`.
+    pub(crate) fn append_statement<T: TranscriptProtocol>(&self, transcript: &mut T) {
+        let _result = transcript.append_scalar(b"n", &ScalarField::from(self.g_vec.len() as u64));
+        for (g_i, h_i) in self.g_vec.iter().zip(self.h_vec.iter()) {
+            let _result = transcript.append_point(b"g_i", g_i);
+            let _result = transcript.append_point(b"h_i", h_i);
         }
+        let _result = transcript.append_point(b"u", self.u);
+        let _result = transcript.append_point(b"T", self.t);
+        let _result = transcript.append_scalar(b"c", self.c);
     }
 
-    fn get_s_vector(&mut self, x_vec: &Vec<ScalarField>, n: usize) -> Vec<ScalarField> {
-        (0..n)
-            .map(|i: usize| {
-                (0..x_vec.len())
-                    .map(|j| {
-                        if self.bit_function(i, j as usize, x_vec.len()) {
-                            x_vec[j as usize] 
-                        } else {
-                            ScalarField::one() 
-                        }
-                    })
-                    .reduce(|accum: ScalarField, item: ScalarField| accum * item)
-                    .unwrap()
-            })
-            .collect()
+    /// Checks that the statement and the proof agree on the number of rounds, and returns the
+    /// length of the generators padded to the next power of two.
+    fn check_lengths(&self, proof: &InnerSigmaProof) -> Result<usize, Error> {
+        let n: usize = self.g_vec.len();
+        let padded_n: usize = n.next_power_of_two();
+        let rounds: usize = padded_n.trailing_zeros() as usize;
+        if n == 0
+            || self.h_vec.len() != n
+            || proof.get_l_vec().len() != rounds
+            || proof.get_r_vec().len() != rounds
+        {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+        Ok(padded_n)
+    }
+
+    /// Generators padded to the next power of two with the NUMS points used by the prover.
+    fn padded_generators(
+        &self,
+        proof: &InnerSigmaProof,
+    ) -> Result<(Vec<G1Point>, Vec<G1Point>), Error> {
+        let padded_n: usize = self.check_lengths(proof)?;
+
+        Ok((
+            Utils::pad_generators(self.g_vec, b"g_vec", padded_n),
+            Utils::pad_generators(self.h_vec, b"h_vec", padded_n),
+        ))
     }
 
-    fn inner_product_argument_multiscalar(
+    /// Every round maps T to L + x * T + x^2 * R, so the folded commitment is
+    /// (prod_j x_j) * T + sum_j (prod_(k > j) x_k) * (L_j + x_j^2 * R_j), checked with a single MSM against
+    /// a * <s, g_vec> + b * <s_rev, h_vec> + (a * b) * u
+    fn inner_product_argument_multiscalar<T: TranscriptProtocol>(
         &mut self,
         g_vec: &Vec<G1Point>,
         h_vec: &Vec<G1Point>,
         u: &G1Point,
         t: &G1Point,
+        c: &ScalarField,
         proof: &InnerSigmaProof,
-        x_vec: &mut Vec<ScalarField>,
-        n: usize,
-        transcript: &mut Transcript,
+        transcript: &mut T,
     ) -> Result<(), Error> {
-        if n == 1 {
-            let a: ScalarField = *proof.get_a();
-            let b: ScalarField = *proof.get_b();
-
-            let _result = transcript.append_scalar(b"a", &a);
-            let _result = transcript.append_scalar(b"b", &b);
-
-            let c: ScalarField = a * b;
+        let n: usize = g_vec.len();
+        let rounds: usize = proof.get_l_vec().len();
 
-            let s: Vec<ScalarField> = self.get_s_vector(x_vec, g_vec.len());
-            let s_rev: Vec<ScalarField> = s.iter().rev().cloned().collect();
+        let mut x_vec: Vec<ScalarField> = Vec::with_capacity(rounds);
+        for (l, r) in proof.get_l_vec().iter().zip(proof.get_r_vec().iter()) {
+            let _result = transcript.append_point(b"l", l);
+            let _result = transcript.append_point(b"r", r);
+            x_vec.push(transcript.challenge_scalar(b"x"));
+        }
 
-            let g: G1Point = Utils::inner_product_point_scalar(&g_vec, &s).unwrap();
-            let h: G1Point = Utils::inner_product_point_scalar(&h_vec, &s_rev).unwrap();
+        let a: ScalarField = *proof.get_a();
+        let b: ScalarField = *proof.get_b();
+        let _result = transcript.append_scalar(b"a", &a);
+        let _result = transcript.append_scalar(b"b", &b);
 
-            let to_check: G1Point = g.mul(a.into_repr()).into_affine()
-                + h.mul(b.into_repr()).into_affine()
-                + u.mul(c.into_repr()).into_affine();
+        // g is folded as g_lo + x * g_hi, h as x * h_lo + h_hi
+        let s: Vec<ScalarField> = Utils::get_s_vector(&ScalarField::one(), &x_vec, n)?;
 
-            if *t == to_check {
-                return Ok(());
-            } else {
-                return Err(throw(ProofError::ProofValidationError));
-            }
+        // suffix_vec[j] = prod_(k > j) x_k
+        let mut suffix_vec: Vec<ScalarField> = vec![ScalarField::one(); rounds];
+        for j in (0..rounds.saturating_sub(1)).rev() {
+            suffix_vec[j] = suffix_vec[j + 1] * x_vec[j + 1];
+        }
+        let x_product: ScalarField = x_vec.iter().product();
+
+        let mut points: Vec<G1Point> = Vec::with_capacity(2 * n + 2 * rounds + 2);
+        let mut scalars: Vec<ScalarField> = Vec::with_capacity(2 * n + 2 * rounds + 2);
+
+        points.extend(g_vec.iter());
+        scalars.extend(s.iter().map(|s_i: &ScalarField| a * s_i));
+        points.extend(h_vec.iter());
+        scalars.extend(s.iter().rev().map(|s_i: &ScalarField| b * s_i));
+        // T' = T + c * u, so u collects both terms
+        points.push(*u);
+        scalars.push(a * b - x_product * c);
+        points.extend(proof.get_l_vec().iter());
+        scalars.extend(suffix_vec.iter().map(|p: &ScalarField| -*p));
+        points.extend(proof.get_r_vec().iter());
+        scalars.extend(
+            suffix_vec
+                .iter()
+                .zip(x_vec.iter())
+                .map(|(p, x): (&ScalarField, &ScalarField)| -(*p * x.square())),
+        );
+        points.push(*t);
+        scalars.push(-x_product);
+
+        if Utils::multiscalar_mul(&points, &scalars)?.is_zero() {
+            Ok(())
         } else {
-            let n_first = n / 2;
-
-            let l: G1Point = proof.get_l_vec()[0];
-            let r: G1Point = proof.get_r_vec()[0];
-            let _result = transcript.append_point(b"l", &l);
-            let _result = transcript.append_point(b"r", &r);
-            let x: ScalarField = transcript.challenge_scalar(b"x");
-
-            let mut x_vec_first: Vec<ScalarField> = [x].to_vec();
-            x_vec_first.append(x_vec);
-        
-            let t_first: G1Point = 
-                    l 
-                    + t.mul(x.into_repr()).into_affine()
-                    + r.mul(x.pow([2]).into_repr()).into_affine();
-
-            let rec_proof: InnerSigmaProof = InnerSigmaProof::new(
-                *proof.get_a(),
-                *proof.get_b(),
-                proof.get_l_vec()[1..].to_vec(),
-                proof.get_r_vec()[1..].to_vec(),
-            );
-
-            self.inner_product_argument_multiscalar(
-                g_vec, 
-                h_vec, 
-                u, 
-                &t_first, 
-                &rec_proof, 
-                &mut x_vec_first, 
-                n_first, 
-                transcript
-            )
+            Err(throw(ProofError::ProofValidationError))
         }
     }
 }
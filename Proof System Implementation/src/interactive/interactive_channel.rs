@@ -0,0 +1,34 @@
+use crate::interactive::prover_channel::ProverChannel;
+use crate::interactive::verifier_channel::VerifierChannel;
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+/// Message of an interactive execution. The prover sends domain separators, scalars, points and
+/// challenge requests, the verifier answers every request with a `Challenge`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InteractiveMessage {
+    DomainSep(&'static [u8]),
+    Scalar(&'static [u8], ScalarField),
+    Point(&'static [u8], G1Point),
+    ChallengeRequest(&'static [u8]),
+    Challenge(&'static [u8], ScalarField),
+}
+
+/// Connects a prover and a verifier for an interactive, public-coin execution of a protocol.
+pub struct InteractiveChannel;
+
+impl InteractiveChannel {
+    pub fn create() -> (ProverChannel, VerifierChannel) {
+        let (message_sender, message_receiver): (
+            Sender<InteractiveMessage>,
+            Receiver<InteractiveMessage>,
+        ) = channel();
+        let (challenge_sender, challenge_receiver): (Sender<ScalarField>, Receiver<ScalarField>) =
+            channel();
+
+        (
+            ProverChannel::new(message_sender, challenge_receiver),
+            VerifierChannel::new(message_receiver, challenge_sender),
+        )
+    }
+}
@@ -0,0 +1,4 @@
+pub(crate) mod interactive_channel;
+pub(crate) mod prover_channel;
+pub(crate) mod replay_transcript;
+pub(crate) mod verifier_channel;
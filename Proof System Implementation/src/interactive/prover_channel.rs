@@ -0,0 +1,86 @@
+use crate::errors::transcript_error::throw;
+use crate::interactive::interactive_channel::InteractiveMessage;
+use crate::{TranscriptError, TranscriptProtocol};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_std::Zero;
+use std::io::Error;
+use std::sync::mpsc::{Receiver, Sender};
+
+/// Prover end of an interactive channel. It can be given to any prover in place of a `merlin`
+/// transcript: the prover messages are sent to the verifier and the challenges are received
+/// from it instead of being derived by hashing. The prover must call `finish` once done, since
+/// a failure of the channel cannot be reported through a challenge.
+pub struct ProverChannel {
+    sender: Sender<InteractiveMessage>,
+    receiver: Receiver<ScalarField>,
+    failed: bool,
+}
+
+impl ProverChannel {
+    pub fn new(sender: Sender<InteractiveMessage>, receiver: Receiver<ScalarField>) -> Self {
+        ProverChannel {
+            sender,
+            receiver,
+            failed: false,
+        }
+    }
+
+    /// Closes the channel. Fails if a message could not be sent or a challenge could not be
+    /// received, i.e. the verifier has hung up: the proof generated on the channel is then
+    /// meaningless.
+    pub fn finish(self) -> Result<(), Error> {
+        if self.failed {
+            Err(throw(TranscriptError::ChannelError))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn send(&mut self, message: InteractiveMessage) -> Result<(), Error> {
+        match self.sender.send(message) {
+            Ok(()) => Ok(()),
+            Err(_) => {
+                self.failed = true;
+                Err(throw(TranscriptError::ChannelError))
+            }
+        }
+    }
+}
+
+impl TranscriptProtocol for ProverChannel {
+    fn domain_sep(&mut self, label: &'static [u8]) {
+        let _result = self.send(InteractiveMessage::DomainSep(label));
+    }
+
+    fn append_scalar(&mut self, label: &'static [u8], scalar: &ScalarField) -> Result<(), Error> {
+        self.send(InteractiveMessage::Scalar(label, *scalar))
+    }
+
+    fn append_point(&mut self, label: &'static [u8], point: &G1Point) -> Result<(), Error> {
+        self.send(InteractiveMessage::Point(label, *point))
+    }
+
+    fn validate_and_append_point(
+        &mut self,
+        label: &'static [u8],
+        point: &G1Point,
+    ) -> Result<(), Error> {
+        if point.is_zero() {
+            return Err(throw(TranscriptError::PointValidationError));
+        }
+        self.append_point(label, point)
+    }
+
+    /// Blocks until the verifier answers. If the verifier has hung up, the failure is recorded
+    /// for `finish` and a zero challenge is returned.
+    fn challenge_scalar(&mut self, label: &'static [u8]) -> ScalarField {
+        let _result = self.send(InteractiveMessage::ChallengeRequest(label));
+        match self.receiver.recv() {
+            Ok(challenge) => challenge,
+            Err(_) => {
+                self.failed = true;
+                ScalarField::zero()
+            }
+        }
+    }
+}
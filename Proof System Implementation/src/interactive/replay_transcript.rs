@@ -0,0 +1,88 @@
+use crate::errors::transcript_error::throw;
+use crate::interactive::interactive_channel::InteractiveMessage;
+use crate::{TranscriptError, TranscriptProtocol};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_std::Zero;
+use std::collections::VecDeque;
+use std::io::Error;
+
+/// Replays a recorded interactive execution to a verifier. The messages the verifier reads from
+/// the proof must be the ones the prover sent, in the same order, and the challenges are the
+/// ones the verifier sampled during the execution.
+pub struct ReplayTranscript {
+    messages: VecDeque<InteractiveMessage>,
+    consistent: bool,
+}
+
+impl ReplayTranscript {
+    /// Runs `verify` on the recorded execution `messages`. Succeeds if `verify` succeeds and has
+    /// read the whole execution, in order: a mismatch makes the challenges meaningless, so the
+    /// replay is only accepted through this check.
+    pub fn verify<F>(messages: &Vec<InteractiveMessage>, verify: F) -> Result<(), Error>
+    where
+        F: FnOnce(&mut ReplayTranscript) -> Result<(), Error>,
+    {
+        let mut replay: ReplayTranscript = ReplayTranscript {
+            messages: messages.iter().cloned().collect(),
+            consistent: true,
+        };
+        verify(&mut replay)?;
+        replay.finish()
+    }
+
+    fn finish(&self) -> Result<(), Error> {
+        if self.consistent && self.messages.is_empty() {
+            Ok(())
+        } else {
+            Err(throw(TranscriptError::ChannelError))
+        }
+    }
+
+    fn expect(&mut self, message: InteractiveMessage) {
+        if self.messages.pop_front() != Some(message) {
+            self.consistent = false;
+        }
+    }
+}
+
+impl TranscriptProtocol for ReplayTranscript {
+    fn domain_sep(&mut self, label: &'static [u8]) {
+        self.expect(InteractiveMessage::DomainSep(label));
+    }
+
+    fn append_scalar(&mut self, label: &'static [u8], scalar: &ScalarField) -> Result<(), Error> {
+        self.expect(InteractiveMessage::Scalar(label, *scalar));
+        Ok(())
+    }
+
+    fn append_point(&mut self, label: &'static [u8], point: &G1Point) -> Result<(), Error> {
+        self.expect(InteractiveMessage::Point(label, *point));
+        Ok(())
+    }
+
+    fn validate_and_append_point(
+        &mut self,
+        label: &'static [u8],
+        point: &G1Point,
+    ) -> Result<(), Error> {
+        if point.is_zero() {
+            return Err(throw(TranscriptError::PointValidationError));
+        }
+        self.append_point(label, point)
+    }
+
+    fn challenge_scalar(&mut self, label: &'static [u8]) -> ScalarField {
+        match self.messages.pop_front() {
+            Some(InteractiveMessage::Challenge(recorded_label, challenge))
+                if recorded_label == label =>
+            {
+                challenge
+            }
+            // The mismatch is reported by `verify` once the verifier returns
+            _ => {
+                self.consistent = false;
+                ScalarField::zero()
+            }
+        }
+    }
+}
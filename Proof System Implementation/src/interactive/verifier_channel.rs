@@ -0,0 +1,40 @@
+use crate::errors::transcript_error::throw;
+use crate::interactive::interactive_channel::InteractiveMessage;
+use crate::{TranscriptError, Utils};
+use ark_bn254::Fr as ScalarField;
+use ark_std::rand::Rng;
+use std::io::Error;
+use std::sync::mpsc::{Receiver, Sender};
+
+/// Verifier end of an interactive channel, acting as the public-coin verifier.
+pub struct VerifierChannel {
+    receiver: Receiver<InteractiveMessage>,
+    sender: Sender<ScalarField>,
+}
+
+impl VerifierChannel {
+    pub fn new(receiver: Receiver<InteractiveMessage>, sender: Sender<ScalarField>) -> Self {
+        VerifierChannel { receiver, sender }
+    }
+
+    /// Serves the prover until it closes the channel, answering every challenge request with a
+    /// uniformly random challenge. Returns the messages exchanged, in order.
+    pub fn run<R: Rng>(&mut self, rng: &mut R) -> Result<Vec<InteractiveMessage>, Error> {
+        let mut messages: Vec<InteractiveMessage> = Vec::new();
+
+        for message in self.receiver.iter() {
+            match message {
+                InteractiveMessage::ChallengeRequest(label) => {
+                    let challenge: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+                    if self.sender.send(challenge).is_err() {
+                        return Err(throw(TranscriptError::ChannelError));
+                    }
+                    messages.push(InteractiveMessage::Challenge(label, challenge));
+                }
+                other => messages.push(other),
+            }
+        }
+
+        Ok(messages)
+    }
+}
@@ -0,0 +1,80 @@
+use crate::range::range_link::{RangeLinkProver, RangeLinkVerifier};
+use crate::transcript::TranscriptProtocol;
+use ark_bn254::Fr as ScalarField;
+use ark_std::rand::Rng;
+use std::io::Error;
+
+use super::range_bounds::RangeBounds;
+
+/// Links a range proof over the 2 * m shifted values v_j - min_j and max_j - v_j to a
+/// Sigma-protocol on the m values v_j: the weighted sum of the shifted values is
+/// sum_j (w_j - w_(m+j)) * v_j plus a public offset, so both halves derive from the same v_j.
+pub(crate) struct BoundedLink<'b, L> {
+    link: &'b mut L,
+    bounds: &'b RangeBounds,
+}
+
+impl<'b, L> BoundedLink<'b, L> {
+    pub(crate) fn new(link: &'b mut L, bounds: &'b RangeBounds) -> Self {
+        BoundedLink { link, bounds }
+    }
+}
+
+impl<'b, L: RangeLinkProver> RangeLinkProver for BoundedLink<'b, L> {
+    type State = L::State;
+    type Proof = L::Proof;
+
+    fn link_statement<T: TranscriptProtocol>(&self, transcript: &mut T) {
+        self.link.link_statement(transcript);
+    }
+
+    fn link_commitments<R: Rng, T: TranscriptProtocol>(
+        &mut self,
+        rng: &mut R,
+        transcript: &mut T,
+        weights: &Vec<ScalarField>,
+        k_ab: &ScalarField,
+    ) -> L::State {
+        let value_weights: Vec<ScalarField> = self.bounds.get_value_weights(weights);
+        self.link
+            .link_commitments(rng, transcript, &value_weights, k_ab)
+    }
+
+    fn link_responses<T: TranscriptProtocol>(
+        &mut self,
+        state: L::State,
+        transcript: &mut T,
+        c: &ScalarField,
+    ) -> L::Proof {
+        self.link.link_responses(state, transcript, c)
+    }
+}
+
+impl<'b, L: RangeLinkVerifier> RangeLinkVerifier for BoundedLink<'b, L> {
+    type Proof = L::Proof;
+
+    fn link_statement<T: TranscriptProtocol>(&self, transcript: &mut T) {
+        self.link.link_statement(transcript);
+    }
+
+    fn link_commitments<T: TranscriptProtocol>(&self, proof: &L::Proof, transcript: &mut T) {
+        self.link.link_commitments(proof, transcript);
+    }
+
+    fn link_responses<T: TranscriptProtocol>(&self, proof: &L::Proof, transcript: &mut T) {
+        self.link.link_responses(proof, transcript);
+    }
+
+    /// The s_ab of the range proof is the one of the linked protocol plus c * offset.
+    fn verify_link(
+        &mut self,
+        proof: &L::Proof,
+        weights: &Vec<ScalarField>,
+        c: &ScalarField,
+        s_ab: &ScalarField,
+    ) -> Result<(), Error> {
+        let value_weights: Vec<ScalarField> = self.bounds.get_value_weights(weights);
+        let value_s_ab: ScalarField = *s_ab - (*c * self.bounds.get_offset(weights));
+        self.link.verify_link(proof, &value_weights, c, &value_s_ab)
+    }
+}
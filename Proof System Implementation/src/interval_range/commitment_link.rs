@@ -0,0 +1,154 @@
+use crate::errors::proof_error::{throw, ProofError};
+use crate::range::range_link::{RangeLinkProver, RangeLinkVerifier};
+use crate::transcript::TranscriptProtocol;
+use crate::utils::Utils;
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_std::rand::Rng;
+use std::io::Error;
+
+use super::interval_commitment_proof::IntervalCommitmentProof;
+
+/// Links a range proof to the Pedersen commitments V_j = v_j * g + gamma_j * h: it opens
+/// sum_j w_j * V_j with the k_ab of the range proof, so that s_v is its s_ab.
+pub(crate) struct CommitmentLinkProver<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    values: &'a Vec<usize>,
+    gamma_vec: &'a Vec<ScalarField>,
+    v_vec: &'a Vec<G1Point>,
+}
+
+impl<'a> CommitmentLinkProver<'a> {
+    pub(crate) fn new(
+        g: &'a G1Point,
+        h: &'a G1Point,
+        values: &'a Vec<usize>,
+        gamma_vec: &'a Vec<ScalarField>,
+        v_vec: &'a Vec<G1Point>,
+    ) -> Self {
+        CommitmentLinkProver {
+            g,
+            h,
+            values,
+            gamma_vec,
+            v_vec,
+        }
+    }
+}
+
+impl<'a> RangeLinkProver for CommitmentLinkProver<'a> {
+    type State = (G1Point, ScalarField, ScalarField, Vec<ScalarField>);
+    type Proof = IntervalCommitmentProof;
+
+    fn link_statement<T: TranscriptProtocol>(&self, transcript: &mut T) {
+        append_commitments(self.v_vec, transcript);
+    }
+
+    fn link_commitments<R: Rng, T: TranscriptProtocol>(
+        &mut self,
+        rng: &mut R,
+        transcript: &mut T,
+        weights: &Vec<ScalarField>,
+        k_ab: &ScalarField,
+    ) -> Self::State {
+        let k_gamma: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let a_v: G1Point = self.g.mul(k_ab.into_repr()).into_affine()
+            + self.h.mul(k_gamma.into_repr()).into_affine();
+        let _result = transcript.append_point(b"A_v", &a_v);
+
+        (a_v, *k_ab, k_gamma, weights.clone())
+    }
+
+    fn link_responses<T: TranscriptProtocol>(
+        &mut self,
+        state: Self::State,
+        transcript: &mut T,
+        c: &ScalarField,
+    ) -> IntervalCommitmentProof {
+        let (a_v, k_ab, k_gamma, weights): (G1Point, ScalarField, ScalarField, Vec<ScalarField>) =
+            state;
+
+        let v_sum: ScalarField = (0..weights.len())
+            .map(|j: usize| weights[j] * ScalarField::from(self.values[j] as u64))
+            .sum();
+        let gamma_sum: ScalarField = (0..weights.len())
+            .map(|j: usize| weights[j] * self.gamma_vec[j])
+            .sum();
+
+        let s_v: ScalarField = k_ab + (*c * v_sum);
+        let s_gamma: ScalarField = k_gamma + (*c * gamma_sum);
+        let _result = transcript.append_scalar(b"s_gamma", &s_gamma);
+
+        IntervalCommitmentProof::new(a_v, s_v, s_gamma)
+    }
+}
+
+pub(crate) struct CommitmentLinkVerifier<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    v_vec: &'a Vec<G1Point>,
+}
+
+impl<'a> CommitmentLinkVerifier<'a> {
+    pub(crate) fn new(g: &'a G1Point, h: &'a G1Point, v_vec: &'a Vec<G1Point>) -> Self {
+        CommitmentLinkVerifier { g, h, v_vec }
+    }
+}
+
+impl<'a> RangeLinkVerifier for CommitmentLinkVerifier<'a> {
+    type Proof = IntervalCommitmentProof;
+
+    fn link_statement<T: TranscriptProtocol>(&self, transcript: &mut T) {
+        append_commitments(self.v_vec, transcript);
+    }
+
+    fn link_commitments<T: TranscriptProtocol>(
+        &self,
+        proof: &IntervalCommitmentProof,
+        transcript: &mut T,
+    ) {
+        let _result = transcript.append_point(b"A_v", proof.get_a_v());
+    }
+
+    fn link_responses<T: TranscriptProtocol>(
+        &self,
+        proof: &IntervalCommitmentProof,
+        transcript: &mut T,
+    ) {
+        let _result = transcript.append_scalar(b"s_gamma", proof.get_s_gamma());
+    }
+
+    fn verify_link(
+        &mut self,
+        proof: &IntervalCommitmentProof,
+        weights: &Vec<ScalarField>,
+        c: &ScalarField,
+        s_ab: &ScalarField,
+    ) -> Result<(), Error> {
+        if proof.get_s_v() != s_ab {
+            return Err(throw(ProofError::ProofValidationError));
+        }
+
+        let v_sum: G1Point = (0..weights.len())
+            .map(|j: usize| self.v_vec[j].mul(weights[j].into_repr()).into_affine())
+            .sum();
+
+        let left_eq: G1Point = self.g.mul(proof.get_s_v().into_repr()).into_affine()
+            + self.h.mul(proof.get_s_gamma().into_repr()).into_affine();
+        let right_eq: G1Point = *proof.get_a_v() + v_sum.mul(c.into_repr()).into_affine();
+
+        if left_eq == right_eq {
+            Ok(())
+        } else {
+            Err(throw(ProofError::ProofValidationError))
+        }
+    }
+}
+
+fn append_commitments<T: TranscriptProtocol>(v_vec: &Vec<G1Point>, transcript: &mut T) {
+    for v in v_vec.iter() {
+        let _result = transcript.append_point(b"V", v);
+    }
+}
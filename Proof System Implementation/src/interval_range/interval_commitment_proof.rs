@@ -0,0 +1,31 @@
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+
+use ark_serialize::*;
+
+/// Opening of sum_j w_j * V_j for the weights w_j of the range proof, which binds the shifted
+/// values of an interval range proof, or the values of the parties of `RangeDealer`, to the
+/// Pedersen commitments V_j = v_j * g + gamma_j * h.
+#[derive(Debug, CanonicalDeserialize, CanonicalSerialize)]
+pub struct IntervalCommitmentProof {
+    a_v: G1Point,
+    s_v: ScalarField,
+    s_gamma: ScalarField,
+}
+
+impl IntervalCommitmentProof {
+    pub fn new(a_v: G1Point, s_v: ScalarField, s_gamma: ScalarField) -> Self {
+        IntervalCommitmentProof { a_v, s_v, s_gamma }
+    }
+
+    pub fn get_a_v(&self) -> &G1Point {
+        &self.a_v
+    }
+
+    pub fn get_s_v(&self) -> &ScalarField {
+        &self.s_v
+    }
+
+    pub fn get_s_gamma(&self) -> &ScalarField {
+        &self.s_gamma
+    }
+}
@@ -0,0 +1,112 @@
+use crate::errors::proof_error::{throw, ProofError};
+use crate::range::range_link::RangeLinkProver;
+use crate::transcript::TranscriptProtocol;
+use crate::{LinkedRangeProof, RangeProver};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_std::rand::Rng;
+use std::io::Error;
+
+use super::bounded_link::BoundedLink;
+use super::commitment_link::CommitmentLinkProver;
+use super::interval_commitment_proof::IntervalCommitmentProof;
+use super::range_bounds::RangeBounds;
+
+/// Proves min_j <= v_j <= max_j for every value with the two-range-proof shift technique:
+/// an aggregated range proof over the 2 * m values v_j - min_j and max_j - v_j, each in [0, 2^n - 1].
+/// Both halves are bound to the Pedersen commitments V_j = v_j * g + gamma_j * h by an
+/// `IntervalCommitmentProof` that shares the s_ab of the range proof.
+/// `g_vec` and `h_vec` hold 2 * m * n generators, and the output is consumed exactly as the one
+/// of `RangeProver` by the inner-product argument.
+pub struct IntervalRangeProver<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    values: &'a Vec<usize>,
+    gamma_vec: &'a Vec<ScalarField>,
+    v_vec: &'a Vec<G1Point>,
+    bounds: &'a RangeBounds,
+    g_vec: &'a Vec<G1Point>,
+    h_vec: &'a Vec<G1Point>,
+    n: usize,
+}
+
+impl<'a> IntervalRangeProver<'a> {
+    /// `values` holds the remaining balance first and then the amounts, as `bounds`, and
+    /// `v_vec` their commitments with the blindings `gamma_vec`.
+    pub fn new(
+        g: &'a G1Point,
+        h: &'a G1Point,
+        values: &'a Vec<usize>,
+        gamma_vec: &'a Vec<ScalarField>,
+        v_vec: &'a Vec<G1Point>,
+        bounds: &'a RangeBounds,
+        g_vec: &'a Vec<G1Point>,
+        h_vec: &'a Vec<G1Point>,
+        n: usize,
+    ) -> Self {
+        IntervalRangeProver {
+            g,
+            h,
+            values,
+            gamma_vec,
+            v_vec,
+            bounds,
+            g_vec,
+            h_vec,
+            n,
+        }
+    }
+
+    pub fn get_ipa_arguments(
+        &mut self,
+        x: &ScalarField,
+        y: &ScalarField,
+        z: &ScalarField,
+        mu: &ScalarField,
+        a: &G1Point,
+        s: &G1Point,
+        h: &G1Point,
+        g_vec: &Vec<G1Point>,
+        h_vec: &Vec<G1Point>,
+    ) -> (Vec<G1Point>, G1Point) {
+        // Only the number of shifted values matters here
+        let amounts: Vec<usize> = vec![0; 2 * self.values.len() - 1];
+        RangeProver::new(self.g, self.h, 0, &amounts, self.g_vec, self.h_vec, self.n)
+            .get_ipa_arguments(x, y, z, mu, a, s, h, g_vec, h_vec)
+    }
+
+    pub fn generate_proof<R: Rng, T: TranscriptProtocol>(
+        &mut self,
+        rng: &mut R,
+        transcript: &mut T,
+    ) -> Result<LinkedRangeProof<IntervalCommitmentProof>, Error> {
+        if self.gamma_vec.len() != self.values.len() || self.v_vec.len() != self.values.len() {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+
+        let mut commitment_link: CommitmentLinkProver =
+            CommitmentLinkProver::new(self.g, self.h, self.values, self.gamma_vec, self.v_vec);
+
+        self.bounds.append_to_transcript(transcript);
+
+        self.generate_proof_with_link(rng, transcript, &mut commitment_link)
+    }
+
+    /// Proves the shifted values with the range proof linked to `link` on the values.
+    /// The bounds are part of the statement of the caller, which appends them.
+    pub(crate) fn generate_proof_with_link<R: Rng, T: TranscriptProtocol, L: RangeLinkProver>(
+        &mut self,
+        rng: &mut R,
+        transcript: &mut T,
+        link: &mut L,
+    ) -> Result<LinkedRangeProof<L::Proof>, Error> {
+        let shifted: Vec<usize> = self.bounds.shift_values(self.values, self.n)?;
+
+        let amounts: Vec<usize> = shifted[1..].to_vec();
+        let mut range_prover: RangeProver = RangeProver::new(
+            self.g, self.h, shifted[0], &amounts, self.g_vec, self.h_vec, self.n,
+        );
+        let mut bounded_link: BoundedLink<L> = BoundedLink::new(link, self.bounds);
+
+        Ok(range_prover.generate_proof_with_link(rng, transcript, &mut bounded_link))
+    }
+}
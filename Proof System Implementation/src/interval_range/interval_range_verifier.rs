@@ -0,0 +1,92 @@
+use crate::errors::proof_error::{throw, ProofError};
+use crate::range::range_link::RangeLinkVerifier;
+use crate::transcript::TranscriptProtocol;
+use crate::{RangeProof, RangeVerifier};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use std::io::Error;
+
+use super::bounded_link::BoundedLink;
+use super::commitment_link::CommitmentLinkVerifier;
+use super::interval_commitment_proof::IntervalCommitmentProof;
+use super::range_bounds::RangeBounds;
+
+pub struct IntervalRangeVerifier<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    v_vec: &'a Vec<G1Point>,
+    bounds: &'a RangeBounds,
+    n: usize,
+}
+
+impl<'a> IntervalRangeVerifier<'a> {
+    /// `v_vec` holds the commitments to the values, as `bounds`.
+    pub fn new(
+        g: &'a G1Point,
+        h: &'a G1Point,
+        v_vec: &'a Vec<G1Point>,
+        bounds: &'a RangeBounds,
+        n: usize,
+    ) -> Self {
+        IntervalRangeVerifier {
+            g,
+            h,
+            v_vec,
+            bounds,
+            n,
+        }
+    }
+
+    pub fn get_ipa_arguments(
+        &mut self,
+        x: &ScalarField,
+        y: &ScalarField,
+        z: &ScalarField,
+        mu: &ScalarField,
+        a: &G1Point,
+        s: &G1Point,
+        h: &G1Point,
+        g_vec: &Vec<G1Point>,
+        h_vec: &Vec<G1Point>,
+    ) -> (Vec<G1Point>, G1Point) {
+        RangeVerifier::new(self.g, self.h, 2 * self.bounds.len(), self.n)
+            .get_ipa_arguments(x, y, z, mu, a, s, h, g_vec, h_vec)
+    }
+
+    pub fn verify_proof<T: TranscriptProtocol>(
+        &mut self,
+        proof: &RangeProof,
+        commitment_proof: &IntervalCommitmentProof,
+        transcript: &mut T,
+    ) -> (Result<(), Error>, ScalarField, ScalarField, ScalarField) {
+        if self.v_vec.len() != self.bounds.len() {
+            let zero: ScalarField = ScalarField::from(0);
+            return (Err(throw(ProofError::VectorLengthError)), zero, zero, zero);
+        }
+
+        let mut commitment_link: CommitmentLinkVerifier =
+            CommitmentLinkVerifier::new(self.g, self.h, self.v_vec);
+
+        self.bounds.append_to_transcript(transcript);
+
+        self.verify_proof_with_link(proof, commitment_proof, &mut commitment_link, transcript)
+    }
+
+    /// Verifies the shifted values with the range proof linked to `link_verifier` on the values.
+    /// The bounds are part of the statement of the caller, which appends them.
+    pub(crate) fn verify_proof_with_link<T: TranscriptProtocol, L: RangeLinkVerifier>(
+        &mut self,
+        proof: &RangeProof,
+        link_proof: &L::Proof,
+        link_verifier: &mut L,
+        transcript: &mut T,
+    ) -> (Result<(), Error>, ScalarField, ScalarField, ScalarField) {
+        let bounds_result: Result<(), Error> = self.bounds.check_bounds(self.n);
+
+        let mut bounded_link: BoundedLink<L> = BoundedLink::new(link_verifier, self.bounds);
+        let (range_result, x, y, z): (Result<(), Error>, ScalarField, ScalarField, ScalarField) =
+            RangeVerifier::new(self.g, self.h, 2 * self.bounds.len(), self.n)
+                .verify_proof_with_link(proof, link_proof, &mut bounded_link, transcript);
+
+        (bounds_result.and(range_result), x, y, z)
+    }
+}
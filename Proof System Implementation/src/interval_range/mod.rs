@@ -0,0 +1,6 @@
+pub(crate) mod bounded_link;
+pub(crate) mod commitment_link;
+pub(crate) mod interval_commitment_proof;
+pub(crate) mod interval_range_prover;
+pub(crate) mod interval_range_verifier;
+pub(crate) mod range_bounds;
@@ -0,0 +1,108 @@
+use crate::errors::proof_error::throw;
+use crate::transcript::TranscriptProtocol;
+use crate::ProofError;
+use ark_bn254::Fr as ScalarField;
+use std::io::Error;
+
+/// One [min, max] interval per value of a range proof, the remaining balance first and then
+/// the amounts, in the order used by `RangeProver`.
+#[derive(Clone, Debug)]
+pub struct RangeBounds {
+    bounds: Vec<(usize, usize)>,
+}
+
+impl RangeBounds {
+    /// `balance_bounds` is the floor (and cap) of the remaining balance,
+    /// `amount_bounds` the minimum and maximum of each transferred amount.
+    pub fn new(balance_bounds: (usize, usize), amount_bounds: Vec<(usize, usize)>) -> Self {
+        let mut bounds: Vec<(usize, usize)> = Vec::with_capacity(amount_bounds.len() + 1);
+        bounds.push(balance_bounds);
+        bounds.extend(amount_bounds);
+
+        RangeBounds { bounds }
+    }
+
+    /// The same [min, max] interval for the amounts, with the remaining balance in [0, balance_max].
+    pub fn per_amount(
+        number_of_amounts: usize,
+        min: usize,
+        max: usize,
+        balance_max: usize,
+    ) -> Self {
+        Self::new((0, balance_max), vec![(min, max); number_of_amounts])
+    }
+
+    pub fn get_bounds(&self) -> &Vec<(usize, usize)> {
+        &self.bounds
+    }
+
+    /// Number of values bounded, i.e. m
+    pub fn len(&self) -> usize {
+        self.bounds.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bounds.is_empty()
+    }
+
+    /// Values proved by the underlying range proof: v_j - min_j for every value, followed by
+    /// max_j - v_j for every value. Both must lie in [0, 2^n - 1] for min_j <= v_j <= max_j to hold.
+    pub fn shift_values(&self, values: &Vec<usize>, n: usize) -> Result<Vec<usize>, Error> {
+        if values.len() != self.bounds.len() {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+        self.check_bounds(n)?;
+
+        let mut lower: Vec<usize> = Vec::with_capacity(2 * values.len());
+        let mut upper: Vec<usize> = Vec::with_capacity(values.len());
+        for (v, (min, max)) in values.iter().zip(self.bounds.iter()) {
+            if v < min || v > max {
+                return Err(throw(ProofError::OutOfBoundsError));
+            }
+            lower.push(v - min);
+            upper.push(max - v);
+        }
+        lower.extend(upper);
+
+        Ok(lower)
+    }
+
+    /// The bounds are public, they are bound into the transcript before the range proof.
+    pub fn append_to_transcript<T: TranscriptProtocol>(&self, transcript: &mut T) {
+        transcript.domain_sep(b"RangeBounds");
+        for (min, max) in self.bounds.iter() {
+            let _result = transcript.append_scalar(b"min", &ScalarField::from(*min as u64));
+            let _result = transcript.append_scalar(b"max", &ScalarField::from(*max as u64));
+        }
+    }
+
+    /// Weights of the m values v_j in the s_ab of the range proof over the 2 * m shifted values,
+    /// w_j - w_(m+j), from the weights w of the shifted values.
+    pub(crate) fn get_value_weights(&self, weights: &Vec<ScalarField>) -> Vec<ScalarField> {
+        let m: usize = self.bounds.len();
+        (0..m).map(|j: usize| weights[j] - weights[m + j]).collect()
+    }
+
+    /// Public part sum_j w_(m+j) * max_j - w_j * min_j of the weighted sum of the shifted values.
+    pub(crate) fn get_offset(&self, weights: &Vec<ScalarField>) -> ScalarField {
+        let m: usize = self.bounds.len();
+        self.bounds
+            .iter()
+            .enumerate()
+            .map(|(j, (min, max)): (usize, &(usize, usize))| {
+                (weights[m + j] * ScalarField::from(*max as u64))
+                    - (weights[j] * ScalarField::from(*min as u64))
+            })
+            .sum()
+    }
+
+    /// Every interval must be non-empty and no wider than 2^n - 1.
+    pub(crate) fn check_bounds(&self, n: usize) -> Result<(), Error> {
+        for (min, max) in self.bounds.iter() {
+            if min > max || (n < usize::BITS as usize && max - min >= 1 << n) {
+                return Err(throw(ProofError::OutOfBoundsError));
+            }
+        }
+        Ok(())
+    }
+}
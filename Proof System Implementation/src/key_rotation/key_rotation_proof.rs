@@ -0,0 +1,50 @@
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+
+use ark_serialize::*;
+
+#[derive(Debug, CanonicalDeserialize, CanonicalSerialize)]
+pub struct KeyRotationProof {
+    a_y: G1Point,
+    a_y_new: G1Point,
+    a_b: G1Point,
+    s_sk: ScalarField,
+    s_sk_new: ScalarField,
+}
+
+impl KeyRotationProof {
+    pub fn new(
+        a_y: G1Point,
+        a_y_new: G1Point,
+        a_b: G1Point,
+        s_sk: ScalarField,
+        s_sk_new: ScalarField,
+    ) -> Self {
+        KeyRotationProof {
+            a_y,
+            a_y_new,
+            a_b,
+            s_sk,
+            s_sk_new,
+        }
+    }
+
+    pub fn get_a_y(&self) -> &G1Point {
+        &self.a_y
+    }
+
+    pub fn get_a_y_new(&self) -> &G1Point {
+        &self.a_y_new
+    }
+
+    pub fn get_a_b(&self) -> &G1Point {
+        &self.a_b
+    }
+
+    pub fn get_s_sk(&self) -> &ScalarField {
+        &self.s_sk
+    }
+
+    pub fn get_s_sk_new(&self) -> &ScalarField {
+        &self.s_sk_new
+    }
+}
@@ -0,0 +1,104 @@
+use crate::key_rotation::key_rotation_proof::KeyRotationProof;
+use crate::transcript::TranscriptProtocol;
+use crate::utils::Utils;
+use crate::{AssociatedData, ElGamal, KeyRotationVerifier};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_std::rand::Rng;
+
+/// Proves that the balance b encrypted in (C_L, C_R) under y = sk * g is encrypted under
+/// y_new = sk_new * g as well, in (C_L_new, C_R_new) = (b * g + r * y_new, r * g), and the
+/// knowledge of both sk and sk_new: the balance moves to the new key without being revealed.
+pub struct KeyRotationProver<'a> {
+    g: &'a G1Point,
+    c_r: &'a G1Point,
+    balance: usize,
+    sk: &'a ScalarField,
+    sk_new: &'a ScalarField,
+    r: &'a ScalarField,
+    associated_data: Option<&'a AssociatedData>,
+}
+
+impl<'a> KeyRotationProver<'a> {
+    /// `r` is the randomness of the new encryption of the balance.
+    pub fn new(
+        g: &'a G1Point,
+        c_r: &'a G1Point,
+        balance: usize,
+        sk: &'a ScalarField,
+        sk_new: &'a ScalarField,
+        r: &'a ScalarField,
+    ) -> Self {
+        KeyRotationProver {
+            g,
+            c_r,
+            balance,
+            sk,
+            sk_new,
+            r,
+            associated_data: None,
+        }
+    }
+
+    /// Binds the proof to the transaction context, e.g. the chain id and the epoch: the proof
+    /// only verifies with the same associated data.
+    pub fn with_associated_data(mut self, associated_data: &'a AssociatedData) -> Self {
+        self.associated_data = Some(associated_data);
+        self
+    }
+
+    pub fn generate_proof<R: Rng, T: TranscriptProtocol>(
+        &mut self,
+        rng: &mut R,
+        transcript: &mut T,
+    ) -> KeyRotationProof {
+        transcript.domain_sep(b"KeyRotation");
+        self.append_statement(transcript);
+
+        let k_sk: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let k_sk_new: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let (_c_l_new, c_r_new): (G1Point, G1Point) = self.get_new_balance();
+
+        let a_y: G1Point = self.g.mul(k_sk.into_repr()).into_affine();
+        let a_y_new: G1Point = self.g.mul(k_sk_new.into_repr()).into_affine();
+        let a_b: G1Point =
+            (self.c_r.mul(k_sk.into_repr()) - c_r_new.mul(k_sk_new.into_repr())).into_affine();
+        let _result = transcript.append_point(b"A_y", &a_y);
+        let _result = transcript.append_point(b"A_y_new", &a_y_new);
+        let _result = transcript.append_point(b"A_b", &a_b);
+
+        let c: ScalarField = transcript.challenge_scalar(b"c");
+        let s_sk: ScalarField = (*self.sk * c) + k_sk;
+        let s_sk_new: ScalarField = (*self.sk_new * c) + k_sk_new;
+        let _result = transcript.append_scalar(b"s_sk", &s_sk);
+        let _result = transcript.append_scalar(b"s_sk_new", &s_sk_new);
+
+        KeyRotationProof::new(a_y, a_y_new, a_b, s_sk, s_sk_new)
+    }
+
+    /// The balance encrypted under the new public key, (b * g + r * y_new, r * g).
+    pub fn get_new_balance(&self) -> (G1Point, G1Point) {
+        let y_new: G1Point = ElGamal::elgamal_calculate_pub_key(self.sk_new, self.g);
+        ElGamal::elgamal_encrypt(self.balance, &y_new, self.g, self.r)
+    }
+
+    /// Appends the statement of `KeyRotationVerifier`, with the public keys and the balances
+    /// computed from the witness: C_L = b * g + sk * C_R.
+    fn append_statement<T: TranscriptProtocol>(&self, transcript: &mut T) {
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(self.sk, self.g);
+        let y_new: G1Point = ElGamal::elgamal_calculate_pub_key(self.sk_new, self.g);
+        let (c_l, _c_r): (G1Point, G1Point) =
+            ElGamal::elgamal_encrypt(self.balance, self.c_r, self.g, self.sk);
+        let (c_l_new, c_r_new): (G1Point, G1Point) = self.get_new_balance();
+
+        let verifier: KeyRotationVerifier =
+            KeyRotationVerifier::new(self.g, &y, &c_l, self.c_r, &y_new, &c_l_new, &c_r_new);
+        match self.associated_data {
+            Some(associated_data) => verifier
+                .with_associated_data(associated_data)
+                .append_statement(transcript),
+            None => verifier.append_statement(transcript),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use crate::errors::proof_error::throw;
+use crate::key_rotation::key_rotation_proof::KeyRotationProof;
+use crate::transcript::TranscriptProtocol;
+use crate::{AssociatedData, ProofError};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use std::io::Error;
+
+pub struct KeyRotationVerifier<'a> {
+    /// public generator
+    g: &'a G1Point,
+    y: &'a G1Point,
+    c_l: &'a G1Point,
+    c_r: &'a G1Point,
+    y_new: &'a G1Point,
+    c_l_new: &'a G1Point,
+    c_r_new: &'a G1Point,
+    associated_data: Option<&'a AssociatedData>,
+}
+
+impl<'a> KeyRotationVerifier<'a> {
+    /// (C_L, C_R) is the balance encrypted under y, (C_L_new, C_R_new) the same balance
+    /// encrypted under y_new.
+    pub fn new(
+        g: &'a G1Point,
+        y: &'a G1Point,
+        c_l: &'a G1Point,
+        c_r: &'a G1Point,
+        y_new: &'a G1Point,
+        c_l_new: &'a G1Point,
+        c_r_new: &'a G1Point,
+    ) -> Self {
+        KeyRotationVerifier {
+            g,
+            y,
+            c_l,
+            c_r,
+            y_new,
+            c_l_new,
+            c_r_new,
+            associated_data: None,
+        }
+    }
+
+    /// Checks the proof within the transaction context of the prover, see
+    /// `KeyRotationProver::with_associated_data`.
+    pub fn with_associated_data(mut self, associated_data: &'a AssociatedData) -> Self {
+        self.associated_data = Some(associated_data);
+        self
+    }
+
+    pub fn verify_proof<T: TranscriptProtocol>(
+        &mut self,
+        proof: &KeyRotationProof,
+        transcript: &mut T,
+    ) -> Result<(), Error> {
+        transcript.domain_sep(b"KeyRotation");
+        self.append_statement(transcript);
+
+        let _result = transcript.append_point(b"A_y", proof.get_a_y());
+        let _result = transcript.append_point(b"A_y_new", proof.get_a_y_new());
+        let _result = transcript.append_point(b"A_b", proof.get_a_b());
+
+        let c: ScalarField = transcript.challenge_scalar(b"c");
+        let _result = transcript.append_scalar(b"s_sk", proof.get_s_sk());
+        let _result = transcript.append_scalar(b"s_sk_new", proof.get_s_sk_new());
+
+        self.verify_with_challenge(proof, &c)
+    }
+
+    /// s_sk * g = A_y + c * y, s_sk_new * g = A_y_new + c * y_new and
+    /// s_sk * C_R - s_sk_new * C_R_new = A_b + c * (C_L - C_L_new)
+    pub fn verify_with_challenge(
+        &mut self,
+        proof: &KeyRotationProof,
+        c: &ScalarField,
+    ) -> Result<(), Error> {
+        let y_check: bool = self.g.mul(proof.get_s_sk().into_repr()).into_affine()
+            == *proof.get_a_y() + self.y.mul(c.into_repr()).into_affine();
+
+        let y_new_check: bool = self.g.mul(proof.get_s_sk_new().into_repr()).into_affine()
+            == *proof.get_a_y_new() + self.y_new.mul(c.into_repr()).into_affine();
+
+        let left_eq: G1Point = (self.c_r.mul(proof.get_s_sk().into_repr())
+            - self.c_r_new.mul(proof.get_s_sk_new().into_repr()))
+        .into_affine();
+        let right_eq: G1Point = *proof.get_a_b()
+            + (self.c_l.into_projective() - self.c_l_new.into_projective())
+                .into_affine()
+                .mul(c.into_repr())
+                .into_affine();
+
+        if y_check && y_new_check && left_eq == right_eq {
+            Ok(())
+        } else {
+            Err(throw(ProofError::ProofValidationError))
+        }
+    }
+
+    /// Appends the associated data, if any, and the statement (g, y, C_L, C_R, y_new, C_L_new,
+    /// C_R_new) to the transcript.
+    pub(crate) fn append_statement<T: TranscriptProtocol>(&self, transcript: &mut T) {
+        if let Some(associated_data) = self.associated_data {
+            associated_data.append_to_transcript(transcript);
+        }
+        let _result = transcript.append_point(b"g", self.g);
+        let _result = transcript.append_point(b"y", self.y);
+        let _result = transcript.append_point(b"C_L", self.c_l);
+        let _result = transcript.append_point(b"C_R", self.c_r);
+        let _result = transcript.append_point(b"y_new", self.y_new);
+        let _result = transcript.append_point(b"C_L_new", self.c_l_new);
+        let _result = transcript.append_point(b"C_R_new", self.c_r_new);
+    }
+}
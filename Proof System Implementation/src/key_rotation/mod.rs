@@ -0,0 +1,3 @@
+pub(crate) mod key_rotation_proof;
+pub(crate) mod key_rotation_prover;
+pub(crate) mod key_rotation_verifier;
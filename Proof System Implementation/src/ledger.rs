@@ -0,0 +1,271 @@
+use crate::errors::ledger_error::throw;
+use crate::{
+    AssociatedData, BurnProof, BurnVerifier, ElGamal, InnerProof, InnerProver, KeyRotationProof,
+    KeyRotationVerifier, LedgerError, RegistrationVerifier, SigmaSKProof, ZeroMTParams,
+    ZeroMTProof, ZeroMTProver, ZeroMTStatement, ZeroMTVerifier,
+};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{One, PrimeField};
+use ark_std::rand::Rng;
+use merlin::Transcript;
+use std::collections::HashMap;
+use std::io::Error;
+
+/// Simulation of the multi-transfer smart contract (MTSC). It stores the encrypted balance
+/// (C_L, C_R) of every registered public key and applies an operation only once its proof
+/// verifies against the stored balances. Proofs are checked on a fresh transcript with label
+/// `ZeroMTLedger` and bound to the associated data of the ledger, the prover must start from
+/// the same transcript and use the same data.
+pub struct Ledger<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    g_vec: &'a Vec<G1Point>,
+    h_vec: &'a Vec<G1Point>,
+    u: &'a G1Point,
+    n: usize,
+    chain_id: u64,
+    fee: usize,
+    collected_fees: usize,
+    accounts: HashMap<G1Point, (G1Point, G1Point)>,
+}
+
+impl<'a> Ledger<'a> {
+    pub fn new(
+        g: &'a G1Point,
+        h: &'a G1Point,
+        g_vec: &'a Vec<G1Point>,
+        h_vec: &'a Vec<G1Point>,
+        u: &'a G1Point,
+        n: usize,
+    ) -> Self {
+        Ledger {
+            g,
+            h,
+            g_vec,
+            h_vec,
+            u,
+            n,
+            chain_id: 0,
+            fee: 0,
+            collected_fees: 0,
+            accounts: HashMap::new(),
+        }
+    }
+
+    /// Public fee charged to the sender of every transfer, see `ZeroMTProver::with_fee`.
+    pub fn with_fee(mut self, fee: usize) -> Self {
+        self.fee = fee;
+        self
+    }
+
+    /// Chain id the registration, transfer, burn and key rotation proofs are bound to, see
+    /// `get_associated_data`.
+    pub fn with_chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    pub fn get_chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    pub fn get_fee(&self) -> usize {
+        self.fee
+    }
+
+    pub fn get_collected_fees(&self) -> usize {
+        self.collected_fees
+    }
+
+    /// Encrypted balance (C_L, C_R) of the account `y`.
+    pub fn get_balance(&self, y: &G1Point) -> Option<&(G1Point, G1Point)> {
+        self.accounts.get(y)
+    }
+
+    /// Pads a transfer from `y` with zero-valued transfers to other registered accounts, see
+    /// `ZeroMTProver::pad_recipients`.
+    pub fn pad_recipients<R: Rng>(
+        &self,
+        y: &G1Point,
+        amounts: &Vec<usize>,
+        y_bar: &Vec<G1Point>,
+        rng: &mut R,
+    ) -> Result<(Vec<usize>, Vec<G1Point>), Error> {
+        let accounts: Vec<G1Point> = self.accounts.keys().copied().collect();
+        ZeroMTProver::<InnerProver>::pad_recipients(y, amounts, y_bar, &accounts, rng)
+    }
+
+    /// Transaction context of the registration, transfer, burn and key rotation proofs, i.e. the
+    /// chain id of the ledger, see `RegistrationProver` and `ZeroMTProver::with_associated_data`.
+    pub fn get_associated_data(&self) -> AssociatedData {
+        AssociatedData::new().with_chain_id(self.chain_id)
+    }
+
+    pub fn new_transcript() -> Transcript {
+        Transcript::new(b"ZeroMTLedger")
+    }
+
+    /// Opens the account `y` with the encryption (y, g) of a zero balance, once the owner of y
+    /// proved knowledge of its private key from the account `address` on the ledger chain.
+    pub fn register(
+        &mut self,
+        y: &G1Point,
+        address: &[u8],
+        proof: &SigmaSKProof,
+    ) -> Result<(), Error> {
+        if self.accounts.contains_key(y) {
+            return Err(throw(LedgerError::DuplicateAccount));
+        }
+        let associated_data: AssociatedData = self.get_associated_data();
+        RegistrationVerifier::new(self.g, y, address, &associated_data).verify_proof(proof)?;
+        let zero_balance: (G1Point, G1Point) =
+            ElGamal::elgamal_encrypt(0, y, self.g, &ScalarField::one());
+        self.accounts.insert(*y, zero_balance);
+        Ok(())
+    }
+
+    /// Adds the public amount to the balance of `y`: C_L + amount * g.
+    pub fn deposit(&mut self, y: &G1Point, amount: usize) -> Result<(), Error> {
+        let g: G1Point = *self.g;
+        let (c_l, _c_r): &mut (G1Point, G1Point) = self
+            .accounts
+            .get_mut(y)
+            .ok_or_else(|| throw(LedgerError::UnknownAccount))?;
+        *c_l = *c_l
+            + g.mul(ScalarField::from(amount as u64).into_repr())
+                .into_affine();
+        Ok(())
+    }
+
+    /// Verifies the withdrawal of the public amount from the balance of `y`, proven with the
+    /// first n generators of `g_vec` and `h_vec`, then moves C_L - amount * g to it.
+    pub fn burn(&mut self, y: &G1Point, amount: usize, proof: &BurnProof) -> Result<(), Error> {
+        let (c_l, c_r): (G1Point, G1Point) = *self
+            .accounts
+            .get(y)
+            .ok_or_else(|| throw(LedgerError::UnknownAccount))?;
+        if self.g_vec.len() < self.n || self.h_vec.len() < self.n {
+            return Err(throw(LedgerError::InvalidTransfer));
+        }
+        let g_vec: Vec<G1Point> = self.g_vec[..self.n].to_vec();
+        let h_vec: Vec<G1Point> = self.h_vec[..self.n].to_vec();
+        let associated_data: AssociatedData = self.get_associated_data();
+
+        BurnVerifier::new(
+            self.g, self.h, &g_vec, &h_vec, self.u, self.n, y, &c_l, &c_r, amount,
+        )
+        .with_associated_data(&associated_data)
+        .verify_proof(proof, &mut Ledger::new_transcript())?;
+
+        let amount_g: G1Point = self
+            .g
+            .mul(ScalarField::from(amount as u64).into_repr())
+            .into_affine();
+        self.accounts.insert(
+            *y,
+            (
+                (c_l.into_projective() - amount_g.into_projective()).into_affine(),
+                c_r,
+            ),
+        );
+
+        Ok(())
+    }
+
+    /// Moves the account `y` to the new public key `y_new`, once the key rotation proof shows
+    /// that (C_L_new, C_R_new) encrypts the stored balance of `y` under `y_new`. The account
+    /// `y` is closed.
+    pub fn rotate(
+        &mut self,
+        y: &G1Point,
+        y_new: &G1Point,
+        c_l_new: &G1Point,
+        c_r_new: &G1Point,
+        proof: &KeyRotationProof,
+    ) -> Result<(), Error> {
+        if self.accounts.contains_key(y_new) {
+            return Err(throw(LedgerError::DuplicateAccount));
+        }
+        let (c_l, c_r): (G1Point, G1Point) = *self
+            .accounts
+            .get(y)
+            .ok_or_else(|| throw(LedgerError::UnknownAccount))?;
+        let associated_data: AssociatedData = self.get_associated_data();
+
+        KeyRotationVerifier::new(self.g, y, &c_l, &c_r, y_new, c_l_new, c_r_new)
+            .with_associated_data(&associated_data)
+            .verify_proof(proof, &mut Ledger::new_transcript())?;
+
+        self.accounts.remove(y);
+        self.accounts.insert(*y_new, (*c_l_new, *c_r_new));
+
+        Ok(())
+    }
+
+    /// Verifies a ZeroMT transfer from `y` to distinct recipients `y_bar` with the ledger fee,
+    /// then moves
+    /// C_L - sum_i C_i - f * g, C_R - (m - 1) * D to the sender and C_L + C_bar_i, C_R + D to
+    /// every recipient.
+    pub fn transfer(
+        &mut self,
+        y: &G1Point,
+        y_bar: &Vec<G1Point>,
+        d: &G1Point,
+        c_vec: &Vec<G1Point>,
+        c_bar_vec: &Vec<G1Point>,
+        proof: &ZeroMTProof<InnerProof>,
+    ) -> Result<(), Error> {
+        if y_bar.contains(y) || c_vec.len() != y_bar.len() || c_bar_vec.len() != y_bar.len() {
+            return Err(throw(LedgerError::InvalidTransfer));
+        }
+        if y_bar
+            .iter()
+            .enumerate()
+            .any(|(i, k): (usize, &G1Point)| y_bar[..i].contains(k))
+        {
+            return Err(throw(LedgerError::InvalidTransfer));
+        }
+        if y_bar
+            .iter()
+            .any(|k: &G1Point| !self.accounts.contains_key(k))
+        {
+            return Err(throw(LedgerError::UnknownAccount));
+        }
+        let (c_l, c_r): (G1Point, G1Point) = *self
+            .accounts
+            .get(y)
+            .ok_or_else(|| throw(LedgerError::UnknownAccount))?;
+        let associated_data: AssociatedData = self.get_associated_data();
+
+        ZeroMTVerifier::<InnerProver>::new(
+            &ZeroMTParams::new(self.g, self.h, self.g_vec, self.h_vec, self.u, self.n),
+            &ZeroMTStatement::new(d, &c_r, &c_l, c_vec, c_bar_vec, y, y_bar),
+        )
+        .with_fee(self.fee)
+        .with_associated_data(&associated_data)
+        .verify_proof(proof, &mut Ledger::new_transcript())?;
+
+        let fee_g: G1Point = self
+            .g
+            .mul(ScalarField::from(self.fee as u64).into_repr())
+            .into_affine();
+        let sender_c_l: G1Point = (c_l.into_projective()
+            - c_vec.iter().sum::<G1Point>().into_projective()
+            - fee_g.into_projective())
+        .into_affine();
+        let sender_c_r: G1Point = (c_r.into_projective()
+            - d.mul(ScalarField::from(c_vec.len() as u64).into_repr()))
+        .into_affine();
+        self.accounts.insert(*y, (sender_c_l, sender_c_r));
+
+        for (k, c_bar) in y_bar.iter().zip(c_bar_vec.iter()) {
+            let (c_l, c_r): &mut (G1Point, G1Point) = self.accounts.get_mut(k).unwrap();
+            *c_l = *c_l + *c_bar;
+            *c_r = *c_r + *d;
+        }
+        self.collected_fees += self.fee;
+
+        Ok(())
+    }
+}
@@ -1,18 +1,40 @@
+mod anon_zeromt;
+mod associated_data;
+mod auditor;
+mod bulletproofs;
+mod burn;
 mod csv_utils;
 mod elgamal;
 mod errors;
+#[cfg(feature = "extractor")]
+mod extractor;
 mod inner;
 mod inner_halo;
+mod inner_product_argument;
 mod inner_sigma;
+mod interactive;
+mod interval_range;
+mod key_rotation;
+mod ledger;
+mod one_out_of_many;
+mod poly_commitment;
+mod r1cs;
 mod range;
+mod range_mpc;
+mod range_plus;
 mod sigma_ab;
+mod sigma_anon;
+mod sigma_commit;
+mod sigma_decrypt;
 mod sigma_r;
 mod sigma_sk;
 mod sigma_y;
 mod transcript;
 mod utils;
+mod weighted_inner;
 mod zeromt;
 
+pub use crate::errors::ledger_error::LedgerError;
 pub use crate::errors::proof_error::ProofError;
 pub use crate::errors::transcript_error::TranscriptError;
 pub use crate::errors::utils_error::UtilsError;
@@ -21,27 +43,78 @@ pub use crate::inner_sigma::inner_sigma_proof::InnerSigmaProof;
 pub use crate::inner_sigma::inner_sigma_prover::InnerSigmaProver;
 pub use crate::inner_sigma::inner_sigma_verifier::InnerSigmaVerifier;
 
+pub use crate::inner_halo::halo_accumulator::HaloAccumulator;
 pub use crate::inner_halo::inner_halo_proof::InnerHaloProof;
 pub use crate::inner_halo::inner_halo_prover::InnerHaloProver;
 pub use crate::inner_halo::inner_halo_verifier::InnerHaloVerifier;
 
+pub use crate::inner_product_argument::{
+    InnerProductArgument, IpaStatement, IpaWitness, PolyIpaStatement, PolyIpaWitness,
+};
+
 pub use crate::inner::inner_proof::InnerProof;
 pub use crate::inner::inner_prover::InnerProver;
 pub use crate::inner::inner_verifier::InnerVerifier;
 
+pub use crate::range::linked_range_proof::LinkedRangeProof;
 pub use crate::range::poly_coefficients::PolyCoefficients;
 pub use crate::range::range_proof::RangeProof;
 pub use crate::range::range_prover::RangeProver;
 pub use crate::range::range_verifier::RangeVerifier;
 
+pub use crate::range_mpc::range_dealer::{
+    RangeDealer, RangeDealerAwaitingProofShares, RangeDealerAwaitingResponseShares,
+    RangeDealerAwaitingTCommitments,
+};
+pub use crate::range_mpc::range_mpc_messages::{
+    BitChallenge, BitCommitment, PolyChallenge, ProofShare, ResponseChallenge, ResponseShare,
+    TCommitment,
+};
+pub use crate::range_mpc::range_party::{
+    RangeParty, RangePartyAwaitingBitChallenge, RangePartyAwaitingPolyChallenge,
+    RangePartyAwaitingResponseChallenge,
+};
+
+pub use crate::weighted_inner::weighted_inner_proof::WeightedInnerProof;
+pub use crate::weighted_inner::weighted_inner_prover::WeightedInnerProver;
+pub use crate::weighted_inner::weighted_inner_verifier::WeightedInnerVerifier;
+
+pub use crate::range_plus::range_plus_proof::RangePlusProof;
+pub use crate::range_plus::range_plus_prover::RangePlusProver;
+pub use crate::range_plus::range_plus_verifier::RangePlusVerifier;
+
+pub use crate::bulletproofs::bulletproofs_proof::BulletproofsProof;
+pub use crate::bulletproofs::bulletproofs_prover::BulletproofsProver;
+pub use crate::bulletproofs::bulletproofs_verifier::BulletproofsVerifier;
+
+pub use crate::r1cs::constraint_system::ConstraintSystem;
+pub use crate::r1cs::linear_combination::{LinearCombination, Variable};
+pub use crate::r1cs::r1cs_proof::R1CSProof;
+pub use crate::r1cs::r1cs_prover::R1CSProver;
+pub use crate::r1cs::r1cs_verifier::R1CSVerifier;
+
+pub use crate::interval_range::interval_commitment_proof::IntervalCommitmentProof;
+pub use crate::interval_range::interval_range_prover::IntervalRangeProver;
+pub use crate::interval_range::interval_range_verifier::IntervalRangeVerifier;
+pub use crate::interval_range::range_bounds::RangeBounds;
+
 pub use crate::sigma_r::sigma_r_proof::SigmaRProof;
 pub use crate::sigma_r::sigma_r_prover::SigmaRProver;
 pub use crate::sigma_r::sigma_r_verifier::SigmaRVerifier;
 
+pub use crate::sigma_sk::registration_prover::RegistrationProver;
+pub use crate::sigma_sk::registration_verifier::RegistrationVerifier;
 pub use crate::sigma_sk::sigma_sk_proof::SigmaSKProof;
 pub use crate::sigma_sk::sigma_sk_prover::SigmaSKProver;
 pub use crate::sigma_sk::sigma_sk_verifier::SigmaSKVerifier;
 
+pub use crate::sigma_decrypt::decrypt_threshold_proof::DecryptThresholdProof;
+pub use crate::sigma_decrypt::decrypt_threshold_prover::DecryptThresholdProver;
+pub use crate::sigma_decrypt::decrypt_threshold_verifier::DecryptThresholdVerifier;
+pub use crate::sigma_decrypt::sigma_decrypt_proof::SigmaDecryptProof;
+pub use crate::sigma_decrypt::sigma_decrypt_prover::SigmaDecryptProver;
+pub use crate::sigma_decrypt::sigma_decrypt_verifier::SigmaDecryptVerifier;
+
 pub use crate::sigma_y::sigma_y_proof::SigmaYProof;
 pub use crate::sigma_y::sigma_y_prover::SigmaYProver;
 pub use crate::sigma_y::sigma_y_verifier::SigmaYVerifier;
@@ -50,11 +123,51 @@ pub use crate::sigma_ab::sigma_ab_proof::SigmaABProof;
 pub use crate::sigma_ab::sigma_ab_prover::SigmaABProver;
 pub use crate::sigma_ab::sigma_ab_verifier::SigmaABVerifier;
 
+pub use crate::sigma_commit::sigma_commit_proof::SigmaCommitProof;
+pub use crate::sigma_commit::sigma_commit_prover::SigmaCommitProver;
+pub use crate::sigma_commit::sigma_commit_verifier::SigmaCommitVerifier;
+
+pub use crate::zeromt::zeromt_plus_proof::ZeroMTPlusProof;
 pub use crate::zeromt::zeromt_proof::ZeroMTProof;
 pub use crate::zeromt::zeromt_prover::ZeroMTProver;
+pub use crate::zeromt::zeromt_statement::{ZeroMTParams, ZeroMTStatement, ZeroMTWitness};
 pub use crate::zeromt::zeromt_verifier::ZeroMTVerifier;
 
+pub use crate::burn::burn_proof::BurnProof;
+pub use crate::burn::burn_prover::BurnProver;
+pub use crate::burn::burn_verifier::BurnVerifier;
+
+pub use crate::key_rotation::key_rotation_proof::KeyRotationProof;
+pub use crate::key_rotation::key_rotation_prover::KeyRotationProver;
+pub use crate::key_rotation::key_rotation_verifier::KeyRotationVerifier;
+
+pub use crate::one_out_of_many::one_out_of_many_proof::OneOutOfManyProof;
+pub use crate::one_out_of_many::one_out_of_many_prover::OneOutOfManyProver;
+pub use crate::one_out_of_many::one_out_of_many_verifier::OneOutOfManyVerifier;
+
+pub use crate::sigma_anon::sigma_anon_proof::SigmaAnonProof;
+pub use crate::sigma_anon::sigma_anon_prover::SigmaAnonProver;
+pub use crate::sigma_anon::sigma_anon_verifier::SigmaAnonVerifier;
+
+pub use crate::anon_zeromt::anon_zeromt_proof::AnonZeroMTProof;
+pub use crate::anon_zeromt::anon_zeromt_prover::AnonZeroMTProver;
+pub use crate::anon_zeromt::anon_zeromt_statement::{AnonZeroMTStatement, AnonZeroMTWitness};
+pub use crate::anon_zeromt::anon_zeromt_verifier::AnonZeroMTVerifier;
+
+pub use crate::interactive::interactive_channel::{InteractiveChannel, InteractiveMessage};
+pub use crate::interactive::prover_channel::ProverChannel;
+pub use crate::interactive::replay_transcript::ReplayTranscript;
+pub use crate::interactive::verifier_channel::VerifierChannel;
+
+pub use crate::associated_data::AssociatedData;
+pub use crate::auditor::Auditor;
 pub use crate::csv_utils::CsvUtils;
 pub use crate::elgamal::ElGamal;
+#[cfg(feature = "extractor")]
+pub use crate::extractor::Extractor;
+pub use crate::ledger::Ledger;
+pub use crate::poly_commitment::PolyCommitment;
+#[cfg(feature = "extractor")]
+pub use crate::transcript::InjectedTranscript;
 pub use crate::transcript::TranscriptProtocol;
 pub use crate::utils::Utils;
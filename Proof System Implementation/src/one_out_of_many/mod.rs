@@ -0,0 +1,3 @@
+pub(crate) mod one_out_of_many_proof;
+pub(crate) mod one_out_of_many_prover;
+pub(crate) mod one_out_of_many_verifier;
@@ -0,0 +1,78 @@
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+
+use ark_serialize::*;
+
+#[derive(Debug, CanonicalDeserialize, CanonicalSerialize)]
+pub struct OneOutOfManyProof {
+    a: G1Point,
+    b: G1Point,
+    c: G1Point,
+    d: G1Point,
+    g_k_vec: Vec<G1Point>,
+    f_vec: Vec<ScalarField>,
+    z_a: ScalarField,
+    z_c: ScalarField,
+    z_d: ScalarField,
+}
+
+impl OneOutOfManyProof {
+    pub fn new(
+        a: G1Point,
+        b: G1Point,
+        c: G1Point,
+        d: G1Point,
+        g_k_vec: Vec<G1Point>,
+        f_vec: Vec<ScalarField>,
+        z_a: ScalarField,
+        z_c: ScalarField,
+        z_d: ScalarField,
+    ) -> Self {
+        OneOutOfManyProof {
+            a,
+            b,
+            c,
+            d,
+            g_k_vec,
+            f_vec,
+            z_a,
+            z_c,
+            z_d,
+        }
+    }
+
+    pub fn get_a(&self) -> &G1Point {
+        &self.a
+    }
+
+    pub fn get_b(&self) -> &G1Point {
+        &self.b
+    }
+
+    pub fn get_c(&self) -> &G1Point {
+        &self.c
+    }
+
+    pub fn get_d(&self) -> &G1Point {
+        &self.d
+    }
+
+    pub fn get_g_k_vec(&self) -> &Vec<G1Point> {
+        &self.g_k_vec
+    }
+
+    pub fn get_f_vec(&self) -> &Vec<ScalarField> {
+        &self.f_vec
+    }
+
+    pub fn get_z_a(&self) -> &ScalarField {
+        &self.z_a
+    }
+
+    pub fn get_z_c(&self) -> &ScalarField {
+        &self.z_c
+    }
+
+    pub fn get_z_d(&self) -> &ScalarField {
+        &self.z_d
+    }
+}
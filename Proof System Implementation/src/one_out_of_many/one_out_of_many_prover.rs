@@ -0,0 +1,197 @@
+use crate::errors::proof_error::throw;
+use crate::one_out_of_many::one_out_of_many_proof::OneOutOfManyProof;
+use crate::one_out_of_many::one_out_of_many_verifier::OneOutOfManyVerifier;
+use crate::transcript::TranscriptProtocol;
+use crate::utils::Utils;
+use crate::ProofError;
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{Field, One, PrimeField, Zero};
+use ark_std::rand::Rng;
+use merlin::Transcript;
+use std::io::Error;
+
+/// Groth-Kohlweiss one-out-of-many proof: the prover knows an index l and a scalar r
+/// such that the l-th point of `commitments` is equal to r * h.
+pub struct OneOutOfManyProver<'a> {
+    g_vec: &'a Vec<G1Point>,
+    h: &'a G1Point,
+    commitments: &'a Vec<G1Point>,
+    index: usize,
+    r: &'a ScalarField,
+}
+
+impl<'a> OneOutOfManyProver<'a> {
+    pub fn new(
+        g_vec: &'a Vec<G1Point>,
+        h: &'a G1Point,
+        commitments: &'a Vec<G1Point>,
+        index: usize,
+        r: &'a ScalarField,
+    ) -> Self {
+        OneOutOfManyProver {
+            g_vec,
+            h,
+            commitments,
+            index,
+            r,
+        }
+    }
+
+    pub fn generate_proof<R: Rng>(
+        &mut self,
+        rng: &mut R,
+        transcript: &mut Transcript,
+    ) -> Result<OneOutOfManyProof, Error> {
+        transcript.domain_sep(b"OneOutOfMany");
+
+        let ring_size: usize = self.commitments.len();
+        let bits_len: usize = ring_size.trailing_zeros() as usize;
+
+        if !ring_size.is_power_of_two()
+            || ring_size < 2
+            || self.g_vec.len() < bits_len
+            || self.index >= ring_size
+        {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+
+        let g_bits: Vec<G1Point> = self.g_vec[..bits_len].to_vec();
+        OneOutOfManyVerifier::new(self.g_vec, self.h, self.commitments)
+            .append_statement(&g_bits, transcript);
+
+        let l_bits: Vec<ScalarField> = Utils::number_to_be_bits_reversed(self.index, bits_len)
+            .iter()
+            .map(|bit: &u8| ScalarField::from(*bit))
+            .collect();
+
+        let r_a: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let r_b: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let r_c: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let r_d: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let a_vec: Vec<ScalarField> = Utils::get_n_random_scalars(bits_len, rng);
+
+        let c_vec: Vec<ScalarField> = a_vec
+            .iter()
+            .zip(l_bits.iter())
+            .map(|(a_j, l_j): (&ScalarField, &ScalarField)| {
+                *a_j * (ScalarField::one() - l_j.double())
+            })
+            .collect();
+        let d_vec: Vec<ScalarField> = a_vec
+            .iter()
+            .map(|a_j: &ScalarField| -a_j.square())
+            .collect();
+
+        let a_commitment: G1Point = self.vector_commitment(&g_bits, &a_vec, &r_a);
+        let b_commitment: G1Point = self.vector_commitment(&g_bits, &l_bits, &r_b);
+        let c_commitment: G1Point = self.vector_commitment(&g_bits, &c_vec, &r_c);
+        let d_commitment: G1Point = self.vector_commitment(&g_bits, &d_vec, &r_d);
+
+        let _result = transcript.append_point(b"A", &a_commitment);
+        let _result = transcript.append_point(b"B", &b_commitment);
+        let _result = transcript.append_point(b"C", &c_commitment);
+        let _result = transcript.append_point(b"D", &d_commitment);
+
+        let p_coefficients: Vec<Vec<ScalarField>> =
+            self.get_p_coefficients(ring_size, bits_len, &l_bits, &a_vec);
+
+        let rho_vec: Vec<ScalarField> = Utils::get_n_random_scalars(bits_len, rng);
+        let g_k_vec: Vec<G1Point> = (0..bits_len)
+            .map(|k: usize| {
+                let p_k: Vec<ScalarField> = p_coefficients
+                    .iter()
+                    .map(|p_i: &Vec<ScalarField>| p_i[k])
+                    .collect();
+
+                Utils::inner_product_point_scalar(self.commitments, &p_k).unwrap()
+                    + self.h.mul(rho_vec[k].into_repr()).into_affine()
+            })
+            .collect();
+
+        for g_k in g_k_vec.iter() {
+            let _result = transcript.append_point(b"G_k", g_k);
+        }
+
+        let x: ScalarField = transcript.challenge_scalar(b"x");
+
+        let f_vec: Vec<ScalarField> = l_bits
+            .iter()
+            .zip(a_vec.iter())
+            .map(|(l_j, a_j): (&ScalarField, &ScalarField)| (*l_j * x) + a_j)
+            .collect();
+
+        let z_a: ScalarField = (r_b * x) + r_a;
+        let z_c: ScalarField = (r_c * x) + r_d;
+        let z_d: ScalarField = (*self.r * x.pow([bits_len as u64]))
+            - (0..bits_len)
+                .map(|k: usize| rho_vec[k] * x.pow([k as u64]))
+                .sum::<ScalarField>();
+
+        for f_j in f_vec.iter() {
+            let _result = transcript.append_scalar(b"f_j", f_j);
+        }
+        let _result = transcript.append_scalar(b"z_A", &z_a);
+        let _result = transcript.append_scalar(b"z_C", &z_c);
+        let _result = transcript.append_scalar(b"z_d", &z_d);
+
+        Ok(OneOutOfManyProof::new(
+            a_commitment,
+            b_commitment,
+            c_commitment,
+            d_commitment,
+            g_k_vec,
+            f_vec,
+            z_a,
+            z_c,
+            z_d,
+        ))
+    }
+
+    fn vector_commitment(
+        &mut self,
+        g_bits: &Vec<G1Point>,
+        values: &Vec<ScalarField>,
+        blinding: &ScalarField,
+    ) -> G1Point {
+        Utils::inner_product_point_scalar(g_bits, values).unwrap()
+            + self.h.mul(blinding.into_repr()).into_affine()
+    }
+
+    /// Coefficients of p_i(x) = prod_j f_{j, i_j}(x), lowest degree first, for every ring index i.
+    fn get_p_coefficients(
+        &mut self,
+        ring_size: usize,
+        bits_len: usize,
+        l_bits: &Vec<ScalarField>,
+        a_vec: &Vec<ScalarField>,
+    ) -> Vec<Vec<ScalarField>> {
+        (0..ring_size)
+            .map(|i: usize| {
+                let i_bits: Vec<u8> = Utils::number_to_be_bits_reversed(i, bits_len);
+                let mut coefficients: Vec<ScalarField> = vec![ScalarField::zero(); bits_len + 1];
+                coefficients[0] = ScalarField::one();
+
+                for j in 0..bits_len {
+                    // f_{j,1}(x) = l_j * x + a_j and f_{j,0}(x) = (1 - l_j) * x - a_j
+                    let (constant, linear): (ScalarField, ScalarField) = if i_bits[j] == 1 {
+                        (a_vec[j], l_bits[j])
+                    } else {
+                        (-a_vec[j], ScalarField::one() - l_bits[j])
+                    };
+
+                    for k in (0..=(j + 1)).rev() {
+                        let shifted: ScalarField = if k > 0 {
+                            coefficients[k - 1] * linear
+                        } else {
+                            ScalarField::zero()
+                        };
+                        coefficients[k] = coefficients[k] * constant + shifted;
+                    }
+                }
+
+                coefficients
+            })
+            .collect()
+    }
+}
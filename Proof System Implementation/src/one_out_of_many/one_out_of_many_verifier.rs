@@ -0,0 +1,120 @@
+use crate::transcript::TranscriptProtocol;
+use crate::ProofError;
+use crate::{
+    errors::proof_error::throw, one_out_of_many::one_out_of_many_proof::OneOutOfManyProof, Utils,
+};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use merlin::Transcript;
+use std::io::Error;
+
+pub struct OneOutOfManyVerifier<'a> {
+    g_vec: &'a Vec<G1Point>,
+    h: &'a G1Point,
+    commitments: &'a Vec<G1Point>,
+}
+
+impl<'a> OneOutOfManyVerifier<'a> {
+    pub fn new(g_vec: &'a Vec<G1Point>, h: &'a G1Point, commitments: &'a Vec<G1Point>) -> Self {
+        OneOutOfManyVerifier {
+            g_vec,
+            h,
+            commitments,
+        }
+    }
+
+    /// Appends the statement (g_0, ..., g_{n-1}, h, c_0, ..., c_{N-1}) to the transcript.
+    pub(crate) fn append_statement(&self, g_bits: &Vec<G1Point>, transcript: &mut Transcript) {
+        for g_j in g_bits.iter() {
+            let _result = transcript.append_point(b"g", g_j);
+        }
+        let _result = transcript.append_point(b"h", self.h);
+        for c_i in self.commitments.iter() {
+            let _result = transcript.append_point(b"c", c_i);
+        }
+    }
+
+    pub fn verify_proof(
+        &mut self,
+        proof: &OneOutOfManyProof,
+        transcript: &mut Transcript,
+    ) -> Result<(), Error> {
+        transcript.domain_sep(b"OneOutOfMany");
+
+        let ring_size: usize = self.commitments.len();
+        let bits_len: usize = ring_size.trailing_zeros() as usize;
+
+        if !ring_size.is_power_of_two()
+            || ring_size < 2
+            || self.g_vec.len() < bits_len
+            || proof.get_f_vec().len() != bits_len
+            || proof.get_g_k_vec().len() != bits_len
+        {
+            return Err(throw(ProofError::ProofValidationError));
+        }
+
+        let g_bits: Vec<G1Point> = self.g_vec[..bits_len].to_vec();
+        self.append_statement(&g_bits, transcript);
+
+        let _result = transcript.append_point(b"A", proof.get_a());
+        let _result = transcript.append_point(b"B", proof.get_b());
+        let _result = transcript.append_point(b"C", proof.get_c());
+        let _result = transcript.append_point(b"D", proof.get_d());
+
+        for g_k in proof.get_g_k_vec().iter() {
+            let _result = transcript.append_point(b"G_k", g_k);
+        }
+
+        let x: ScalarField = transcript.challenge_scalar(b"x");
+
+        for f_j in proof.get_f_vec().iter() {
+            let _result = transcript.append_scalar(b"f_j", f_j);
+        }
+        let _result = transcript.append_scalar(b"z_A", proof.get_z_a());
+        let _result = transcript.append_scalar(b"z_C", proof.get_z_c());
+        let _result = transcript.append_scalar(b"z_d", proof.get_z_d());
+
+        let f_vec: &Vec<ScalarField> = proof.get_f_vec();
+
+        // A + x * B = Com(f_0, ..., f_{n-1}; z_A)
+        let left_bits: G1Point = *proof.get_a() + proof.get_b().mul(x.into_repr()).into_affine();
+        let right_bits: G1Point = Utils::inner_product_point_scalar(&g_bits, f_vec).unwrap()
+            + self.h.mul(proof.get_z_a().into_repr()).into_affine();
+
+        // x * C + D = Com(f_0 * (x - f_0), ..., f_{n-1} * (x - f_{n-1}); z_C)
+        let f_x_f_vec: Vec<ScalarField> = f_vec
+            .iter()
+            .map(|f_j: &ScalarField| *f_j * (x - f_j))
+            .collect();
+        let left_square: G1Point = proof.get_c().mul(x.into_repr()).into_affine() + *proof.get_d();
+        let right_square: G1Point = Utils::inner_product_point_scalar(&g_bits, &f_x_f_vec).unwrap()
+            + self.h.mul(proof.get_z_c().into_repr()).into_affine();
+
+        // sum_i p_i(x) * c_i - sum_k x^k * G_k = z_d * h
+        let p_evaluations: Vec<ScalarField> = (0..ring_size)
+            .map(|i: usize| {
+                Utils::number_to_be_bits_reversed(i, bits_len)
+                    .iter()
+                    .zip(f_vec.iter())
+                    .map(|(bit, f_j): (&u8, &ScalarField)| if *bit == 1 { *f_j } else { x - f_j })
+                    .product::<ScalarField>()
+            })
+            .collect();
+
+        let left_ring: G1Point =
+            Utils::inner_product_point_scalar(self.commitments, &p_evaluations).unwrap()
+                + -Utils::inner_product_point_scalar(
+                    proof.get_g_k_vec(),
+                    &Utils::generate_scalar_exp_vector(bits_len, &x),
+                )
+                .unwrap();
+        let right_ring: G1Point = self.h.mul(proof.get_z_d().into_repr()).into_affine();
+
+        if left_bits == right_bits && left_square == right_square && left_ring == right_ring {
+            Ok(())
+        } else {
+            Err(throw(ProofError::ProofValidationError))
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use crate::errors::proof_error::throw as proof_throw;
+use crate::errors::utils_error::throw;
+use crate::transcript::TranscriptProtocol;
+use crate::{InnerHaloProof, InnerHaloProver, InnerHaloVerifier, ProofError, Utils, UtilsError};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{PrimeField, Zero};
+use std::io::Error;
+
+/// Polynomial commitment scheme on top of the InnerHalo argument. A polynomial
+/// t(X) = t_0 + t_1 * X + ... + t_d * X^d is committed as T = <t_vec, g_vec> + r * h,
+/// and an opening at x is an InnerHalo proof of t(x) = <t_vec, (1, x, ..., x^d)>.
+/// The length of `g_vec` fixes the maximum degree: shorter polynomials are padded with zeros.
+pub struct PolyCommitment<'a> {
+    g_vec: &'a Vec<G1Point>,
+    h: &'a G1Point,
+    u: &'a G1Point,
+}
+
+impl<'a> PolyCommitment<'a> {
+    pub fn new(g_vec: &'a Vec<G1Point>, h: &'a G1Point, u: &'a G1Point) -> Self {
+        PolyCommitment { g_vec, h, u }
+    }
+
+    /// T = <t_vec, g_vec> + blind * h
+    pub fn commit(&self, coeffs: &Vec<ScalarField>, blind: &ScalarField) -> Result<G1Point, Error> {
+        let t_vec: Vec<ScalarField> = self.pad_coefficients(coeffs)?;
+
+        Ok(Utils::inner_product_point_scalar(self.g_vec, &t_vec)?
+            + self.h.mul(blind.into_repr()).into_affine())
+    }
+
+    /// Returns t(point) and the proof that the committed polynomial evaluates to it.
+    pub fn open<T: TranscriptProtocol>(
+        &self,
+        coeffs: &Vec<ScalarField>,
+        blind: &ScalarField,
+        commitment: &G1Point,
+        point: &ScalarField,
+        transcript: &mut T,
+    ) -> Result<(ScalarField, InnerHaloProof), Error> {
+        let t_vec: Vec<ScalarField> = self.pad_coefficients(coeffs)?;
+        let b_vec: Vec<ScalarField> = Utils::generate_scalar_exp_vector(self.g_vec.len(), point);
+        let value: ScalarField = Utils::inner_product_scalar_scalar(&t_vec, &b_vec)?;
+
+        transcript.domain_sep(b"PolyCommitment");
+        self.append_parameters(transcript);
+        let _result = transcript.append_point(b"T", commitment);
+        let _result = transcript.append_scalar(b"x", point);
+        let _result = transcript.append_scalar(b"t_hat", &value);
+
+        let proof: InnerHaloProof = InnerHaloProver::new(
+            self.g_vec, self.h, commitment, blind, &value, &t_vec, &b_vec, self.u,
+        )
+        .generate_proof(transcript);
+
+        Ok((value, proof))
+    }
+
+    pub fn verify<T: TranscriptProtocol>(
+        &self,
+        commitment: &G1Point,
+        point: &ScalarField,
+        value: &ScalarField,
+        proof: &InnerHaloProof,
+        transcript: &mut T,
+    ) -> Result<(), Error> {
+        let b_vec: Vec<ScalarField> = Utils::generate_scalar_exp_vector(self.g_vec.len(), point);
+
+        transcript.domain_sep(b"PolyCommitment");
+        self.append_parameters(transcript);
+        let _result = transcript.append_point(b"T", commitment);
+        let _result = transcript.append_scalar(b"x", point);
+        let _result = transcript.append_scalar(b"t_hat", value);
+
+        InnerHaloVerifier::new(self.g_vec, &b_vec, self.h, commitment, value, self.u)
+            .verify_proof(proof, transcript)
+    }
+
+    /// Opens several polynomials at the same point with a single proof. The commitments and
+    /// the evaluations are combined with the powers of a challenge xi, so that
+    /// sum_i xi^i * T_i is a commitment to sum_i xi^i * t_i(X), which evaluates to sum_i xi^i * t_i(x).
+    pub fn batch_open<T: TranscriptProtocol>(
+        &self,
+        coeffs_vec: &Vec<Vec<ScalarField>>,
+        blinds: &Vec<ScalarField>,
+        commitments: &Vec<G1Point>,
+        point: &ScalarField,
+        transcript: &mut T,
+    ) -> Result<(Vec<ScalarField>, InnerHaloProof), Error> {
+        if coeffs_vec.is_empty()
+            || coeffs_vec.len() != blinds.len()
+            || coeffs_vec.len() != commitments.len()
+        {
+            return Err(throw(UtilsError::MathError));
+        }
+
+        let b_vec: Vec<ScalarField> = Utils::generate_scalar_exp_vector(self.g_vec.len(), point);
+        let mut t_vecs: Vec<Vec<ScalarField>> = Vec::new();
+        let mut values: Vec<ScalarField> = Vec::new();
+        for coeffs in coeffs_vec.iter() {
+            let t_vec: Vec<ScalarField> = self.pad_coefficients(coeffs)?;
+            values.push(Utils::inner_product_scalar_scalar(&t_vec, &b_vec)?);
+            t_vecs.push(t_vec);
+        }
+
+        let xi_vec: Vec<ScalarField> =
+            self.batch_challenges(commitments, point, &values, transcript);
+
+        let mut t_vec: Vec<ScalarField> = vec![ScalarField::zero(); self.g_vec.len()];
+        for (xi, t_i) in xi_vec.iter().zip(t_vecs.iter()) {
+            t_vec = Utils::sum_scalar_scalar(&t_vec, &Utils::product_scalar(xi, t_i))?;
+        }
+        let blind: ScalarField = Utils::inner_product_scalar_scalar(&xi_vec, blinds)?;
+        let value: ScalarField = Utils::inner_product_scalar_scalar(&xi_vec, &values)?;
+        let commitment: G1Point = Utils::inner_product_point_scalar(commitments, &xi_vec)?;
+
+        let proof: InnerHaloProof = InnerHaloProver::new(
+            self.g_vec,
+            self.h,
+            &commitment,
+            &blind,
+            &value,
+            &t_vec,
+            &b_vec,
+            self.u,
+        )
+        .generate_proof(transcript);
+
+        Ok((values, proof))
+    }
+
+    pub fn batch_verify<T: TranscriptProtocol>(
+        &self,
+        commitments: &Vec<G1Point>,
+        point: &ScalarField,
+        values: &Vec<ScalarField>,
+        proof: &InnerHaloProof,
+        transcript: &mut T,
+    ) -> Result<(), Error> {
+        if commitments.is_empty() || commitments.len() != values.len() {
+            return Err(proof_throw(ProofError::ProofValidationError));
+        }
+
+        let b_vec: Vec<ScalarField> = Utils::generate_scalar_exp_vector(self.g_vec.len(), point);
+
+        let xi_vec: Vec<ScalarField> =
+            self.batch_challenges(commitments, point, values, transcript);
+        let value: ScalarField = Utils::inner_product_scalar_scalar(&xi_vec, values)?;
+        let commitment: G1Point = Utils::inner_product_point_scalar(commitments, &xi_vec)?;
+
+        InnerHaloVerifier::new(self.g_vec, &b_vec, self.h, &commitment, &value, self.u)
+            .verify_proof(proof, transcript)
+    }
+
+    /// (1, xi, xi^2, ...) after absorbing every commitment and claimed evaluation
+    fn batch_challenges<T: TranscriptProtocol>(
+        &self,
+        commitments: &Vec<G1Point>,
+        point: &ScalarField,
+        values: &Vec<ScalarField>,
+        transcript: &mut T,
+    ) -> Vec<ScalarField> {
+        transcript.domain_sep(b"PolyCommitmentBatch");
+        self.append_parameters(transcript);
+        for (commitment, value) in commitments.iter().zip(values.iter()) {
+            let _result = transcript.append_point(b"T", commitment);
+            let _result = transcript.append_scalar(b"t_hat", value);
+        }
+        let _result = transcript.append_scalar(b"x", point);
+        let xi: ScalarField = transcript.challenge_scalar(b"xi");
+
+        Utils::generate_scalar_exp_vector(commitments.len(), &xi)
+    }
+
+    /// Appends the maximum number of coefficients and the generators g_vec, h and u.
+    fn append_parameters<T: TranscriptProtocol>(&self, transcript: &mut T) {
+        let _result = transcript.append_scalar(b"n", &ScalarField::from(self.g_vec.len() as u64));
+        for g_i in self.g_vec.iter() {
+            let _result = transcript.append_point(b"g_i", g_i);
+        }
+        let _result = transcript.append_point(b"h", self.h);
+        let _result = transcript.append_point(b"u", self.u);
+    }
+
+    fn pad_coefficients(&self, coeffs: &Vec<ScalarField>) -> Result<Vec<ScalarField>, Error> {
+        if coeffs.is_empty() || coeffs.len() > self.g_vec.len() {
+            return Err(throw(UtilsError::MathError));
+        }
+
+        let mut t_vec: Vec<ScalarField> = coeffs.clone();
+        t_vec.resize(self.g_vec.len(), ScalarField::zero());
+
+        Ok(t_vec)
+    }
+}
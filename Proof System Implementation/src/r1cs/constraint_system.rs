@@ -0,0 +1,117 @@
+use crate::errors::proof_error::throw;
+use crate::ProofError;
+use ark_bn254::Fr as ScalarField;
+use ark_ff::{One, Zero};
+use std::io::Error;
+
+use super::linear_combination::{LinearCombination, Variable};
+
+/// Gadgets are written against this trait, so that the same code builds the circuit on
+/// the prover side, where assignments are known, and on the verifier side, where they are not.
+pub trait ConstraintSystem {
+    /// Adds a multiplication gate fed by two linear combinations and returns its left, right
+    /// and output wires.
+    fn multiply(
+        &mut self,
+        left: LinearCombination,
+        right: LinearCombination,
+    ) -> Result<(Variable, Variable, Variable), Error>;
+
+    /// Adds a multiplication gate with free inputs. The prover must give their assignment,
+    /// the verifier passes `None`.
+    fn allocate_multiplier(
+        &mut self,
+        assignment: Option<(ScalarField, ScalarField)>,
+    ) -> Result<(Variable, Variable, Variable), Error>;
+
+    /// Constrains the linear combination to be zero.
+    fn constrain(&mut self, lc: LinearCombination);
+
+    /// Constrains the linear combination to lie in [0, 2^n) by decomposing it into n bits,
+    /// each bit costing one multiplier. `value` is the assignment of `lc` on the prover side.
+    fn constrain_range(
+        &mut self,
+        lc: LinearCombination,
+        value: Option<u64>,
+        n: usize,
+    ) -> Result<(), Error> {
+        if n == 0 || n > 64 {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+        if let Some(v) = value {
+            if n < 64 && v >> n != 0 {
+                return Err(throw(ProofError::OutOfBoundsError));
+            }
+        }
+
+        let mut bits_sum: LinearCombination = lc;
+        let mut exp_2: ScalarField = ScalarField::one();
+        for i in 0..n {
+            // (1 - b) * b = 0, so b is a bit
+            let assignment: Option<(ScalarField, ScalarField)> = value.map(|v: u64| {
+                let bit: u64 = (v >> i) & 1;
+                (ScalarField::from(1 - bit), ScalarField::from(bit))
+            });
+            let (a, b, o): (Variable, Variable, Variable) = self.allocate_multiplier(assignment)?;
+            self.constrain(o.into());
+            self.constrain(a + b - Variable::One);
+
+            bits_sum = bits_sum - b * exp_2;
+            exp_2 = exp_2 + exp_2;
+        }
+        self.constrain(bits_sum);
+
+        Ok(())
+    }
+}
+
+/// Constraints folded with the powers of the challenge z: constraint q is weighted by
+/// z^(q + 1), so that all of them hold iff
+/// <w_l, a_l> + <w_r, a_r> + <w_o, a_o> + <w_v, v> + w_c = 0 for a random z.
+pub(crate) struct FlattenedConstraints {
+    pub(crate) w_l: Vec<ScalarField>,
+    pub(crate) w_r: Vec<ScalarField>,
+    pub(crate) w_o: Vec<ScalarField>,
+    pub(crate) w_v: Vec<ScalarField>,
+    pub(crate) w_c: ScalarField,
+}
+
+impl FlattenedConstraints {
+    pub(crate) fn new(
+        constraints: &Vec<LinearCombination>,
+        n: usize,
+        m: usize,
+        z: &ScalarField,
+    ) -> Result<Self, Error> {
+        let mut w_l: Vec<ScalarField> = vec![ScalarField::zero(); n];
+        let mut w_r: Vec<ScalarField> = vec![ScalarField::zero(); n];
+        let mut w_o: Vec<ScalarField> = vec![ScalarField::zero(); n];
+        let mut w_v: Vec<ScalarField> = vec![ScalarField::zero(); m];
+        let mut w_c: ScalarField = ScalarField::zero();
+
+        let mut z_exp: ScalarField = *z;
+        for lc in constraints.iter() {
+            for (variable, coefficient) in lc.get_terms().iter() {
+                let weight: ScalarField = z_exp * coefficient;
+                let entry: &mut ScalarField = match *variable {
+                    Variable::MultiplierLeft(i) if i < n => &mut w_l[i],
+                    Variable::MultiplierRight(i) if i < n => &mut w_r[i],
+                    Variable::MultiplierOutput(i) if i < n => &mut w_o[i],
+                    Variable::Committed(j) if j < m => &mut w_v[j],
+                    Variable::One => &mut w_c,
+                    _ => return Err(throw(ProofError::OutOfBoundsError)),
+                };
+                *entry += weight;
+            }
+            z_exp *= z;
+        }
+
+        Ok(FlattenedConstraints {
+            w_l,
+            w_r,
+            w_o,
+            w_v,
+            w_c,
+        })
+    }
+}
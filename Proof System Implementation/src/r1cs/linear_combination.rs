@@ -0,0 +1,116 @@
+use ark_bn254::Fr as ScalarField;
+use ark_ff::One;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A variable of the constraint system: a committed input, one of the three wires of a
+/// multiplication gate, or the constant one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Variable {
+    Committed(usize),
+    MultiplierLeft(usize),
+    MultiplierRight(usize),
+    MultiplierOutput(usize),
+    One,
+}
+
+/// Sum of variables weighted by scalars, constrained to be zero by `ConstraintSystem::constrain`.
+#[derive(Clone, Debug, Default)]
+pub struct LinearCombination {
+    terms: Vec<(Variable, ScalarField)>,
+}
+
+impl LinearCombination {
+    pub fn new(terms: Vec<(Variable, ScalarField)>) -> Self {
+        LinearCombination { terms }
+    }
+
+    pub fn get_terms(&self) -> &Vec<(Variable, ScalarField)> {
+        &self.terms
+    }
+}
+
+impl From<Variable> for LinearCombination {
+    fn from(variable: Variable) -> Self {
+        LinearCombination::new(vec![(variable, ScalarField::one())])
+    }
+}
+
+impl From<ScalarField> for LinearCombination {
+    fn from(constant: ScalarField) -> Self {
+        LinearCombination::new(vec![(Variable::One, constant)])
+    }
+}
+
+impl<L: Into<LinearCombination>> Add<L> for LinearCombination {
+    type Output = LinearCombination;
+
+    fn add(mut self, rhs: L) -> LinearCombination {
+        self.terms.extend(rhs.into().terms);
+        self
+    }
+}
+
+impl<L: Into<LinearCombination>> Sub<L> for LinearCombination {
+    type Output = LinearCombination;
+
+    fn sub(mut self, rhs: L) -> LinearCombination {
+        self.terms.extend(
+            rhs.into()
+                .terms
+                .into_iter()
+                .map(|(variable, coefficient): (Variable, ScalarField)| (variable, -coefficient)),
+        );
+        self
+    }
+}
+
+impl Mul<ScalarField> for LinearCombination {
+    type Output = LinearCombination;
+
+    fn mul(mut self, rhs: ScalarField) -> LinearCombination {
+        for (_variable, coefficient) in self.terms.iter_mut() {
+            *coefficient *= rhs;
+        }
+        self
+    }
+}
+
+impl Neg for LinearCombination {
+    type Output = LinearCombination;
+
+    fn neg(self) -> LinearCombination {
+        self * -ScalarField::one()
+    }
+}
+
+impl<L: Into<LinearCombination>> Add<L> for Variable {
+    type Output = LinearCombination;
+
+    fn add(self, rhs: L) -> LinearCombination {
+        LinearCombination::from(self) + rhs
+    }
+}
+
+impl<L: Into<LinearCombination>> Sub<L> for Variable {
+    type Output = LinearCombination;
+
+    fn sub(self, rhs: L) -> LinearCombination {
+        LinearCombination::from(self) - rhs
+    }
+}
+
+impl Mul<ScalarField> for Variable {
+    type Output = LinearCombination;
+
+    fn mul(self, rhs: ScalarField) -> LinearCombination {
+        LinearCombination::new(vec![(self, rhs)])
+    }
+}
+
+impl Neg for Variable {
+    type Output = LinearCombination;
+
+    fn neg(self) -> LinearCombination {
+        -LinearCombination::from(self)
+    }
+}
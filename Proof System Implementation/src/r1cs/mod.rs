@@ -0,0 +1,5 @@
+pub(crate) mod constraint_system;
+pub(crate) mod linear_combination;
+pub(crate) mod r1cs_proof;
+pub(crate) mod r1cs_prover;
+pub(crate) mod r1cs_verifier;
@@ -0,0 +1,73 @@
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_serialize::*;
+
+use crate::InnerProof;
+
+#[derive(Debug, CanonicalDeserialize, CanonicalSerialize)]
+pub struct R1CSProof {
+    a_i: G1Point,
+    a_o: G1Point,
+    s: G1Point,
+    /// T1, T3, T4, T5, T6, t_2 is not committed since the verifier derives it
+    t_commitments: Vec<G1Point>,
+    t_hat: ScalarField,
+    tau_x: ScalarField,
+    mu: ScalarField,
+    inner_proof: InnerProof,
+}
+
+impl R1CSProof {
+    pub fn new(
+        a_i: G1Point,
+        a_o: G1Point,
+        s: G1Point,
+        t_commitments: Vec<G1Point>,
+        t_hat: ScalarField,
+        tau_x: ScalarField,
+        mu: ScalarField,
+        inner_proof: InnerProof,
+    ) -> Self {
+        R1CSProof {
+            a_i,
+            a_o,
+            s,
+            t_commitments,
+            t_hat,
+            tau_x,
+            mu,
+            inner_proof,
+        }
+    }
+
+    pub fn get_a_i(&self) -> &G1Point {
+        &self.a_i
+    }
+
+    pub fn get_a_o(&self) -> &G1Point {
+        &self.a_o
+    }
+
+    pub fn get_s(&self) -> &G1Point {
+        &self.s
+    }
+
+    pub fn get_t_commitments(&self) -> &Vec<G1Point> {
+        &self.t_commitments
+    }
+
+    pub fn get_t_hat(&self) -> &ScalarField {
+        &self.t_hat
+    }
+
+    pub fn get_tau_x(&self) -> &ScalarField {
+        &self.tau_x
+    }
+
+    pub fn get_mu(&self) -> &ScalarField {
+        &self.mu
+    }
+
+    pub fn get_inner_proof(&self) -> &InnerProof {
+        &self.inner_proof
+    }
+}
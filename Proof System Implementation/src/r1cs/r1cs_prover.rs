@@ -0,0 +1,299 @@
+use crate::errors::proof_error::throw;
+use crate::transcript::TranscriptProtocol;
+use crate::utils::Utils;
+use crate::{InnerProof, InnerProver, ProofError, R1CSVerifier};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{Field, One, PrimeField, Zero};
+use ark_std::rand::Rng;
+use std::io::Error;
+
+use super::constraint_system::{ConstraintSystem, FlattenedConstraints};
+use super::linear_combination::{LinearCombination, Variable};
+use super::r1cs_proof::R1CSProof;
+
+/// Bulletproofs arithmetic-circuit prover. The circuit is built through `ConstraintSystem`
+/// on top of inputs committed as V_j = v_j * g + gamma_j * h, and proved with the
+/// multiplier wires committed against the first n elements of `g_vec` and `h_vec`.
+pub struct R1CSProver<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    g_vec: &'a Vec<G1Point>,
+    h_vec: &'a Vec<G1Point>,
+    u: &'a G1Point,
+    v: Vec<ScalarField>,
+    gammas: Vec<ScalarField>,
+    commitments: Vec<G1Point>,
+    a_l: Vec<ScalarField>,
+    a_r: Vec<ScalarField>,
+    a_o: Vec<ScalarField>,
+    constraints: Vec<LinearCombination>,
+}
+
+impl<'a> R1CSProver<'a> {
+    pub fn new(
+        g: &'a G1Point,
+        h: &'a G1Point,
+        g_vec: &'a Vec<G1Point>,
+        h_vec: &'a Vec<G1Point>,
+        u: &'a G1Point,
+    ) -> Self {
+        R1CSProver {
+            g,
+            h,
+            g_vec,
+            h_vec,
+            u,
+            v: Vec::new(),
+            gammas: Vec::new(),
+            commitments: Vec::new(),
+            a_l: Vec::new(),
+            a_r: Vec::new(),
+            a_o: Vec::new(),
+            constraints: Vec::new(),
+        }
+    }
+
+    /// Commits to an input of the circuit, V = v * g + gamma * h, and returns the
+    /// commitment together with the variable standing for v.
+    pub fn commit(&mut self, v: ScalarField, gamma: ScalarField) -> (G1Point, Variable) {
+        let commitment: G1Point = Utils::pedersen_commitment(&v, self.g, &gamma, self.h);
+        self.v.push(v);
+        self.gammas.push(gamma);
+        self.commitments.push(commitment);
+
+        (commitment, Variable::Committed(self.v.len() - 1))
+    }
+
+    pub fn get_commitments(&self) -> &Vec<G1Point> {
+        &self.commitments
+    }
+
+    fn evaluate(&self, lc: &LinearCombination) -> Result<ScalarField, Error> {
+        let one: ScalarField = ScalarField::one();
+        let mut result: ScalarField = ScalarField::zero();
+        for (variable, coefficient) in lc.get_terms().iter() {
+            let value: &ScalarField = match *variable {
+                Variable::Committed(j) => self.v.get(j),
+                Variable::MultiplierLeft(i) => self.a_l.get(i),
+                Variable::MultiplierRight(i) => self.a_r.get(i),
+                Variable::MultiplierOutput(i) => self.a_o.get(i),
+                Variable::One => Some(&one),
+            }
+            .ok_or_else(|| throw(ProofError::OutOfBoundsError))?;
+            result += *value * coefficient;
+        }
+
+        Ok(result)
+    }
+
+    /// Proves that the assignment satisfies every multiplication gate and linear constraint.
+    pub fn generate_proof<R: Rng, T: TranscriptProtocol>(
+        &mut self,
+        rng: &mut R,
+        transcript: &mut T,
+    ) -> Result<R1CSProof, Error> {
+        // The inner-product argument needs at least one multiplier
+        if self.a_l.is_empty() {
+            let _wires =
+                self.allocate_multiplier(Some((ScalarField::zero(), ScalarField::zero())))?;
+        }
+        for lc in self.constraints.iter() {
+            if !self.evaluate(lc)?.is_zero() {
+                return Err(throw(ProofError::ProofValidationError));
+            }
+        }
+
+        let n: usize = self.a_l.len();
+        let m: usize = self.v.len();
+        if self.g_vec.len() < n || self.h_vec.len() < n {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+        let g_vec: Vec<G1Point> = self.g_vec[..n].to_vec();
+        let h_vec: Vec<G1Point> = self.h_vec[..n].to_vec();
+
+        transcript.domain_sep(b"R1CSProof");
+        R1CSVerifier::new(self.g, self.h, self.g_vec, self.h_vec, self.u).append_statement(
+            &self.commitments,
+            &g_vec,
+            &h_vec,
+            transcript,
+        );
+
+        let alpha: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let beta: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let rho: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let s_l: Vec<ScalarField> = Utils::get_n_random_scalars(n, rng);
+        let s_r: Vec<ScalarField> = Utils::get_n_random_scalars(n, rng);
+
+        let a_i_commitment: G1Point = Utils::pedersen_vector_commitment(
+            &alpha, self.h, &self.a_l, &g_vec, &self.a_r, &h_vec,
+        )?;
+        let mut points: Vec<G1Point> = vec![*self.h];
+        let mut scalars: Vec<ScalarField> = vec![beta];
+        points.extend(g_vec.iter());
+        scalars.extend(self.a_o.iter());
+        let a_o_commitment: G1Point = Utils::multiscalar_mul(&points, &scalars)?;
+        let s_commitment: G1Point =
+            Utils::pedersen_vector_commitment(&rho, self.h, &s_l, &g_vec, &s_r, &h_vec)?;
+
+        let _result = transcript.append_point(b"A_I", &a_i_commitment);
+        let _result = transcript.append_point(b"A_O", &a_o_commitment);
+        let _result = transcript.append_point(b"S", &s_commitment);
+
+        let y: ScalarField = transcript.challenge_scalar(b"y");
+        let z: ScalarField = transcript.challenge_scalar(b"z");
+
+        let weights: FlattenedConstraints = FlattenedConstraints::new(&self.constraints, n, m, &z)?;
+
+        let y_vec: Vec<ScalarField> = Utils::generate_scalar_exp_vector(n, &y);
+        let y_inv_vec: Vec<ScalarField> = Utils::batch_inverse(&y_vec);
+        let zero_vec: Vec<ScalarField> = vec![ScalarField::zero(); n];
+
+        // l(X) = (a_l + y^-n o w_r) * X + a_o * X^2 + s_l * X^3
+        // r(X) = w_o - y^n + (y^n o a_r + w_l) * X + y^n o s_r * X^3
+        let l_coefficients: Vec<Vec<ScalarField>> = vec![
+            zero_vec.clone(),
+            Utils::sum_scalar_scalar(
+                &self.a_l,
+                &Utils::hadamard_product_scalar_scalar(&y_inv_vec, &weights.w_r)?,
+            )?,
+            self.a_o.clone(),
+            s_l,
+        ];
+        let r_coefficients: Vec<Vec<ScalarField>> = vec![
+            Utils::sum_scalar_scalar(
+                &weights.w_o,
+                &Utils::product_scalar(&-ScalarField::one(), &y_vec),
+            )?,
+            Utils::sum_scalar_scalar(
+                &Utils::hadamard_product_scalar_scalar(&y_vec, &self.a_r)?,
+                &weights.w_l,
+            )?,
+            zero_vec,
+            Utils::hadamard_product_scalar_scalar(&y_vec, &s_r)?,
+        ];
+
+        // t(X) = <l(X), r(X)>, its coefficient t_2 is the one the constraints pin down
+        let mut t_coefficients: Vec<ScalarField> = vec![ScalarField::zero(); 7];
+        for (i, l_i) in l_coefficients.iter().enumerate() {
+            for (j, r_j) in r_coefficients.iter().enumerate() {
+                t_coefficients[i + j] += Utils::inner_product_scalar_scalar(l_i, r_j)?;
+            }
+        }
+
+        let t_indexes: [usize; 5] = [1, 3, 4, 5, 6];
+        let taus: Vec<ScalarField> = Utils::get_n_random_scalars(t_indexes.len(), rng);
+        let t_commitments: Vec<G1Point> = t_indexes
+            .iter()
+            .zip(taus.iter())
+            .map(|(k, tau): (&usize, &ScalarField)| {
+                Utils::pedersen_commitment(&t_coefficients[*k], self.g, tau, self.h)
+            })
+            .collect();
+        for t in t_commitments.iter() {
+            let _result = transcript.append_point(b"T", t);
+        }
+
+        let x: ScalarField = transcript.challenge_scalar(b"x");
+
+        let evaluate_poly =
+            |coefficients: &Vec<Vec<ScalarField>>| -> Result<Vec<ScalarField>, Error> {
+                let mut result: Vec<ScalarField> = vec![ScalarField::zero(); n];
+                for (k, coefficient) in coefficients.iter().enumerate() {
+                    result = Utils::sum_scalar_scalar(
+                        &result,
+                        &Utils::product_scalar(&x.pow([k as u64]), coefficient),
+                    )?;
+                }
+                Ok(result)
+            };
+        let l_poly_vec: Vec<ScalarField> = evaluate_poly(&l_coefficients)?;
+        let r_poly_vec: Vec<ScalarField> = evaluate_poly(&r_coefficients)?;
+        let t_hat: ScalarField = Utils::inner_product_scalar_scalar(&l_poly_vec, &r_poly_vec)?;
+
+        // t_2 * g is matched by -sum_j w_v_j * V_j, whose blinding is -<w_v, gamma>
+        let tau_x: ScalarField = t_indexes
+            .iter()
+            .zip(taus.iter())
+            .map(|(k, tau): (&usize, &ScalarField)| x.pow([*k as u64]) * tau)
+            .sum::<ScalarField>()
+            - x * x * Utils::inner_product_scalar_scalar(&weights.w_v, &self.gammas)?;
+        let mu: ScalarField = alpha * x + beta * x * x + rho * x * x * x;
+
+        let _result = transcript.append_scalar(b"t_hat", &t_hat);
+        let _result = transcript.append_scalar(b"tau_x", &tau_x);
+        let _result = transcript.append_scalar(b"mu", &mu);
+
+        // P = <l, g_vec> + <r, h'_vec> with h'_i = y^-i * h_i
+        let h_first_vec: Vec<G1Point> = h_vec
+            .iter()
+            .zip(y_inv_vec.iter())
+            .map(|(h, y_inv): (&G1Point, &ScalarField)| h.mul(y_inv.into_repr()).into_affine())
+            .collect();
+        let p: G1Point = Utils::multiscalar_mul(&g_vec, &l_poly_vec)?
+            + Utils::multiscalar_mul(&h_first_vec, &r_poly_vec)?;
+
+        let inner_proof: InnerProof = InnerProver::new(
+            &g_vec,
+            &h_first_vec,
+            &p,
+            &t_hat,
+            &l_poly_vec,
+            &r_poly_vec,
+            self.u,
+        )
+        .generate_proof(transcript)?;
+
+        Ok(R1CSProof::new(
+            a_i_commitment,
+            a_o_commitment,
+            s_commitment,
+            t_commitments,
+            t_hat,
+            tau_x,
+            mu,
+            inner_proof,
+        ))
+    }
+}
+
+impl<'a> ConstraintSystem for R1CSProver<'a> {
+    fn multiply(
+        &mut self,
+        left: LinearCombination,
+        right: LinearCombination,
+    ) -> Result<(Variable, Variable, Variable), Error> {
+        let l: ScalarField = self.evaluate(&left)?;
+        let r: ScalarField = self.evaluate(&right)?;
+        let (l_var, r_var, o_var): (Variable, Variable, Variable) =
+            self.allocate_multiplier(Some((l, r)))?;
+
+        self.constrain(left - l_var);
+        self.constrain(right - r_var);
+
+        Ok((l_var, r_var, o_var))
+    }
+
+    fn allocate_multiplier(
+        &mut self,
+        assignment: Option<(ScalarField, ScalarField)>,
+    ) -> Result<(Variable, Variable, Variable), Error> {
+        let (l, r): (ScalarField, ScalarField) =
+            assignment.ok_or_else(|| throw(ProofError::ProofValidationError))?;
+        let i: usize = self.a_l.len();
+        self.a_l.push(l);
+        self.a_r.push(r);
+        self.a_o.push(l * r);
+
+        Ok((
+            Variable::MultiplierLeft(i),
+            Variable::MultiplierRight(i),
+            Variable::MultiplierOutput(i),
+        ))
+    }
+
+    fn constrain(&mut self, lc: LinearCombination) {
+        self.constraints.push(lc);
+    }
+}
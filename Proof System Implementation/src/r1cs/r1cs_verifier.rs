@@ -0,0 +1,203 @@
+use crate::errors::proof_error::throw;
+use crate::transcript::TranscriptProtocol;
+use crate::utils::Utils;
+use crate::{InnerVerifier, ProofError};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{Field, One, PrimeField, Zero};
+use std::io::Error;
+
+use super::constraint_system::{ConstraintSystem, FlattenedConstraints};
+use super::linear_combination::{LinearCombination, Variable};
+use super::r1cs_proof::R1CSProof;
+
+/// Verifier counterpart of `R1CSProver`: the same gadgets must be run against it, in the
+/// same order, with the commitments the prover published.
+pub struct R1CSVerifier<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    g_vec: &'a Vec<G1Point>,
+    h_vec: &'a Vec<G1Point>,
+    u: &'a G1Point,
+    commitments: Vec<G1Point>,
+    num_multipliers: usize,
+    constraints: Vec<LinearCombination>,
+}
+
+impl<'a> R1CSVerifier<'a> {
+    pub fn new(
+        g: &'a G1Point,
+        h: &'a G1Point,
+        g_vec: &'a Vec<G1Point>,
+        h_vec: &'a Vec<G1Point>,
+        u: &'a G1Point,
+    ) -> Self {
+        R1CSVerifier {
+            g,
+            h,
+            g_vec,
+            h_vec,
+            u,
+            commitments: Vec::new(),
+            num_multipliers: 0,
+            constraints: Vec::new(),
+        }
+    }
+
+    /// Registers a commitment published by the prover and returns the variable it opens to.
+    pub fn commit(&mut self, commitment: G1Point) -> Variable {
+        self.commitments.push(commitment);
+
+        Variable::Committed(self.commitments.len() - 1)
+    }
+
+    pub fn verify_proof<T: TranscriptProtocol>(
+        &mut self,
+        proof: &R1CSProof,
+        transcript: &mut T,
+    ) -> Result<(), Error> {
+        if self.num_multipliers == 0 {
+            let _wires = self.allocate_multiplier(None)?;
+        }
+
+        let n: usize = self.num_multipliers;
+        let m: usize = self.commitments.len();
+        if self.g_vec.len() < n || self.h_vec.len() < n || proof.get_t_commitments().len() != 5 {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+        let g_vec: Vec<G1Point> = self.g_vec[..n].to_vec();
+        let h_vec: Vec<G1Point> = self.h_vec[..n].to_vec();
+
+        transcript.domain_sep(b"R1CSProof");
+        self.append_statement(&self.commitments, &g_vec, &h_vec, transcript);
+
+        let _result = transcript.append_point(b"A_I", proof.get_a_i());
+        let _result = transcript.append_point(b"A_O", proof.get_a_o());
+        let _result = transcript.append_point(b"S", proof.get_s());
+
+        let y: ScalarField = transcript.challenge_scalar(b"y");
+        let z: ScalarField = transcript.challenge_scalar(b"z");
+
+        for t in proof.get_t_commitments().iter() {
+            let _result = transcript.append_point(b"T", t);
+        }
+
+        let x: ScalarField = transcript.challenge_scalar(b"x");
+
+        let _result = transcript.append_scalar(b"t_hat", proof.get_t_hat());
+        let _result = transcript.append_scalar(b"tau_x", proof.get_tau_x());
+        let _result = transcript.append_scalar(b"mu", proof.get_mu());
+
+        let weights: FlattenedConstraints = FlattenedConstraints::new(&self.constraints, n, m, &z)?;
+
+        let y_vec: Vec<ScalarField> = Utils::generate_scalar_exp_vector(n, &y);
+        let y_inv_vec: Vec<ScalarField> = Utils::batch_inverse(&y_vec);
+        let x_square: ScalarField = x * x;
+
+        // delta(y, z) = <y^-n o w_r, w_l>
+        let delta_y_z: ScalarField = Utils::inner_product_scalar_scalar(
+            &Utils::hadamard_product_scalar_scalar(&y_inv_vec, &weights.w_r)?,
+            &weights.w_l,
+        )?;
+
+        // t_hat * g + tau_x * h = x^2 * ((delta(y, z) - w_c) * g - sum_j w_v_j * V_j)
+        //                         + x * T1 + x^3 * T3 + x^4 * T4 + x^5 * T5 + x^6 * T6
+        let mut points: Vec<G1Point> = self.commitments.clone();
+        let mut scalars: Vec<ScalarField> = Utils::product_scalar(&-x_square, &weights.w_v);
+        points.extend([*self.g, *self.h]);
+        scalars.extend([
+            x_square * (delta_y_z - weights.w_c) - proof.get_t_hat(),
+            -*proof.get_tau_x(),
+        ]);
+        points.extend(proof.get_t_commitments().iter());
+        scalars.extend([1u64, 3, 4, 5, 6].iter().map(|k: &u64| x.pow([*k])));
+        if !Utils::multiscalar_mul(&points, &scalars)?.is_zero() {
+            return Err(throw(ProofError::ProofValidationError));
+        }
+
+        // P = x * A_I + x^2 * A_O + x^3 * S - mu * h + <x * y^-n o w_r, g_vec>
+        //     + <x * w_l + w_o, h'_vec> - <1, h_vec>, with h'_i = y^-i * h_i
+        let h_first_vec: Vec<G1Point> = h_vec
+            .iter()
+            .zip(y_inv_vec.iter())
+            .map(|(h, y_inv): (&G1Point, &ScalarField)| h.mul(y_inv.into_repr()).into_affine())
+            .collect();
+        let g_scalars: Vec<ScalarField> = (0..n)
+            .map(|i: usize| x * y_inv_vec[i] * weights.w_r[i])
+            .collect();
+        let h_scalars: Vec<ScalarField> = (0..n)
+            .map(|i: usize| {
+                y_inv_vec[i] * (x * weights.w_l[i] + weights.w_o[i]) - ScalarField::one()
+            })
+            .collect();
+
+        let mut points: Vec<G1Point> =
+            vec![*proof.get_a_i(), *proof.get_a_o(), *proof.get_s(), *self.h];
+        let mut scalars: Vec<ScalarField> = vec![x, x_square, x_square * x, -*proof.get_mu()];
+        points.extend(g_vec.iter());
+        scalars.extend(g_scalars);
+        points.extend(h_vec.iter());
+        scalars.extend(h_scalars);
+        let p: G1Point = Utils::multiscalar_mul(&points, &scalars)?;
+
+        InnerVerifier::new(&g_vec, &h_first_vec, &p, proof.get_t_hat(), self.u)
+            .verify_proof_multiscalar(proof.get_inner_proof(), transcript)
+    }
+
+    /// Appends m, n, the generators g, h, u and the n used of `g_vec` and `h_vec`, and the
+    /// commitments to the circuit inputs.
+    pub(crate) fn append_statement<T: TranscriptProtocol>(
+        &self,
+        commitments: &Vec<G1Point>,
+        g_vec: &Vec<G1Point>,
+        h_vec: &Vec<G1Point>,
+        transcript: &mut T,
+    ) {
+        let _result = transcript.append_scalar(b"m", &ScalarField::from(commitments.len() as u64));
+        let _result = transcript.append_scalar(b"n", &ScalarField::from(g_vec.len() as u64));
+        let _result = transcript.append_point(b"g", self.g);
+        let _result = transcript.append_point(b"h", self.h);
+        let _result = transcript.append_point(b"u", self.u);
+        for (g_i, h_i) in g_vec.iter().zip(h_vec.iter()) {
+            let _result = transcript.append_point(b"g_i", g_i);
+            let _result = transcript.append_point(b"h_i", h_i);
+        }
+        for v in commitments.iter() {
+            let _result = transcript.append_point(b"V", v);
+        }
+    }
+}
+
+impl<'a> ConstraintSystem for R1CSVerifier<'a> {
+    fn multiply(
+        &mut self,
+        left: LinearCombination,
+        right: LinearCombination,
+    ) -> Result<(Variable, Variable, Variable), Error> {
+        let (l_var, r_var, o_var): (Variable, Variable, Variable) =
+            self.allocate_multiplier(None)?;
+
+        self.constrain(left - l_var);
+        self.constrain(right - r_var);
+
+        Ok((l_var, r_var, o_var))
+    }
+
+    fn allocate_multiplier(
+        &mut self,
+        _assignment: Option<(ScalarField, ScalarField)>,
+    ) -> Result<(Variable, Variable, Variable), Error> {
+        let i: usize = self.num_multipliers;
+        self.num_multipliers += 1;
+
+        Ok((
+            Variable::MultiplierLeft(i),
+            Variable::MultiplierRight(i),
+            Variable::MultiplierOutput(i),
+        ))
+    }
+
+    fn constrain(&mut self, lc: LinearCombination) {
+        self.constraints.push(lc);
+    }
+}
@@ -0,0 +1,83 @@
+use crate::RangeProof;
+use ark_bn254::Fr as ScalarField;
+
+/// Range proof linked to a Sigma-protocol on the same values, see `RangeLinkProver`: the proof
+/// `P` of the Sigma-protocol comes with the range proof, the vectors l(x) and r(x) of the
+/// inner-product argument and the challenges x, y and z.
+pub struct LinkedRangeProof<P> {
+    range_proof: RangeProof,
+    l_poly_vec: Vec<ScalarField>,
+    r_poly_vec: Vec<ScalarField>,
+    x: ScalarField,
+    y: ScalarField,
+    z: ScalarField,
+    link_proof: P,
+}
+
+impl<P> LinkedRangeProof<P> {
+    pub fn new(
+        range_proof: RangeProof,
+        l_poly_vec: Vec<ScalarField>,
+        r_poly_vec: Vec<ScalarField>,
+        x: ScalarField,
+        y: ScalarField,
+        z: ScalarField,
+        link_proof: P,
+    ) -> Self {
+        LinkedRangeProof {
+            range_proof,
+            l_poly_vec,
+            r_poly_vec,
+            x,
+            y,
+            z,
+            link_proof,
+        }
+    }
+
+    pub fn get_range_proof(&self) -> &RangeProof {
+        &self.range_proof
+    }
+
+    pub fn get_l_poly_vec(&self) -> &Vec<ScalarField> {
+        &self.l_poly_vec
+    }
+
+    pub fn get_r_poly_vec(&self) -> &Vec<ScalarField> {
+        &self.r_poly_vec
+    }
+
+    pub fn get_x(&self) -> &ScalarField {
+        &self.x
+    }
+
+    pub fn get_y(&self) -> &ScalarField {
+        &self.y
+    }
+
+    pub fn get_z(&self) -> &ScalarField {
+        &self.z
+    }
+
+    pub fn get_link_proof(&self) -> &P {
+        &self.link_proof
+    }
+
+    /// Moves out the range proof and the proof of the Sigma-protocol, e.g. to embed them in the
+    /// proof of a transfer.
+    pub fn into_proofs(self) -> (RangeProof, P) {
+        (self.range_proof, self.link_proof)
+    }
+
+    pub(crate) fn map_link_proof<Q, F: FnOnce(P) -> Q>(self, f: F) -> LinkedRangeProof<Q> {
+        LinkedRangeProof {
+            range_proof: self.range_proof,
+            l_poly_vec: self.l_poly_vec,
+            r_poly_vec: self.r_poly_vec,
+            x: self.x,
+            y: self.y,
+            z: self.z,
+            link_proof: f(self.link_proof),
+        }
+    }
+}
@@ -1,5 +1,7 @@
+pub(crate) mod linked_range_proof;
 pub(crate) mod poly_coefficients;
 pub(crate) mod poly_vector;
+pub(crate) mod range_link;
 pub(crate) mod range_proof;
 pub(crate) mod range_prover;
 pub(crate) mod range_verifier;
@@ -0,0 +1,105 @@
+use crate::transcript::TranscriptProtocol;
+use ark_bn254::Fr as ScalarField;
+use ark_ff::Field;
+use ark_std::rand::Rng;
+use std::io::Error;
+
+/// Weights z^(2+j) of the m values of a range proof in its s_ab.
+pub(crate) fn get_link_weights(m: usize, z: &ScalarField) -> Vec<ScalarField> {
+    (0..m).map(|j: usize| z.pow([2 + j as u64])).collect()
+}
+
+/// A Sigma-protocol linked to a range proof: it runs on the challenges z and c of the range
+/// proof and shares its k_ab, so that its response for sum_j w_j * v_j, with the weights
+/// w_j = z^(2+j), is the s_ab of the range proof. The values proven in range are then the ones
+/// of its statement.
+pub(crate) trait RangeLinkProver {
+    type State;
+    type Proof;
+
+    /// Appended right after the statement of the range proof.
+    fn link_statement<T: TranscriptProtocol>(&self, transcript: &mut T);
+
+    /// Appends the first messages for the `weights` of the values, after A_t and before c.
+    fn link_commitments<R: Rng, T: TranscriptProtocol>(
+        &mut self,
+        rng: &mut R,
+        transcript: &mut T,
+        weights: &Vec<ScalarField>,
+        k_ab: &ScalarField,
+    ) -> Self::State;
+
+    /// Appends the responses to the challenge `c`, after s_ab and s_tau.
+    fn link_responses<T: TranscriptProtocol>(
+        &mut self,
+        state: Self::State,
+        transcript: &mut T,
+        c: &ScalarField,
+    ) -> Self::Proof;
+}
+
+/// Verifier side of `RangeLinkProver`.
+pub(crate) trait RangeLinkVerifier {
+    type Proof;
+
+    fn link_statement<T: TranscriptProtocol>(&self, transcript: &mut T);
+
+    fn link_commitments<T: TranscriptProtocol>(&self, proof: &Self::Proof, transcript: &mut T);
+
+    fn link_responses<T: TranscriptProtocol>(&self, proof: &Self::Proof, transcript: &mut T);
+
+    /// Checks the proof on the weights and the challenge c of the range proof, whose s_ab it
+    /// must share.
+    fn verify_link(
+        &mut self,
+        proof: &Self::Proof,
+        weights: &Vec<ScalarField>,
+        c: &ScalarField,
+        s_ab: &ScalarField,
+    ) -> Result<(), Error>;
+}
+
+/// No Sigma-protocol, for a range proof on its own.
+impl RangeLinkProver for () {
+    type State = ();
+    type Proof = ();
+
+    fn link_statement<T: TranscriptProtocol>(&self, _transcript: &mut T) {}
+
+    fn link_commitments<R: Rng, T: TranscriptProtocol>(
+        &mut self,
+        _rng: &mut R,
+        _transcript: &mut T,
+        _weights: &Vec<ScalarField>,
+        _k_ab: &ScalarField,
+    ) {
+    }
+
+    fn link_responses<T: TranscriptProtocol>(
+        &mut self,
+        _state: (),
+        _transcript: &mut T,
+        _c: &ScalarField,
+    ) {
+    }
+}
+
+impl RangeLinkVerifier for () {
+    type Proof = ();
+
+    fn link_statement<T: TranscriptProtocol>(&self, _transcript: &mut T) {}
+
+    fn link_commitments<T: TranscriptProtocol>(&self, _proof: &(), _transcript: &mut T) {}
+
+    fn link_responses<T: TranscriptProtocol>(&self, _proof: &(), _transcript: &mut T) {}
+
+    fn verify_link(
+        &mut self,
+        _proof: &(),
+        _weights: &Vec<ScalarField>,
+        _c: &ScalarField,
+        _s_ab: &ScalarField,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+}
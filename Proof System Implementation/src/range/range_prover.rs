@@ -1,10 +1,13 @@
+use crate::errors::proof_error::throw;
+use crate::range::range_link::{get_link_weights, RangeLinkProver};
 use crate::transcript::TranscriptProtocol;
 use crate::utils::Utils;
+use crate::{LinkedRangeProof, ProofError, SigmaABProof, SigmaABProver};
 use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
 use ark_ec::{AffineCurve, ProjectiveCurve};
 use ark_ff::{Field, One, PrimeField, Zero};
 use ark_std::rand::Rng;
-use merlin::Transcript;
+use std::io::Error;
 
 use super::{
     poly_coefficients::PolyCoefficients, poly_vector::PolyVector, range_proof::RangeProof,
@@ -17,7 +20,7 @@ pub struct RangeProver<'a> {
     amounts: &'a Vec<usize>,
     g_vec: &'a Vec<G1Point>,
     h_vec: &'a Vec<G1Point>,
-    n: usize,
+    bit_lengths: Vec<usize>,
 }
 
 impl<'a> RangeProver<'a> {
@@ -37,10 +40,38 @@ impl<'a> RangeProver<'a> {
             amounts,
             g_vec,
             h_vec,
-            n,
+            bit_lengths: vec![n; amounts.len() + 1],
         }
     }
 
+    /// Proves each value in its own number of bits, the remaining balance first and then the
+    /// amounts, instead of n bits for all of them. `g_vec` and `h_vec` must then hold
+    /// sum_j n_j generators, and each value must fit in its n_j > 0 bits.
+    pub fn with_bit_lengths(mut self, bit_lengths: Vec<usize>) -> Result<Self, Error> {
+        let total_bits: usize = bit_lengths.iter().sum();
+        if bit_lengths.len() != self.amounts.len() + 1
+            || total_bits != self.g_vec.len()
+            || total_bits != self.h_vec.len()
+        {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+
+        let values: Vec<usize> = std::iter::once(self.remaining_balance)
+            .chain(self.amounts.iter().copied())
+            .collect();
+        for (value, n_j) in values.iter().zip(bit_lengths.iter()) {
+            if *n_j == 0
+                || *n_j > usize::BITS as usize
+                || (*n_j < usize::BITS as usize && *value >> n_j != 0)
+            {
+                return Err(throw(ProofError::OutOfBoundsError));
+            }
+        }
+
+        self.bit_lengths = bit_lengths;
+        Ok(self)
+    }
+
     pub fn get_ipa_arguments(
         &mut self,
         x: &ScalarField,
@@ -77,11 +108,13 @@ impl<'a> RangeProver<'a> {
             .unwrap()
             .mul((z).into_repr())
             .into_affine()
-            + (1..=(h_first_vec.len() / self.n))
+            + (1..=self.bit_lengths.len())
                 .map(|j: usize| {
+                    let offset: usize = self.bit_lengths[..(j - 1)].iter().sum();
+                    let n_j: usize = self.bit_lengths[j - 1];
                     Utils::inner_product_point_scalar(
-                        &h_first_vec[((j - 1) * self.n)..(j * self.n)].to_vec(),
-                        &Utils::generate_scalar_exp_vector(self.n, &ScalarField::from(2)),
+                        &h_first_vec[offset..(offset + n_j)].to_vec(),
+                        &Utils::generate_scalar_exp_vector(n_j, &ScalarField::from(2)),
                     )
                     .unwrap()
                     .mul((z.pow([1 + (j as u64)])).into_repr())
@@ -93,10 +126,10 @@ impl<'a> RangeProver<'a> {
         (h_first_vec, phu)
     }
 
-    pub fn generate_proof<R: Rng>(
+    pub fn generate_proof<R: Rng, T: TranscriptProtocol>(
         &mut self,
         rng: &mut R,
-        transcript: &mut Transcript,
+        transcript: &mut T,
     ) -> (
         RangeProof,
         Vec<ScalarField>,
@@ -106,17 +139,68 @@ impl<'a> RangeProver<'a> {
         ScalarField,
         PolyCoefficients,
     ) {
+        let (linked_proof, t_coefficients): (LinkedRangeProof<Option<()>>, PolyCoefficients) =
+            self.prove::<R, T, ()>(rng, transcript, None);
+        let l_poly_vec: Vec<ScalarField> = linked_proof.get_l_poly_vec().clone();
+        let r_poly_vec: Vec<ScalarField> = linked_proof.get_r_poly_vec().clone();
+        let (x, y, z): (ScalarField, ScalarField, ScalarField) = (
+            *linked_proof.get_x(),
+            *linked_proof.get_y(),
+            *linked_proof.get_z(),
+        );
+        let (proof, _link_proof): (RangeProof, Option<()>) = linked_proof.into_proofs();
+        (proof, l_poly_vec, r_poly_vec, x, y, z, t_coefficients)
+    }
+
+    /// Range proof whose s_ab is shared with the Sigma-protocol `ab` of `sigma_ab_prover`,
+    /// which runs on the challenges `z` and `c` of the range proof: the proven values are then
+    /// the ones encrypted in the balances of the statement.
+    pub fn generate_linked_proof<R: Rng, T: TranscriptProtocol>(
+        &mut self,
+        rng: &mut R,
+        transcript: &mut T,
+        sigma_ab_prover: &mut SigmaABProver,
+    ) -> LinkedRangeProof<SigmaABProof> {
+        self.generate_proof_with_link(rng, transcript, sigma_ab_prover)
+    }
+
+    /// Range proof linked to any Sigma-protocol on the same values, see `RangeLinkProver`.
+    pub(crate) fn generate_proof_with_link<R: Rng, T: TranscriptProtocol, L: RangeLinkProver>(
+        &mut self,
+        rng: &mut R,
+        transcript: &mut T,
+        link: &mut L,
+    ) -> LinkedRangeProof<L::Proof> {
+        let (linked_proof, _t_coefficients): (
+            LinkedRangeProof<Option<L::Proof>>,
+            PolyCoefficients,
+        ) = self.prove(rng, transcript, Some(link));
+        linked_proof.map_link_proof(|link_proof: Option<L::Proof>| link_proof.unwrap())
+    }
+
+    fn prove<R: Rng, T: TranscriptProtocol, L: RangeLinkProver>(
+        &mut self,
+        rng: &mut R,
+        transcript: &mut T,
+        mut link: Option<&mut L>,
+    ) -> (LinkedRangeProof<Option<L::Proof>>, PolyCoefficients) {
         transcript.domain_sep(b"RangeProof");
+        self.append_statement(transcript);
+        if let Some(link) = link.as_ref() {
+            link.link_statement(transcript);
+        }
+
         let m: usize = self.amounts.len() + 1;
+        let total_bits: usize = self.bit_lengths.iter().sum();
 
         let alpha: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
         let rho: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
 
-        let a_l: Vec<ScalarField> = self.get_a_l(self.remaining_balance, self.amounts, m, self.n);
+        let a_l: Vec<ScalarField> = self.get_a_l(self.remaining_balance, self.amounts);
         let a_r: Vec<ScalarField> = self.get_a_r(&a_l);
 
-        let s_l: Vec<ScalarField> = Utils::get_n_random_scalars(m * self.n, rng);
-        let s_r: Vec<ScalarField> = Utils::get_n_random_scalars(m * self.n, rng);
+        let s_l: Vec<ScalarField> = Utils::get_n_random_scalars(total_bits, rng);
+        let s_r: Vec<ScalarField> = Utils::get_n_random_scalars(total_bits, rng);
 
         let a_commitment: G1Point =
             Utils::pedersen_vector_commitment(&alpha, self.h, &a_l, self.g_vec, &a_r, self.h_vec)
@@ -133,7 +217,7 @@ impl<'a> RangeProver<'a> {
         let z: ScalarField = transcript.challenge_scalar(b"z");
 
         let l: PolyVector = self.get_l_poly_vec(&z, &a_l, &s_l);
-        let r: PolyVector = self.get_r_poly_vec(m, self.n, &y, &z, &a_r, &s_r);
+        let r: PolyVector = self.get_r_poly_vec(m, total_bits, &y, &z, &a_r, &s_r);
 
         let t_coefficients: PolyCoefficients = PolyCoefficients::new(&l, &r);
 
@@ -170,6 +254,11 @@ impl<'a> RangeProver<'a> {
         let _result = transcript.append_scalar(b"mu", &mu);
         let _result = transcript.append_point(b"A_t", &a_t_commitment);
 
+        let weights: Vec<ScalarField> = get_link_weights(m, &z);
+        let link_state: Option<L::State> = link
+            .as_mut()
+            .map(|link| link.link_commitments(rng, transcript, &weights, &k_ab));
+
         let c: ScalarField = transcript.challenge_scalar(b"c");
 
         let s_ab: ScalarField = self.get_s_ab(&k_ab, &c, self.remaining_balance, &z, self.amounts);
@@ -178,27 +267,44 @@ impl<'a> RangeProver<'a> {
         let _result = transcript.append_scalar(b"s_ab", &s_ab);
         let _result = transcript.append_scalar(b"s_tau", &s_tau);
 
+        let link_proof: Option<L::Proof> = match (link, link_state) {
+            (Some(link), Some(link_state)) => Some(link.link_responses(link_state, transcript, &c)),
+            _ => None,
+        };
+
         (
-            RangeProof::new(
-                a_commitment,
-                s_commitment,
-                t_commitment_1,
-                t_commitment_2,
-                t_hat,
-                mu,
-                a_t_commitment,
-                s_ab,
-                s_tau,
+            LinkedRangeProof::new(
+                RangeProof::new(
+                    a_commitment,
+                    s_commitment,
+                    t_commitment_1,
+                    t_commitment_2,
+                    t_hat,
+                    mu,
+                    a_t_commitment,
+                    s_ab,
+                    s_tau,
+                ),
+                l_poly_vec,
+                r_poly_vec,
+                x,
+                y,
+                z,
+                link_proof,
             ),
-            l_poly_vec,
-            r_poly_vec,
-            x,
-            y,
-            z,
             t_coefficients,
         )
     }
 
+    /// Appends the generators g, h and the bit length of each value to the transcript.
+    fn append_statement<T: TranscriptProtocol>(&self, transcript: &mut T) {
+        let _result = transcript.append_point(b"g", self.g);
+        let _result = transcript.append_point(b"h", self.h);
+        for n_j in self.bit_lengths.iter() {
+            let _result = transcript.append_scalar(b"n", &ScalarField::from(*n_j as u64));
+        }
+    }
+
     fn get_s_ab(
         &mut self,
         k_ab: &ScalarField,
@@ -217,36 +323,40 @@ impl<'a> RangeProver<'a> {
         *k_ab + (*c * right)
     }
 
-    fn generate_zero_two_zero_vec(&mut self, m: usize, n: usize, j: usize) -> Vec<ScalarField> {
-        let mut to_return: Vec<ScalarField> = Vec::<ScalarField>::with_capacity(m * n);
+    /// (0, ..., 0, 1, 2, ..., 2^(n_j - 1), 0, ..., 0), with the powers of two on the bits of value j
+    fn generate_zero_two_zero_vec(&mut self, j: usize) -> Vec<ScalarField> {
+        let total_bits: usize = self.bit_lengths.iter().sum();
+        let offset: usize = self.bit_lengths[..(j - 1)].iter().sum();
+        let n_j: usize = self.bit_lengths[j - 1];
 
-        to_return.append(&mut (0..((j - 1) * n)).map(|_| ScalarField::zero()).collect());
+        let mut to_return: Vec<ScalarField> = Vec::<ScalarField>::with_capacity(total_bits);
+
+        to_return.append(&mut (0..offset).map(|_| ScalarField::zero()).collect());
 
         to_return.append(&mut Utils::generate_scalar_exp_vector(
-            n,
+            n_j,
             &ScalarField::from(2),
         ));
 
-        to_return.append(&mut (0..((m - j) * n)).map(|_| ScalarField::zero()).collect());
+        to_return.append(
+            &mut (0..(total_bits - offset - n_j))
+                .map(|_| ScalarField::zero())
+                .collect(),
+        );
 
         return to_return;
     }
 
-    fn get_a_l(
-        &mut self,
-        balance: usize,
-        amounts: &Vec<usize>,
-        m: usize,
-        n: usize,
-    ) -> Vec<ScalarField> {
-        let mut bits: Vec<u8> = Vec::<u8>::with_capacity(m * n);
-        Utils::number_to_be_bits_reversed(balance, n)
+    fn get_a_l(&mut self, balance: usize, amounts: &Vec<usize>) -> Vec<ScalarField> {
+        let mut bits: Vec<u8> = Vec::<u8>::with_capacity(self.bit_lengths.iter().sum());
+        Utils::number_to_be_bits_reversed(balance, self.bit_lengths[0])
             .iter()
             .for_each(|bit| bits.push(*bit));
 
         amounts
             .iter()
-            .map(|amount| Utils::number_to_be_bits_reversed(*amount, n))
+            .zip(self.bit_lengths[1..].iter())
+            .map(|(amount, n_j)| Utils::number_to_be_bits_reversed(*amount, *n_j))
             .for_each(|bit_array| {
                 bit_array.iter().for_each(|bit| bits.push(*bit));
             });
@@ -258,16 +368,16 @@ impl<'a> RangeProver<'a> {
         return a_l.iter().map(|bit| *bit - ScalarField::one()).collect();
     }
 
-    fn get_y_vec(&mut self, m: usize, n: usize, y: &ScalarField) -> Vec<ScalarField> {
-        Utils::generate_scalar_exp_vector(m * n, y)
+    fn get_y_vec(&mut self, total_bits: usize, y: &ScalarField) -> Vec<ScalarField> {
+        Utils::generate_scalar_exp_vector(total_bits, y)
     }
 
-    fn get_z_vec(&mut self, m: usize, n: usize, z: &ScalarField) -> Vec<ScalarField> {
+    fn get_z_vec(&mut self, m: usize, z: &ScalarField) -> Vec<ScalarField> {
         (1..=m)
             .map(|j: usize| {
                 Utils::product_scalar(
                     &z.pow([(1 + j) as u64]),
-                    &self.generate_zero_two_zero_vec(m, n, j),
+                    &self.generate_zero_two_zero_vec(j),
                 )
             })
             .reduce(|accum: Vec<ScalarField>, item: Vec<ScalarField>| {
@@ -291,14 +401,14 @@ impl<'a> RangeProver<'a> {
     fn get_r_poly_vec(
         &mut self,
         m: usize,
-        n: usize,
+        total_bits: usize,
         y: &ScalarField,
         z: &ScalarField,
         a_r: &Vec<ScalarField>,
         s_r: &Vec<ScalarField>,
     ) -> PolyVector {
-        let y_vec: Vec<ScalarField> = self.get_y_vec(m, n, &y);
-        let z_vec: Vec<ScalarField> = self.get_z_vec(m, n, &z);
+        let y_vec: Vec<ScalarField> = self.get_y_vec(total_bits, y);
+        let z_vec: Vec<ScalarField> = self.get_z_vec(m, z);
 
         let r_vec_left_hadamard: Vec<ScalarField> =
             Utils::hadamard_product_scalar_scalar(&y_vec, &Utils::sum_scalar(&z, &a_r)).unwrap();
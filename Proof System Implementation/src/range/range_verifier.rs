@@ -1,11 +1,12 @@
 use crate::errors::proof_error::throw;
-use crate::ProofError;
+use crate::interval_range::commitment_link::CommitmentLinkVerifier;
+use crate::range::range_link::{get_link_weights, RangeLinkVerifier};
 use crate::{transcript::TranscriptProtocol, Utils};
+use crate::{IntervalCommitmentProof, ProofError, SigmaABProof, SigmaABVerifier};
 use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
 
 use ark_ec::{AffineCurve, ProjectiveCurve};
 use ark_ff::{Field, One, PrimeField};
-use merlin::Transcript;
 use std::io::Error;
 
 use super::range_proof::RangeProof;
@@ -13,13 +14,43 @@ use super::range_proof::RangeProof;
 pub struct RangeVerifier<'a> {
     g: &'a G1Point,
     h: &'a G1Point,
-    m: usize,
-    n: usize,
+    bit_lengths: Vec<usize>,
 }
 
 impl<'a> RangeVerifier<'a> {
     pub fn new(g: &'a G1Point, h: &'a G1Point, m: usize, n: usize) -> Self {
-        RangeVerifier { g, h, m, n }
+        RangeVerifier {
+            g,
+            h,
+            bit_lengths: vec![n; m],
+        }
+    }
+
+    /// Bit length of each of the m values, see `RangeProver::with_bit_lengths`. `g_vec` and
+    /// `h_vec` are the generators later given to `get_ipa_arguments`, they must hold sum_j n_j
+    /// generators.
+    pub fn with_bit_lengths(
+        mut self,
+        bit_lengths: Vec<usize>,
+        g_vec: &Vec<G1Point>,
+        h_vec: &Vec<G1Point>,
+    ) -> Result<Self, Error> {
+        let total_bits: usize = bit_lengths.iter().sum();
+        if bit_lengths.len() != self.bit_lengths.len()
+            || total_bits != g_vec.len()
+            || total_bits != h_vec.len()
+        {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+        if bit_lengths
+            .iter()
+            .any(|n_j: &usize| *n_j == 0 || *n_j > usize::BITS as usize)
+        {
+            return Err(throw(ProofError::OutOfBoundsError));
+        }
+
+        self.bit_lengths = bit_lengths;
+        Ok(self)
     }
 
     pub fn get_ipa_arguments(
@@ -58,11 +89,13 @@ impl<'a> RangeVerifier<'a> {
             .unwrap()
             .mul((z).into_repr())
             .into_affine()
-            + (1..=self.m)
+            + (1..=self.bit_lengths.len())
                 .map(|j: usize| {
+                    let offset: usize = self.bit_lengths[..(j - 1)].iter().sum();
+                    let n_j: usize = self.bit_lengths[j - 1];
                     Utils::inner_product_point_scalar(
-                        &h_first_vec[((j - 1) * self.n)..(j * self.n)].to_vec(),
-                        &Utils::generate_scalar_exp_vector(self.n, &ScalarField::from(2)),
+                        &h_first_vec[offset..(offset + n_j)].to_vec(),
+                        &Utils::generate_scalar_exp_vector(n_j, &ScalarField::from(2)),
                     )
                     .unwrap()
                     .mul((z.pow([1 + (j as u64)])).into_repr())
@@ -74,12 +107,77 @@ impl<'a> RangeVerifier<'a> {
         (h_first_vec, phu)
     }
 
-    pub fn verify_proof(
+    /// Appends the generators g, h and the bit length of each value to the transcript, see
+    /// `RangeProver::generate_proof`.
+    pub(crate) fn append_statement<T: TranscriptProtocol>(&self, transcript: &mut T) {
+        let _result = transcript.append_point(b"g", self.g);
+        let _result = transcript.append_point(b"h", self.h);
+        for n_j in self.bit_lengths.iter() {
+            let _result = transcript.append_scalar(b"n", &ScalarField::from(*n_j as u64));
+        }
+    }
+
+    pub fn verify_proof<T: TranscriptProtocol>(
+        &mut self,
+        proof: &RangeProof,
+        transcript: &mut T,
+    ) -> (Result<(), Error>, ScalarField, ScalarField, ScalarField) {
+        self.verify::<T, ()>(proof, transcript, None)
+    }
+
+    /// Verifies a proof of `RangeProver::generate_linked_proof`: the Sigma-protocol `ab` is
+    /// checked on the challenges of the range proof and must share its s_ab.
+    pub fn verify_linked_proof<T: TranscriptProtocol>(
         &mut self,
         proof: &RangeProof,
-        transcript: &mut Transcript,
+        sigma_ab_proof: &SigmaABProof,
+        sigma_ab_verifier: &mut SigmaABVerifier,
+        transcript: &mut T,
+    ) -> (Result<(), Error>, ScalarField, ScalarField, ScalarField) {
+        self.verify_proof_with_link(proof, sigma_ab_proof, sigma_ab_verifier, transcript)
+    }
+
+    /// Verifies a proof of `RangeDealer`, bound to the commitments V_j of the parties in
+    /// `v_vec`.
+    pub fn verify_committed_proof<T: TranscriptProtocol>(
+        &mut self,
+        proof: &RangeProof,
+        commitment_proof: &IntervalCommitmentProof,
+        v_vec: &Vec<G1Point>,
+        transcript: &mut T,
+    ) -> (Result<(), Error>, ScalarField, ScalarField, ScalarField) {
+        if v_vec.len() != self.bit_lengths.len() {
+            let zero: ScalarField = ScalarField::from(0);
+            return (Err(throw(ProofError::VectorLengthError)), zero, zero, zero);
+        }
+
+        let mut commitment_link: CommitmentLinkVerifier =
+            CommitmentLinkVerifier::new(self.g, self.h, v_vec);
+        self.verify_proof_with_link(proof, commitment_proof, &mut commitment_link, transcript)
+    }
+
+    /// Verifies a proof of `RangeProver::generate_proof_with_link`.
+    pub(crate) fn verify_proof_with_link<T: TranscriptProtocol, L: RangeLinkVerifier>(
+        &mut self,
+        proof: &RangeProof,
+        link_proof: &L::Proof,
+        link_verifier: &mut L,
+        transcript: &mut T,
+    ) -> (Result<(), Error>, ScalarField, ScalarField, ScalarField) {
+        self.verify(proof, transcript, Some((link_proof, link_verifier)))
+    }
+
+    fn verify<T: TranscriptProtocol, L: RangeLinkVerifier>(
+        &mut self,
+        proof: &RangeProof,
+        transcript: &mut T,
+        link: Option<(&L::Proof, &mut L)>,
     ) -> (Result<(), Error>, ScalarField, ScalarField, ScalarField) {
         transcript.domain_sep(b"RangeProof");
+        self.append_statement(transcript);
+        if let Some((_, link_verifier)) = link.as_ref() {
+            link_verifier.link_statement(transcript);
+        }
 
         let _result = transcript.append_point(b"A", proof.get_a());
         let _result = transcript.append_point(b"S", proof.get_s());
@@ -95,23 +193,41 @@ impl<'a> RangeVerifier<'a> {
         let _result = transcript.append_scalar(b"t_hat", proof.get_t_hat());
         let _result = transcript.append_scalar(b"mu", proof.get_mu());
         let _result = transcript.append_point(b"A_t", proof.get_a_t());
+        if let Some((link_proof, link_verifier)) = link.as_ref() {
+            link_verifier.link_commitments(*link_proof, transcript);
+        }
 
         let c: ScalarField = transcript.challenge_scalar(b"c");
 
         let _result = transcript.append_scalar(b"s_ab", proof.get_s_ab());
         let _result = transcript.append_scalar(b"s_tau", proof.get_s_tau());
 
+        if let Some((link_proof, link_verifier)) = link {
+            link_verifier.link_responses(link_proof, transcript);
+
+            let weights: Vec<ScalarField> = get_link_weights(self.bit_lengths.len(), &z);
+            if link_verifier
+                .verify_link(link_proof, &weights, &c, proof.get_s_ab())
+                .is_err()
+            {
+                return (Err(throw(ProofError::ProofValidationError)), x, y, z);
+            }
+        }
+
         let delta_left: ScalarField = (z - (z * z))
-            * Utils::generate_scalar_exp_vector(self.m * self.n, &y)
+            * Utils::generate_scalar_exp_vector(self.bit_lengths.iter().sum(), &y)
                 .iter()
                 .sum::<ScalarField>();
 
-        let delta_right: ScalarField = (1..=self.m)
+        let delta_right: ScalarField = (1..=self.bit_lengths.len())
             .map(|j: usize| {
                 z.pow([2 + (j as u64)])
-                    * Utils::generate_scalar_exp_vector(self.n, &ScalarField::from(2))
-                        .iter()
-                        .sum::<ScalarField>()
+                    * Utils::generate_scalar_exp_vector(
+                        self.bit_lengths[j - 1],
+                        &ScalarField::from(2),
+                    )
+                    .iter()
+                    .sum::<ScalarField>()
             })
             .sum::<ScalarField>();
 
@@ -0,0 +1,3 @@
+pub(crate) mod range_dealer;
+pub(crate) mod range_mpc_messages;
+pub(crate) mod range_party;
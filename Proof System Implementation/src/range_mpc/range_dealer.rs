@@ -0,0 +1,401 @@
+use crate::errors::proof_error::throw;
+use crate::interval_range::commitment_link::CommitmentLinkVerifier;
+use crate::range::range_link::RangeLinkVerifier;
+use crate::transcript::TranscriptProtocol;
+use crate::utils::Utils;
+use crate::{
+    InnerProof, InnerProver, IntervalCommitmentProof, ProofError, RangeProof, RangeVerifier,
+};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{Field, One, PrimeField};
+use std::io::Error;
+
+use super::range_mpc_messages::{
+    BitChallenge, BitCommitment, PolyChallenge, ProofShare, ResponseChallenge, ResponseShare,
+    TCommitment,
+};
+
+/// Dealer of the multi-party aggregated range proof. It sums the messages of the m parties,
+/// derives the challenges from the transcript exactly as `RangeProver` does, and outputs a
+/// `RangeProof`, the `IntervalCommitmentProof` binding it to the commitments V_j of the parties
+/// and an `InnerProof`, checked with `RangeVerifier::verify_committed_proof` and
+/// `InnerVerifier`. Messages are expected in the order of the party positions.
+pub struct RangeDealer<'a, T: TranscriptProtocol> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    g_vec: &'a Vec<G1Point>,
+    h_vec: &'a Vec<G1Point>,
+    u: &'a G1Point,
+    m: usize,
+    n: usize,
+    transcript: &'a mut T,
+}
+
+impl<'a, T: TranscriptProtocol> RangeDealer<'a, T> {
+    pub fn new(
+        g: &'a G1Point,
+        h: &'a G1Point,
+        g_vec: &'a Vec<G1Point>,
+        h_vec: &'a Vec<G1Point>,
+        u: &'a G1Point,
+        m: usize,
+        n: usize,
+        transcript: &'a mut T,
+    ) -> Self {
+        RangeDealer {
+            g,
+            h,
+            g_vec,
+            h_vec,
+            u,
+            m,
+            n,
+            transcript,
+        }
+    }
+
+    pub fn receive_bit_commitments(
+        self,
+        bit_commitments: &Vec<BitCommitment>,
+    ) -> Result<(RangeDealerAwaitingTCommitments<'a, T>, BitChallenge), Error> {
+        let total_bits: usize = self.m * self.n;
+        if self.m == 0
+            || self.n == 0
+            || self.n > 64
+            || bit_commitments.len() != self.m
+            || self.g_vec.len() != total_bits
+            || self.h_vec.len() != total_bits
+        {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+
+        let a_commitment: G1Point = bit_commitments
+            .iter()
+            .map(|commitment: &BitCommitment| *commitment.get_a())
+            .sum::<G1Point>();
+        let s_commitment: G1Point = bit_commitments
+            .iter()
+            .map(|commitment: &BitCommitment| *commitment.get_s())
+            .sum::<G1Point>();
+        let v_vec: Vec<G1Point> = bit_commitments
+            .iter()
+            .map(|commitment: &BitCommitment| *commitment.get_v())
+            .collect();
+
+        self.transcript.domain_sep(b"RangeProof");
+        RangeVerifier::new(self.g, self.h, self.m, self.n).append_statement(self.transcript);
+        CommitmentLinkVerifier::new(self.g, self.h, &v_vec).link_statement(self.transcript);
+        let _result = self.transcript.append_point(b"A", &a_commitment);
+        let _result = self.transcript.append_point(b"S", &s_commitment);
+
+        let y: ScalarField = self.transcript.challenge_scalar(b"y");
+        let z: ScalarField = self.transcript.challenge_scalar(b"z");
+
+        Ok((
+            RangeDealerAwaitingTCommitments {
+                dealer: self,
+                v_vec,
+                a: a_commitment,
+                s: s_commitment,
+                y,
+                z,
+            },
+            BitChallenge::new(y, z),
+        ))
+    }
+}
+
+pub struct RangeDealerAwaitingTCommitments<'a, T: TranscriptProtocol> {
+    dealer: RangeDealer<'a, T>,
+    v_vec: Vec<G1Point>,
+    a: G1Point,
+    s: G1Point,
+    y: ScalarField,
+    z: ScalarField,
+}
+
+impl<'a, T: TranscriptProtocol> RangeDealerAwaitingTCommitments<'a, T> {
+    pub fn receive_t_commitments(
+        self,
+        t_commitments: &Vec<TCommitment>,
+    ) -> Result<(RangeDealerAwaitingProofShares<'a, T>, PolyChallenge), Error> {
+        if t_commitments.len() != self.dealer.m {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+
+        let t_commitment_1: G1Point = t_commitments
+            .iter()
+            .map(|commitment: &TCommitment| *commitment.get_t_1())
+            .sum::<G1Point>();
+        let t_commitment_2: G1Point = t_commitments
+            .iter()
+            .map(|commitment: &TCommitment| *commitment.get_t_2())
+            .sum::<G1Point>();
+
+        let dealer: RangeDealer<'a, T> = self.dealer;
+        let _result = dealer.transcript.append_point(b"T1", &t_commitment_1);
+        let _result = dealer.transcript.append_point(b"T2", &t_commitment_2);
+
+        let x: ScalarField = dealer.transcript.challenge_scalar(b"x");
+
+        Ok((
+            RangeDealerAwaitingProofShares {
+                dealer,
+                v_vec: self.v_vec,
+                a: self.a,
+                s: self.s,
+                y: self.y,
+                z: self.z,
+                x,
+                t_commitments: t_commitments.clone(),
+            },
+            PolyChallenge::new(x),
+        ))
+    }
+}
+
+pub struct RangeDealerAwaitingProofShares<'a, T: TranscriptProtocol> {
+    dealer: RangeDealer<'a, T>,
+    v_vec: Vec<G1Point>,
+    a: G1Point,
+    s: G1Point,
+    y: ScalarField,
+    z: ScalarField,
+    x: ScalarField,
+    t_commitments: Vec<TCommitment>,
+}
+
+impl<'a, T: TranscriptProtocol> RangeDealerAwaitingProofShares<'a, T> {
+    pub fn receive_proof_shares(
+        self,
+        proof_shares: &Vec<ProofShare>,
+    ) -> Result<(RangeDealerAwaitingResponseShares<'a, T>, ResponseChallenge), Error> {
+        if proof_shares.len() != self.dealer.m {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+        for share in proof_shares.iter() {
+            if share.get_l_vec().len() != self.dealer.n || share.get_r_vec().len() != self.dealer.n
+            {
+                return Err(throw(ProofError::VectorLengthError));
+            }
+            if Utils::inner_product_scalar_scalar(share.get_l_vec(), share.get_r_vec())?
+                != *share.get_t_hat()
+            {
+                return Err(throw(ProofError::ProofValidationError));
+            }
+        }
+
+        let t_hat: ScalarField = proof_shares
+            .iter()
+            .map(|share: &ProofShare| *share.get_t_hat())
+            .sum::<ScalarField>();
+        let mu: ScalarField = proof_shares
+            .iter()
+            .map(|share: &ProofShare| *share.get_mu())
+            .sum::<ScalarField>();
+        let a_t_commitment: G1Point = proof_shares
+            .iter()
+            .map(|share: &ProofShare| *share.get_a_t())
+            .sum::<G1Point>();
+        let a_v_commitment: G1Point = proof_shares
+            .iter()
+            .map(|share: &ProofShare| *share.get_a_v())
+            .sum::<G1Point>();
+
+        let dealer: RangeDealer<'a, T> = self.dealer;
+        let _result = dealer.transcript.append_scalar(b"t_hat", &t_hat);
+        let _result = dealer.transcript.append_scalar(b"mu", &mu);
+        let _result = dealer.transcript.append_point(b"A_t", &a_t_commitment);
+        let _result = dealer.transcript.append_point(b"A_v", &a_v_commitment);
+
+        let c: ScalarField = dealer.transcript.challenge_scalar(b"c");
+
+        Ok((
+            RangeDealerAwaitingResponseShares {
+                dealer,
+                v_vec: self.v_vec,
+                a: self.a,
+                s: self.s,
+                y: self.y,
+                z: self.z,
+                x: self.x,
+                c,
+                t_hat,
+                mu,
+                a_t: a_t_commitment,
+                a_v: a_v_commitment,
+                t_commitments: self.t_commitments,
+                proof_shares: proof_shares.clone(),
+            },
+            ResponseChallenge::new(c),
+        ))
+    }
+}
+
+pub struct RangeDealerAwaitingResponseShares<'a, T: TranscriptProtocol> {
+    dealer: RangeDealer<'a, T>,
+    v_vec: Vec<G1Point>,
+    a: G1Point,
+    s: G1Point,
+    y: ScalarField,
+    z: ScalarField,
+    x: ScalarField,
+    c: ScalarField,
+    t_hat: ScalarField,
+    mu: ScalarField,
+    a_t: G1Point,
+    a_v: G1Point,
+    t_commitments: Vec<TCommitment>,
+    proof_shares: Vec<ProofShare>,
+}
+
+impl<'a, T: TranscriptProtocol> RangeDealerAwaitingResponseShares<'a, T> {
+    /// Checks every response against the share and the commitment V_j of the party that sent
+    /// it, so that a misbehaving party is caught here instead of making the aggregated proof
+    /// fail.
+    pub fn receive_response_shares(
+        self,
+        response_shares: &Vec<ResponseShare>,
+    ) -> Result<(RangeProof, IntervalCommitmentProof, InnerProof), Error> {
+        let m: usize = self.dealer.m;
+        let n: usize = self.dealer.n;
+        if response_shares.len() != m {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+
+        // Share of party j of delta(y, z), on its bits from jn to (j + 1)n - 1
+        let (x, y, z, c): (ScalarField, ScalarField, ScalarField, ScalarField) =
+            (self.x, self.y, self.z, self.c);
+        let y_n_sum: ScalarField = Utils::generate_scalar_exp_vector(n, &y)
+            .iter()
+            .sum::<ScalarField>();
+        let two_n_sum: ScalarField = ScalarField::from(2).pow([n as u64]) - ScalarField::one();
+        let shares = self
+            .proof_shares
+            .iter()
+            .zip(self.t_commitments.iter())
+            .zip(response_shares.iter())
+            .zip(self.v_vec.iter())
+            .enumerate();
+        for (j, (((proof_share, t_commitment), response_share), v_commitment)) in shares {
+            let delta_j: ScalarField = (z - (z * z)) * y.pow([(j * n) as u64]) * y_n_sum
+                - z.pow([3 + (j as u64)]) * two_n_sum;
+
+            let left_eq: G1Point = Utils::pedersen_commitment(
+                &((c * proof_share.get_t_hat()) - (c * delta_j) - response_share.get_s_ab()),
+                self.dealer.g,
+                response_share.get_s_tau(),
+                self.dealer.h,
+            );
+            let right_eq: G1Point = *proof_share.get_a_t()
+                + Utils::pedersen_commitment(
+                    &(c * x),
+                    t_commitment.get_t_1(),
+                    &(c * x * x),
+                    t_commitment.get_t_2(),
+                );
+            if left_eq != right_eq {
+                return Err(throw(ProofError::ProofValidationError));
+            }
+
+            // s_ab_j opens z^(2+j) * V_j, the aggregated s_ab then opens sum_j z^(2+j) * V_j
+            let left_eq: G1Point = Utils::pedersen_commitment(
+                response_share.get_s_ab(),
+                self.dealer.g,
+                response_share.get_s_gamma(),
+                self.dealer.h,
+            );
+            let right_eq: G1Point = *proof_share.get_a_v()
+                + v_commitment
+                    .mul((c * z.pow([2 + (j as u64)])).into_repr())
+                    .into_affine();
+            if left_eq != right_eq {
+                return Err(throw(ProofError::ProofValidationError));
+            }
+        }
+
+        let s_ab: ScalarField = response_shares
+            .iter()
+            .map(|share: &ResponseShare| *share.get_s_ab())
+            .sum::<ScalarField>();
+        let s_tau: ScalarField = response_shares
+            .iter()
+            .map(|share: &ResponseShare| *share.get_s_tau())
+            .sum::<ScalarField>();
+        let s_gamma: ScalarField = response_shares
+            .iter()
+            .map(|share: &ResponseShare| *share.get_s_gamma())
+            .sum::<ScalarField>();
+
+        let dealer: RangeDealer<'a, T> = self.dealer;
+        let _result = dealer.transcript.append_scalar(b"s_ab", &s_ab);
+        let _result = dealer.transcript.append_scalar(b"s_tau", &s_tau);
+        let _result = dealer.transcript.append_scalar(b"s_gamma", &s_gamma);
+
+        let t_commitment_1: G1Point = self
+            .t_commitments
+            .iter()
+            .map(|commitment: &TCommitment| *commitment.get_t_1())
+            .sum::<G1Point>();
+        let t_commitment_2: G1Point = self
+            .t_commitments
+            .iter()
+            .map(|commitment: &TCommitment| *commitment.get_t_2())
+            .sum::<G1Point>();
+
+        let range_proof: RangeProof = RangeProof::new(
+            self.a,
+            self.s,
+            t_commitment_1,
+            t_commitment_2,
+            self.t_hat,
+            self.mu,
+            self.a_t,
+            s_ab,
+            s_tau,
+        );
+
+        // l(x) and r(x) are the concatenation of the shares
+        let l_poly_vec: Vec<ScalarField> = self
+            .proof_shares
+            .iter()
+            .flat_map(|share: &ProofShare| share.get_l_vec().clone())
+            .collect();
+        let r_poly_vec: Vec<ScalarField> = self
+            .proof_shares
+            .iter()
+            .flat_map(|share: &ProofShare| share.get_r_vec().clone())
+            .collect();
+
+        let (h_first_vec, phu): (Vec<G1Point>, G1Point) =
+            RangeVerifier::new(dealer.g, dealer.h, m, n).get_ipa_arguments(
+                &x,
+                &y,
+                &z,
+                &self.mu,
+                &self.a,
+                &self.s,
+                dealer.h,
+                dealer.g_vec,
+                dealer.h_vec,
+            );
+
+        let inner_proof: InnerProof = InnerProver::new(
+            dealer.g_vec,
+            &h_first_vec,
+            &phu,
+            &self.t_hat,
+            &l_poly_vec,
+            &r_poly_vec,
+            dealer.u,
+        )
+        .generate_proof(dealer.transcript)?;
+
+        Ok((
+            range_proof,
+            IntervalCommitmentProof::new(self.a_v, s_ab, s_gamma),
+            inner_proof,
+        ))
+    }
+}
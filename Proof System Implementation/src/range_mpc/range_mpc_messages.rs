@@ -0,0 +1,189 @@
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_serialize::*;
+
+// Messages exchanged between the parties and the dealer of the aggregated range proof. They
+// are serializable so that the protocol can run over any transport.
+
+/// First message of party j: A_j and S_j on its slice of `g_vec` and `h_vec`, and the
+/// commitment V_j = v_j * g + gamma_j * h to its value.
+#[derive(Debug, Clone, CanonicalDeserialize, CanonicalSerialize)]
+pub struct BitCommitment {
+    a: G1Point,
+    s: G1Point,
+    v: G1Point,
+}
+
+impl BitCommitment {
+    pub fn new(a: G1Point, s: G1Point, v: G1Point) -> Self {
+        BitCommitment { a, s, v }
+    }
+
+    pub fn get_a(&self) -> &G1Point {
+        &self.a
+    }
+
+    pub fn get_s(&self) -> &G1Point {
+        &self.s
+    }
+
+    pub fn get_v(&self) -> &G1Point {
+        &self.v
+    }
+}
+
+#[derive(Debug, Clone, CanonicalDeserialize, CanonicalSerialize)]
+pub struct BitChallenge {
+    y: ScalarField,
+    z: ScalarField,
+}
+
+impl BitChallenge {
+    pub fn new(y: ScalarField, z: ScalarField) -> Self {
+        BitChallenge { y, z }
+    }
+
+    pub fn get_y(&self) -> &ScalarField {
+        &self.y
+    }
+
+    pub fn get_z(&self) -> &ScalarField {
+        &self.z
+    }
+}
+
+/// Commitments to the coefficients t_1 and t_2 of the share <l_j(X), r_j(X)> of party j.
+#[derive(Debug, Clone, CanonicalDeserialize, CanonicalSerialize)]
+pub struct TCommitment {
+    t_1: G1Point,
+    t_2: G1Point,
+}
+
+impl TCommitment {
+    pub fn new(t_1: G1Point, t_2: G1Point) -> Self {
+        TCommitment { t_1, t_2 }
+    }
+
+    pub fn get_t_1(&self) -> &G1Point {
+        &self.t_1
+    }
+
+    pub fn get_t_2(&self) -> &G1Point {
+        &self.t_2
+    }
+}
+
+#[derive(Debug, Clone, CanonicalDeserialize, CanonicalSerialize)]
+pub struct PolyChallenge {
+    x: ScalarField,
+}
+
+impl PolyChallenge {
+    pub fn new(x: ScalarField) -> Self {
+        PolyChallenge { x }
+    }
+
+    pub fn get_x(&self) -> &ScalarField {
+        &self.x
+    }
+}
+
+/// Evaluations l_j(x) and r_j(x), the matching share of t_hat and mu, and the commitments
+/// A_t_j and A_v_j of the party's shares of the final Sigma-protocol and of the opening of V_j.
+#[derive(Debug, Clone, CanonicalDeserialize, CanonicalSerialize)]
+pub struct ProofShare {
+    l_vec: Vec<ScalarField>,
+    r_vec: Vec<ScalarField>,
+    t_hat: ScalarField,
+    mu: ScalarField,
+    a_t: G1Point,
+    a_v: G1Point,
+}
+
+impl ProofShare {
+    pub fn new(
+        l_vec: Vec<ScalarField>,
+        r_vec: Vec<ScalarField>,
+        t_hat: ScalarField,
+        mu: ScalarField,
+        a_t: G1Point,
+        a_v: G1Point,
+    ) -> Self {
+        ProofShare {
+            l_vec,
+            r_vec,
+            t_hat,
+            mu,
+            a_t,
+            a_v,
+        }
+    }
+
+    pub fn get_l_vec(&self) -> &Vec<ScalarField> {
+        &self.l_vec
+    }
+
+    pub fn get_r_vec(&self) -> &Vec<ScalarField> {
+        &self.r_vec
+    }
+
+    pub fn get_t_hat(&self) -> &ScalarField {
+        &self.t_hat
+    }
+
+    pub fn get_mu(&self) -> &ScalarField {
+        &self.mu
+    }
+
+    pub fn get_a_t(&self) -> &G1Point {
+        &self.a_t
+    }
+
+    pub fn get_a_v(&self) -> &G1Point {
+        &self.a_v
+    }
+}
+
+#[derive(Debug, Clone, CanonicalDeserialize, CanonicalSerialize)]
+pub struct ResponseChallenge {
+    c: ScalarField,
+}
+
+impl ResponseChallenge {
+    pub fn new(c: ScalarField) -> Self {
+        ResponseChallenge { c }
+    }
+
+    pub fn get_c(&self) -> &ScalarField {
+        &self.c
+    }
+}
+
+/// Responses of party j: its shares of s_ab and s_tau, and s_gamma_j for the opening of V_j.
+#[derive(Debug, Clone, CanonicalDeserialize, CanonicalSerialize)]
+pub struct ResponseShare {
+    s_ab: ScalarField,
+    s_tau: ScalarField,
+    s_gamma: ScalarField,
+}
+
+impl ResponseShare {
+    pub fn new(s_ab: ScalarField, s_tau: ScalarField, s_gamma: ScalarField) -> Self {
+        ResponseShare {
+            s_ab,
+            s_tau,
+            s_gamma,
+        }
+    }
+
+    pub fn get_s_ab(&self) -> &ScalarField {
+        &self.s_ab
+    }
+
+    pub fn get_s_tau(&self) -> &ScalarField {
+        &self.s_tau
+    }
+
+    pub fn get_s_gamma(&self) -> &ScalarField {
+        &self.s_gamma
+    }
+}
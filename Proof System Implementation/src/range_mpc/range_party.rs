@@ -0,0 +1,268 @@
+use crate::errors::proof_error::throw;
+use crate::range::{poly_coefficients::PolyCoefficients, poly_vector::PolyVector};
+use crate::utils::Utils;
+use crate::ProofError;
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ff::{Field, One};
+use ark_std::rand::Rng;
+use std::io::Error;
+
+use super::range_mpc_messages::{
+    BitChallenge, BitCommitment, PolyChallenge, ProofShare, ResponseChallenge, ResponseShare,
+    TCommitment,
+};
+
+/// Party of the multi-party aggregated range proof, holding a single secret value committed in
+/// V = value * g + gamma * h. Each round consumes the party and returns the next state, so that
+/// no blinding is ever reused.
+pub struct RangeParty<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    g_vec: &'a Vec<G1Point>,
+    h_vec: &'a Vec<G1Point>,
+    n: usize,
+    value: usize,
+    gamma: ScalarField,
+}
+
+impl<'a> RangeParty<'a> {
+    /// `g_vec` and `h_vec` are the generators of the whole aggregated proof, the party only
+    /// uses the n of them at its position.
+    pub fn new(
+        g: &'a G1Point,
+        h: &'a G1Point,
+        g_vec: &'a Vec<G1Point>,
+        h_vec: &'a Vec<G1Point>,
+        n: usize,
+        value: usize,
+        gamma: ScalarField,
+    ) -> Self {
+        RangeParty {
+            g,
+            h,
+            g_vec,
+            h_vec,
+            n,
+            value,
+            gamma,
+        }
+    }
+
+    /// Commits to the bits of the value as the party at position j, that is the (j + 1)-th
+    /// value of the aggregated proof.
+    pub fn commit_bits<R: Rng>(
+        self,
+        j: usize,
+        rng: &mut R,
+    ) -> Result<(RangePartyAwaitingBitChallenge<'a>, BitCommitment), Error> {
+        let n: usize = self.n;
+        if n == 0 || n > 64 || self.g_vec.len() < (j + 1) * n || self.h_vec.len() < (j + 1) * n {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+        if n < 64 && (self.value as u64) >> n != 0 {
+            return Err(throw(ProofError::OutOfBoundsError));
+        }
+
+        let g_vec: Vec<G1Point> = self.g_vec[(j * n)..((j + 1) * n)].to_vec();
+        let h_vec: Vec<G1Point> = self.h_vec[(j * n)..((j + 1) * n)].to_vec();
+
+        let a_l: Vec<ScalarField> = Utils::number_to_be_bits_reversed(self.value, n)
+            .iter()
+            .map(|bit: &u8| ScalarField::from(*bit))
+            .collect();
+        let a_r: Vec<ScalarField> = a_l.iter().map(|bit| *bit - ScalarField::one()).collect();
+
+        let alpha: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let rho: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let s_l: Vec<ScalarField> = Utils::get_n_random_scalars(n, rng);
+        let s_r: Vec<ScalarField> = Utils::get_n_random_scalars(n, rng);
+
+        let a_commitment: G1Point =
+            Utils::pedersen_vector_commitment(&alpha, self.h, &a_l, &g_vec, &a_r, &h_vec)?;
+        let s_commitment: G1Point =
+            Utils::pedersen_vector_commitment(&rho, self.h, &s_l, &g_vec, &s_r, &h_vec)?;
+        let v_commitment: G1Point = Utils::pedersen_commitment(
+            &ScalarField::from(self.value as u64),
+            self.g,
+            &self.gamma,
+            self.h,
+        );
+
+        Ok((
+            RangePartyAwaitingBitChallenge {
+                g: self.g,
+                h: self.h,
+                n,
+                j,
+                value: self.value,
+                gamma: self.gamma,
+                alpha,
+                rho,
+                a_l,
+                a_r,
+                s_l,
+                s_r,
+            },
+            BitCommitment::new(a_commitment, s_commitment, v_commitment),
+        ))
+    }
+}
+
+pub struct RangePartyAwaitingBitChallenge<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    n: usize,
+    j: usize,
+    value: usize,
+    gamma: ScalarField,
+    alpha: ScalarField,
+    rho: ScalarField,
+    a_l: Vec<ScalarField>,
+    a_r: Vec<ScalarField>,
+    s_l: Vec<ScalarField>,
+    s_r: Vec<ScalarField>,
+}
+
+impl<'a> RangePartyAwaitingBitChallenge<'a> {
+    pub fn commit_poly<R: Rng>(
+        self,
+        challenge: &BitChallenge,
+        rng: &mut R,
+    ) -> Result<(RangePartyAwaitingPolyChallenge<'a>, TCommitment), Error> {
+        let y: &ScalarField = challenge.get_y();
+        let z: &ScalarField = challenge.get_z();
+
+        // l_j(X) = a_l - z + s_l * X
+        // r_j(X) = y^(jn) * y^n o (a_r + z + s_r * X) + z^(2 + j) * 2^n
+        let y_vec: Vec<ScalarField> = Utils::product_scalar(
+            &y.pow([(self.j * self.n) as u64]),
+            &Utils::generate_scalar_exp_vector(self.n, y),
+        );
+        let z_two_vec: Vec<ScalarField> = Utils::product_scalar(
+            &z.pow([2 + (self.j as u64)]),
+            &Utils::generate_scalar_exp_vector(self.n, &ScalarField::from(2)),
+        );
+
+        let l: PolyVector = PolyVector::new(Utils::subtract_scalar(z, &self.a_l), self.s_l);
+        let r: PolyVector = PolyVector::new(
+            Utils::sum_scalar_scalar(
+                &Utils::hadamard_product_scalar_scalar(&y_vec, &Utils::sum_scalar(z, &self.a_r))?,
+                &z_two_vec,
+            )?,
+            Utils::hadamard_product_scalar_scalar(&y_vec, &self.s_r)?,
+        );
+
+        let t_coefficients: PolyCoefficients = PolyCoefficients::new(&l, &r);
+
+        let tau_1: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let tau_2: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+
+        let t_commitment_1: G1Point =
+            Utils::pedersen_commitment(t_coefficients.get_t_1(), self.g, &tau_1, self.h);
+        let t_commitment_2: G1Point =
+            Utils::pedersen_commitment(t_coefficients.get_t_2(), self.g, &tau_2, self.h);
+
+        Ok((
+            RangePartyAwaitingPolyChallenge {
+                g: self.g,
+                h: self.h,
+                j: self.j,
+                value: self.value,
+                gamma: self.gamma,
+                z: *z,
+                alpha: self.alpha,
+                rho: self.rho,
+                tau_1,
+                tau_2,
+                l,
+                r,
+            },
+            TCommitment::new(t_commitment_1, t_commitment_2),
+        ))
+    }
+}
+
+pub struct RangePartyAwaitingPolyChallenge<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    j: usize,
+    value: usize,
+    gamma: ScalarField,
+    z: ScalarField,
+    alpha: ScalarField,
+    rho: ScalarField,
+    tau_1: ScalarField,
+    tau_2: ScalarField,
+    l: PolyVector,
+    r: PolyVector,
+}
+
+impl<'a> RangePartyAwaitingPolyChallenge<'a> {
+    pub fn share_proof<R: Rng>(
+        self,
+        challenge: &PolyChallenge,
+        rng: &mut R,
+    ) -> Result<(RangePartyAwaitingResponseChallenge, ProofShare), Error> {
+        let x: &ScalarField = challenge.get_x();
+
+        let l_poly_vec: Vec<ScalarField> = self.l.evaluate(x);
+        let r_poly_vec: Vec<ScalarField> = self.r.evaluate(x);
+        let t_hat: ScalarField = Utils::inner_product_scalar_scalar(&l_poly_vec, &r_poly_vec)?;
+
+        let tau_x: ScalarField = (*x * self.tau_1) + (*x * *x * self.tau_2);
+        let mu: ScalarField = self.alpha + self.rho * x;
+
+        let k_ab: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let k_tau: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let k_gamma: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let a_t_commitment: G1Point = Utils::pedersen_commitment(&(-k_ab), self.g, &k_tau, self.h);
+        let a_v_commitment: G1Point = Utils::pedersen_commitment(&k_ab, self.g, &k_gamma, self.h);
+
+        Ok((
+            RangePartyAwaitingResponseChallenge {
+                j: self.j,
+                value: self.value,
+                gamma: self.gamma,
+                z: self.z,
+                tau_x,
+                k_ab,
+                k_tau,
+                k_gamma,
+            },
+            ProofShare::new(
+                l_poly_vec,
+                r_poly_vec,
+                t_hat,
+                mu,
+                a_t_commitment,
+                a_v_commitment,
+            ),
+        ))
+    }
+}
+
+pub struct RangePartyAwaitingResponseChallenge {
+    j: usize,
+    value: usize,
+    gamma: ScalarField,
+    z: ScalarField,
+    tau_x: ScalarField,
+    k_ab: ScalarField,
+    k_tau: ScalarField,
+    k_gamma: ScalarField,
+}
+
+impl RangePartyAwaitingResponseChallenge {
+    /// Last message of the party: its shares of s_ab and s_tau, and the opening of its V with
+    /// the same weight z^(2+j).
+    pub fn respond(self, challenge: &ResponseChallenge) -> ResponseShare {
+        let c: &ScalarField = challenge.get_c();
+        let weight: ScalarField = self.z.pow([2 + (self.j as u64)]);
+
+        let s_ab: ScalarField = self.k_ab + (*c * ScalarField::from(self.value as u64) * weight);
+        let s_tau: ScalarField = (self.tau_x * c) + self.k_tau;
+        let s_gamma: ScalarField = self.k_gamma + (*c * self.gamma * weight);
+
+        ResponseShare::new(s_ab, s_tau, s_gamma)
+    }
+}
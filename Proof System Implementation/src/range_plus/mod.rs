@@ -0,0 +1,3 @@
+pub(crate) mod range_plus_proof;
+pub(crate) mod range_plus_prover;
+pub(crate) mod range_plus_verifier;
@@ -0,0 +1,58 @@
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_serialize::*;
+
+use crate::WeightedInnerProof;
+
+#[derive(Debug, CanonicalDeserialize, CanonicalSerialize)]
+pub struct RangePlusProof {
+    a: G1Point,
+    v: G1Point,
+    a_t: G1Point,
+    s_ab: ScalarField,
+    s_tau: ScalarField,
+    weighted_inner_proof: WeightedInnerProof,
+}
+
+impl RangePlusProof {
+    pub fn new(
+        a: G1Point,
+        v: G1Point,
+        a_t: G1Point,
+        s_ab: ScalarField,
+        s_tau: ScalarField,
+        weighted_inner_proof: WeightedInnerProof,
+    ) -> Self {
+        RangePlusProof {
+            a,
+            v,
+            a_t,
+            s_ab,
+            s_tau,
+            weighted_inner_proof,
+        }
+    }
+
+    pub fn get_a(&self) -> &G1Point {
+        &self.a
+    }
+
+    pub fn get_v(&self) -> &G1Point {
+        &self.v
+    }
+
+    pub fn get_a_t(&self) -> &G1Point {
+        &self.a_t
+    }
+
+    pub fn get_s_ab(&self) -> &ScalarField {
+        &self.s_ab
+    }
+
+    pub fn get_s_tau(&self) -> &ScalarField {
+        &self.s_tau
+    }
+
+    pub fn get_weighted_inner_proof(&self) -> &WeightedInnerProof {
+        &self.weighted_inner_proof
+    }
+}
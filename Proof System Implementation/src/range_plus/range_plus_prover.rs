@@ -0,0 +1,236 @@
+use crate::errors::proof_error::throw;
+use crate::transcript::TranscriptProtocol;
+use crate::utils::Utils;
+use crate::{ProofError, SigmaABProof, SigmaABProver, WeightedInnerProof, WeightedInnerProver};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{Field, One, PrimeField};
+use ark_std::rand::Rng;
+use std::io::Error;
+
+use super::range_plus_proof::RangePlusProof;
+
+/// Bulletproofs+ range proof for the statement of `RangeProver`: the remaining balance and the
+/// m - 1 amounts lie in [0, 2^n). The weighted inner-product argument replaces T1, T2, t_hat and
+/// tau_x. The values are bound to the proof through V = w * g + gamma * h, with
+/// w = b * z^2 + sum_i a_i * z^(2+i) the combination proved by `SigmaABProver`.
+pub struct RangePlusProver<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    remaining_balance: usize,
+    amounts: &'a Vec<usize>,
+    g_vec: &'a Vec<G1Point>,
+    h_vec: &'a Vec<G1Point>,
+    n: usize,
+}
+
+impl<'a> RangePlusProver<'a> {
+    pub fn new(
+        g: &'a G1Point,
+        h: &'a G1Point,
+        remaining_balance: usize,
+        amounts: &'a Vec<usize>,
+        g_vec: &'a Vec<G1Point>,
+        h_vec: &'a Vec<G1Point>,
+        n: usize,
+    ) -> Self {
+        RangePlusProver {
+            g,
+            h,
+            remaining_balance,
+            amounts,
+            g_vec,
+            h_vec,
+            n,
+        }
+    }
+
+    pub fn generate_proof<R: Rng, T: TranscriptProtocol>(
+        &mut self,
+        rng: &mut R,
+        transcript: &mut T,
+    ) -> Result<RangePlusProof, Error> {
+        let (proof, _sigma_ab_proof): (RangePlusProof, Option<SigmaABProof>) =
+            self.prove(rng, transcript, None)?;
+        Ok(proof)
+    }
+
+    /// Generates the range proof together with the Sigma-protocol `ab` on the same challenges
+    /// z and c and with a shared k_ab, so that both proofs carry the same s_ab. The verifier
+    /// then knows that the range-proved values are the ones encrypted in C_L - sum_i C_i and C_i.
+    pub fn generate_linked_proof<R: Rng, T: TranscriptProtocol>(
+        &mut self,
+        rng: &mut R,
+        transcript: &mut T,
+        sigma_ab_prover: &mut SigmaABProver,
+    ) -> Result<(RangePlusProof, SigmaABProof), Error> {
+        let (proof, sigma_ab_proof): (RangePlusProof, Option<SigmaABProof>) =
+            self.prove(rng, transcript, Some(sigma_ab_prover))?;
+        Ok((proof, sigma_ab_proof.unwrap()))
+    }
+
+    fn prove<R: Rng, T: TranscriptProtocol>(
+        &mut self,
+        rng: &mut R,
+        transcript: &mut T,
+        sigma_ab_prover: Option<&mut SigmaABProver>,
+    ) -> Result<(RangePlusProof, Option<SigmaABProof>), Error> {
+        let m: usize = self.amounts.len() + 1;
+        let n: usize = self.n;
+        let total_bits: usize = m * n;
+        if n == 0 || n > 64 || self.g_vec.len() != total_bits || self.h_vec.len() != total_bits {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+
+        let values: Vec<usize> = std::iter::once(self.remaining_balance)
+            .chain(self.amounts.iter().copied())
+            .collect();
+        if n < 64 && values.iter().any(|v: &usize| (*v as u64) >> n != 0) {
+            return Err(throw(ProofError::OutOfBoundsError));
+        }
+
+        transcript.domain_sep(b"RangePlusProof");
+        RangePlusProver::append_statement(self.g, self.h, self.g_vec, self.h_vec, m, n, transcript);
+        if let Some(sigma_ab_prover) = sigma_ab_prover.as_ref() {
+            sigma_ab_prover.append_statement(transcript);
+        }
+
+        let a_l: Vec<ScalarField> = values
+            .iter()
+            .flat_map(|v: &usize| Utils::number_to_be_bits_reversed(*v, n))
+            .map(|bit: u8| ScalarField::from(bit))
+            .collect();
+        let a_r: Vec<ScalarField> = Utils::subtract_scalar(&ScalarField::one(), &a_l);
+
+        let alpha: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let a_commitment: G1Point =
+            Utils::pedersen_vector_commitment(&alpha, self.h, &a_l, self.g_vec, &a_r, self.h_vec)?;
+
+        let _result = transcript.append_point(b"A", &a_commitment);
+
+        let y: ScalarField = transcript.challenge_scalar(b"y");
+        let z: ScalarField = transcript.challenge_scalar(b"z");
+
+        // Value j is weighted by z^(1 + j), as in RangeProver and SigmaABProver
+        let d_vec: Vec<ScalarField> = Self::get_d_vec(m, n, &z);
+        let y_vec: Vec<ScalarField> = Utils::generate_scalar_exp_vector(total_bits + 2, &y);
+
+        // a_l' = a_l - z, a_r' = a_r + d o (y^N, ..., y) + z
+        let a_l_first: Vec<ScalarField> = Utils::subtract_scalar(&z, &a_l);
+        let a_r_first: Vec<ScalarField> = (0..total_bits)
+            .map(|i: usize| a_r[i] + d_vec[i] * y_vec[total_bits - i] + z)
+            .collect();
+
+        let w: ScalarField = values
+            .iter()
+            .enumerate()
+            .map(|(j, v): (usize, &usize)| z.pow([2 + (j as u64)]) * ScalarField::from(*v as u64))
+            .sum();
+        let gamma: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let v_commitment: G1Point = Utils::pedersen_commitment(&w, self.g, &gamma, self.h);
+
+        let _result = transcript.append_point(b"V", &v_commitment);
+
+        // Opening of V, sharing k_ab with the linked Sigma-protocol ab
+        let k_ab: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let k_tau: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let a_t_commitment: G1Point = Utils::pedersen_commitment(&k_ab, self.g, &k_tau, self.h);
+
+        let _result = transcript.append_point(b"A_t", &a_t_commitment);
+
+        let k_sk: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let a_ab: Option<G1Point> = sigma_ab_prover
+            .as_ref()
+            .map(|sigma_ab_prover| sigma_ab_prover.get_a_ab(&z, &k_sk, &k_ab));
+        if let Some(a_ab) = a_ab.as_ref() {
+            let _result = transcript.append_point(b"A_ab", a_ab);
+        }
+
+        let c: ScalarField = transcript.challenge_scalar(b"c");
+
+        let s_ab: ScalarField = k_ab + c * w;
+        let s_tau: ScalarField = k_tau + c * gamma;
+
+        let _result = transcript.append_scalar(b"s_ab", &s_ab);
+        let _result = transcript.append_scalar(b"s_tau", &s_tau);
+
+        let sigma_ab_proof: Option<SigmaABProof> = match (sigma_ab_prover, a_ab) {
+            (Some(sigma_ab_prover), Some(a_ab)) => {
+                let sigma_ab_proof: SigmaABProof =
+                    sigma_ab_prover.get_linked_proof(a_ab, &k_sk, &k_ab, &z, &c);
+                let _result = transcript.append_scalar(b"s_sk", sigma_ab_proof.get_s_sk());
+                Some(sigma_ab_proof)
+            }
+            _ => None,
+        };
+
+        // A' = <a_l', g_vec> + <a_r', h_vec> + (a_l' ⊙_y a_r') * g + (alpha + y^(N+1) * gamma) * h
+        let alpha_first: ScalarField = alpha + y_vec[total_bits + 1] * gamma;
+        let a_first: G1Point = Utils::pedersen_vector_commitment(
+            &alpha_first,
+            self.h,
+            &a_l_first,
+            self.g_vec,
+            &a_r_first,
+            self.h_vec,
+        )? + self
+            .g
+            .mul(
+                WeightedInnerProver::weighted_inner_product(&a_l_first, &a_r_first, &y).into_repr(),
+            )
+            .into_affine();
+
+        let weighted_inner_proof: WeightedInnerProof = WeightedInnerProver::new(
+            self.g_vec,
+            self.h_vec,
+            self.g,
+            self.h,
+            &a_first,
+            &a_l_first,
+            &a_r_first,
+            &alpha_first,
+            &y,
+        )
+        .generate_proof(rng, transcript)?;
+
+        Ok((
+            RangePlusProof::new(
+                a_commitment,
+                v_commitment,
+                a_t_commitment,
+                s_ab,
+                s_tau,
+                weighted_inner_proof,
+            ),
+            sigma_ab_proof,
+        ))
+    }
+
+    /// Appends the generators and the dimensions m and n of the statement to the transcript.
+    pub(crate) fn append_statement<T: TranscriptProtocol>(
+        g: &G1Point,
+        h: &G1Point,
+        g_vec: &Vec<G1Point>,
+        h_vec: &Vec<G1Point>,
+        m: usize,
+        n: usize,
+        transcript: &mut T,
+    ) {
+        let _result = transcript.append_scalar(b"m", &ScalarField::from(m as u64));
+        let _result = transcript.append_scalar(b"n", &ScalarField::from(n as u64));
+        let _result = transcript.append_point(b"g", g);
+        let _result = transcript.append_point(b"h", h);
+        for (g_i, h_i) in g_vec.iter().zip(h_vec.iter()) {
+            let _result = transcript.append_point(b"g_i", g_i);
+            let _result = transcript.append_point(b"h_i", h_i);
+        }
+    }
+
+    /// d = sum_j z^(1 + j) * (0, ..., 0, 1, 2, ..., 2^(n - 1), 0, ..., 0)
+    pub(crate) fn get_d_vec(m: usize, n: usize, z: &ScalarField) -> Vec<ScalarField> {
+        let two_vec: Vec<ScalarField> = Utils::generate_scalar_exp_vector(n, &ScalarField::from(2));
+        (1..=m)
+            .flat_map(|j: usize| Utils::product_scalar(&z.pow([1 + (j as u64)]), &two_vec))
+            .collect()
+    }
+}
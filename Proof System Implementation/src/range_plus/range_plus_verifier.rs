@@ -0,0 +1,136 @@
+use crate::errors::proof_error::throw;
+use crate::transcript::TranscriptProtocol;
+use crate::utils::Utils;
+use crate::{ProofError, RangePlusProver, SigmaABProof, SigmaABVerifier, WeightedInnerVerifier};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{One, PrimeField};
+use std::io::Error;
+
+use super::range_plus_proof::RangePlusProof;
+
+pub struct RangePlusVerifier<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    g_vec: &'a Vec<G1Point>,
+    h_vec: &'a Vec<G1Point>,
+    m: usize,
+    n: usize,
+}
+
+impl<'a> RangePlusVerifier<'a> {
+    pub fn new(
+        g: &'a G1Point,
+        h: &'a G1Point,
+        g_vec: &'a Vec<G1Point>,
+        h_vec: &'a Vec<G1Point>,
+        m: usize,
+        n: usize,
+    ) -> Self {
+        RangePlusVerifier {
+            g,
+            h,
+            g_vec,
+            h_vec,
+            m,
+            n,
+        }
+    }
+
+    pub fn verify_proof<T: TranscriptProtocol>(
+        &mut self,
+        proof: &RangePlusProof,
+        transcript: &mut T,
+    ) -> Result<(), Error> {
+        self.verify(proof, transcript, None)
+    }
+
+    /// Verifies a proof of `RangePlusProver::generate_linked_proof`: the Sigma-protocol `ab` is
+    /// checked on the challenges of the range proof and must share its s_ab.
+    pub fn verify_linked_proof<T: TranscriptProtocol>(
+        &mut self,
+        proof: &RangePlusProof,
+        sigma_ab_proof: &SigmaABProof,
+        sigma_ab_verifier: &mut SigmaABVerifier,
+        transcript: &mut T,
+    ) -> Result<(), Error> {
+        self.verify(proof, transcript, Some((sigma_ab_proof, sigma_ab_verifier)))
+    }
+
+    fn verify<T: TranscriptProtocol>(
+        &mut self,
+        proof: &RangePlusProof,
+        transcript: &mut T,
+        sigma_ab: Option<(&SigmaABProof, &mut SigmaABVerifier)>,
+    ) -> Result<(), Error> {
+        let m: usize = self.m;
+        let n: usize = self.n;
+        let total_bits: usize = m * n;
+        if m == 0
+            || n == 0
+            || n > 64
+            || self.g_vec.len() != total_bits
+            || self.h_vec.len() != total_bits
+        {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+
+        transcript.domain_sep(b"RangePlusProof");
+        RangePlusProver::append_statement(self.g, self.h, self.g_vec, self.h_vec, m, n, transcript);
+        if let Some((_, sigma_ab_verifier)) = sigma_ab.as_ref() {
+            sigma_ab_verifier.append_statement(transcript);
+        }
+
+        let _result = transcript.append_point(b"A", proof.get_a());
+
+        let y: ScalarField = transcript.challenge_scalar(b"y");
+        let z: ScalarField = transcript.challenge_scalar(b"z");
+
+        let _result = transcript.append_point(b"V", proof.get_v());
+        let _result = transcript.append_point(b"A_t", proof.get_a_t());
+        if let Some((sigma_ab_proof, _)) = sigma_ab.as_ref() {
+            let _result = transcript.append_point(b"A_ab", sigma_ab_proof.get_a_ab());
+        }
+
+        let c: ScalarField = transcript.challenge_scalar(b"c");
+
+        let _result = transcript.append_scalar(b"s_ab", proof.get_s_ab());
+        let _result = transcript.append_scalar(b"s_tau", proof.get_s_tau());
+
+        // s_ab * g + s_tau * h = A_t + c * V
+        let left_eq: G1Point =
+            Utils::pedersen_commitment(proof.get_s_ab(), self.g, proof.get_s_tau(), self.h);
+        let right_eq: G1Point = *proof.get_a_t() + proof.get_v().mul(c.into_repr()).into_affine();
+        if left_eq != right_eq {
+            return Err(throw(ProofError::ProofValidationError));
+        }
+
+        if let Some((sigma_ab_proof, sigma_ab_verifier)) = sigma_ab {
+            let _result = transcript.append_scalar(b"s_sk", sigma_ab_proof.get_s_sk());
+
+            if sigma_ab_proof.get_s_ab() != proof.get_s_ab() {
+                return Err(throw(ProofError::ProofValidationError));
+            }
+            sigma_ab_verifier.verify_with_challenges(sigma_ab_proof, &z, &c)?;
+        }
+
+        // A' = A - z * <1, g_vec> + <d o (y^N, ..., y) + z, h_vec> + y^(N+1) * V + zeta * g, with
+        // zeta = (z - z^2) * sum_(i=1..N) y^i - z * y^(N+1) * <1, d>
+        let d_vec: Vec<ScalarField> = RangePlusProver::get_d_vec(m, n, &z);
+        let y_vec: Vec<ScalarField> = Utils::generate_scalar_exp_vector(total_bits + 2, &y);
+        let y_sum: ScalarField = y_vec[1..=total_bits].iter().sum();
+        let zeta: ScalarField =
+            (z - z * z) * y_sum - z * y_vec[total_bits + 1] * d_vec.iter().sum::<ScalarField>();
+
+        let mut points: Vec<G1Point> = vec![*proof.get_a(), *proof.get_v(), *self.g];
+        let mut scalars: Vec<ScalarField> = vec![ScalarField::one(), y_vec[total_bits + 1], zeta];
+        points.extend(self.g_vec.iter());
+        scalars.extend(vec![-z; total_bits]);
+        points.extend(self.h_vec.iter());
+        scalars.extend((0..total_bits).map(|i: usize| d_vec[i] * y_vec[total_bits - i] + z));
+        let a_first: G1Point = Utils::multiscalar_mul(&points, &scalars)?;
+
+        WeightedInnerVerifier::new(self.g_vec, self.h_vec, self.g, self.h, &a_first, &y)
+            .verify_proof(proof.get_weighted_inner_proof(), transcript)
+    }
+}
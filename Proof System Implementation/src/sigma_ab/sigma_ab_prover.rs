@@ -1,19 +1,24 @@
+use crate::range::range_link::{get_link_weights, RangeLinkProver};
 use crate::sigma_ab::sigma_ab_proof::SigmaABProof;
+use crate::sigma_ab::sigma_ab_verifier::SigmaABVerifier;
 use crate::transcript::TranscriptProtocol;
 use crate::utils::Utils;
 use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
 use ark_ec::{AffineCurve, ProjectiveCurve};
-use ark_ff::{Field, PrimeField};
+use ark_ff::PrimeField;
 use ark_std::rand::Rng;
-use merlin::Transcript;
+
+/// Amounts of a single-value statement, see `SigmaABProver::new_single`.
+static NO_AMOUNTS: Vec<usize> = Vec::new();
 
 pub struct SigmaABProver<'a> {
     g: &'a G1Point,
-    d: &'a G1Point,
+    d: Option<&'a G1Point>,
     c_r: &'a G1Point,
     b: usize,
     a: &'a Vec<usize>,
     sk: &'a ScalarField,
+    fee: Option<usize>,
 }
 
 impl<'a> SigmaABProver<'a> {
@@ -27,49 +32,189 @@ impl<'a> SigmaABProver<'a> {
     ) -> Self {
         SigmaABProver {
             g,
-            d,
+            d: Some(d),
             c_r,
             b,
             a,
             sk,
+            fee: None,
+        }
+    }
+
+    /// Statement C_L = b * g + sk * C_R on a single value, without amounts and so without D.
+    pub fn new_single(g: &'a G1Point, c_r: &'a G1Point, b: usize, sk: &'a ScalarField) -> Self {
+        SigmaABProver {
+            g,
+            d: None,
+            c_r,
+            b,
+            a: &NO_AMOUNTS,
+            sk,
+            fee: None,
         }
     }
 
-    pub fn generate_proof<R: Rng>(
+    /// Binds the public fee f paid by the sender to the transcript: b is then the balance left
+    /// after the amounts and the fee, see `SigmaABVerifier::with_fee`.
+    pub fn with_fee(mut self, fee: usize) -> Self {
+        self.fee = Some(fee);
+        self
+    }
+
+    pub fn generate_proof<R: Rng, T: TranscriptProtocol>(
         &mut self,
         rng: &mut R,
-        transcript: &'a mut Transcript,
+        transcript: &mut T,
     ) -> SigmaABProof {
         transcript.domain_sep(b"SigmaAB");
+        self.append_statement(transcript);
 
         let k_sk: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
         let k_ab: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
 
         let z: ScalarField = transcript.challenge_scalar(b"z");
 
-        let sum_d_z: G1Point = (1..=self.a.len())
-            .map(|i| self.d.mul(z.pow([2 + (i as u64)])).into_affine())
-            .sum::<G1Point>();
-
-        let c_r_d_z: G1Point = (self.c_r.into_projective()
-            - self.d.mul(ScalarField::from(self.a.len() as i128)))
-        .into_affine()
-        .mul(z.pow([2]).into_repr())
-        .into_affine();
-
-        let a_ab: G1Point = (c_r_d_z + sum_d_z).mul(k_sk.into_repr()).into_affine()
-            + self.g.mul(k_ab.into_repr()).into_affine();
+        let a_ab: G1Point = self.get_a_ab(&z, &k_sk, &k_ab);
 
         let _result = transcript.append_point(b"A_ab", &a_ab);
 
         let c: ScalarField = transcript.challenge_scalar(b"c");
 
-        let s_ab: ScalarField = self.get_s_ab(&k_ab, &c, self.b, &z, self.a);
+        let proof: SigmaABProof = self.get_linked_proof(a_ab, &k_sk, &k_ab, &z, &c);
+
+        let _result = transcript.append_scalar(b"s_ab", proof.get_s_ab());
+        let _result = transcript.append_scalar(b"s_sk", proof.get_s_sk());
+
+        proof
+    }
+
+    /// Appends the statement (g, D, C_R, C_L, C, f) to the transcript, with the balance and the
+    /// amounts encrypted from the witness: C_L = (b + sum_i a_i + f) * g + sk * C_R and
+    /// C_i = a_i * g + sk * D.
+    pub(crate) fn append_statement<T: TranscriptProtocol>(&self, transcript: &mut T) {
+        let balance: usize = self.b + self.a.iter().sum::<usize>() + self.fee.unwrap_or(0);
+        let c_l: G1Point = self
+            .g
+            .mul(ScalarField::from(balance as u64).into_repr())
+            .into_affine()
+            + self.c_r.mul(self.sk.into_repr()).into_affine();
+        let c_vec: Vec<G1Point> = match self.d {
+            Some(d) => {
+                let sk_d: G1Point = d.mul(self.sk.into_repr()).into_affine();
+                self.a
+                    .iter()
+                    .map(|a_i: &usize| {
+                        self.g
+                            .mul(ScalarField::from(*a_i as u64).into_repr())
+                            .into_affine()
+                            + sk_d
+                    })
+                    .collect()
+            }
+            None => Vec::new(),
+        };
+
+        let sigma_ab_verifier: SigmaABVerifier = match self.d {
+            Some(d) => SigmaABVerifier::new(self.g, d, self.c_r, &c_l, &c_vec),
+            None => SigmaABVerifier::new_single(self.g, self.c_r, &c_l),
+        };
+        match self.fee {
+            Some(fee) => sigma_ab_verifier.with_fee(fee).append_statement(transcript),
+            None => sigma_ab_verifier.append_statement(transcript),
+        }
+    }
+
+    /// A_ab = k_sk * ((C_R - (m - 1) * D) * z^2 + sum_i D * z^(2+i)) + k_ab * g
+    pub(crate) fn get_a_ab(
+        &self,
+        z: &ScalarField,
+        k_sk: &ScalarField,
+        k_ab: &ScalarField,
+    ) -> G1Point {
+        self.get_weighted_a_ab(&get_link_weights(self.a.len() + 1, z), k_sk, k_ab)
+    }
+
+    /// A_ab = k_sk * ((C_R - (m - 1) * D) * w_0 + sum_i D * w_i) + k_ab * g, with the terms in D
+    /// dropped for a single value.
+    pub(crate) fn get_weighted_a_ab(
+        &self,
+        weights: &Vec<ScalarField>,
+        k_sk: &ScalarField,
+        k_ab: &ScalarField,
+    ) -> G1Point {
+        let sk_base: G1Point = match self.d {
+            Some(d) => {
+                let sum_d_w: G1Point = (1..=self.a.len())
+                    .map(|i| d.mul(weights[i].into_repr()).into_affine())
+                    .sum::<G1Point>();
+
+                let c_r_d_w: G1Point = (self.c_r.into_projective()
+                    - d.mul(ScalarField::from(self.a.len() as i128)))
+                .into_affine()
+                .mul(weights[0].into_repr())
+                .into_affine();
+
+                c_r_d_w + sum_d_w
+            }
+            None => self.c_r.mul(weights[0].into_repr()).into_affine(),
+        };
+
+        sk_base.mul(k_sk.into_repr()).into_affine() + self.g.mul(k_ab.into_repr()).into_affine()
+    }
+
+    /// Responses for challenges `z` and `c` chosen by another protocol: a range proof sharing
+    /// k_ab, and then s_ab, with this proof binds its values to the encrypted balances.
+    pub(crate) fn get_linked_proof(
+        &mut self,
+        a_ab: G1Point,
+        k_sk: &ScalarField,
+        k_ab: &ScalarField,
+        z: &ScalarField,
+        c: &ScalarField,
+    ) -> SigmaABProof {
+        let weights: Vec<ScalarField> = get_link_weights(self.a.len() + 1, z);
+        self.get_weighted_linked_proof(a_ab, k_sk, k_ab, &weights, c)
+    }
+
+    /// Responses with s_ab = k_ab + c * (w_0 * b + sum_i w_i * a_i).
+    pub(crate) fn get_weighted_linked_proof(
+        &mut self,
+        a_ab: G1Point,
+        k_sk: &ScalarField,
+        k_ab: &ScalarField,
+        weights: &Vec<ScalarField>,
+        c: &ScalarField,
+    ) -> SigmaABProof {
+        let s_ab: ScalarField = self.get_s_ab(k_ab, c, self.b, weights, self.a);
 
         let s_sk: ScalarField = (*self.sk * c) + k_sk;
 
-        let _result = transcript.append_scalar(b"s_ab", &s_ab);
-        let _result = transcript.append_scalar(b"s_sk", &s_sk);
+        SigmaABProof::new(a_ab, s_sk, s_ab)
+    }
+
+    /// Honest-verifier simulator: outputs a proof for the statement (C_L, C_R, D, C) that is
+    /// accepting for the challenges `z` and `c`, without knowing sk and the amounts.
+    pub fn simulate<R: Rng>(
+        g: &G1Point,
+        d: &G1Point,
+        c_r: &G1Point,
+        c_l: &G1Point,
+        c_vec: &Vec<G1Point>,
+        z: &ScalarField,
+        c: &ScalarField,
+        rng: &mut R,
+    ) -> SigmaABProof {
+        let s_sk: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let s_ab: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+
+        let verifier: SigmaABVerifier = SigmaABVerifier::new(g, d, c_r, c_l, c_vec);
+
+        let a_ab: G1Point = verifier.get_sk_base(z).mul(s_sk.into_repr()).into_affine()
+            + g.mul(s_ab.into_repr()).into_affine()
+            + -verifier
+                .get_statement_point(z)
+                .mul(c.into_repr())
+                .into_affine();
 
         SigmaABProof::new(a_ab, s_sk, s_ab)
     }
@@ -79,16 +224,56 @@ impl<'a> SigmaABProver<'a> {
         k_ab: &ScalarField,
         c: &ScalarField,
         b: usize,
-        z: &ScalarField,
+        weights: &Vec<ScalarField>,
         a: &Vec<usize>,
     ) -> ScalarField {
         let n: usize = a.len();
-        let sum_a_z: ScalarField = (1..=n)
-            .map(|i: usize| ScalarField::from(a[i - 1] as i128) * z.pow([2 + (i as u64)]))
+        let sum_a_w: ScalarField = (1..=n)
+            .map(|i: usize| ScalarField::from(a[i - 1] as i128) * weights[i])
             .sum();
 
-        let right: ScalarField = ((ScalarField::from(b as i128)) * z.pow([2])) + sum_a_z;
+        let right: ScalarField = ((ScalarField::from(b as i128)) * weights[0]) + sum_a_w;
 
         *k_ab + (*c * right)
     }
 }
+
+impl<'a> RangeLinkProver for SigmaABProver<'a> {
+    /// (A_ab, k_sk, k_ab, weights)
+    type State = (G1Point, ScalarField, ScalarField, Vec<ScalarField>);
+    type Proof = SigmaABProof;
+
+    fn link_statement<T: TranscriptProtocol>(&self, transcript: &mut T) {
+        self.append_statement(transcript);
+    }
+
+    fn link_commitments<R: Rng, T: TranscriptProtocol>(
+        &mut self,
+        rng: &mut R,
+        transcript: &mut T,
+        weights: &Vec<ScalarField>,
+        k_ab: &ScalarField,
+    ) -> Self::State {
+        let k_sk: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let a_ab: G1Point = self.get_weighted_a_ab(weights, &k_sk, k_ab);
+
+        let _result = transcript.append_point(b"A_ab", &a_ab);
+
+        (a_ab, k_sk, *k_ab, weights.clone())
+    }
+
+    fn link_responses<T: TranscriptProtocol>(
+        &mut self,
+        state: Self::State,
+        transcript: &mut T,
+        c: &ScalarField,
+    ) -> Self::Proof {
+        let (a_ab, k_sk, k_ab, weights): (G1Point, ScalarField, ScalarField, Vec<ScalarField>) =
+            state;
+        let proof: SigmaABProof = self.get_weighted_linked_proof(a_ab, &k_sk, &k_ab, &weights, c);
+
+        let _result = transcript.append_scalar(b"s_sk", proof.get_s_sk());
+
+        proof
+    }
+}
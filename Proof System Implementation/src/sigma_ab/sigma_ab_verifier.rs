@@ -1,19 +1,23 @@
+use crate::range::range_link::{get_link_weights, RangeLinkVerifier};
 use crate::transcript::TranscriptProtocol;
 use crate::ProofError;
 use crate::{errors::proof_error::throw, sigma_ab::sigma_ab_proof::SigmaABProof};
 use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
 use ark_ec::{AffineCurve, ProjectiveCurve};
-use ark_ff::{Field, PrimeField};
-use merlin::Transcript;
+use ark_ff::PrimeField;
 use std::io::Error;
 
+/// Ciphertexts of a single-value statement, see `SigmaABVerifier::new_single`.
+static NO_CIPHERTEXTS: Vec<G1Point> = Vec::new();
+
 pub struct SigmaABVerifier<'a> {
     /// public generator
     g: &'a G1Point,
-    d: &'a G1Point,
+    d: Option<&'a G1Point>,
     c_r: &'a G1Point,
     c_l: &'a G1Point,
     c_vec: &'a Vec<G1Point>,
+    fee: Option<usize>,
 }
 
 impl<'a> SigmaABVerifier<'a> {
@@ -26,19 +30,40 @@ impl<'a> SigmaABVerifier<'a> {
     ) -> Self {
         SigmaABVerifier {
             g,
-            d,
+            d: Some(d),
             c_r,
             c_l,
             c_vec,
+            fee: None,
         }
     }
 
-    pub fn verify_proof(
+    /// Statement C_L = b * g + sk * C_R on a single value, see `SigmaABProver::new_single`.
+    pub fn new_single(g: &'a G1Point, c_r: &'a G1Point, c_l: &'a G1Point) -> Self {
+        SigmaABVerifier {
+            g,
+            d: None,
+            c_r,
+            c_l,
+            c_vec: &NO_CIPHERTEXTS,
+            fee: None,
+        }
+    }
+
+    /// Subtracts the public fee f from the sender balance, so that the statement becomes
+    /// C_L - f * g - sum_i C_i = b * g + sk * (C_R - (m - 1) * D).
+    pub fn with_fee(mut self, fee: usize) -> Self {
+        self.fee = Some(fee);
+        self
+    }
+
+    pub fn verify_proof<T: TranscriptProtocol>(
         &mut self,
         proof: &SigmaABProof,
-        transcript: &mut Transcript,
+        transcript: &mut T,
     ) -> Result<(), Error> {
         transcript.domain_sep(b"SigmaAB");
+        self.append_statement(transcript);
 
         let z: ScalarField = transcript.challenge_scalar(b"z");
 
@@ -49,46 +74,137 @@ impl<'a> SigmaABVerifier<'a> {
         let _result = transcript.append_scalar(b"s_ab", proof.get_s_ab());
         let _result = transcript.append_scalar(b"s_sk", proof.get_s_sk());
 
-        let left_eq_sum_d_z: G1Point = (1..=self.c_vec.len())
-            .map(|i| self.d.mul(z.pow([2 + (i as u64)])).into_affine())
-            .sum::<G1Point>();
+        self.verify_with_challenges(proof, &z, &c)
+    }
 
-        let left_eq_c_r_d_z: G1Point = (self.c_r.into_projective()
-            - self.d.mul(ScalarField::from(self.c_vec.len() as i128)))
-        .into_affine()
-        .mul(z.pow([2]).into_repr())
-        .into_affine();
+    /// Appends the statement (g, D, C_R, C_L, C) and the fee, if any, to the transcript, without
+    /// D for a single value.
+    pub(crate) fn append_statement<T: TranscriptProtocol>(&self, transcript: &mut T) {
+        if let Some(fee) = self.fee {
+            let _result = transcript.append_scalar(b"fee", &ScalarField::from(fee as u64));
+        }
+        let _result = transcript.append_point(b"g", self.g);
+        if let Some(d) = self.d {
+            let _result = transcript.append_point(b"D", d);
+        }
+        let _result = transcript.append_point(b"C_R", self.c_r);
+        let _result = transcript.append_point(b"C_L", self.c_l);
+        for c in self.c_vec.iter() {
+            let _result = transcript.append_point(b"C", c);
+        }
+    }
+
+    pub fn verify_with_challenges(
+        &mut self,
+        proof: &SigmaABProof,
+        z: &ScalarField,
+        c: &ScalarField,
+    ) -> Result<(), Error> {
+        let weights: Vec<ScalarField> = get_link_weights(self.c_vec.len() + 1, z);
+        self.verify_with_weights(proof, &weights, c)
+    }
 
-        let left_eq: G1Point = (left_eq_c_r_d_z + left_eq_sum_d_z)
+    /// Checks the proof for the weights w_j of the balance and of the amounts instead of the
+    /// powers z^(2+j), see `RangeLinkVerifier`.
+    pub(crate) fn verify_with_weights(
+        &mut self,
+        proof: &SigmaABProof,
+        weights: &Vec<ScalarField>,
+        c: &ScalarField,
+    ) -> Result<(), Error> {
+        let left_eq: G1Point = self
+            .get_weighted_sk_base(weights)
             .mul(proof.get_s_sk().into_repr())
             .into_affine()
             + self.g.mul(proof.get_s_ab().into_repr()).into_affine();
 
-        let right_eq_sum_c_z: G1Point = (1..=self.c_vec.len())
-            .map(|i| {
-                self.c_vec
-                    .get(i - 1)
-                    .unwrap()
-                    .mul(z.pow([2 + (i as u64)]))
-                    .into_affine()
-            })
+        let right_eq: G1Point = self
+            .get_weighted_statement_point(weights)
+            .mul(c.into_repr())
+            .into_affine()
+            + *proof.get_a_ab();
+
+        if left_eq == right_eq {
+            Ok(())
+        } else {
+            Err(throw(ProofError::ProofValidationError))
+        }
+    }
+
+    /// (C_R - (m - 1) * D) * z^2 + sum_i D * z^(2+i), the base raised to sk in the statement.
+    pub(crate) fn get_sk_base(&self, z: &ScalarField) -> G1Point {
+        self.get_weighted_sk_base(&get_link_weights(self.c_vec.len() + 1, z))
+    }
+
+    /// (C_R - (m - 1) * D) * w_0 + sum_i D * w_i, or C_R * w_0 for a single value
+    fn get_weighted_sk_base(&self, weights: &Vec<ScalarField>) -> G1Point {
+        let d: &G1Point = match self.d {
+            Some(d) => d,
+            None => return self.c_r.mul(weights[0].into_repr()).into_affine(),
+        };
+
+        let sum_d_w: G1Point = (1..=self.c_vec.len())
+            .map(|i| d.mul(weights[i].into_repr()).into_affine())
             .sum::<G1Point>();
 
-        let right_eq_cl_ci_z: G1Point = (self.c_l.into_projective()
+        let c_r_d_w: G1Point = (self.c_r.into_projective()
+            - d.mul(ScalarField::from(self.c_vec.len() as i128)))
+        .into_affine()
+        .mul(weights[0].into_repr())
+        .into_affine();
+
+        c_r_d_w + sum_d_w
+    }
+
+    /// (C_L - f * g - sum_i C_i) * z^2 + sum_i C_i * z^(2+i), the statement point of the
+    /// protocol, with f = 0 when no fee is set.
+    pub(crate) fn get_statement_point(&self, z: &ScalarField) -> G1Point {
+        self.get_weighted_statement_point(&get_link_weights(self.c_vec.len() + 1, z))
+    }
+
+    /// (C_L - f * g - sum_i C_i) * w_0 + sum_i C_i * w_i
+    fn get_weighted_statement_point(&self, weights: &Vec<ScalarField>) -> G1Point {
+        let sum_c_w: G1Point = (1..=self.c_vec.len())
+            .map(|i| self.c_vec[i - 1].mul(weights[i].into_repr()).into_affine())
+            .sum::<G1Point>();
+
+        let fee: ScalarField = ScalarField::from(self.fee.unwrap_or(0) as u64);
+        let cl_ci_w: G1Point = (self.c_l.into_projective()
+            - self.g.mul(fee.into_repr())
             - self.c_vec.iter().sum::<G1Point>().into_projective())
         .into_affine()
-        .mul(z.pow([2]).into_repr())
+        .mul(weights[0].into_repr())
         .into_affine();
 
-        let right_eq: G1Point = (right_eq_sum_c_z + right_eq_cl_ci_z)
-            .mul(c.into_repr())
-            .into_affine()
-            + *proof.get_a_ab();
+        sum_c_w + cl_ci_w
+    }
+}
 
-        if left_eq == right_eq {
-            return Ok(());
-        } else {
+impl<'a> RangeLinkVerifier for SigmaABVerifier<'a> {
+    type Proof = SigmaABProof;
+
+    fn link_statement<T: TranscriptProtocol>(&self, transcript: &mut T) {
+        self.append_statement(transcript);
+    }
+
+    fn link_commitments<T: TranscriptProtocol>(&self, proof: &SigmaABProof, transcript: &mut T) {
+        let _result = transcript.append_point(b"A_ab", proof.get_a_ab());
+    }
+
+    fn link_responses<T: TranscriptProtocol>(&self, proof: &SigmaABProof, transcript: &mut T) {
+        let _result = transcript.append_scalar(b"s_sk", proof.get_s_sk());
+    }
+
+    fn verify_link(
+        &mut self,
+        proof: &SigmaABProof,
+        weights: &Vec<ScalarField>,
+        c: &ScalarField,
+        s_ab: &ScalarField,
+    ) -> Result<(), Error> {
+        if proof.get_s_ab() != s_ab {
             return Err(throw(ProofError::ProofValidationError));
         }
+        self.verify_with_weights(proof, weights, c)
     }
 }
@@ -0,0 +1,3 @@
+pub(crate) mod sigma_anon_proof;
+pub(crate) mod sigma_anon_prover;
+pub(crate) mod sigma_anon_verifier;
@@ -0,0 +1,78 @@
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+
+use ark_serialize::*;
+
+#[derive(Debug, CanonicalDeserialize, CanonicalSerialize)]
+pub struct SigmaAnonProof {
+    a_y: G1Point,
+    a_p: G1Point,
+    a_d: G1Point,
+    a_c: G1Point,
+    s_sk: ScalarField,
+    s_rho: ScalarField,
+    s_w: ScalarField,
+    s_tau: ScalarField,
+    s_r: ScalarField,
+}
+
+impl SigmaAnonProof {
+    pub fn new(
+        a_y: G1Point,
+        a_p: G1Point,
+        a_d: G1Point,
+        a_c: G1Point,
+        s_sk: ScalarField,
+        s_rho: ScalarField,
+        s_w: ScalarField,
+        s_tau: ScalarField,
+        s_r: ScalarField,
+    ) -> Self {
+        SigmaAnonProof {
+            a_y,
+            a_p,
+            a_d,
+            a_c,
+            s_sk,
+            s_rho,
+            s_w,
+            s_tau,
+            s_r,
+        }
+    }
+
+    pub fn get_a_y(&self) -> &G1Point {
+        &self.a_y
+    }
+
+    pub fn get_a_p(&self) -> &G1Point {
+        &self.a_p
+    }
+
+    pub fn get_a_d(&self) -> &G1Point {
+        &self.a_d
+    }
+
+    pub fn get_a_c(&self) -> &G1Point {
+        &self.a_c
+    }
+
+    pub fn get_s_sk(&self) -> &ScalarField {
+        &self.s_sk
+    }
+
+    pub fn get_s_rho(&self) -> &ScalarField {
+        &self.s_rho
+    }
+
+    pub fn get_s_w(&self) -> &ScalarField {
+        &self.s_w
+    }
+
+    pub fn get_s_tau(&self) -> &ScalarField {
+        &self.s_tau
+    }
+
+    pub fn get_s_r(&self) -> &ScalarField {
+        &self.s_r
+    }
+}
@@ -0,0 +1,138 @@
+use crate::sigma_anon::sigma_anon_proof::SigmaAnonProof;
+use crate::sigma_anon::sigma_anon_verifier::SigmaAnonVerifier;
+use crate::transcript::TranscriptProtocol;
+use crate::utils::Utils;
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_std::rand::Rng;
+use merlin::Transcript;
+
+/// Proves knowledge of (sk, rho, w, tau, r) such that
+/// Y' = sk * g + rho * h, P = w * g + sk * E_R + r * Q + tau * h, D = r * g and sum(C) = r * sum(y).
+pub struct SigmaAnonProver<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    e_r: &'a G1Point,
+    q: &'a G1Point,
+    y_sum: &'a G1Point,
+    sk: &'a ScalarField,
+    rho: &'a ScalarField,
+    w: &'a ScalarField,
+    tau: &'a ScalarField,
+    r: &'a ScalarField,
+}
+
+impl<'a> SigmaAnonProver<'a> {
+    pub fn new(
+        g: &'a G1Point,
+        h: &'a G1Point,
+        e_r: &'a G1Point,
+        q: &'a G1Point,
+        y_sum: &'a G1Point,
+        sk: &'a ScalarField,
+        rho: &'a ScalarField,
+        w: &'a ScalarField,
+        tau: &'a ScalarField,
+        r: &'a ScalarField,
+    ) -> Self {
+        SigmaAnonProver {
+            g,
+            h,
+            e_r,
+            q,
+            y_sum,
+            sk,
+            rho,
+            w,
+            tau,
+            r,
+        }
+    }
+
+    pub fn generate_proof<R: Rng>(
+        &mut self,
+        rng: &mut R,
+        transcript: &mut Transcript,
+    ) -> SigmaAnonProof {
+        transcript.domain_sep(b"SigmaAnon");
+        self.append_statement(transcript);
+
+        let k_vec: Vec<ScalarField> = Utils::get_n_random_scalars(5, rng);
+        let commitments: Vec<G1Point> = self.get_commitments(&k_vec);
+
+        let _result = transcript.append_point(b"A_y", &commitments[0]);
+        let _result = transcript.append_point(b"A_p", &commitments[1]);
+        let _result = transcript.append_point(b"A_D", &commitments[2]);
+        let _result = transcript.append_point(b"A_C", &commitments[3]);
+
+        let c: ScalarField = transcript.challenge_scalar(b"c");
+
+        let proof: SigmaAnonProof = self.get_linked_proof(commitments, &k_vec, &c);
+
+        let _result = transcript.append_scalar(b"s_sk", proof.get_s_sk());
+        let _result = transcript.append_scalar(b"s_rho", proof.get_s_rho());
+        let _result = transcript.append_scalar(b"s_w", proof.get_s_w());
+        let _result = transcript.append_scalar(b"s_tau", proof.get_s_tau());
+        let _result = transcript.append_scalar(b"s_r", proof.get_s_r());
+
+        proof
+    }
+
+    /// Appends the statement (g, h, D, Y', E_R, P, Q, sum(y), sum(C)) to the transcript, with
+    /// the points computed from the witness.
+    fn append_statement(&self, transcript: &mut Transcript) {
+        let y_first: G1Point = Utils::pedersen_commitment(self.sk, self.g, self.rho, self.h);
+        let p: G1Point = Utils::pedersen_commitment(self.w, self.g, self.tau, self.h)
+            + self.e_r.mul(self.sk.into_repr()).into_affine()
+            + self.q.mul(self.r.into_repr()).into_affine();
+        let d: G1Point = self.g.mul(self.r.into_repr()).into_affine();
+        let c_sum: G1Point = self.y_sum.mul(self.r.into_repr()).into_affine();
+
+        SigmaAnonVerifier::new(
+            self.g, self.h, &d, &y_first, self.e_r, &p, self.q, self.y_sum, &c_sum,
+        )
+        .append_statement(transcript);
+    }
+
+    /// (A_y, A_p, A_D, A_C) for the nonces (k_sk, k_rho, k_w, k_tau, k_r).
+    pub(crate) fn get_commitments(&self, k_vec: &Vec<ScalarField>) -> Vec<G1Point> {
+        let a_y: G1Point = Utils::pedersen_commitment(&k_vec[0], self.g, &k_vec[1], self.h);
+
+        let a_p: G1Point = Utils::pedersen_commitment(&k_vec[2], self.g, &k_vec[3], self.h)
+            + self.e_r.mul(k_vec[0].into_repr()).into_affine()
+            + self.q.mul(k_vec[4].into_repr()).into_affine();
+
+        let a_d: G1Point = self.g.mul(k_vec[4].into_repr()).into_affine();
+        let a_c: G1Point = self.y_sum.mul(k_vec[4].into_repr()).into_affine();
+
+        vec![a_y, a_p, a_d, a_c]
+    }
+
+    /// Responses for a challenge `c` chosen by another protocol: a range proof sharing k_w as
+    /// its k_ab has then s_w as its s_ab, see `AnonZeroMTProver`.
+    pub(crate) fn get_linked_proof(
+        &self,
+        commitments: Vec<G1Point>,
+        k_vec: &Vec<ScalarField>,
+        c: &ScalarField,
+    ) -> SigmaAnonProof {
+        let s_sk: ScalarField = (*self.sk * c) + k_vec[0];
+        let s_rho: ScalarField = (*self.rho * c) + k_vec[1];
+        let s_w: ScalarField = (*self.w * c) + k_vec[2];
+        let s_tau: ScalarField = (*self.tau * c) + k_vec[3];
+        let s_r: ScalarField = (*self.r * c) + k_vec[4];
+
+        SigmaAnonProof::new(
+            commitments[0],
+            commitments[1],
+            commitments[2],
+            commitments[3],
+            s_sk,
+            s_rho,
+            s_w,
+            s_tau,
+            s_r,
+        )
+    }
+}
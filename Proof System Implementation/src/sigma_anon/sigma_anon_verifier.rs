@@ -0,0 +1,115 @@
+use crate::transcript::TranscriptProtocol;
+use crate::ProofError;
+use crate::{errors::proof_error::throw, sigma_anon::sigma_anon_proof::SigmaAnonProof, Utils};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use merlin::Transcript;
+use std::io::Error;
+
+pub struct SigmaAnonVerifier<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    d: &'a G1Point,
+    y_first: &'a G1Point,
+    e_r: &'a G1Point,
+    p: &'a G1Point,
+    q: &'a G1Point,
+    y_sum: &'a G1Point,
+    c_sum: &'a G1Point,
+}
+
+impl<'a> SigmaAnonVerifier<'a> {
+    pub fn new(
+        g: &'a G1Point,
+        h: &'a G1Point,
+        d: &'a G1Point,
+        y_first: &'a G1Point,
+        e_r: &'a G1Point,
+        p: &'a G1Point,
+        q: &'a G1Point,
+        y_sum: &'a G1Point,
+        c_sum: &'a G1Point,
+    ) -> Self {
+        SigmaAnonVerifier {
+            g,
+            h,
+            d,
+            y_first,
+            e_r,
+            p,
+            q,
+            y_sum,
+            c_sum,
+        }
+    }
+
+    pub fn verify_proof(
+        &mut self,
+        proof: &SigmaAnonProof,
+        transcript: &mut Transcript,
+    ) -> Result<(), Error> {
+        transcript.domain_sep(b"SigmaAnon");
+        self.append_statement(transcript);
+
+        let _result = transcript.append_point(b"A_y", proof.get_a_y());
+        let _result = transcript.append_point(b"A_p", proof.get_a_p());
+        let _result = transcript.append_point(b"A_D", proof.get_a_d());
+        let _result = transcript.append_point(b"A_C", proof.get_a_c());
+
+        let c: ScalarField = transcript.challenge_scalar(b"c");
+
+        let _result = transcript.append_scalar(b"s_sk", proof.get_s_sk());
+        let _result = transcript.append_scalar(b"s_rho", proof.get_s_rho());
+        let _result = transcript.append_scalar(b"s_w", proof.get_s_w());
+        let _result = transcript.append_scalar(b"s_tau", proof.get_s_tau());
+        let _result = transcript.append_scalar(b"s_r", proof.get_s_r());
+
+        self.verify_with_challenges(proof, &c)
+    }
+
+    /// Appends the statement (g, h, D, Y', E_R, P, Q, sum(y), sum(C)) to the transcript.
+    pub(crate) fn append_statement(&self, transcript: &mut Transcript) {
+        let _result = transcript.append_point(b"g", self.g);
+        let _result = transcript.append_point(b"h", self.h);
+        let _result = transcript.append_point(b"D", self.d);
+        let _result = transcript.append_point(b"Y'", self.y_first);
+        let _result = transcript.append_point(b"E_R", self.e_r);
+        let _result = transcript.append_point(b"P", self.p);
+        let _result = transcript.append_point(b"Q", self.q);
+        let _result = transcript.append_point(b"y_sum", self.y_sum);
+        let _result = transcript.append_point(b"C_sum", self.c_sum);
+    }
+
+    pub fn verify_with_challenges(
+        &mut self,
+        proof: &SigmaAnonProof,
+        c: &ScalarField,
+    ) -> Result<(), Error> {
+        let left_eq_y: G1Point =
+            Utils::pedersen_commitment(proof.get_s_sk(), self.g, proof.get_s_rho(), self.h);
+        let right_eq_y: G1Point = *proof.get_a_y() + self.y_first.mul(c.into_repr()).into_affine();
+
+        let left_eq_p: G1Point =
+            Utils::pedersen_commitment(proof.get_s_w(), self.g, proof.get_s_tau(), self.h)
+                + self.e_r.mul(proof.get_s_sk().into_repr()).into_affine()
+                + self.q.mul(proof.get_s_r().into_repr()).into_affine();
+        let right_eq_p: G1Point = *proof.get_a_p() + self.p.mul(c.into_repr()).into_affine();
+
+        let left_eq_d: G1Point = self.g.mul(proof.get_s_r().into_repr()).into_affine();
+        let right_eq_d: G1Point = *proof.get_a_d() + self.d.mul(c.into_repr()).into_affine();
+
+        let left_eq_c: G1Point = self.y_sum.mul(proof.get_s_r().into_repr()).into_affine();
+        let right_eq_c: G1Point = *proof.get_a_c() + self.c_sum.mul(c.into_repr()).into_affine();
+
+        if left_eq_y == right_eq_y
+            && left_eq_p == right_eq_p
+            && left_eq_d == right_eq_d
+            && left_eq_c == right_eq_c
+        {
+            Ok(())
+        } else {
+            Err(throw(ProofError::ProofValidationError))
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub(crate) mod sigma_commit_proof;
+pub(crate) mod sigma_commit_prover;
+pub(crate) mod sigma_commit_verifier;
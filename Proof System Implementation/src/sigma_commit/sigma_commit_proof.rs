@@ -0,0 +1,38 @@
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+
+use ark_serialize::*;
+
+#[derive(Debug, CanonicalDeserialize, CanonicalSerialize)]
+pub struct SigmaCommitProof {
+    a_y: G1Point,
+    a_c: G1Point,
+    s_sk: ScalarField,
+    s_gamma: ScalarField,
+}
+
+impl SigmaCommitProof {
+    pub fn new(a_y: G1Point, a_c: G1Point, s_sk: ScalarField, s_gamma: ScalarField) -> Self {
+        SigmaCommitProof {
+            a_y,
+            a_c,
+            s_sk,
+            s_gamma,
+        }
+    }
+
+    pub fn get_a_y(&self) -> &G1Point {
+        &self.a_y
+    }
+
+    pub fn get_a_c(&self) -> &G1Point {
+        &self.a_c
+    }
+
+    pub fn get_s_sk(&self) -> &ScalarField {
+        &self.s_sk
+    }
+
+    pub fn get_s_gamma(&self) -> &ScalarField {
+        &self.s_gamma
+    }
+}
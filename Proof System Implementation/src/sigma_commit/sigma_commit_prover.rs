@@ -0,0 +1,90 @@
+use crate::errors::proof_error::throw;
+use crate::sigma_commit::sigma_commit_proof::SigmaCommitProof;
+use crate::sigma_commit::sigma_commit_verifier::SigmaCommitVerifier;
+use crate::transcript::TranscriptProtocol;
+use crate::utils::Utils;
+use crate::ProofError;
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_std::rand::Rng;
+use std::io::Error;
+
+/// Links the transfer ciphertexts C_i = a_i * g + sk * D to Pedersen commitments
+/// V_i = a_i * g + gamma_i * h, e.g. the committed inputs of an R1CS circuit, by proving
+/// C_i - V_i = sk * D - gamma_i * h for the sk behind the sender public key y.
+pub struct SigmaCommitProver<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    d: &'a G1Point,
+    c_vec: &'a Vec<G1Point>,
+    v_vec: &'a Vec<G1Point>,
+    sk: &'a ScalarField,
+    gamma_vec: &'a Vec<ScalarField>,
+}
+
+impl<'a> SigmaCommitProver<'a> {
+    pub fn new(
+        g: &'a G1Point,
+        h: &'a G1Point,
+        d: &'a G1Point,
+        c_vec: &'a Vec<G1Point>,
+        v_vec: &'a Vec<G1Point>,
+        sk: &'a ScalarField,
+        gamma_vec: &'a Vec<ScalarField>,
+    ) -> Self {
+        SigmaCommitProver {
+            g,
+            h,
+            d,
+            c_vec,
+            v_vec,
+            sk,
+            gamma_vec,
+        }
+    }
+
+    pub fn generate_proof<R: Rng, T: TranscriptProtocol>(
+        &mut self,
+        rng: &mut R,
+        transcript: &mut T,
+    ) -> Result<SigmaCommitProof, Error> {
+        let m: usize = self.c_vec.len();
+        if m == 0 || self.v_vec.len() != m || self.gamma_vec.len() != m {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+
+        transcript.domain_sep(b"SigmaCommit");
+        let y: G1Point = self.g.mul(self.sk.into_repr()).into_affine();
+        SigmaCommitVerifier::new(self.g, self.h, self.d, &y, self.c_vec, self.v_vec)
+            .append_statement(transcript);
+
+        // The m statements are batched with the powers z, z^2, ..., z^m
+        let z: ScalarField = transcript.challenge_scalar(b"z");
+        let z_vec: Vec<ScalarField> =
+            Utils::product_scalar(&z, &Utils::generate_scalar_exp_vector(m, &z));
+        let d_z: G1Point = self
+            .d
+            .mul(z_vec.iter().sum::<ScalarField>().into_repr())
+            .into_affine();
+        let gamma_z: ScalarField = Utils::inner_product_scalar_scalar(&z_vec, self.gamma_vec)?;
+
+        let k_sk: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let k_gamma: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+
+        let a_y: G1Point = self.g.mul(k_sk.into_repr()).into_affine();
+        let a_c: G1Point = Utils::pedersen_commitment(&k_sk, &d_z, &k_gamma, self.h);
+
+        let _result = transcript.append_point(b"A_y", &a_y);
+        let _result = transcript.append_point(b"A_C", &a_c);
+
+        let c: ScalarField = transcript.challenge_scalar(b"c");
+        let s_sk: ScalarField = k_sk + (c * self.sk);
+        let s_gamma: ScalarField = k_gamma - (c * gamma_z);
+
+        let _result = transcript.append_scalar(b"s_sk", &s_sk);
+        let _result = transcript.append_scalar(b"s_gamma", &s_gamma);
+
+        Ok(SigmaCommitProof::new(a_y, a_c, s_sk, s_gamma))
+    }
+}
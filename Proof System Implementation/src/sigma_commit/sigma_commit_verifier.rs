@@ -0,0 +1,99 @@
+use crate::errors::proof_error::throw;
+use crate::sigma_commit::sigma_commit_proof::SigmaCommitProof;
+use crate::transcript::TranscriptProtocol;
+use crate::utils::Utils;
+use crate::ProofError;
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{One, PrimeField, Zero};
+use std::io::Error;
+
+pub struct SigmaCommitVerifier<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    d: &'a G1Point,
+    y: &'a G1Point,
+    c_vec: &'a Vec<G1Point>,
+    v_vec: &'a Vec<G1Point>,
+}
+
+impl<'a> SigmaCommitVerifier<'a> {
+    pub fn new(
+        g: &'a G1Point,
+        h: &'a G1Point,
+        d: &'a G1Point,
+        y: &'a G1Point,
+        c_vec: &'a Vec<G1Point>,
+        v_vec: &'a Vec<G1Point>,
+    ) -> Self {
+        SigmaCommitVerifier {
+            g,
+            h,
+            d,
+            y,
+            c_vec,
+            v_vec,
+        }
+    }
+
+    pub fn verify_proof<T: TranscriptProtocol>(
+        &mut self,
+        proof: &SigmaCommitProof,
+        transcript: &mut T,
+    ) -> Result<(), Error> {
+        let m: usize = self.c_vec.len();
+        if m == 0 || self.v_vec.len() != m {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+
+        transcript.domain_sep(b"SigmaCommit");
+        self.append_statement(transcript);
+
+        let z: ScalarField = transcript.challenge_scalar(b"z");
+        let z_vec: Vec<ScalarField> =
+            Utils::product_scalar(&z, &Utils::generate_scalar_exp_vector(m, &z));
+
+        let _result = transcript.append_point(b"A_y", proof.get_a_y());
+        let _result = transcript.append_point(b"A_C", proof.get_a_c());
+
+        let c: ScalarField = transcript.challenge_scalar(b"c");
+
+        let _result = transcript.append_scalar(b"s_sk", proof.get_s_sk());
+        let _result = transcript.append_scalar(b"s_gamma", proof.get_s_gamma());
+
+        // s_sk * g = A_y + c * y
+        let left_eq_y: G1Point = self.g.mul(proof.get_s_sk().into_repr()).into_affine();
+        let right_eq_y: G1Point = *proof.get_a_y() + self.y.mul(c.into_repr()).into_affine();
+
+        // s_sk * (sum_i z^i) * D + s_gamma * h = A_C + c * sum_i z^i * (C_i - V_i)
+        let mut points: Vec<G1Point> = vec![*self.d, *self.h, *proof.get_a_c()];
+        let mut scalars: Vec<ScalarField> = vec![
+            *proof.get_s_sk() * z_vec.iter().sum::<ScalarField>(),
+            *proof.get_s_gamma(),
+            -ScalarField::one(),
+        ];
+        points.extend(self.c_vec.iter());
+        scalars.extend(Utils::product_scalar(&-c, &z_vec));
+        points.extend(self.v_vec.iter());
+        scalars.extend(Utils::product_scalar(&c, &z_vec));
+        let left_eq_c: G1Point = Utils::multiscalar_mul(&points, &scalars)?;
+
+        if left_eq_y == right_eq_y && left_eq_c.is_zero() {
+            Ok(())
+        } else {
+            Err(throw(ProofError::ProofValidationError))
+        }
+    }
+
+    /// Appends g, h, D, y and the pairs (C_i, V_i).
+    pub(crate) fn append_statement<T: TranscriptProtocol>(&self, transcript: &mut T) {
+        let _result = transcript.append_point(b"g", self.g);
+        let _result = transcript.append_point(b"h", self.h);
+        let _result = transcript.append_point(b"D", self.d);
+        let _result = transcript.append_point(b"y", self.y);
+        for (c, v) in self.c_vec.iter().zip(self.v_vec.iter()) {
+            let _result = transcript.append_point(b"C", c);
+            let _result = transcript.append_point(b"V", v);
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use ark_serialize::*;
+
+use crate::{RangePlusProof, SigmaABProof, SigmaSKProof};
+
+#[derive(Debug, CanonicalDeserialize, CanonicalSerialize)]
+pub struct DecryptThresholdProof {
+    range_plus_proof: RangePlusProof,
+    sigma_ab_proof: SigmaABProof,
+    sigma_sk_proof: SigmaSKProof,
+}
+
+impl DecryptThresholdProof {
+    pub fn new(
+        range_plus_proof: RangePlusProof,
+        sigma_ab_proof: SigmaABProof,
+        sigma_sk_proof: SigmaSKProof,
+    ) -> Self {
+        DecryptThresholdProof {
+            range_plus_proof,
+            sigma_ab_proof,
+            sigma_sk_proof,
+        }
+    }
+
+    pub fn get_range_plus_proof(&self) -> &RangePlusProof {
+        &self.range_plus_proof
+    }
+
+    pub fn get_sigma_ab_proof(&self) -> &SigmaABProof {
+        &self.sigma_ab_proof
+    }
+
+    pub fn get_sigma_sk_proof(&self) -> &SigmaSKProof {
+        &self.sigma_sk_proof
+    }
+}
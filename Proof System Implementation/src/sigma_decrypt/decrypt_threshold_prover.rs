@@ -0,0 +1,93 @@
+use crate::errors::proof_error::throw;
+use crate::sigma_decrypt::decrypt_threshold_proof::DecryptThresholdProof;
+use crate::transcript::TranscriptProtocol;
+use crate::{
+    ProofError, RangePlusProof, RangePlusProver, SigmaABProof, SigmaABProver, SigmaSKProof,
+    SigmaSKProver,
+};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_std::rand::Rng;
+use std::io::Error;
+
+/// Proves that the balance (C_L, C_R) encrypts a value b >= threshold without disclosing it.
+/// The range proof is on b - threshold, the value encrypted in (C_L - threshold * g, C_R), and
+/// is linked to the ciphertext by the Sigma-protocol `ab` on the same challenges.
+pub struct DecryptThresholdProver<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    g_vec: &'a Vec<G1Point>,
+    h_vec: &'a Vec<G1Point>,
+    n: usize,
+    c_r: &'a G1Point,
+    balance: usize,
+    threshold: usize,
+    sk: &'a ScalarField,
+}
+
+impl<'a> DecryptThresholdProver<'a> {
+    /// `g_vec` and `h_vec` must hold exactly n generators.
+    pub fn new(
+        g: &'a G1Point,
+        h: &'a G1Point,
+        g_vec: &'a Vec<G1Point>,
+        h_vec: &'a Vec<G1Point>,
+        n: usize,
+        c_r: &'a G1Point,
+        balance: usize,
+        threshold: usize,
+        sk: &'a ScalarField,
+    ) -> Self {
+        DecryptThresholdProver {
+            g,
+            h,
+            g_vec,
+            h_vec,
+            n,
+            c_r,
+            balance,
+            threshold,
+            sk,
+        }
+    }
+
+    pub fn generate_proof<R: Rng, T: TranscriptProtocol>(
+        &mut self,
+        rng: &mut R,
+        transcript: &mut T,
+    ) -> Result<DecryptThresholdProof, Error> {
+        let excess: usize = self
+            .balance
+            .checked_sub(self.threshold)
+            .ok_or_else(|| throw(ProofError::OutOfBoundsError))?;
+
+        transcript.domain_sep(b"DecryptThreshold");
+        let _result =
+            transcript.append_scalar(b"threshold", &ScalarField::from(self.threshold as u64));
+
+        let no_amounts: Vec<usize> = Vec::new();
+        let (range_plus_proof, sigma_ab_proof): (RangePlusProof, SigmaABProof) =
+            RangePlusProver::new(
+                self.g,
+                self.h,
+                excess,
+                &no_amounts,
+                self.g_vec,
+                self.h_vec,
+                self.n,
+            )
+            .generate_linked_proof(
+                rng,
+                transcript,
+                &mut SigmaABProver::new_single(self.g, self.c_r, excess, self.sk),
+            )?;
+
+        let sigma_sk_proof: SigmaSKProof =
+            SigmaSKProver::new(self.g, self.sk).generate_proof(rng, transcript);
+
+        Ok(DecryptThresholdProof::new(
+            range_plus_proof,
+            sigma_ab_proof,
+            sigma_sk_proof,
+        ))
+    }
+}
@@ -0,0 +1,77 @@
+use crate::errors::proof_error::throw;
+use crate::sigma_decrypt::decrypt_threshold_proof::DecryptThresholdProof;
+use crate::transcript::TranscriptProtocol;
+use crate::{ProofError, RangePlusVerifier, SigmaABVerifier, SigmaSKVerifier};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use std::io::Error;
+
+pub struct DecryptThresholdVerifier<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    g_vec: &'a Vec<G1Point>,
+    h_vec: &'a Vec<G1Point>,
+    n: usize,
+    y: &'a G1Point,
+    c_l: &'a G1Point,
+    c_r: &'a G1Point,
+    threshold: usize,
+}
+
+impl<'a> DecryptThresholdVerifier<'a> {
+    pub fn new(
+        g: &'a G1Point,
+        h: &'a G1Point,
+        g_vec: &'a Vec<G1Point>,
+        h_vec: &'a Vec<G1Point>,
+        n: usize,
+        y: &'a G1Point,
+        c_l: &'a G1Point,
+        c_r: &'a G1Point,
+        threshold: usize,
+    ) -> Self {
+        DecryptThresholdVerifier {
+            g,
+            h,
+            g_vec,
+            h_vec,
+            n,
+            y,
+            c_l,
+            c_r,
+            threshold,
+        }
+    }
+
+    pub fn verify_proof<T: TranscriptProtocol>(
+        &mut self,
+        proof: &DecryptThresholdProof,
+        transcript: &mut T,
+    ) -> Result<(), Error> {
+        let threshold: ScalarField = ScalarField::from(self.threshold as u64);
+
+        transcript.domain_sep(b"DecryptThreshold");
+        let _result = transcript.append_scalar(b"threshold", &threshold);
+
+        let c_l_excess: G1Point =
+            (self.c_l.into_projective() - self.g.mul(threshold.into_repr())).into_affine();
+        let range_plus_result: Result<(), Error> =
+            RangePlusVerifier::new(self.g, self.h, self.g_vec, self.h_vec, 1, self.n)
+                .verify_linked_proof(
+                    proof.get_range_plus_proof(),
+                    proof.get_sigma_ab_proof(),
+                    &mut SigmaABVerifier::new_single(self.g, self.c_r, &c_l_excess),
+                    transcript,
+                );
+
+        let sigma_sk_result: Result<(), Error> = SigmaSKVerifier::new(self.g, self.y)
+            .verify_proof(proof.get_sigma_sk_proof(), transcript);
+
+        if range_plus_result.is_ok() && sigma_sk_result.is_ok() {
+            Ok(())
+        } else {
+            Err(throw(ProofError::ProofValidationError))
+        }
+    }
+}
@@ -0,0 +1,6 @@
+pub(crate) mod decrypt_threshold_proof;
+pub(crate) mod decrypt_threshold_prover;
+pub(crate) mod decrypt_threshold_verifier;
+pub(crate) mod sigma_decrypt_proof;
+pub(crate) mod sigma_decrypt_prover;
+pub(crate) mod sigma_decrypt_verifier;
@@ -0,0 +1,28 @@
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+
+use ark_serialize::*;
+
+#[derive(Debug, CanonicalDeserialize, CanonicalSerialize)]
+pub struct SigmaDecryptProof {
+    a_y: G1Point,
+    a_c: G1Point,
+    s_sk: ScalarField,
+}
+
+impl SigmaDecryptProof {
+    pub fn new(a_y: G1Point, a_c: G1Point, s_sk: ScalarField) -> Self {
+        SigmaDecryptProof { a_y, a_c, s_sk }
+    }
+
+    pub fn get_a_y(&self) -> &G1Point {
+        &self.a_y
+    }
+
+    pub fn get_a_c(&self) -> &G1Point {
+        &self.a_c
+    }
+
+    pub fn get_s_sk(&self) -> &ScalarField {
+        &self.s_sk
+    }
+}
@@ -0,0 +1,51 @@
+use crate::sigma_decrypt::sigma_decrypt_proof::SigmaDecryptProof;
+use crate::sigma_decrypt::sigma_decrypt_verifier::SigmaDecryptVerifier;
+use crate::transcript::TranscriptProtocol;
+use crate::utils::Utils;
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_std::rand::Rng;
+
+/// Discloses the value v encrypted in the balance (C_L, C_R) without revealing sk, proving
+/// C_L - v * g = sk * C_R and y = sk * g.
+pub struct SigmaDecryptProver<'a> {
+    g: &'a G1Point,
+    c_r: &'a G1Point,
+    value: usize,
+    sk: &'a ScalarField,
+}
+
+impl<'a> SigmaDecryptProver<'a> {
+    pub fn new(g: &'a G1Point, c_r: &'a G1Point, value: usize, sk: &'a ScalarField) -> Self {
+        SigmaDecryptProver { g, c_r, value, sk }
+    }
+
+    pub fn generate_proof<R: Rng, T: TranscriptProtocol>(
+        &mut self,
+        rng: &mut R,
+        transcript: &mut T,
+    ) -> SigmaDecryptProof {
+        transcript.domain_sep(b"SigmaDecrypt");
+        let y: G1Point = self.g.mul(self.sk.into_repr()).into_affine();
+        let c_l: G1Point = self
+            .g
+            .mul(ScalarField::from(self.value as u64).into_repr())
+            .into_affine()
+            + self.c_r.mul(self.sk.into_repr()).into_affine();
+        SigmaDecryptVerifier::new(self.g, &y, &c_l, self.c_r, self.value)
+            .append_statement(transcript);
+
+        let k_sk: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let a_y: G1Point = self.g.mul(k_sk.into_repr()).into_affine();
+        let a_c: G1Point = self.c_r.mul(k_sk.into_repr()).into_affine();
+        let _result = transcript.append_point(b"A_y", &a_y);
+        let _result = transcript.append_point(b"A_C", &a_c);
+
+        let c: ScalarField = transcript.challenge_scalar(b"c");
+        let s_sk: ScalarField = (*self.sk * c) + k_sk;
+        let _result = transcript.append_scalar(b"s_sk", &s_sk);
+
+        SigmaDecryptProof::new(a_y, a_c, s_sk)
+    }
+}
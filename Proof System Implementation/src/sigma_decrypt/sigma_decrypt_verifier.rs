@@ -0,0 +1,77 @@
+use crate::transcript::TranscriptProtocol;
+use crate::ProofError;
+use crate::{errors::proof_error::throw, sigma_decrypt::sigma_decrypt_proof::SigmaDecryptProof};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use std::io::Error;
+
+pub struct SigmaDecryptVerifier<'a> {
+    g: &'a G1Point,
+    y: &'a G1Point,
+    c_l: &'a G1Point,
+    c_r: &'a G1Point,
+    value: usize,
+}
+
+impl<'a> SigmaDecryptVerifier<'a> {
+    pub fn new(
+        g: &'a G1Point,
+        y: &'a G1Point,
+        c_l: &'a G1Point,
+        c_r: &'a G1Point,
+        value: usize,
+    ) -> Self {
+        SigmaDecryptVerifier {
+            g,
+            y,
+            c_l,
+            c_r,
+            value,
+        }
+    }
+
+    pub fn verify_proof<T: TranscriptProtocol>(
+        &mut self,
+        proof: &SigmaDecryptProof,
+        transcript: &mut T,
+    ) -> Result<(), Error> {
+        let v: ScalarField = ScalarField::from(self.value as u64);
+
+        transcript.domain_sep(b"SigmaDecrypt");
+        self.append_statement(transcript);
+
+        let _result = transcript.append_point(b"A_y", proof.get_a_y());
+        let _result = transcript.append_point(b"A_C", proof.get_a_c());
+
+        let c: ScalarField = transcript.challenge_scalar(b"c");
+        let _result = transcript.append_scalar(b"s_sk", proof.get_s_sk());
+
+        // s_sk * g = A_y + c * y
+        let left_eq_y: G1Point = self.g.mul(proof.get_s_sk().into_repr()).into_affine();
+        let right_eq_y: G1Point = *proof.get_a_y() + self.y.mul(c.into_repr()).into_affine();
+
+        // s_sk * C_R = A_C + c * (C_L - v * g)
+        let left_eq_c: G1Point = self.c_r.mul(proof.get_s_sk().into_repr()).into_affine();
+        let right_eq_c: G1Point = *proof.get_a_c()
+            + (self.c_l.into_projective() - self.g.mul(v.into_repr()))
+                .into_affine()
+                .mul(c.into_repr())
+                .into_affine();
+
+        if left_eq_y == right_eq_y && left_eq_c == right_eq_c {
+            Ok(())
+        } else {
+            Err(throw(ProofError::ProofValidationError))
+        }
+    }
+
+    /// Appends g, y, C_L, C_R and v.
+    pub(crate) fn append_statement<T: TranscriptProtocol>(&self, transcript: &mut T) {
+        let _result = transcript.append_point(b"g", self.g);
+        let _result = transcript.append_point(b"y", self.y);
+        let _result = transcript.append_point(b"C_L", self.c_l);
+        let _result = transcript.append_point(b"C_R", self.c_r);
+        let _result = transcript.append_scalar(b"v", &ScalarField::from(self.value as u64));
+    }
+}
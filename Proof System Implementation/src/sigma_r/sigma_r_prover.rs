@@ -5,7 +5,6 @@ use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
 use ark_ec::{AffineCurve, ProjectiveCurve};
 use ark_ff::PrimeField;
 use ark_std::rand::Rng;
-use merlin::Transcript;
 
 pub struct SigmaRProver<'a> {
     g: &'a G1Point,
@@ -17,12 +16,15 @@ impl<'a> SigmaRProver<'a> {
         SigmaRProver { g, r }
     }
 
-    pub fn generate_proof<R: Rng>(
+    pub fn generate_proof<R: Rng, T: TranscriptProtocol>(
         &mut self,
         rng: &mut R,
-        transcript: &mut Transcript,
+        transcript: &mut T,
     ) -> SigmaRProof {
         transcript.domain_sep(b"SigmaR");
+        let d: G1Point = self.g.mul(self.r.into_repr()).into_affine();
+        let _result = transcript.append_point(b"g", self.g);
+        let _result = transcript.append_point(b"D", &d);
 
         let k_r: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
         let a_d: G1Point = self.g.mul(k_r.into_repr()).into_affine();
@@ -34,4 +36,14 @@ impl<'a> SigmaRProver<'a> {
 
         SigmaRProof::new(a_d, s_r)
     }
+
+    /// Honest-verifier simulator: outputs a proof for D = r * g that is accepting for the
+    /// challenge `c`, without knowing r.
+    pub fn simulate<R: Rng>(g: &G1Point, d: &G1Point, c: &ScalarField, rng: &mut R) -> SigmaRProof {
+        let s_r: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let a_d: G1Point =
+            g.mul(s_r.into_repr()).into_affine() + -d.mul(c.into_repr()).into_affine();
+
+        SigmaRProof::new(a_d, s_r)
+    }
 }
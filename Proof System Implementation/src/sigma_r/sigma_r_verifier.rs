@@ -4,7 +4,6 @@ use crate::{errors::proof_error::throw, sigma_r::sigma_r_proof::SigmaRProof};
 use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
 use ark_ec::{AffineCurve, ProjectiveCurve};
 use ark_ff::PrimeField;
-use merlin::Transcript;
 use std::io::Error;
 
 pub struct SigmaRVerifier<'a> {
@@ -18,18 +17,28 @@ impl<'a> SigmaRVerifier<'a> {
         SigmaRVerifier { g, d }
     }
 
-    pub fn verify_proof(
+    pub fn verify_proof<T: TranscriptProtocol>(
         &mut self,
         proof: &SigmaRProof,
-        transcript: &mut Transcript,
+        transcript: &mut T,
     ) -> Result<(), Error> {
         transcript.domain_sep(b"SigmaR");
+        let _result = transcript.append_point(b"g", self.g);
+        let _result = transcript.append_point(b"D", self.d);
 
         let _result = transcript.append_point(b"A_D", proof.get_a_d());
 
         let c: ScalarField = transcript.challenge_scalar(b"c");
         let _result = transcript.append_scalar(b"s_r", proof.get_s_r());
 
+        self.verify_with_challenge(proof, &c)
+    }
+
+    pub fn verify_with_challenge(
+        &mut self,
+        proof: &SigmaRProof,
+        c: &ScalarField,
+    ) -> Result<(), Error> {
         let left_eq: G1Point = self.g.mul(proof.get_s_r().into_repr()).into_affine();
         let right_eq: G1Point = *proof.get_a_d() + (self.d.mul(c.into_repr()).into_affine());
 
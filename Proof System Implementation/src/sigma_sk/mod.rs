@@ -1,3 +1,5 @@
+pub(crate) mod registration_prover;
+pub(crate) mod registration_verifier;
 pub(crate) mod sigma_sk_proof;
 pub(crate) mod sigma_sk_prover;
-pub(crate) mod sigma_sk_verifier;
\ No newline at end of file
+pub(crate) mod sigma_sk_verifier;
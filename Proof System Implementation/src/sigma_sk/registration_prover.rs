@@ -0,0 +1,57 @@
+use crate::sigma_sk::sigma_sk_proof::SigmaSKProof;
+use crate::transcript::TranscriptProtocol;
+use crate::{AssociatedData, ElGamal, SigmaSKProver};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_std::rand::Rng;
+use merlin::Transcript;
+
+/// Proves knowledge of the private key of y = sk * g on registration. The Sigma-protocol `sk`
+/// runs on a transcript that absorbs the associated data, e.g. the chain id, extended with the
+/// registering account address, and then g and y, so the proof cannot be replayed to register y
+/// from another account or in another context.
+pub struct RegistrationProver<'a> {
+    g: &'a G1Point,
+    sk: &'a ScalarField,
+    address: &'a [u8],
+    associated_data: &'a AssociatedData,
+}
+
+impl<'a> RegistrationProver<'a> {
+    pub fn new(
+        g: &'a G1Point,
+        sk: &'a ScalarField,
+        address: &'a [u8],
+        associated_data: &'a AssociatedData,
+    ) -> Self {
+        RegistrationProver {
+            g,
+            sk,
+            address,
+            associated_data,
+        }
+    }
+
+    pub fn generate_proof<R: Rng>(&mut self, rng: &mut R) -> SigmaSKProof {
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(self.sk, self.g);
+        let mut transcript: Transcript =
+            RegistrationProver::get_transcript(self.g, &y, self.address, self.associated_data);
+
+        SigmaSKProver::new(self.g, self.sk).generate_proof(rng, &mut transcript)
+    }
+
+    pub(crate) fn get_transcript(
+        g: &G1Point,
+        y: &G1Point,
+        address: &[u8],
+        associated_data: &AssociatedData,
+    ) -> Transcript {
+        let mut transcript: Transcript = Transcript::new(b"ZeroMTRegistration");
+        associated_data
+            .clone()
+            .with_account_address(address)
+            .append_to_transcript(&mut transcript);
+        let _result = transcript.append_point(b"g", g);
+        let _result = transcript.append_point(b"y", y);
+        transcript
+    }
+}
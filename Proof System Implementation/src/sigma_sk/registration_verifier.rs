@@ -0,0 +1,35 @@
+use crate::sigma_sk::sigma_sk_proof::SigmaSKProof;
+use crate::{AssociatedData, RegistrationProver, SigmaSKVerifier};
+use ark_bn254::G1Affine as G1Point;
+use merlin::Transcript;
+use std::io::Error;
+
+pub struct RegistrationVerifier<'a> {
+    g: &'a G1Point,
+    y: &'a G1Point,
+    address: &'a [u8],
+    associated_data: &'a AssociatedData,
+}
+
+impl<'a> RegistrationVerifier<'a> {
+    pub fn new(
+        g: &'a G1Point,
+        y: &'a G1Point,
+        address: &'a [u8],
+        associated_data: &'a AssociatedData,
+    ) -> Self {
+        RegistrationVerifier {
+            g,
+            y,
+            address,
+            associated_data,
+        }
+    }
+
+    pub fn verify_proof(&mut self, proof: &SigmaSKProof) -> Result<(), Error> {
+        let mut transcript: Transcript =
+            RegistrationProver::get_transcript(self.g, self.y, self.address, self.associated_data);
+
+        SigmaSKVerifier::new(self.g, self.y).verify_proof(proof, &mut transcript)
+    }
+}
@@ -5,7 +5,6 @@ use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
 use ark_ec::{AffineCurve, ProjectiveCurve};
 use ark_ff::PrimeField;
 use ark_std::rand::Rng;
-use merlin::Transcript;
 
 pub struct SigmaSKProver<'a> {
     g: &'a G1Point,
@@ -17,12 +16,16 @@ impl<'a> SigmaSKProver<'a> {
         SigmaSKProver { g, sk }
     }
 
-    pub fn generate_proof<R: Rng>(
+    pub fn generate_proof<R: Rng, T: TranscriptProtocol>(
         &mut self,
         rng: &mut R,
-        transcript: &mut Transcript,
+        transcript: &mut T,
     ) -> SigmaSKProof {
         transcript.domain_sep(b"SigmaSK");
+        let y: G1Point = self.g.mul(self.sk.into_repr()).into_affine();
+        let _result = transcript.append_point(b"g", self.g);
+        let _result = transcript.append_point(b"y", &y);
+
         let k_sk: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
         let a_y: G1Point = self.g.mul(k_sk.into_repr()).into_affine();
         let _result = transcript.append_point(b"A_y", &a_y);
@@ -33,4 +36,19 @@ impl<'a> SigmaSKProver<'a> {
 
         SigmaSKProof::new(a_y, s_sk)
     }
+
+    /// Honest-verifier simulator: outputs a proof for y = sk * g that is accepting for the
+    /// challenge `c`, without knowing sk.
+    pub fn simulate<R: Rng>(
+        g: &G1Point,
+        y: &G1Point,
+        c: &ScalarField,
+        rng: &mut R,
+    ) -> SigmaSKProof {
+        let s_sk: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let a_y: G1Point =
+            g.mul(s_sk.into_repr()).into_affine() + -y.mul(c.into_repr()).into_affine();
+
+        SigmaSKProof::new(a_y, s_sk)
+    }
 }
@@ -4,7 +4,6 @@ use crate::{errors::proof_error::throw, sigma_sk::sigma_sk_proof::SigmaSKProof};
 use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
 use ark_ec::{AffineCurve, ProjectiveCurve};
 use ark_ff::PrimeField;
-use merlin::Transcript;
 use std::io::Error;
 
 pub struct SigmaSKVerifier<'a> {
@@ -17,18 +16,28 @@ impl<'a> SigmaSKVerifier<'a> {
         SigmaSKVerifier { g, y }
     }
 
-    pub fn verify_proof(
+    pub fn verify_proof<T: TranscriptProtocol>(
         &mut self,
         proof: &SigmaSKProof,
-        transcript: &mut Transcript,
+        transcript: &mut T,
     ) -> Result<(), Error> {
         transcript.domain_sep(b"SigmaSK");
+        let _result = transcript.append_point(b"g", self.g);
+        let _result = transcript.append_point(b"y", self.y);
 
         let _result = transcript.append_point(b"A_y", proof.get_a_y());
 
         let c: ScalarField = transcript.challenge_scalar(b"c");
         let _result = transcript.append_scalar(b"s_sk", proof.get_s_sk());
 
+        self.verify_with_challenge(proof, &c)
+    }
+
+    pub fn verify_with_challenge(
+        &mut self,
+        proof: &SigmaSKProof,
+        c: &ScalarField,
+    ) -> Result<(), Error> {
         let left_eq: G1Point = self.g.mul(proof.get_s_sk().into_repr()).into_affine();
         let right_eq: G1Point = *proof.get_a_y() + (self.y.mul(c.into_repr()).into_affine());
 
@@ -6,11 +6,22 @@ use ark_serialize::*;
 pub struct SigmaYProof {
     a_y_bar: G1Point,
     s_r: ScalarField,
+    a_auditor: Option<G1Point>,
 }
 
 impl SigmaYProof {
     pub fn new(a_y_bar: G1Point, s_r: ScalarField) -> Self {
-        SigmaYProof { a_y_bar, s_r }
+        SigmaYProof {
+            a_y_bar,
+            s_r,
+            a_auditor: None,
+        }
+    }
+
+    /// Commitment of the auditor ciphertexts consistency, see `SigmaYProver::with_auditor`.
+    pub fn with_a_auditor(mut self, a_auditor: G1Point) -> Self {
+        self.a_auditor = Some(a_auditor);
+        self
     }
 
     pub fn get_a_y_bar(&self) -> &G1Point {
@@ -20,4 +31,8 @@ impl SigmaYProof {
     pub fn get_s_r(&self) -> &ScalarField {
         &self.s_r
     }
+
+    pub fn get_a_auditor(&self) -> Option<&G1Point> {
+        self.a_auditor.as_ref()
+    }
 }
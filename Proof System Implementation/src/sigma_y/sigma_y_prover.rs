@@ -3,27 +3,67 @@ use crate::transcript::TranscriptProtocol;
 use crate::utils::Utils;
 use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
 use ark_ec::{AffineCurve, ProjectiveCurve};
-use ark_ff::PrimeField;
+use ark_ff::{Field, PrimeField};
 use ark_std::rand::Rng;
-use merlin::Transcript;
 
 pub struct SigmaYProver<'a> {
     r: &'a ScalarField,
     y: &'a G1Point,
     y_bar: &'a Vec<G1Point>,
+    auditor: Option<(&'a G1Point, &'a Vec<G1Point>)>,
 }
 
 impl<'a> SigmaYProver<'a> {
     pub fn new(r: &'a ScalarField, y: &'a G1Point, y_bar: &'a Vec<G1Point>) -> Self {
-        SigmaYProver { r, y, y_bar }
+        SigmaYProver {
+            r,
+            y,
+            y_bar,
+            auditor: None,
+        }
     }
 
-    pub fn generate_proof<R: Rng>(
+    /// Also proves that the amounts are encrypted under the auditor public key with the same
+    /// r, i.e. C_i - C_auditor_i = r * (y - y_auditor) for every i. The statements are weighted
+    /// by the powers of a challenge z, and share s_r with the recipients' one.
+    pub fn with_auditor(mut self, y_auditor: &'a G1Point, c_auditor_vec: &'a Vec<G1Point>) -> Self {
+        self.auditor = Some((y_auditor, c_auditor_vec));
+        self
+    }
+
+    pub fn generate_proof<R: Rng, T: TranscriptProtocol>(
         &mut self,
         rng: &mut R,
-        transcript: &mut Transcript,
+        transcript: &mut T,
     ) -> SigmaYProof {
         transcript.domain_sep(b"SigmaY");
+        // The ciphertexts enter the statement as C_i - C_bar_i = r * (y - y_bar_i)
+        let _result = transcript.append_point(b"y", self.y);
+        for y_i in self.y_bar.iter() {
+            let _result = transcript.append_point(b"y_bar", y_i);
+            let c_diff: G1Point = (self.y.into_projective() - y_i.into_projective())
+                .into_affine()
+                .mul(self.r.into_repr())
+                .into_affine();
+            let _result = transcript.append_point(b"C_diff", &c_diff);
+        }
+
+        // (y - y_auditor) * sum_i z^i, the base raised to r in the auditor statement
+        let auditor_base: Option<G1Point> = self.auditor.map(|(y_auditor, c_auditor_vec)| {
+            let _result = transcript.append_point(b"y_auditor", y_auditor);
+            for c_auditor in c_auditor_vec.iter() {
+                let _result = transcript.append_point(b"C_auditor", c_auditor);
+            }
+            let z: ScalarField = transcript.challenge_scalar(b"z");
+            let z_sum: ScalarField = (1..=c_auditor_vec.len())
+                .map(|i: usize| z.pow([i as u64]))
+                .sum();
+
+            (self.y.into_projective() - y_auditor.into_projective())
+                .into_affine()
+                .mul(z_sum.into_repr())
+                .into_affine()
+        });
 
         let k_r: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
 
@@ -37,10 +77,94 @@ impl<'a> SigmaYProver<'a> {
 
         let _result = transcript.append_point(b"A_y_bar", &a_y_bar);
 
+        let a_auditor: Option<G1Point> =
+            auditor_base.map(|base: G1Point| base.mul(k_r.into_repr()).into_affine());
+        if let Some(a) = a_auditor.as_ref() {
+            let _result = transcript.append_point(b"A_auditor", a);
+        }
+
         let c: ScalarField = transcript.challenge_scalar(b"c");
         let s_r: ScalarField = (*self.r * c) + k_r;
         let _result = transcript.append_scalar(b"s_r", &s_r);
 
+        match a_auditor {
+            Some(a) => SigmaYProof::new(a_y_bar, s_r).with_a_auditor(a),
+            None => SigmaYProof::new(a_y_bar, s_r),
+        }
+    }
+
+    /// Honest-verifier simulator: outputs a proof that `c_vec` and `c_bar_vec` encrypt the
+    /// same amounts which is accepting for the challenge `c`, without knowing r.
+    pub fn simulate<R: Rng>(
+        y: &G1Point,
+        y_bar: &Vec<G1Point>,
+        c_vec: &Vec<G1Point>,
+        c_bar_vec: &Vec<G1Point>,
+        c: &ScalarField,
+        rng: &mut R,
+    ) -> SigmaYProof {
+        let s_r: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+
+        let y_diff: G1Point = y_bar
+            .iter()
+            .map(|y_i: &G1Point| (y.into_projective() - y_i.into_projective()).into_affine())
+            .sum::<G1Point>();
+
+        let c_diff: G1Point = c_vec
+            .iter()
+            .zip(c_bar_vec.iter())
+            .map(|(c_i, c_bar_i): (&G1Point, &G1Point)| {
+                (c_i.into_projective() - c_bar_i.into_projective()).into_affine()
+            })
+            .sum::<G1Point>();
+
+        let a_y_bar: G1Point =
+            y_diff.mul(s_r.into_repr()).into_affine() + -c_diff.mul(c.into_repr()).into_affine();
+
         SigmaYProof::new(a_y_bar, s_r)
     }
+
+    /// Honest-verifier simulator for the statement extended with the auditor ciphertexts, see
+    /// `with_auditor`: the proof is accepting for the challenges `z` and `c`, and A_auditor is
+    /// fixed by the same s_r as A_y_bar.
+    pub fn simulate_with_auditor<R: Rng>(
+        y: &G1Point,
+        y_bar: &Vec<G1Point>,
+        c_vec: &Vec<G1Point>,
+        c_bar_vec: &Vec<G1Point>,
+        y_auditor: &G1Point,
+        c_auditor_vec: &Vec<G1Point>,
+        z: &ScalarField,
+        c: &ScalarField,
+        rng: &mut R,
+    ) -> SigmaYProof {
+        let proof: SigmaYProof = SigmaYProver::simulate(y, y_bar, c_vec, c_bar_vec, c, rng);
+        let s_r: ScalarField = *proof.get_s_r();
+
+        let z_vec: Vec<ScalarField> = (1..=c_auditor_vec.len())
+            .map(|i: usize| z.pow([i as u64]))
+            .collect();
+        let auditor_base: G1Point = (y.into_projective() - y_auditor.into_projective())
+            .into_affine()
+            .mul(z_vec.iter().sum::<ScalarField>().into_repr())
+            .into_affine();
+        let c_auditor_diff: G1Point = c_vec
+            .iter()
+            .zip(c_auditor_vec.iter())
+            .zip(z_vec.iter())
+            .map(
+                |((c_i, c_auditor_i), z_i): ((&G1Point, &G1Point), &ScalarField)| {
+                    (c_i.into_projective() - c_auditor_i.into_projective())
+                        .into_affine()
+                        .mul(z_i.into_repr())
+                        .into_affine()
+                },
+            )
+            .sum::<G1Point>();
+
+        let a_auditor: G1Point = auditor_base.mul(s_r.into_repr()).into_affine()
+            + -c_auditor_diff.mul(c.into_repr()).into_affine();
+
+        proof.with_a_auditor(a_auditor)
+    }
 }
@@ -3,8 +3,7 @@ use crate::ProofError;
 use crate::{errors::proof_error::throw, sigma_y::sigma_y_proof::SigmaYProof};
 use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
 use ark_ec::{AffineCurve, ProjectiveCurve};
-use ark_ff::PrimeField;
-use merlin::Transcript;
+use ark_ff::{Field, PrimeField};
 use std::io::Error;
 
 pub struct SigmaYVerifier<'a> {
@@ -12,6 +11,7 @@ pub struct SigmaYVerifier<'a> {
     y_bar: &'a Vec<G1Point>,
     c_vec: &'a Vec<G1Point>,
     c_bar_vec: &'a Vec<G1Point>,
+    auditor: Option<(&'a G1Point, &'a Vec<G1Point>)>,
 }
 
 impl<'a> SigmaYVerifier<'a> {
@@ -26,21 +26,130 @@ impl<'a> SigmaYVerifier<'a> {
             y_bar,
             c_vec,
             c_bar_vec,
+            auditor: None,
         }
     }
 
-    pub fn verify_proof(
+    /// Checks the auditor ciphertexts as well, see `SigmaYProver::with_auditor`.
+    pub fn with_auditor(mut self, y_auditor: &'a G1Point, c_auditor_vec: &'a Vec<G1Point>) -> Self {
+        self.auditor = Some((y_auditor, c_auditor_vec));
+        self
+    }
+
+    pub fn verify_proof<T: TranscriptProtocol>(
         &mut self,
         proof: &SigmaYProof,
-        transcript: &mut Transcript,
+        transcript: &mut T,
     ) -> Result<(), Error> {
         transcript.domain_sep(b"SigmaY");
+        if self.y_bar.len() != self.c_vec.len() || self.c_bar_vec.len() != self.c_vec.len() {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+        let _result = transcript.append_point(b"y", self.y);
+        for ((y_i, c_i), c_bar_i) in self
+            .y_bar
+            .iter()
+            .zip(self.c_vec.iter())
+            .zip(self.c_bar_vec.iter())
+        {
+            let _result = transcript.append_point(b"y_bar", y_i);
+            let c_diff: G1Point = (c_i.into_projective() - c_bar_i.into_projective()).into_affine();
+            let _result = transcript.append_point(b"C_diff", &c_diff);
+        }
+
+        let z: Option<ScalarField> = match self.auditor {
+            Some((y_auditor, c_auditor_vec)) => {
+                if c_auditor_vec.len() != self.c_vec.len() {
+                    return Err(throw(ProofError::VectorLengthError));
+                }
+                let _result = transcript.append_point(b"y_auditor", y_auditor);
+                for c_auditor in c_auditor_vec.iter() {
+                    let _result = transcript.append_point(b"C_auditor", c_auditor);
+                }
+                Some(transcript.challenge_scalar(b"z"))
+            }
+            None => None,
+        };
 
         let _result = transcript.append_point(b"A_y_bar", proof.get_a_y_bar());
+        if let Some(a_auditor) = proof.get_a_auditor() {
+            let _result = transcript.append_point(b"A_auditor", a_auditor);
+        }
 
         let c: ScalarField = transcript.challenge_scalar(b"c");
         let _result = transcript.append_scalar(b"s_r", proof.get_s_r());
 
+        match (z, proof.get_a_auditor()) {
+            (Some(z), Some(_)) => self.verify_with_challenges(proof, &z, &c),
+            (None, None) => self.verify_with_challenge(proof, &c),
+            _ => Err(throw(ProofError::ProofValidationError)),
+        }
+    }
+
+    /// Checks the proof of the statement extended with the auditor ciphertexts against the
+    /// challenges `z` and `c` instead of deriving them from a transcript.
+    pub fn verify_with_challenges(
+        &mut self,
+        proof: &SigmaYProof,
+        z: &ScalarField,
+        c: &ScalarField,
+    ) -> Result<(), Error> {
+        let a_auditor: &G1Point = proof
+            .get_a_auditor()
+            .ok_or_else(|| throw(ProofError::ProofValidationError))?;
+        self.verify_with_challenge(proof, c)?;
+        self.verify_auditor(proof.get_s_r(), a_auditor, z, c)
+    }
+
+    /// s_r * (y - y_auditor) * sum_i z^i = A_auditor + c * sum_i z^i * (C_i - C_auditor_i)
+    fn verify_auditor(
+        &self,
+        s_r: &ScalarField,
+        a_auditor: &G1Point,
+        z: &ScalarField,
+        c: &ScalarField,
+    ) -> Result<(), Error> {
+        let (y_auditor, c_auditor_vec): (&G1Point, &Vec<G1Point>) = match self.auditor {
+            Some(auditor) => auditor,
+            None => return Err(throw(ProofError::ProofValidationError)),
+        };
+        let z_vec: Vec<ScalarField> = (1..=self.c_vec.len())
+            .map(|i: usize| z.pow([i as u64]))
+            .collect();
+
+        let left_eq: G1Point = (self.y.into_projective() - y_auditor.into_projective())
+            .into_affine()
+            .mul((z_vec.iter().sum::<ScalarField>() * s_r).into_repr())
+            .into_affine();
+
+        let right_eq: G1Point = *a_auditor
+            + self
+                .c_vec
+                .iter()
+                .zip(c_auditor_vec.iter())
+                .zip(z_vec.iter())
+                .map(
+                    |((c_i, c_auditor_i), z_i): ((&G1Point, &G1Point), &ScalarField)| {
+                        (c_i.into_projective() - c_auditor_i.into_projective())
+                            .into_affine()
+                            .mul((*z_i * c).into_repr())
+                            .into_affine()
+                    },
+                )
+                .sum::<G1Point>();
+
+        if left_eq == right_eq {
+            Ok(())
+        } else {
+            Err(throw(ProofError::ProofValidationError))
+        }
+    }
+
+    pub fn verify_with_challenge(
+        &mut self,
+        proof: &SigmaYProof,
+        c: &ScalarField,
+    ) -> Result<(), Error> {
         let left_eq: G1Point = self
             .y_bar
             .iter()
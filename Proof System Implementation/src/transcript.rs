@@ -5,6 +5,8 @@ use ark_ff::PrimeField;
 use ark_serialize::CanonicalSerialize;
 use ark_std::Zero;
 use merlin::Transcript;
+#[cfg(feature = "extractor")]
+use std::collections::VecDeque;
 use std::io::Error;
 
 use crate::{errors::transcript_error::throw, TranscriptError};
@@ -76,3 +78,55 @@ impl TranscriptProtocol for Transcript {
         return self.append_point(label, &point);
     }
 }
+
+/// Transcript answering the challenge requests with the injected challenges, in order, and
+/// with the `merlin` challenges once they run out. Used to rewind a prover on chosen challenges,
+/// so it is only built with the `extractor` feature.
+#[cfg(feature = "extractor")]
+pub struct InjectedTranscript {
+    transcript: Transcript,
+    challenges: VecDeque<ScalarField>,
+}
+
+#[cfg(feature = "extractor")]
+impl InjectedTranscript {
+    pub fn new(label: &'static [u8], challenges: &Vec<ScalarField>) -> Self {
+        InjectedTranscript {
+            transcript: Transcript::new(label),
+            challenges: challenges.iter().cloned().collect(),
+        }
+    }
+}
+
+#[cfg(feature = "extractor")]
+impl TranscriptProtocol for InjectedTranscript {
+    fn domain_sep(&mut self, label: &'static [u8]) {
+        self.transcript.domain_sep(label);
+    }
+
+    fn append_scalar(&mut self, label: &'static [u8], scalar: &ScalarField) -> Result<(), Error> {
+        self.transcript.append_scalar(label, scalar)
+    }
+
+    fn append_point(&mut self, label: &'static [u8], point: &G1Point) -> Result<(), Error> {
+        self.transcript.append_point(label, point)
+    }
+
+    fn validate_and_append_point(
+        &mut self,
+        label: &'static [u8],
+        point: &G1Point,
+    ) -> Result<(), Error> {
+        self.transcript.validate_and_append_point(label, point)
+    }
+
+    fn challenge_scalar(&mut self, label: &'static [u8]) -> ScalarField {
+        match self.challenges.pop_front() {
+            Some(challenge) => {
+                let _result = self.transcript.append_scalar(label, &challenge);
+                challenge
+            }
+            None => self.transcript.challenge_scalar(label),
+        }
+    }
+}
@@ -2,10 +2,12 @@ use ark_bn254::g1::{G1_GENERATOR_X, G1_GENERATOR_Y};
 use ark_bn254::{Fr as ScalarField, G1Affine as G1Point, G1Projective};
 use ark_crypto_primitives::commitment::pedersen::Window;
 use ark_crypto_primitives::crh::pedersen::CRH;
+use ark_ec::msm::VariableBaseMSM;
 use ark_ec::{AffineCurve, ProjectiveCurve};
-use ark_ff::{Field, PrimeField, Zero};
+use ark_ff::{batch_inversion, Field, PrimeField, Zero};
 use ark_std::rand::Rng;
 use ark_std::UniformRand;
+use merlin::Transcript;
 use std::io::Error;
 
 use crate::errors::utils_error::throw;
@@ -66,6 +68,44 @@ impl Utils {
         return G1Point::new(G1_GENERATOR_X, G1_GENERATOR_Y, false);
     }
 
+    /// Nothing-up-my-sleeve point: the x coordinate is read from the output of a merlin
+    /// transcript bound to `label` and `index`, retrying until it lands on the curve.
+    /// Nobody knows the discrete logarithm of the result with respect to any other point.
+    pub fn hash_to_point(label: &[u8], index: usize) -> G1Point {
+        let mut transcript: Transcript = Transcript::new(b"ZeroMTGenerators");
+        transcript.append_message(b"label", label);
+        transcript.append_u64(b"index", index as u64);
+
+        loop {
+            let mut bytes: [u8; 32] = [0u8; 32];
+            transcript.challenge_bytes(b"point", &mut bytes);
+            if let Some(point) = G1Point::from_random_bytes(&bytes) {
+                if !point.is_zero() {
+                    return point;
+                }
+            }
+        }
+    }
+
+    /// Extends the generators to n points with `hash_to_point(label, i)` for the missing indexes.
+    pub fn pad_generators(vec: &Vec<G1Point>, label: &[u8], n: usize) -> Vec<G1Point> {
+        let mut padded: Vec<G1Point> = Vec::with_capacity(n.max(vec.len()));
+        padded.extend(vec.iter());
+        for i in vec.len()..n {
+            padded.push(Self::hash_to_point(label, i));
+        }
+        padded
+    }
+
+    /// Extends the vector to n entries with zeros.
+    pub fn pad_scalars(vec: &Vec<ScalarField>, n: usize) -> Vec<ScalarField> {
+        let mut padded: Vec<ScalarField> = vec.clone();
+        if n > padded.len() {
+            padded.resize(n, ScalarField::zero());
+        }
+        padded
+    }
+
     /// Each scalar is multiplied to each point. The sum of all results is then made.
     pub fn inner_product_point_scalar(
         points: &Vec<G1Point>,
@@ -86,6 +126,68 @@ impl Utils {
         return Ok(result);
     }
 
+    /// Inner product between projective points and scalars given as slices, without intermediate
+    /// vectors or affine conversions. Used by the in-place inner-product arguments.
+    pub fn inner_product_projective_scalar(
+        points: &[G1Projective],
+        scalars: &[ScalarField],
+    ) -> G1Projective {
+        points
+            .iter()
+            .zip(scalars.iter())
+            .map(|(p, s): (&G1Projective, &ScalarField)| p.mul(s.into_repr()))
+            .sum()
+    }
+
+    /// Multi-scalar multiplication sum_i s_i * P_i, computed with a single Pippenger MSM.
+    pub fn multiscalar_mul(
+        points: &Vec<G1Point>,
+        scalars: &Vec<ScalarField>,
+    ) -> Result<G1Point, Error> {
+        if points.len() != scalars.len() {
+            return Err(throw(UtilsError::MathError));
+        }
+
+        let scalars_repr: Vec<<ScalarField as PrimeField>::BigInt> = scalars
+            .iter()
+            .map(|s: &ScalarField| s.into_repr())
+            .collect();
+
+        Ok(VariableBaseMSM::multi_scalar_mul(points, &scalars_repr).into_affine())
+    }
+
+    /// First n entries of the folding vector of an inner-product argument,
+    /// s_i = s_zero * prod_j ratio_j over the rounds j whose bit is set in i, reading the bits of i
+    /// from the most significant one (the first round). Every entry costs one multiplication,
+    /// s_i = s_(i - 2^k) * ratio_(rounds - 1 - k) with 2^k the highest power of two in i.
+    pub fn get_s_vector(
+        s_zero: &ScalarField,
+        ratio_vec: &Vec<ScalarField>,
+        n: usize,
+    ) -> Result<Vec<ScalarField>, Error> {
+        let rounds: usize = ratio_vec.len();
+        if n == 0 || n > 1 << rounds {
+            return Err(throw(UtilsError::MathError));
+        }
+
+        let mut s: Vec<ScalarField> = Vec::with_capacity(n);
+        s.push(*s_zero);
+        for i in 1..n {
+            let k: usize = (usize::BITS - 1 - i.leading_zeros()) as usize;
+            let s_i: ScalarField = s[i - (1 << k)] * ratio_vec[rounds - 1 - k];
+            s.push(s_i);
+        }
+
+        Ok(s)
+    }
+
+    /// Inverses of the challenges with a single field inversion.
+    pub fn batch_inverse(vec: &Vec<ScalarField>) -> Vec<ScalarField> {
+        let mut inverses: Vec<ScalarField> = vec.clone();
+        batch_inversion(&mut inverses);
+        inverses
+    }
+
     /// The scalar is multiplied to each scalar of the vector.
     pub fn product_scalar(s: &ScalarField, vec: &Vec<ScalarField>) -> Vec<ScalarField> {
         vec.iter().map(|v: &ScalarField| *v * *s).collect()
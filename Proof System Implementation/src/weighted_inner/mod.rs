@@ -0,0 +1,3 @@
+pub(crate) mod weighted_inner_proof;
+pub(crate) mod weighted_inner_prover;
+pub(crate) mod weighted_inner_verifier;
@@ -0,0 +1,63 @@
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_serialize::*;
+
+#[derive(Debug, CanonicalDeserialize, CanonicalSerialize)]
+pub struct WeightedInnerProof {
+    l_vec: Vec<G1Point>,
+    r_vec: Vec<G1Point>,
+    a: G1Point,
+    b: G1Point,
+    r_first: ScalarField,
+    s_first: ScalarField,
+    delta_first: ScalarField,
+}
+
+impl WeightedInnerProof {
+    pub fn new(
+        l_vec: Vec<G1Point>,
+        r_vec: Vec<G1Point>,
+        a: G1Point,
+        b: G1Point,
+        r_first: ScalarField,
+        s_first: ScalarField,
+        delta_first: ScalarField,
+    ) -> Self {
+        WeightedInnerProof {
+            l_vec,
+            r_vec,
+            a,
+            b,
+            r_first,
+            s_first,
+            delta_first,
+        }
+    }
+
+    pub fn get_l_vec(&self) -> &Vec<G1Point> {
+        &self.l_vec
+    }
+
+    pub fn get_r_vec(&self) -> &Vec<G1Point> {
+        &self.r_vec
+    }
+
+    pub fn get_a(&self) -> &G1Point {
+        &self.a
+    }
+
+    pub fn get_b(&self) -> &G1Point {
+        &self.b
+    }
+
+    pub fn get_r_first(&self) -> &ScalarField {
+        &self.r_first
+    }
+
+    pub fn get_s_first(&self) -> &ScalarField {
+        &self.s_first
+    }
+
+    pub fn get_delta_first(&self) -> &ScalarField {
+        &self.delta_first
+    }
+}
@@ -0,0 +1,213 @@
+use crate::errors::proof_error::throw;
+use crate::transcript::TranscriptProtocol;
+use crate::utils::Utils;
+use crate::{ProofError, WeightedInnerVerifier};
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point, G1Projective};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{Field, PrimeField, Zero};
+use ark_std::rand::Rng;
+use std::io::Error;
+
+use super::weighted_inner_proof::WeightedInnerProof;
+
+/// Zero-knowledge weighted inner-product argument of Bulletproofs+, for the statement
+/// P = <a, g_vec> + <b, h_vec> + (a ⊙_y b) * g + alpha * h, with a ⊙_y b = sum_i a_i * b_i * y^(i + 1).
+/// The final inner product is never sent: it is blinded in the last round together with alpha.
+pub struct WeightedInnerProver<'a> {
+    g_vec: &'a Vec<G1Point>,
+    h_vec: &'a Vec<G1Point>,
+    g: &'a G1Point,
+    h: &'a G1Point,
+    p: &'a G1Point,
+    a_vec: &'a Vec<ScalarField>,
+    b_vec: &'a Vec<ScalarField>,
+    alpha: &'a ScalarField,
+    y: &'a ScalarField,
+}
+
+impl<'a> WeightedInnerProver<'a> {
+    pub fn new(
+        g_vec: &'a Vec<G1Point>,
+        h_vec: &'a Vec<G1Point>,
+        g: &'a G1Point,
+        h: &'a G1Point,
+        p: &'a G1Point,
+        a_vec: &'a Vec<ScalarField>,
+        b_vec: &'a Vec<ScalarField>,
+        alpha: &'a ScalarField,
+        y: &'a ScalarField,
+    ) -> Self {
+        WeightedInnerProver {
+            g_vec,
+            h_vec,
+            g,
+            h,
+            p,
+            a_vec,
+            b_vec,
+            alpha,
+            y,
+        }
+    }
+
+    pub fn generate_proof<R: Rng, T: TranscriptProtocol>(
+        &mut self,
+        rng: &mut R,
+        transcript: &mut T,
+    ) -> Result<WeightedInnerProof, Error> {
+        let n: usize = self.g_vec.len();
+        if n == 0 || self.h_vec.len() != n || self.a_vec.len() != n || self.b_vec.len() != n {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+
+        transcript.domain_sep(b"WeightedInnerProductArgument");
+        WeightedInnerVerifier::new(self.g_vec, self.h_vec, self.g, self.h, self.p, self.y)
+            .append_statement(transcript);
+
+        // Same padding rule as InnerProver: zeros and NUMS generators up to the next power of two
+        let padded_n: usize = n.next_power_of_two();
+        let g_vec: Vec<G1Point> = Utils::pad_generators(self.g_vec, b"g_vec", padded_n);
+        let h_vec: Vec<G1Point> = Utils::pad_generators(self.h_vec, b"h_vec", padded_n);
+        let a_vec: Vec<ScalarField> = Utils::pad_scalars(self.a_vec, padded_n);
+        let b_vec: Vec<ScalarField> = Utils::pad_scalars(self.b_vec, padded_n);
+
+        Ok(self.weighted_inner_product_argument(&g_vec, &h_vec, &a_vec, &b_vec, rng, transcript))
+    }
+
+    /// a ⊙_y b = sum_i a_i * b_i * y^(i + 1)
+    pub(crate) fn weighted_inner_product(
+        a_vec: &[ScalarField],
+        b_vec: &[ScalarField],
+        y: &ScalarField,
+    ) -> ScalarField {
+        let mut y_i: ScalarField = *y;
+        let mut result: ScalarField = ScalarField::zero();
+        for (a, b) in a_vec.iter().zip(b_vec.iter()) {
+            result += *a * b * y_i;
+            y_i *= y;
+        }
+        result
+    }
+
+    /// Every round folds a as e * a_lo + e^-1 * y^n * a_hi and b as e^-1 * b_lo + e * b_hi, so that
+    /// P' = e^2 * L + P + e^-2 * R. The vectors are folded in place, as in InnerProver.
+    fn weighted_inner_product_argument<R: Rng, T: TranscriptProtocol>(
+        &mut self,
+        g_vec: &Vec<G1Point>,
+        h_vec: &Vec<G1Point>,
+        a_vec: &Vec<ScalarField>,
+        b_vec: &Vec<ScalarField>,
+        rng: &mut R,
+        transcript: &mut T,
+    ) -> WeightedInnerProof {
+        let mut n: usize = g_vec.len();
+        let rounds: usize = n.trailing_zeros() as usize;
+        let mut l_vec: Vec<G1Point> = Vec::with_capacity(rounds);
+        let mut r_vec: Vec<G1Point> = Vec::with_capacity(rounds);
+
+        let y: ScalarField = *self.y;
+        let mut alpha: ScalarField = *self.alpha;
+        let mut a_buf: Vec<ScalarField> = a_vec.clone();
+        let mut b_buf: Vec<ScalarField> = b_vec.clone();
+        let mut g_buf: Vec<G1Projective> = g_vec
+            .iter()
+            .map(|g: &G1Point| g.into_projective())
+            .collect();
+        let mut h_buf: Vec<G1Projective> = h_vec
+            .iter()
+            .map(|h: &G1Point| h.into_projective())
+            .collect();
+        let g: G1Projective = self.g.into_projective();
+        let h: G1Projective = self.h.into_projective();
+
+        while n > 1 {
+            n /= 2;
+
+            let (a_lo, a_hi) = a_buf[..2 * n].split_at_mut(n);
+            let (b_lo, b_hi) = b_buf[..2 * n].split_at_mut(n);
+            let (g_lo, g_hi) = g_buf[..2 * n].split_at_mut(n);
+            let (h_lo, h_hi) = h_buf[..2 * n].split_at_mut(n);
+
+            let y_n: ScalarField = y.pow([n as u64]);
+            let y_n_inv: ScalarField = y_n.inverse().unwrap();
+
+            let c_l: ScalarField = Self::weighted_inner_product(a_lo, b_hi, &y);
+            let c_r: ScalarField = y_n * Self::weighted_inner_product(a_hi, b_lo, &y);
+
+            let d_l: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+            let d_r: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+
+            // l = <y^-n * a_lo, g_hi> + <b_hi, h_lo> + c_l * g + d_l * h
+            let a_lo_y: Vec<ScalarField> =
+                a_lo.iter().map(|a: &ScalarField| *a * y_n_inv).collect();
+            let l: G1Point = (Utils::inner_product_projective_scalar(g_hi, &a_lo_y)
+                + Utils::inner_product_projective_scalar(h_lo, b_hi)
+                + g.mul(c_l.into_repr())
+                + h.mul(d_l.into_repr()))
+            .into_affine();
+
+            // r = <y^n * a_hi, g_lo> + <b_lo, h_hi> + c_r * g + d_r * h
+            let a_hi_y: Vec<ScalarField> = a_hi.iter().map(|a: &ScalarField| *a * y_n).collect();
+            let r: G1Point = (Utils::inner_product_projective_scalar(g_lo, &a_hi_y)
+                + Utils::inner_product_projective_scalar(h_hi, b_lo)
+                + g.mul(c_r.into_repr())
+                + h.mul(d_r.into_repr()))
+            .into_affine();
+
+            l_vec.push(l);
+            r_vec.push(r);
+
+            let _result = transcript.append_point(b"l", &l);
+            let _result = transcript.append_point(b"r", &r);
+            let e: ScalarField = transcript.challenge_scalar(b"e");
+            let e_inv: ScalarField = e.inverse().unwrap();
+
+            for i in 0..n {
+                a_lo[i] = a_lo[i] * e + a_hi[i] * e_inv * y_n;
+                b_lo[i] = b_lo[i] * e_inv + b_hi[i] * e;
+                g_lo[i] = g_lo[i].mul(e_inv.into_repr()) + g_hi[i].mul((e * y_n_inv).into_repr());
+                h_lo[i] = h_lo[i].mul(e.into_repr()) + h_hi[i].mul(e_inv.into_repr());
+            }
+            alpha += d_l * e.square() + d_r * e_inv.square();
+        }
+
+        // Last round on single elements a, b: a Schnorr-like proof of the folded opening
+        let a: ScalarField = a_buf[0];
+        let b: ScalarField = b_buf[0];
+        let r: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let s: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let delta: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+        let eta: ScalarField = Utils::get_n_random_scalars(1, rng)[0];
+
+        // A = r * g_vec + s * h_vec + y * (r * b + s * a) * g + delta * h, B = y * r * s * g + eta * h
+        let a_commitment: G1Point = (g_buf[0].mul(r.into_repr())
+            + h_buf[0].mul(s.into_repr())
+            + g.mul((y * (r * b + s * a)).into_repr())
+            + h.mul(delta.into_repr()))
+        .into_affine();
+        let b_commitment: G1Point =
+            (g.mul((y * r * s).into_repr()) + h.mul(eta.into_repr())).into_affine();
+
+        let _result = transcript.append_point(b"A", &a_commitment);
+        let _result = transcript.append_point(b"B", &b_commitment);
+        let e: ScalarField = transcript.challenge_scalar(b"e");
+
+        let r_first: ScalarField = r + a * e;
+        let s_first: ScalarField = s + b * e;
+        let delta_first: ScalarField = eta + delta * e + alpha * e.square();
+
+        let _result = transcript.append_scalar(b"r_first", &r_first);
+        let _result = transcript.append_scalar(b"s_first", &s_first);
+        let _result = transcript.append_scalar(b"delta_first", &delta_first);
+
+        WeightedInnerProof::new(
+            l_vec,
+            r_vec,
+            a_commitment,
+            b_commitment,
+            r_first,
+            s_first,
+            delta_first,
+        )
+    }
+}
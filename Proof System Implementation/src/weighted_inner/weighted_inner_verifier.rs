@@ -0,0 +1,131 @@
+use crate::errors::proof_error::throw;
+use crate::transcript::TranscriptProtocol;
+use crate::utils::Utils;
+use crate::ProofError;
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point, G1Projective};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{Field, PrimeField};
+use std::io::Error;
+
+use super::weighted_inner_proof::WeightedInnerProof;
+
+pub struct WeightedInnerVerifier<'a> {
+    g_vec: &'a Vec<G1Point>,
+    h_vec: &'a Vec<G1Point>,
+    g: &'a G1Point,
+    h: &'a G1Point,
+    p: &'a G1Point,
+    y: &'a ScalarField,
+}
+
+impl<'a> WeightedInnerVerifier<'a> {
+    pub fn new(
+        g_vec: &'a Vec<G1Point>,
+        h_vec: &'a Vec<G1Point>,
+        g: &'a G1Point,
+        h: &'a G1Point,
+        p: &'a G1Point,
+        y: &'a ScalarField,
+    ) -> Self {
+        WeightedInnerVerifier {
+            g_vec,
+            h_vec,
+            g,
+            h,
+            p,
+            y,
+        }
+    }
+
+    pub fn verify_proof<T: TranscriptProtocol>(
+        &mut self,
+        proof: &WeightedInnerProof,
+        transcript: &mut T,
+    ) -> Result<(), Error> {
+        let n: usize = self.g_vec.len();
+        let padded_n: usize = n.next_power_of_two();
+        let rounds: usize = padded_n.trailing_zeros() as usize;
+        if n == 0
+            || self.h_vec.len() != n
+            || proof.get_l_vec().len() != rounds
+            || proof.get_r_vec().len() != rounds
+        {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+
+        transcript.domain_sep(b"WeightedInnerProductArgument");
+        self.append_statement(transcript);
+
+        let y: ScalarField = *self.y;
+        let mut n: usize = padded_n;
+        let mut g_buf: Vec<G1Projective> = Utils::pad_generators(self.g_vec, b"g_vec", padded_n)
+            .iter()
+            .map(|g: &G1Point| g.into_projective())
+            .collect();
+        let mut h_buf: Vec<G1Projective> = Utils::pad_generators(self.h_vec, b"h_vec", padded_n)
+            .iter()
+            .map(|h: &G1Point| h.into_projective())
+            .collect();
+        let mut p: G1Projective = self.p.into_projective();
+
+        for (l, r) in proof.get_l_vec().iter().zip(proof.get_r_vec().iter()) {
+            n /= 2;
+
+            let _result = transcript.append_point(b"l", l);
+            let _result = transcript.append_point(b"r", r);
+            let e: ScalarField = transcript.challenge_scalar(b"e");
+            let e_inv: ScalarField = e.inverse().unwrap();
+            let y_n_inv: ScalarField = y.pow([n as u64]).inverse().unwrap();
+
+            let (g_lo, g_hi) = g_buf[..2 * n].split_at_mut(n);
+            let (h_lo, h_hi) = h_buf[..2 * n].split_at_mut(n);
+            for i in 0..n {
+                g_lo[i] = g_lo[i].mul(e_inv.into_repr()) + g_hi[i].mul((e * y_n_inv).into_repr());
+                h_lo[i] = h_lo[i].mul(e.into_repr()) + h_hi[i].mul(e_inv.into_repr());
+            }
+
+            p += l.mul(e.square().into_repr()) + r.mul(e_inv.square().into_repr());
+        }
+
+        let _result = transcript.append_point(b"A", proof.get_a());
+        let _result = transcript.append_point(b"B", proof.get_b());
+        let e: ScalarField = transcript.challenge_scalar(b"e");
+
+        let r_first: ScalarField = *proof.get_r_first();
+        let s_first: ScalarField = *proof.get_s_first();
+        let delta_first: ScalarField = *proof.get_delta_first();
+
+        let _result = transcript.append_scalar(b"r_first", &r_first);
+        let _result = transcript.append_scalar(b"s_first", &s_first);
+        let _result = transcript.append_scalar(b"delta_first", &delta_first);
+
+        // e^2 * P + e * A + B = e * r' * g_vec + e * s' * h_vec + y * r' * s' * g + delta' * h
+        let left_eq: G1Projective = p.mul(e.square().into_repr())
+            + proof.get_a().mul(e.into_repr())
+            + proof.get_b().into_projective();
+        let right_eq: G1Projective = g_buf[0].mul((e * r_first).into_repr())
+            + h_buf[0].mul((e * s_first).into_repr())
+            + self.g.mul((y * r_first * s_first).into_repr())
+            + self.h.mul(delta_first.into_repr());
+
+        if left_eq == right_eq {
+            Ok(())
+        } else {
+            Err(throw(ProofError::ProofValidationError))
+        }
+    }
+
+    /// Appends n, the generators, the statement P and the weight y. The lengths of `g_vec` and
+    /// `h_vec` are checked beforehand.
+    pub(crate) fn append_statement<T: TranscriptProtocol>(&self, transcript: &mut T) {
+        let _result = transcript.append_scalar(b"n", &ScalarField::from(self.g_vec.len() as u64));
+        for (g_i, h_i) in self.g_vec.iter().zip(self.h_vec.iter()) {
+            let _result = transcript.append_point(b"g_i", g_i);
+            let _result = transcript.append_point(b"h_i", h_i);
+        }
+        let _result = transcript.append_point(b"g", self.g);
+        let _result = transcript.append_point(b"h", self.h);
+        let _result = transcript.append_point(b"P", self.p);
+        let _result = transcript.append_scalar(b"y", self.y);
+    }
+}
@@ -1,3 +1,5 @@
+pub(crate) mod zeromt_plus_proof;
 pub(crate) mod zeromt_proof;
 pub(crate) mod zeromt_prover;
+pub(crate) mod zeromt_statement;
 pub(crate) mod zeromt_verifier;
@@ -0,0 +1,51 @@
+use ark_serialize::*;
+
+use crate::{RangePlusProof, SigmaABProof, SigmaRProof, SigmaSKProof, SigmaYProof};
+
+/// ZeroMT proof with the Bulletproofs+ range proof, whose Sigma-protocol `ab` is linked to it.
+#[derive(Debug, CanonicalDeserialize, CanonicalSerialize)]
+pub struct ZeroMTPlusProof {
+    range_plus_proof: RangePlusProof,
+    sigma_ab_proof: SigmaABProof,
+    sigma_r_proof: SigmaRProof,
+    sigma_sk_proof: SigmaSKProof,
+    sigma_y_proof: SigmaYProof,
+}
+
+impl ZeroMTPlusProof {
+    pub fn new(
+        range_plus_proof: RangePlusProof,
+        sigma_ab_proof: SigmaABProof,
+        sigma_r_proof: SigmaRProof,
+        sigma_sk_proof: SigmaSKProof,
+        sigma_y_proof: SigmaYProof,
+    ) -> Self {
+        ZeroMTPlusProof {
+            range_plus_proof,
+            sigma_ab_proof,
+            sigma_r_proof,
+            sigma_sk_proof,
+            sigma_y_proof,
+        }
+    }
+
+    pub fn get_range_plus_proof(&self) -> &RangePlusProof {
+        &self.range_plus_proof
+    }
+
+    pub fn get_sigma_ab_proof(&self) -> &SigmaABProof {
+        &self.sigma_ab_proof
+    }
+
+    pub fn get_sigma_r_proof(&self) -> &SigmaRProof {
+        &self.sigma_r_proof
+    }
+
+    pub fn get_sigma_sk_proof(&self) -> &SigmaSKProof {
+        &self.sigma_sk_proof
+    }
+
+    pub fn get_sigma_y_proof(&self) -> &SigmaYProof {
+        &self.sigma_y_proof
+    }
+}
@@ -2,26 +2,31 @@ use ark_serialize::*;
 
 use crate::{InnerProof, RangeProof, SigmaABProof, SigmaRProof, SigmaSKProof, SigmaYProof};
 
+/// The inner-product proof type depends on the argument selected for the prover, whose identifier
+/// is the first field of the encoding.
 #[derive(Debug, CanonicalDeserialize, CanonicalSerialize)]
-pub struct ZeroMTProof {
+pub struct ZeroMTProof<P: CanonicalSerialize + CanonicalDeserialize = InnerProof> {
+    ipa_id: u8,
     range_proof: RangeProof,
-    inner_proof: InnerProof,
+    inner_proof: P,
     sigma_ab_proof: SigmaABProof,
     sigma_r_proof: SigmaRProof,
     sigma_sk_proof: SigmaSKProof,
     sigma_y_proof: SigmaYProof,
 }
 
-impl ZeroMTProof {
+impl<P: CanonicalSerialize + CanonicalDeserialize> ZeroMTProof<P> {
     pub fn new(
+        ipa_id: u8,
         range_proof: RangeProof,
-        inner_proof: InnerProof,
+        inner_proof: P,
         sigma_ab_proof: SigmaABProof,
         sigma_r_proof: SigmaRProof,
         sigma_sk_proof: SigmaSKProof,
         sigma_y_proof: SigmaYProof,
     ) -> Self {
         ZeroMTProof {
+            ipa_id,
             range_proof,
             inner_proof,
             sigma_ab_proof,
@@ -31,11 +36,15 @@ impl ZeroMTProof {
         }
     }
 
+    pub fn get_ipa_id(&self) -> u8 {
+        self.ipa_id
+    }
+
     pub fn get_range_proof(&self) -> &RangeProof {
         &self.range_proof
     }
 
-    pub fn get_inner_proof(&self) -> &InnerProof {
+    pub fn get_inner_proof(&self) -> &P {
         &self.inner_proof
     }
 
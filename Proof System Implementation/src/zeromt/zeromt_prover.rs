@@ -1,13 +1,21 @@
+use crate::errors::proof_error::throw;
 use crate::{
-    range::poly_coefficients::PolyCoefficients, InnerProof, InnerProver, RangeProof, RangeProver,
-    SigmaABProof, SigmaABProver, SigmaRProof, SigmaRProver, SigmaSKProof, SigmaSKProver,
-    SigmaYProof, SigmaYProver, TranscriptProtocol, ZeroMTProof,
+    AssociatedData, InnerProductArgument, IntervalRangeProver, IpaStatement, IpaWitness,
+    LinkedRangeProof, ProofError, RangeBounds, RangePlusProof, RangePlusProver, RangeProof,
+    RangeProver, SigmaABProof, SigmaABProver, SigmaRProof, SigmaRProver, SigmaSKProof,
+    SigmaSKProver, SigmaYProof, SigmaYProver, TranscriptProtocol, ZeroMTParams, ZeroMTPlusProof,
+    ZeroMTProof, ZeroMTStatement, ZeroMTVerifier, ZeroMTWitness,
 };
 use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+use ark_std::rand::seq::SliceRandom;
 use ark_std::rand::Rng;
 use merlin::Transcript;
+use std::io::Error;
+use std::marker::PhantomData;
 
-pub struct ZeroMTProver<'a> {
+/// `I` is the inner-product argument run on the range proof vectors, e.g. `InnerProver` or
+/// the zero-knowledge `InnerSigmaProver`.
+pub struct ZeroMTProver<'a, I> {
     g: &'a G1Point,
     h: &'a G1Point,
     remaining_balance: usize,
@@ -18,53 +26,135 @@ pub struct ZeroMTProver<'a> {
     n: usize,
     d: &'a G1Point,
     c_r: &'a G1Point,
+    c_l: &'a G1Point,
+    c_vec: &'a Vec<G1Point>,
+    c_bar_vec: &'a Vec<G1Point>,
     sk: &'a ScalarField,
     r: &'a ScalarField,
     y: &'a G1Point,
     y_bar: &'a Vec<G1Point>,
+    bounds: Option<&'a RangeBounds>,
+    auditor: Option<(&'a G1Point, &'a Vec<G1Point>)>,
+    fee: Option<usize>,
+    associated_data: Option<&'a AssociatedData>,
+    ipa: PhantomData<I>,
 }
 
-impl<'a> ZeroMTProver<'a> {
+impl<'a, I> ZeroMTProver<'a, I>
+where
+    I: InnerProductArgument<Statement = IpaStatement, Witness = IpaWitness>,
+{
     pub fn new(
-        g: &'a G1Point,
-        h: &'a G1Point,
-        remaining_balance: usize,
-        amounts: &'a Vec<usize>,
-        g_vec: &'a Vec<G1Point>,
-        h_vec: &'a Vec<G1Point>,
-        u: &'a G1Point,
-        n: usize,
-        d: &'a G1Point,
-        c_r: &'a G1Point,
-        sk: &'a ScalarField,
-        r: &'a ScalarField,
-        y: &'a G1Point,
-        y_bar: &'a Vec<G1Point>,
+        params: &ZeroMTParams<'a>,
+        statement: &ZeroMTStatement<'a>,
+        witness: &ZeroMTWitness<'a>,
     ) -> Self {
         ZeroMTProver {
-            g,
-            h,
-            remaining_balance,
-            amounts,
-            g_vec,
-            h_vec,
-            u,
-            n,
-            d,
-            c_r,
-            sk,
-            r,
-            y,
-            y_bar,
+            g: params.get_g(),
+            h: params.get_h(),
+            remaining_balance: witness.get_remaining_balance(),
+            amounts: witness.get_amounts(),
+            g_vec: params.get_g_vec(),
+            h_vec: params.get_h_vec(),
+            u: params.get_u(),
+            n: params.get_n(),
+            d: statement.get_d(),
+            c_r: statement.get_c_r(),
+            c_l: statement.get_c_l(),
+            c_vec: statement.get_c_vec(),
+            c_bar_vec: statement.get_c_bar_vec(),
+            sk: witness.get_sk(),
+            r: witness.get_r(),
+            y: statement.get_y(),
+            y_bar: statement.get_y_bar(),
+            bounds: None,
+            auditor: None,
+            fee: None,
+            associated_data: None,
+            ipa: PhantomData,
         }
     }
 
+    /// Proves min <= v <= max for the remaining balance and every amount, instead of
+    /// 0 <= v < 2^n. The range proof then covers 2 * m values: `g_vec` and `h_vec` must hold
+    /// 2 * m * n generators.
+    pub fn with_bounds(mut self, bounds: &'a RangeBounds) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Adds the auditor to the statement: `c_auditor_vec` are the amounts encrypted under the
+    /// auditor public key with the same r, and the Sigma-protocol `y` proves it.
+    pub fn with_auditor(mut self, y_auditor: &'a G1Point, c_auditor_vec: &'a Vec<G1Point>) -> Self {
+        self.auditor = Some((y_auditor, c_auditor_vec));
+        self
+    }
+
+    /// Charges the public fee f to the sender: the remaining balance is b - sum_i a_i - f,
+    /// and f is bound to the transcript.
+    pub fn with_fee(mut self, fee: usize) -> Self {
+        self.fee = Some(fee);
+        self
+    }
+
+    /// Binds the proof to the transaction context, e.g. the chain id, the contract address and
+    /// the epoch: the proof only verifies with the same associated data.
+    pub fn with_associated_data(mut self, associated_data: &'a AssociatedData) -> Self {
+        self.associated_data = Some(associated_data);
+        self
+    }
+
+    /// Pads a transfer with zero-valued transfers to dummy recipients, so that
+    /// m = amounts.len() + 1 becomes the next power of two and m * n needs no padding in the
+    /// inner-product argument. The dummy recipients are distinct accounts drawn at random from
+    /// `accounts`, e.g. the registered accounts of the ledger, other than the sender `y` and the
+    /// recipients, and the recipients are shuffled. Once encrypted as usual, the padded transfer
+    /// is a standard transfer: each dummy C_bar_i is under its own key, while C_i = r * y as for
+    /// any zero amount. Returns the padded amounts and the matching recipients' public keys.
+    pub fn pad_recipients<R: Rng>(
+        y: &G1Point,
+        amounts: &Vec<usize>,
+        y_bar: &Vec<G1Point>,
+        accounts: &Vec<G1Point>,
+        rng: &mut R,
+    ) -> Result<(Vec<usize>, Vec<G1Point>), Error> {
+        if amounts.is_empty() || amounts.len() != y_bar.len() {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+
+        let dummies: usize = (amounts.len() + 1).next_power_of_two() - 1 - amounts.len();
+        let candidates: Vec<G1Point> = accounts
+            .iter()
+            .filter(|k: &&G1Point| *k != y && !y_bar.contains(k))
+            .copied()
+            .collect();
+        if candidates.len() < dummies {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+
+        let mut recipients: Vec<(usize, G1Point)> = amounts
+            .iter()
+            .zip(y_bar.iter())
+            .map(|(a, k): (&usize, &G1Point)| (*a, *k))
+            .chain(
+                candidates
+                    .choose_multiple(rng, dummies)
+                    .map(|k: &G1Point| (0, *k)),
+            )
+            .collect();
+        recipients.shuffle(rng);
+
+        Ok(recipients.into_iter().unzip())
+    }
+
     pub fn generate_proof<R: Rng>(
         &mut self,
         rng: &mut R,
         transcript: &mut Transcript,
-    ) -> ZeroMTProof {
+    ) -> Result<ZeroMTProof<I::Proof>, Error> {
         transcript.domain_sep(b"ZeroMTProof");
+        let _result = transcript.append_scalar(b"ipa_id", &ScalarField::from(I::ID));
+        self.append_statement(transcript)?;
 
         let mut range_prover: RangeProver = RangeProver::new(
             self.g,
@@ -76,52 +166,86 @@ impl<'a> ZeroMTProver<'a> {
             self.n,
         );
 
-        let (range_proof, l_poly_vec, r_poly_vec, x_prover, y_prover, z_prover, t_coefficients): (
-            RangeProof,
-            Vec<ScalarField>,
-            Vec<ScalarField>,
-            ScalarField,
-            ScalarField,
-            ScalarField,
-            PolyCoefficients,
-        ) = range_prover.generate_proof(rng, transcript);
-
-        let (h_first_vec_prover, phu_prover): (Vec<G1Point>, G1Point) = range_prover
-            .get_ipa_arguments(
-                &x_prover,
-                &y_prover,
-                &z_prover,
-                range_proof.get_mu(),
-                range_proof.get_a(),
-                range_proof.get_s(),
-                self.h,
-                self.g_vec,
-                self.h_vec,
-            );
+        // With bounds, the range proof covers the 2 * m shifted values and the link to the
+        // ciphertexts takes the place of the commitments to the values
+        let values: Vec<usize> = std::iter::once(self.remaining_balance)
+            .chain(self.amounts.iter().copied())
+            .collect();
+        let no_gamma_vec: Vec<ScalarField> = Vec::new();
+        let no_v_vec: Vec<G1Point> = Vec::new();
+        let mut interval_prover: Option<IntervalRangeProver> =
+            self.bounds.map(|bounds: &RangeBounds| {
+                IntervalRangeProver::new(
+                    self.g,
+                    self.h,
+                    &values,
+                    &no_gamma_vec,
+                    &no_v_vec,
+                    bounds,
+                    self.g_vec,
+                    self.h_vec,
+                    self.n,
+                )
+            });
 
-        let inner_proof: InnerProof = InnerProver::new(
-            self.g_vec,
-            &h_first_vec_prover,
-            &phu_prover,
-            range_proof.get_t_hat(),
-            &l_poly_vec,
-            &r_poly_vec,
-            self.u,
-        )
-        .generate_proof(transcript);
-
-        let sigma_ab_proof: SigmaABProof = SigmaABProver::new(
-            self.g,
-            self.d,
-            self.c_r,
-            self.remaining_balance,
-            self.amounts,
-            self.sk,
-        )
-        .generate_proof(rng, transcript);
+        // SigmaAB is linked to the range proof, so that the range-proved values are the ones
+        // encrypted in C_L - sum_i C_i and C_i, or with bounds the shifted values derived from them
+        let linked_proof: LinkedRangeProof<SigmaABProof> = match interval_prover.as_mut() {
+            Some(interval_prover) => interval_prover.generate_proof_with_link(
+                rng,
+                transcript,
+                &mut self.get_sigma_ab_prover(),
+            )?,
+            None => {
+                range_prover.generate_linked_proof(rng, transcript, &mut self.get_sigma_ab_prover())
+            }
+        };
 
-        let sigma_y_proof: SigmaYProof =
-            SigmaYProver::new(self.r, self.y, self.y_bar).generate_proof(rng, transcript);
+        let range_proof: &RangeProof = linked_proof.get_range_proof();
+        let (h_first_vec_prover, phu_prover): (Vec<G1Point>, G1Point) =
+            match interval_prover.as_mut() {
+                Some(interval_prover) => interval_prover.get_ipa_arguments(
+                    linked_proof.get_x(),
+                    linked_proof.get_y(),
+                    linked_proof.get_z(),
+                    range_proof.get_mu(),
+                    range_proof.get_a(),
+                    range_proof.get_s(),
+                    self.h,
+                    self.g_vec,
+                    self.h_vec,
+                ),
+                None => range_prover.get_ipa_arguments(
+                    linked_proof.get_x(),
+                    linked_proof.get_y(),
+                    linked_proof.get_z(),
+                    range_proof.get_mu(),
+                    range_proof.get_a(),
+                    range_proof.get_s(),
+                    self.h,
+                    self.g_vec,
+                    self.h_vec,
+                ),
+            };
+
+        let inner_proof: I::Proof = I::prove(
+            &IpaStatement::new(
+                self.g_vec.clone(),
+                h_first_vec_prover,
+                phu_prover,
+                *range_proof.get_t_hat(),
+                *self.u,
+            ),
+            &IpaWitness::new(
+                linked_proof.get_l_poly_vec().clone(),
+                linked_proof.get_r_poly_vec().clone(),
+            ),
+            transcript,
+        )?;
+
+        let (range_proof, sigma_ab_proof): (RangeProof, SigmaABProof) = linked_proof.into_proofs();
+
+        let sigma_y_proof: SigmaYProof = self.get_sigma_y_prover().generate_proof(rng, transcript);
 
         let sigma_sk_proof: SigmaSKProof =
             SigmaSKProver::new(self.g, self.sk).generate_proof(rng, transcript);
@@ -129,13 +253,115 @@ impl<'a> ZeroMTProver<'a> {
         let sigma_r_proof: SigmaRProof =
             SigmaRProver::new(self.g, self.r).generate_proof(rng, transcript);
 
-        ZeroMTProof::new(
+        Ok(ZeroMTProof::new(
+            I::ID,
             range_proof,
             inner_proof,
             sigma_ab_proof,
             sigma_r_proof,
             sigma_sk_proof,
             sigma_y_proof,
-        )
+        ))
+    }
+
+    /// Same transfer proved with `RangePlusProver` instead of `RangeProver` and `I`, for smaller
+    /// proofs. `g_vec` and `h_vec` hold m * n generators, the bound policy is not supported.
+    pub fn generate_plus_proof<R: Rng>(
+        &mut self,
+        rng: &mut R,
+        transcript: &mut Transcript,
+    ) -> Result<ZeroMTPlusProof, Error> {
+        if self.bounds.is_some() {
+            return Err(throw(ProofError::ProofValidationError));
+        }
+
+        transcript.domain_sep(b"ZeroMTPlusProof");
+        self.append_statement(transcript)?;
+
+        let (range_plus_proof, sigma_ab_proof): (RangePlusProof, SigmaABProof) =
+            RangePlusProver::new(
+                self.g,
+                self.h,
+                self.remaining_balance,
+                self.amounts,
+                self.g_vec,
+                self.h_vec,
+                self.n,
+            )
+            .generate_linked_proof(rng, transcript, &mut self.get_sigma_ab_prover())?;
+
+        let sigma_y_proof: SigmaYProof = self.get_sigma_y_prover().generate_proof(rng, transcript);
+
+        let sigma_sk_proof: SigmaSKProof =
+            SigmaSKProver::new(self.g, self.sk).generate_proof(rng, transcript);
+
+        let sigma_r_proof: SigmaRProof =
+            SigmaRProver::new(self.g, self.r).generate_proof(rng, transcript);
+
+        Ok(ZeroMTPlusProof::new(
+            range_plus_proof,
+            sigma_ab_proof,
+            sigma_r_proof,
+            sigma_sk_proof,
+            sigma_y_proof,
+        ))
+    }
+
+    /// Appends the statement of `ZeroMTVerifier`, with the same public inputs and options.
+    fn append_statement(&self, transcript: &mut Transcript) -> Result<(), Error> {
+        let verifier: ZeroMTVerifier<I> = ZeroMTVerifier::new(
+            &ZeroMTParams::new(self.g, self.h, self.g_vec, self.h_vec, self.u, self.n),
+            &ZeroMTStatement::new(
+                self.d,
+                self.c_r,
+                self.c_l,
+                self.c_vec,
+                self.c_bar_vec,
+                self.y,
+                self.y_bar,
+            ),
+        );
+        let verifier: ZeroMTVerifier<I> = match self.bounds {
+            Some(bounds) => verifier.with_bounds(bounds),
+            None => verifier,
+        };
+        let verifier: ZeroMTVerifier<I> = match self.auditor {
+            Some((y_auditor, c_auditor_vec)) => verifier.with_auditor(y_auditor, c_auditor_vec),
+            None => verifier,
+        };
+        let verifier: ZeroMTVerifier<I> = match self.fee {
+            Some(fee) => verifier.with_fee(fee),
+            None => verifier,
+        };
+        let verifier: ZeroMTVerifier<I> = match self.associated_data {
+            Some(associated_data) => verifier.with_associated_data(associated_data),
+            None => verifier,
+        };
+        verifier.append_statement(transcript)
+    }
+
+    fn get_sigma_ab_prover(&self) -> SigmaABProver<'a> {
+        let sigma_ab_prover: SigmaABProver<'a> = SigmaABProver::new(
+            self.g,
+            self.d,
+            self.c_r,
+            self.remaining_balance,
+            self.amounts,
+            self.sk,
+        );
+        match self.fee {
+            Some(fee) => sigma_ab_prover.with_fee(fee),
+            None => sigma_ab_prover,
+        }
+    }
+
+    fn get_sigma_y_prover(&self) -> SigmaYProver<'a> {
+        let sigma_y_prover: SigmaYProver<'a> = SigmaYProver::new(self.r, self.y, self.y_bar);
+        match self.auditor {
+            Some((y_auditor, c_auditor_vec)) => {
+                sigma_y_prover.with_auditor(y_auditor, c_auditor_vec)
+            }
+            None => sigma_y_prover,
+        }
     }
 }
@@ -0,0 +1,162 @@
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+
+/// Public parameters of a transfer: the generators g, h and u, the m * n range proof generators
+/// and the bit length n
+#[derive(Clone, Copy)]
+pub struct ZeroMTParams<'a> {
+    g: &'a G1Point,
+    h: &'a G1Point,
+    g_vec: &'a Vec<G1Point>,
+    h_vec: &'a Vec<G1Point>,
+    u: &'a G1Point,
+    n: usize,
+}
+
+impl<'a> ZeroMTParams<'a> {
+    pub fn new(
+        g: &'a G1Point,
+        h: &'a G1Point,
+        g_vec: &'a Vec<G1Point>,
+        h_vec: &'a Vec<G1Point>,
+        u: &'a G1Point,
+        n: usize,
+    ) -> Self {
+        ZeroMTParams {
+            g,
+            h,
+            g_vec,
+            h_vec,
+            u,
+            n,
+        }
+    }
+
+    pub fn get_g(&self) -> &'a G1Point {
+        self.g
+    }
+
+    pub fn get_h(&self) -> &'a G1Point {
+        self.h
+    }
+
+    pub fn get_g_vec(&self) -> &'a Vec<G1Point> {
+        self.g_vec
+    }
+
+    pub fn get_h_vec(&self) -> &'a Vec<G1Point> {
+        self.h_vec
+    }
+
+    pub fn get_u(&self) -> &'a G1Point {
+        self.u
+    }
+
+    pub fn get_n(&self) -> usize {
+        self.n
+    }
+}
+
+/// Public inputs of a transfer: D = r * g, the sender balance (C_L, C_R), the amounts encrypted
+/// under the sender public key y (C_vec) and under the recipients' public keys y_bar (C_bar_vec)
+#[derive(Clone, Copy)]
+pub struct ZeroMTStatement<'a> {
+    d: &'a G1Point,
+    c_r: &'a G1Point,
+    c_l: &'a G1Point,
+    c_vec: &'a Vec<G1Point>,
+    c_bar_vec: &'a Vec<G1Point>,
+    y: &'a G1Point,
+    y_bar: &'a Vec<G1Point>,
+}
+
+impl<'a> ZeroMTStatement<'a> {
+    pub fn new(
+        d: &'a G1Point,
+        c_r: &'a G1Point,
+        c_l: &'a G1Point,
+        c_vec: &'a Vec<G1Point>,
+        c_bar_vec: &'a Vec<G1Point>,
+        y: &'a G1Point,
+        y_bar: &'a Vec<G1Point>,
+    ) -> Self {
+        ZeroMTStatement {
+            d,
+            c_r,
+            c_l,
+            c_vec,
+            c_bar_vec,
+            y,
+            y_bar,
+        }
+    }
+
+    pub fn get_d(&self) -> &'a G1Point {
+        self.d
+    }
+
+    pub fn get_c_r(&self) -> &'a G1Point {
+        self.c_r
+    }
+
+    pub fn get_c_l(&self) -> &'a G1Point {
+        self.c_l
+    }
+
+    pub fn get_c_vec(&self) -> &'a Vec<G1Point> {
+        self.c_vec
+    }
+
+    pub fn get_c_bar_vec(&self) -> &'a Vec<G1Point> {
+        self.c_bar_vec
+    }
+
+    pub fn get_y(&self) -> &'a G1Point {
+        self.y
+    }
+
+    pub fn get_y_bar(&self) -> &'a Vec<G1Point> {
+        self.y_bar
+    }
+}
+
+/// Secret inputs of the sender: the remaining balance, the amounts, the private key sk and the
+/// randomness r of the ciphertexts
+#[derive(Clone, Copy)]
+pub struct ZeroMTWitness<'a> {
+    remaining_balance: usize,
+    amounts: &'a Vec<usize>,
+    sk: &'a ScalarField,
+    r: &'a ScalarField,
+}
+
+impl<'a> ZeroMTWitness<'a> {
+    pub fn new(
+        remaining_balance: usize,
+        amounts: &'a Vec<usize>,
+        sk: &'a ScalarField,
+        r: &'a ScalarField,
+    ) -> Self {
+        ZeroMTWitness {
+            remaining_balance,
+            amounts,
+            sk,
+            r,
+        }
+    }
+
+    pub fn get_remaining_balance(&self) -> usize {
+        self.remaining_balance
+    }
+
+    pub fn get_amounts(&self) -> &'a Vec<usize> {
+        self.amounts
+    }
+
+    pub fn get_sk(&self) -> &'a ScalarField {
+        self.sk
+    }
+
+    pub fn get_r(&self) -> &'a ScalarField {
+        self.r
+    }
+}
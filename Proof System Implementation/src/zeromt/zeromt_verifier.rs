@@ -1,13 +1,16 @@
 use crate::errors::proof_error::throw;
 use crate::{
-    InnerVerifier, ProofError, RangeVerifier, SigmaABVerifier, SigmaRVerifier, SigmaSKVerifier,
-    SigmaYVerifier, TranscriptProtocol, ZeroMTProof,
+    AssociatedData, InnerProductArgument, IntervalRangeVerifier, IpaStatement, IpaWitness,
+    ProofError, RangeBounds, RangePlusVerifier, RangeVerifier, SigmaABVerifier, SigmaRVerifier,
+    SigmaSKVerifier, SigmaYVerifier, TranscriptProtocol, ZeroMTParams, ZeroMTPlusProof,
+    ZeroMTProof, ZeroMTStatement,
 };
-use ark_bn254::G1Affine as G1Point;
+use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
 
 use merlin::Transcript;
 use std::io::Error;
-pub struct ZeroMTVerifier<'a> {
+use std::marker::PhantomData;
+pub struct ZeroMTVerifier<'a, I> {
     g: &'a G1Point,
     h: &'a G1Point,
     n: usize,
@@ -21,53 +24,104 @@ pub struct ZeroMTVerifier<'a> {
     c_bar_vec: &'a Vec<G1Point>,
     y: &'a G1Point,
     y_bar: &'a Vec<G1Point>,
+    bounds: Option<&'a RangeBounds>,
+    auditor: Option<(&'a G1Point, &'a Vec<G1Point>)>,
+    fee: Option<usize>,
+    associated_data: Option<&'a AssociatedData>,
+    ipa: PhantomData<I>,
 }
 
-impl<'a> ZeroMTVerifier<'a> {
-    pub fn new(
-        g: &'a G1Point,
-        h: &'a G1Point,
-        n: usize,
-        g_vec: &'a Vec<G1Point>,
-        h_vec: &'a Vec<G1Point>,
-        u: &'a G1Point,
-        d: &'a G1Point,
-        c_r: &'a G1Point,
-        c_l: &'a G1Point,
-        c_vec: &'a Vec<G1Point>,
-        c_bar_vec: &'a Vec<G1Point>,
-        y: &'a G1Point,
-        y_bar: &'a Vec<G1Point>,
-    ) -> Self {
+impl<'a, I> ZeroMTVerifier<'a, I>
+where
+    I: InnerProductArgument<Statement = IpaStatement, Witness = IpaWitness>,
+{
+    pub fn new(params: &ZeroMTParams<'a>, statement: &ZeroMTStatement<'a>) -> Self {
         ZeroMTVerifier {
-            g,
-            h,
-            n,
-            g_vec,
-            h_vec,
-            u,
-            d,
-            c_r,
-            c_l,
-            c_vec,
-            c_bar_vec,
-            y,
-            y_bar,
+            g: params.get_g(),
+            h: params.get_h(),
+            n: params.get_n(),
+            g_vec: params.get_g_vec(),
+            h_vec: params.get_h_vec(),
+            u: params.get_u(),
+            d: statement.get_d(),
+            c_r: statement.get_c_r(),
+            c_l: statement.get_c_l(),
+            c_vec: statement.get_c_vec(),
+            c_bar_vec: statement.get_c_bar_vec(),
+            y: statement.get_y(),
+            y_bar: statement.get_y_bar(),
+            bounds: None,
+            auditor: None,
+            fee: None,
+            associated_data: None,
+            ipa: PhantomData,
         }
     }
 
+    /// Checks the proof against the bound policy of the prover, see `ZeroMTProver::with_bounds`.
+    pub fn with_bounds(mut self, bounds: &'a RangeBounds) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Checks the amounts encrypted under the auditor public key, see
+    /// `ZeroMTProver::with_auditor`.
+    pub fn with_auditor(mut self, y_auditor: &'a G1Point, c_auditor_vec: &'a Vec<G1Point>) -> Self {
+        self.auditor = Some((y_auditor, c_auditor_vec));
+        self
+    }
+
+    /// Checks the balance of the sender after the public fee f, see `ZeroMTProver::with_fee`.
+    pub fn with_fee(mut self, fee: usize) -> Self {
+        self.fee = Some(fee);
+        self
+    }
+
+    /// Checks the proof within the transaction context of the prover, see
+    /// `ZeroMTProver::with_associated_data`.
+    pub fn with_associated_data(mut self, associated_data: &'a AssociatedData) -> Self {
+        self.associated_data = Some(associated_data);
+        self
+    }
+
     pub fn verify_proof(
         &mut self,
-        proof: &ZeroMTProof,
+        proof: &ZeroMTProof<I::Proof>,
         transcript: &mut Transcript,
     ) -> Result<(), Error> {
+        // The proof must have been generated with the same inner-product argument
+        if proof.get_ipa_id() != I::ID {
+            return Err(throw(ProofError::ProofValidationError));
+        }
+
         transcript.domain_sep(b"ZeroMTProof");
+        let _result = transcript.append_scalar(b"ipa_id", &ScalarField::from(I::ID));
+        self.append_statement(transcript)?;
 
-        let mut range_verifier: RangeVerifier =
-            RangeVerifier::new(self.g, self.h, self.c_vec.len() + 1, self.n);
+        // SigmaAB is linked to the range proof over the values, or over the shifted values
+        let m: usize = self.c_vec.len() + 1;
+        let (range_proof_result, x_verifier, y_verifier, z_verifier) = match self.bounds {
+            Some(bounds) => {
+                let no_v_vec: Vec<G1Point> = Vec::new();
+                IntervalRangeVerifier::new(self.g, self.h, &no_v_vec, bounds, self.n)
+                    .verify_proof_with_link(
+                        proof.get_range_proof(),
+                        proof.get_sigma_ab_proof(),
+                        &mut self.get_sigma_ab_verifier(),
+                        transcript,
+                    )
+            }
+            None => RangeVerifier::new(self.g, self.h, m, self.n).verify_linked_proof(
+                proof.get_range_proof(),
+                proof.get_sigma_ab_proof(),
+                &mut self.get_sigma_ab_verifier(),
+                transcript,
+            ),
+        };
 
-        let (range_proof_result, x_verifier, y_verifier, z_verifier) =
-            range_verifier.verify_proof(proof.get_range_proof(), transcript);
+        // The bounded range proof covers the 2 * m shifted values
+        let range_m: usize = if self.bounds.is_some() { 2 * m } else { m };
+        let mut range_verifier: RangeVerifier = RangeVerifier::new(self.g, self.h, range_m, self.n);
 
         let (h_first_vec_verifier, phu_verifier): (Vec<G1Point>, G1Point) = range_verifier
             .get_ipa_arguments(
@@ -82,19 +136,20 @@ impl<'a> ZeroMTVerifier<'a> {
                 self.h_vec,
             );
 
-        let inner_result = InnerVerifier::new(
-            self.g_vec,
-            &h_first_vec_verifier,
-            &phu_verifier,
-            proof.get_range_proof().get_t_hat(),
-            self.u,
-        )
-        .verify_proof_multiscalar(proof.get_inner_proof(), transcript);
+        let inner_result: Result<(), Error> = I::verify(
+            &IpaStatement::new(
+                self.g_vec.clone(),
+                h_first_vec_verifier,
+                phu_verifier,
+                *proof.get_range_proof().get_t_hat(),
+                *self.u,
+            ),
+            proof.get_inner_proof(),
+            transcript,
+        );
 
-        let sigma_ab_result = SigmaABVerifier::new(self.g, self.d, self.c_r, self.c_l, self.c_vec)
-            .verify_proof(proof.get_sigma_ab_proof(), transcript);
-
-        let sigma_y_result = SigmaYVerifier::new(self.y, self.y_bar, self.c_vec, self.c_bar_vec)
+        let sigma_y_result = self
+            .get_sigma_y_verifier()
             .verify_proof(proof.get_sigma_y_proof(), transcript);
 
         let sigma_sk_result = SigmaSKVerifier::new(self.g, self.y)
@@ -106,14 +161,144 @@ impl<'a> ZeroMTVerifier<'a> {
         let proof_check: bool = range_proof_result.is_ok()
             && sigma_sk_result.is_ok()
             && sigma_r_result.is_ok()
-            && sigma_ab_result.is_ok()
             && sigma_y_result.is_ok()
             && inner_result.is_ok();
 
         if proof_check {
-            return Ok(());
+            Ok(())
         } else {
+            Err(throw(ProofError::ProofValidationError))
+        }
+    }
+
+    /// Verifies a proof of `ZeroMTProver::generate_plus_proof`.
+    pub fn verify_plus_proof(
+        &mut self,
+        proof: &ZeroMTPlusProof,
+        transcript: &mut Transcript,
+    ) -> Result<(), Error> {
+        if self.bounds.is_some() {
             return Err(throw(ProofError::ProofValidationError));
         }
+
+        transcript.domain_sep(b"ZeroMTPlusProof");
+        self.append_statement(transcript)?;
+
+        let m: usize = self.c_vec.len() + 1;
+        let range_plus_result: Result<(), Error> =
+            RangePlusVerifier::new(self.g, self.h, self.g_vec, self.h_vec, m, self.n)
+                .verify_linked_proof(
+                    proof.get_range_plus_proof(),
+                    proof.get_sigma_ab_proof(),
+                    &mut self.get_sigma_ab_verifier(),
+                    transcript,
+                );
+
+        let sigma_y_result = self
+            .get_sigma_y_verifier()
+            .verify_proof(proof.get_sigma_y_proof(), transcript);
+
+        let sigma_sk_result = SigmaSKVerifier::new(self.g, self.y)
+            .verify_proof(proof.get_sigma_sk_proof(), transcript);
+
+        let sigma_r_result =
+            SigmaRVerifier::new(self.g, self.d).verify_proof(proof.get_sigma_r_proof(), transcript);
+
+        let proof_check: bool = range_plus_result.is_ok()
+            && sigma_sk_result.is_ok()
+            && sigma_r_result.is_ok()
+            && sigma_y_result.is_ok();
+
+        if proof_check {
+            Ok(())
+        } else {
+            Err(throw(ProofError::ProofValidationError))
+        }
+    }
+
+    /// Appends the associated data and the fee, if any, and then the whole statement: the
+    /// dimensions, the generators, the public keys and the ciphertexts. Every length is checked
+    /// first, so that nothing is absorbed for a malformed statement.
+    pub(crate) fn append_statement(&self, transcript: &mut Transcript) -> Result<(), Error> {
+        let m: usize = self.c_vec.len() + 1;
+        // The bounded range proof covers the 2 * m shifted values
+        let range_m: usize = if self.bounds.is_some() { 2 * m } else { m };
+        if self.c_bar_vec.len() != m - 1
+            || self.y_bar.len() != m - 1
+            || self.g_vec.len() != range_m * self.n
+            || self.h_vec.len() != range_m * self.n
+            || self
+                .bounds
+                .is_some_and(|bounds: &RangeBounds| bounds.len() != m)
+            || self
+                .auditor
+                .is_some_and(|(_, c_auditor_vec)| c_auditor_vec.len() != m - 1)
+        {
+            return Err(throw(ProofError::VectorLengthError));
+        }
+
+        if let Some(associated_data) = self.associated_data {
+            associated_data.append_to_transcript(transcript);
+        }
+        if let Some(fee) = self.fee {
+            let _result = transcript.append_scalar(b"fee", &ScalarField::from(fee as u64));
+        }
+
+        let _result = transcript.append_scalar(b"n", &ScalarField::from(self.n as u64));
+        let _result =
+            transcript.append_scalar(b"m", &ScalarField::from((self.c_vec.len() + 1) as u64));
+        let _result = transcript.append_point(b"g", self.g);
+        let _result = transcript.append_point(b"h", self.h);
+        let _result = transcript.append_point(b"u", self.u);
+        for (g_i, h_i) in self.g_vec.iter().zip(self.h_vec.iter()) {
+            let _result = transcript.append_point(b"g_i", g_i);
+            let _result = transcript.append_point(b"h_i", h_i);
+        }
+
+        let _result = transcript.append_point(b"y", self.y);
+        let _result = transcript.append_point(b"C_L", self.c_l);
+        let _result = transcript.append_point(b"C_R", self.c_r);
+        let _result = transcript.append_point(b"D", self.d);
+        for ((y_i, c_i), c_bar_i) in self
+            .y_bar
+            .iter()
+            .zip(self.c_vec.iter())
+            .zip(self.c_bar_vec.iter())
+        {
+            let _result = transcript.append_point(b"y_bar", y_i);
+            let _result = transcript.append_point(b"C", c_i);
+            let _result = transcript.append_point(b"C_bar", c_bar_i);
+        }
+
+        if let Some((y_auditor, c_auditor_vec)) = self.auditor {
+            let _result = transcript.append_point(b"y_auditor", y_auditor);
+            for c_auditor_i in c_auditor_vec.iter() {
+                let _result = transcript.append_point(b"C_auditor", c_auditor_i);
+            }
+        }
+        if let Some(bounds) = self.bounds {
+            bounds.append_to_transcript(transcript);
+        }
+        Ok(())
+    }
+
+    fn get_sigma_ab_verifier(&self) -> SigmaABVerifier<'a> {
+        let sigma_ab_verifier: SigmaABVerifier<'a> =
+            SigmaABVerifier::new(self.g, self.d, self.c_r, self.c_l, self.c_vec);
+        match self.fee {
+            Some(fee) => sigma_ab_verifier.with_fee(fee),
+            None => sigma_ab_verifier,
+        }
+    }
+
+    fn get_sigma_y_verifier(&self) -> SigmaYVerifier<'a> {
+        let sigma_y_verifier: SigmaYVerifier<'a> =
+            SigmaYVerifier::new(self.y, self.y_bar, self.c_vec, self.c_bar_vec);
+        match self.auditor {
+            Some((y_auditor, c_auditor_vec)) => {
+                sigma_y_verifier.with_auditor(y_auditor, c_auditor_vec)
+            }
+            None => sigma_y_verifier,
+        }
     }
 }
@@ -0,0 +1,339 @@
+#[cfg(test)]
+mod anon_zeromt_tests {
+    use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_ff::{Field, PrimeField};
+    use merlin::Transcript;
+    use serial_test::serial;
+    use std::io::Error;
+    use zeromt::{
+        AnonZeroMTProof, AnonZeroMTProver, AnonZeroMTStatement, AnonZeroMTVerifier,
+        AnonZeroMTWitness, ElGamal, InnerProof, InnerProver, OneOutOfManyProof, OneOutOfManyProver,
+        RangeProver, SigmaAnonProof, SigmaAnonProver, TranscriptProtocol, Utils, ZeroMTParams,
+    };
+
+    struct AnonZeroMTSetup {
+        g: G1Point,
+        h: G1Point,
+        u: G1Point,
+        g_vec: Vec<G1Point>,
+        h_vec: Vec<G1Point>,
+        y_vec: Vec<G1Point>,
+        c_l_vec: Vec<G1Point>,
+        c_r_vec: Vec<G1Point>,
+        c_vec: Vec<G1Point>,
+        d: G1Point,
+        sender_index: usize,
+        remaining_balance: usize,
+        amounts: Vec<usize>,
+        sk: ScalarField,
+        r: ScalarField,
+    }
+
+    fn get_setup(ring_size: usize, n: usize) -> AnonZeroMTSetup {
+        let mut rng = ark_std::rand::thread_rng();
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(ring_size * n, &mut rng);
+        let h_vec: Vec<G1Point> = Utils::get_n_generators(ring_size * n, &mut rng);
+
+        let (balance, recipients_amounts, remaining_balance) =
+            Utils::get_mock_balances(ring_size, n, &mut rng);
+
+        // The sender sits in the middle of the ring, the last ring member acts as a decoy
+        let sender_index: usize = ring_size / 2 - 1;
+        let mut amounts: Vec<usize> = recipients_amounts.clone();
+        amounts.insert(sender_index, 0);
+        let decoy_amount: usize = amounts[ring_size - 1];
+        amounts[ring_size - 1] = 0;
+        let remaining_balance: usize = remaining_balance + decoy_amount;
+
+        let priv_keys: Vec<ScalarField> = Utils::get_n_random_scalars_not_zero(ring_size, &mut rng);
+        let y_vec: Vec<G1Point> = priv_keys
+            .iter()
+            .map(|key: &ScalarField| ElGamal::elgamal_calculate_pub_key(key, &g))
+            .collect();
+
+        // Encrypted balances of every ring member
+        let balances_randomness: Vec<ScalarField> =
+            Utils::get_n_random_scalars_not_zero(ring_size, &mut rng);
+        let (c_l_vec, c_r_vec): (Vec<G1Point>, Vec<G1Point>) = (0..ring_size)
+            .map(|i: usize| {
+                let member_balance: usize = if i == sender_index { balance } else { i };
+                ElGamal::elgamal_encrypt(member_balance, &y_vec[i], &g, &balances_randomness[i])
+            })
+            .unzip();
+
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let d: G1Point = ElGamal::elgamal_d(&g, &r);
+
+        let total_amount: ScalarField = ScalarField::from(amounts.iter().sum::<usize>() as i128);
+        let c_vec: Vec<G1Point> = (0..ring_size)
+            .map(|i: usize| {
+                if i == sender_index {
+                    ElGamal::elgamal_encrypt_scalar(&-total_amount, &y_vec[i], &g, &r).0
+                } else {
+                    ElGamal::elgamal_encrypt(amounts[i], &y_vec[i], &g, &r).0
+                }
+            })
+            .collect();
+
+        AnonZeroMTSetup {
+            g,
+            h,
+            u,
+            g_vec,
+            h_vec,
+            y_vec,
+            c_l_vec,
+            c_r_vec,
+            c_vec,
+            d,
+            sender_index,
+            remaining_balance,
+            amounts,
+            sk: priv_keys[sender_index],
+            r,
+        }
+    }
+
+    fn generate_proof(
+        setup: &AnonZeroMTSetup,
+        n: usize,
+        transcript: &mut Transcript,
+    ) -> AnonZeroMTProof {
+        let mut rng = ark_std::rand::thread_rng();
+
+        AnonZeroMTProver::new(
+            &ZeroMTParams::new(&setup.g, &setup.h, &setup.g_vec, &setup.h_vec, &setup.u, n),
+            &AnonZeroMTStatement::new(
+                &setup.y_vec,
+                &setup.c_l_vec,
+                &setup.c_r_vec,
+                &setup.c_vec,
+                &setup.d,
+            ),
+            &AnonZeroMTWitness::new(
+                setup.sender_index,
+                setup.remaining_balance,
+                &setup.amounts,
+                &setup.sk,
+                &setup.r,
+            ),
+        )
+        .generate_proof(&mut rng, transcript)
+        .unwrap()
+    }
+
+    fn verify_proof(
+        setup: &AnonZeroMTSetup,
+        n: usize,
+        c_vec: &Vec<G1Point>,
+        proof: &AnonZeroMTProof,
+        transcript: &mut Transcript,
+    ) -> Result<(), Error> {
+        AnonZeroMTVerifier::new(
+            &ZeroMTParams::new(&setup.g, &setup.h, &setup.g_vec, &setup.h_vec, &setup.u, n),
+            &AnonZeroMTStatement::new(
+                &setup.y_vec,
+                &setup.c_l_vec,
+                &setup.c_r_vec,
+                c_vec,
+                &setup.d,
+            ),
+        )
+        .verify_proof(proof, transcript)
+    }
+
+    #[test]
+    #[serial]
+    fn anon_zeromt_proof_test() {
+        let n: usize = 16;
+        let ring_increases: usize = 4;
+
+        let mut ring_size: usize = 4;
+        for _ in 0..=ring_increases {
+            let mut prover_trans: Transcript = Transcript::new(b"AnonZeroMTTest");
+            let mut verifier_trans: Transcript = Transcript::new(b"AnonZeroMTTest");
+
+            let setup: AnonZeroMTSetup = get_setup(ring_size, n);
+            let proof: AnonZeroMTProof = generate_proof(&setup, n, &mut prover_trans);
+
+            let verification_result: Result<(), Error> =
+                verify_proof(&setup, n, &setup.c_vec, &proof, &mut verifier_trans);
+
+            assert!(verification_result.is_ok(), "Verifier fails");
+
+            ring_size *= 2;
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn anon_zeromt_tampered_ciphertext_test() {
+        let n: usize = 16;
+        let ring_size: usize = 8;
+
+        let mut prover_trans: Transcript = Transcript::new(b"AnonZeroMTTest");
+        let mut verifier_trans: Transcript = Transcript::new(b"AnonZeroMTTest");
+
+        let setup: AnonZeroMTSetup = get_setup(ring_size, n);
+        let proof: AnonZeroMTProof = generate_proof(&setup, n, &mut prover_trans);
+
+        // A decoy ciphertext is swapped with an encryption of a non-zero amount
+        let mut tampered_c_vec: Vec<G1Point> = setup.c_vec.clone();
+        tampered_c_vec[ring_size - 1] =
+            ElGamal::elgamal_encrypt(1, &setup.y_vec[ring_size - 1], &setup.g, &setup.r).0;
+
+        let verification_result: Result<(), Error> =
+            verify_proof(&setup, n, &tampered_c_vec, &proof, &mut verifier_trans);
+
+        assert!(verification_result.is_err(), "Verifier must fail");
+    }
+
+    #[test]
+    #[serial]
+    fn anon_zeromt_negative_amount_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let n: usize = 16;
+        let ring_size: usize = 8;
+
+        let mut prover_trans: Transcript = Transcript::new(b"AnonZeroMTTest");
+        let mut verifier_trans: Transcript = Transcript::new(b"AnonZeroMTTest");
+
+        let setup: AnonZeroMTSetup = get_setup(ring_size, n);
+        let l: usize = setup.sender_index;
+        let recipient: usize = 0;
+        let decoy: usize = ring_size - 1;
+
+        // The decoy receives -minted and the recipient a + minted: the sender update and the
+        // remaining balance are unchanged, while the recipient is credited out of nothing
+        let minted: ScalarField = ScalarField::from(1000u64);
+        let mut values: Vec<ScalarField> = (0..ring_size)
+            .map(|i: usize| ScalarField::from(setup.amounts[i] as u64))
+            .collect();
+        values[recipient] += minted;
+        values[decoy] = -minted;
+        let c_vec: Vec<G1Point> = (0..ring_size)
+            .map(|i: usize| {
+                if i == l {
+                    setup.c_vec[i]
+                } else {
+                    ElGamal::elgamal_encrypt_scalar(&values[i], &setup.y_vec[i], &setup.g, &setup.r)
+                        .0
+                }
+            })
+            .collect();
+        values[l] = ScalarField::from(setup.remaining_balance as u64);
+
+        // The range proof covers the honest in-range values, the balance relation the minted ones
+        let range_amounts: Vec<usize> = (0..ring_size)
+            .filter(|i: &usize| *i != l)
+            .map(|i: usize| setup.amounts[i])
+            .collect();
+        let mut range_prover: RangeProver = RangeProver::new(
+            &setup.g,
+            &setup.h,
+            setup.remaining_balance,
+            &range_amounts,
+            &setup.g_vec,
+            &setup.h_vec,
+            n,
+        );
+
+        prover_trans.domain_sep(b"AnonZeroMTProof");
+        let (range_proof, l_poly_vec, r_poly_vec, x, y, z, _t_coefficients) =
+            range_prover.generate_proof(&mut rng, &mut prover_trans);
+        let (h_first_vec, phu): (Vec<G1Point>, G1Point) = range_prover.get_ipa_arguments(
+            &x,
+            &y,
+            &z,
+            range_proof.get_mu(),
+            range_proof.get_a(),
+            range_proof.get_s(),
+            &setup.h,
+            &setup.g_vec,
+            &setup.h_vec,
+        );
+        let inner_proof: InnerProof = InnerProver::new(
+            &setup.g_vec,
+            &h_first_vec,
+            &phu,
+            range_proof.get_t_hat(),
+            &l_poly_vec,
+            &r_poly_vec,
+            &setup.u,
+        )
+        .generate_proof(&mut prover_trans)
+        .unwrap();
+
+        let z: ScalarField = prover_trans.challenge_scalar(b"z");
+        let z_vec: Vec<ScalarField> = (0..ring_size)
+            .map(|i: usize| z.pow([2 + i as u64]))
+            .collect();
+
+        let rho_vec: Vec<ScalarField> = Utils::get_n_random_scalars(3, &mut rng);
+        let y_first: G1Point = setup.y_vec[l] + setup.h.mul(rho_vec[0].into_repr()).into_affine();
+        let e_l: G1Point = setup.c_l_vec[l].mul(z_vec[l].into_repr()).into_affine()
+            + setup.h.mul(rho_vec[1].into_repr()).into_affine();
+        let e_r: G1Point = setup.c_r_vec[l].mul(z_vec[l].into_repr()).into_affine()
+            + setup.h.mul(rho_vec[2].into_repr()).into_affine();
+
+        let _result = prover_trans.append_point(b"Y'", &y_first);
+        let _result = prover_trans.append_point(b"E_L", &e_l);
+        let _result = prover_trans.append_point(b"E_R", &e_r);
+
+        let xi: ScalarField = prover_trans.challenge_scalar(b"xi");
+        let ring_commitments: Vec<G1Point> = (0..ring_size)
+            .map(|i: usize| {
+                let key: G1Point = setup.y_vec[i] + -y_first;
+                let left: G1Point = setup.c_l_vec[i].mul(z_vec[i].into_repr()).into_affine() + -e_l;
+                let right: G1Point =
+                    setup.c_r_vec[i].mul(z_vec[i].into_repr()).into_affine() + -e_r;
+                key + left.mul(xi.into_repr()).into_affine()
+                    + right.mul(xi.square().into_repr()).into_affine()
+            })
+            .collect();
+        let opening: ScalarField = -(rho_vec[0] + (xi * rho_vec[1]) + (xi.square() * rho_vec[2]));
+        let one_out_of_many_proof: OneOutOfManyProof =
+            OneOutOfManyProver::new(&setup.g_vec, &setup.h, &ring_commitments, l, &opening)
+                .generate_proof(&mut rng, &mut prover_trans)
+                .unwrap();
+
+        let q: G1Point = Utils::inner_product_point_scalar(&setup.y_vec, &z_vec).unwrap();
+        let y_sum: G1Point = setup.y_vec.iter().sum::<G1Point>();
+        let w: ScalarField = (0..ring_size).map(|i: usize| values[i] * z_vec[i]).sum();
+        let tau: ScalarField = rho_vec[1] - (setup.sk * rho_vec[2]);
+        let sigma_anon_proof: SigmaAnonProof = SigmaAnonProver::new(
+            &setup.g,
+            &setup.h,
+            &e_r,
+            &q,
+            &y_sum,
+            &setup.sk,
+            &rho_vec[0],
+            &w,
+            &tau,
+            &setup.r,
+        )
+        .generate_proof(&mut rng, &mut prover_trans);
+
+        let proof: AnonZeroMTProof = AnonZeroMTProof::new(
+            range_proof,
+            inner_proof,
+            y_first,
+            e_l,
+            e_r,
+            one_out_of_many_proof,
+            sigma_anon_proof,
+        );
+
+        let verification_result: Result<(), Error> =
+            verify_proof(&setup, n, &c_vec, &proof, &mut verifier_trans);
+
+        assert!(verification_result.is_err(), "Verifier must fail");
+    }
+}
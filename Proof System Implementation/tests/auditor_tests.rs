@@ -0,0 +1,230 @@
+#[cfg(test)]
+mod auditor_tests {
+    use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+    use merlin::Transcript;
+    use serial_test::serial;
+    use std::io::Error;
+    use zeromt::{
+        Auditor, ElGamal, InnerProver, SigmaYProof, SigmaYProver, SigmaYVerifier, Utils,
+        ZeroMTParams, ZeroMTProof, ZeroMTProver, ZeroMTStatement, ZeroMTVerifier, ZeroMTWitness,
+    };
+
+    #[test]
+    fn sigma_y_auditor_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let m: usize = 4;
+        let n: usize = 16;
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let (_balance, amounts, _remaining_balance) = Utils::get_mock_balances(m, n, &mut rng);
+
+        let sender_pub_key: G1Point =
+            ElGamal::elgamal_calculate_pub_key(&Utils::get_n_random_scalars(1, &mut rng)[0], &g);
+        let recipients_pub_keys: Vec<G1Point> = Utils::get_n_generators(amounts.len(), &mut rng);
+        let auditor_pub_key: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+
+        let c_vec: Vec<G1Point> = amounts
+            .iter()
+            .map(|a: &usize| ElGamal::elgamal_encrypt(*a, &sender_pub_key, &g, &r).0)
+            .collect();
+        let c_bar_vec: Vec<G1Point> = amounts
+            .iter()
+            .zip(recipients_pub_keys.iter())
+            .map(|(a, k)| ElGamal::elgamal_encrypt(*a, k, &g, &r).0)
+            .collect();
+        let c_auditor_vec: Vec<G1Point> = amounts
+            .iter()
+            .map(|a: &usize| ElGamal::elgamal_encrypt(*a, &auditor_pub_key, &g, &r).0)
+            .collect();
+
+        let mut prover_trans: Transcript = Transcript::new(b"AuditorTest");
+        let proof: SigmaYProof = SigmaYProver::new(&r, &sender_pub_key, &recipients_pub_keys)
+            .with_auditor(&auditor_pub_key, &c_auditor_vec)
+            .generate_proof(&mut rng, &mut prover_trans);
+        assert!(proof.get_a_auditor().is_some());
+
+        let mut verifier_trans: Transcript = Transcript::new(b"AuditorTest");
+        let result: Result<(), Error> =
+            SigmaYVerifier::new(&sender_pub_key, &recipients_pub_keys, &c_vec, &c_bar_vec)
+                .with_auditor(&auditor_pub_key, &c_auditor_vec)
+                .verify_proof(&proof, &mut verifier_trans);
+        assert!(result.is_ok(), "Verifier fails");
+
+        // An auditor ciphertext of a different amount
+        let mut wrong_c_auditor_vec: Vec<G1Point> = c_auditor_vec.clone();
+        wrong_c_auditor_vec[0] =
+            ElGamal::elgamal_encrypt(amounts[0] + 1, &auditor_pub_key, &g, &r).0;
+        let mut verifier_trans: Transcript = Transcript::new(b"AuditorTest");
+        let result: Result<(), Error> =
+            SigmaYVerifier::new(&sender_pub_key, &recipients_pub_keys, &c_vec, &c_bar_vec)
+                .with_auditor(&auditor_pub_key, &wrong_c_auditor_vec)
+                .verify_proof(&proof, &mut verifier_trans);
+        assert!(
+            result.is_err(),
+            "Verifier accepts a wrong auditor ciphertext"
+        );
+
+        // The auditor statement cannot be dropped by the verifier
+        let mut verifier_trans: Transcript = Transcript::new(b"AuditorTest");
+        let result: Result<(), Error> =
+            SigmaYVerifier::new(&sender_pub_key, &recipients_pub_keys, &c_vec, &c_bar_vec)
+                .verify_proof(&proof, &mut verifier_trans);
+        assert!(result.is_err(), "Verifier ignores the auditor proof");
+    }
+
+    #[test]
+    fn auditor_decrypt_amounts_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let n: usize = 20;
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let auditor_priv_key: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let auditor: Auditor = Auditor::new(&g, &auditor_priv_key, n, n / 2).unwrap();
+        let auditor_pub_key: G1Point = auditor.get_pub_key();
+        assert_eq!(
+            auditor_pub_key,
+            ElGamal::elgamal_calculate_pub_key(&auditor_priv_key, &g)
+        );
+
+        let amounts: Vec<usize> = vec![0, 1, 1023, 1024, 777_777, (1 << n) - 1];
+        let c_auditor_vec: Vec<G1Point> = amounts
+            .iter()
+            .map(|a: &usize| ElGamal::elgamal_encrypt(*a, &auditor_pub_key, &g, &r).0)
+            .collect();
+        let d: G1Point = ElGamal::elgamal_d(&g, &r);
+
+        let decrypted: Vec<usize> = auditor.decrypt_amounts(&d, &c_auditor_vec).unwrap();
+        assert_eq!(decrypted, amounts);
+
+        // Out of the [0, 2^n) range
+        let c_out_of_range: Vec<G1Point> =
+            vec![ElGamal::elgamal_encrypt(1 << n, &auditor_pub_key, &g, &r).0];
+        assert!(auditor.decrypt_amounts(&d, &c_out_of_range).is_err());
+
+        // A smaller table decrypts the same amounts with more giant steps
+        let small_table_auditor: Auditor = Auditor::new(&g, &auditor_priv_key, n, 4).unwrap();
+        assert_eq!(
+            small_table_auditor
+                .decrypt_amounts(&d, &c_auditor_vec)
+                .unwrap(),
+            amounts
+        );
+
+        assert!(Auditor::new(&g, &auditor_priv_key, 0, 0).is_err());
+        assert!(Auditor::new(&g, &auditor_priv_key, 65, 20).is_err());
+        assert!(Auditor::new(&g, &auditor_priv_key, n, 0).is_err());
+        assert!(Auditor::new(&g, &auditor_priv_key, 8, 9).is_err());
+        // 64-bit amounts do not get a 2^32 table
+        assert!(Auditor::new(&g, &auditor_priv_key, 64, 32).is_err());
+    }
+
+    #[test]
+    #[serial]
+    fn zeromt_auditor_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let m: usize = 4;
+        let n: usize = 16;
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+        let h_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+
+        let (balance, amounts, remaining_balance) = Utils::get_mock_balances(m, n, &mut rng);
+
+        let sender_priv_key: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let sender_pub_key: G1Point = ElGamal::elgamal_calculate_pub_key(&sender_priv_key, &g);
+        let recipients_pub_keys: Vec<G1Point> =
+            Utils::get_n_random_scalars_not_zero(m - 1, &mut rng)
+                .iter()
+                .map(|key: &ScalarField| ElGamal::elgamal_calculate_pub_key(key, &g))
+                .collect();
+        let auditor_priv_key: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let auditor: Auditor = Auditor::new(&g, &auditor_priv_key, n, n / 2).unwrap();
+        let auditor_pub_key: G1Point = auditor.get_pub_key();
+
+        let (c_l, c_r): (G1Point, G1Point) =
+            ElGamal::elgamal_encrypt(balance, &sender_pub_key, &g, &r);
+        let d: G1Point = ElGamal::elgamal_d(&g, &r);
+        let c_vec: Vec<G1Point> = amounts
+            .iter()
+            .map(|a: &usize| ElGamal::elgamal_encrypt(*a, &sender_pub_key, &g, &r).0)
+            .collect();
+        let c_bar_vec: Vec<G1Point> = amounts
+            .iter()
+            .zip(recipients_pub_keys.iter())
+            .map(|(a, k)| ElGamal::elgamal_encrypt(*a, k, &g, &r).0)
+            .collect();
+        let c_auditor_vec: Vec<G1Point> = amounts
+            .iter()
+            .map(|a: &usize| ElGamal::elgamal_encrypt(*a, &auditor_pub_key, &g, &r).0)
+            .collect();
+
+        let mut prover_trans: Transcript = Transcript::new(b"AuditorTest");
+        let proof: ZeroMTProof = ZeroMTProver::<InnerProver>::new(
+            &ZeroMTParams::new(&g, &h, &g_vec, &h_vec, &u, n),
+            &ZeroMTStatement::new(
+                &d,
+                &c_r,
+                &c_l,
+                &c_vec,
+                &c_bar_vec,
+                &sender_pub_key,
+                &recipients_pub_keys,
+            ),
+            &ZeroMTWitness::new(remaining_balance, &amounts, &sender_priv_key, &r),
+        )
+        .with_auditor(&auditor_pub_key, &c_auditor_vec)
+        .generate_proof(&mut rng, &mut prover_trans)
+        .unwrap();
+
+        let verify = |auditor: Option<(&G1Point, &Vec<G1Point>)>| -> Result<(), Error> {
+            let mut verifier_trans: Transcript = Transcript::new(b"AuditorTest");
+            let verifier: ZeroMTVerifier<InnerProver> = ZeroMTVerifier::<InnerProver>::new(
+                &ZeroMTParams::new(&g, &h, &g_vec, &h_vec, &u, n),
+                &ZeroMTStatement::new(
+                    &d,
+                    &c_r,
+                    &c_l,
+                    &c_vec,
+                    &c_bar_vec,
+                    &sender_pub_key,
+                    &recipients_pub_keys,
+                ),
+            );
+            match auditor {
+                Some((y_auditor, c_auditor_vec)) => verifier.with_auditor(y_auditor, c_auditor_vec),
+                None => verifier,
+            }
+            .verify_proof(&proof, &mut verifier_trans)
+        };
+        assert!(
+            verify(Some((&auditor_pub_key, &c_auditor_vec))).is_ok(),
+            "Verifier fails"
+        );
+
+        let mut wrong_c_auditor_vec: Vec<G1Point> = c_auditor_vec.clone();
+        wrong_c_auditor_vec.swap(0, 1);
+        assert!(
+            amounts[0] == amounts[1]
+                || verify(Some((&auditor_pub_key, &wrong_c_auditor_vec))).is_err(),
+            "Verifier accepts swapped auditor ciphertexts"
+        );
+
+        // The auditor key and ciphertexts are part of the statement
+        let point: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let mut other_c_auditor_vec: Vec<G1Point> = c_auditor_vec.clone();
+        other_c_auditor_vec[0] = point;
+        assert!(verify(Some((&point, &c_auditor_vec))).is_err());
+        assert!(verify(Some((&auditor_pub_key, &other_c_auditor_vec))).is_err());
+        assert!(verify(None).is_err(), "Verifier accepts without auditor");
+
+        // The auditor recovers the transferred amounts
+        let decrypted: Vec<usize> = auditor.decrypt_amounts(&d, &c_auditor_vec).unwrap();
+        assert_eq!(decrypted, amounts);
+    }
+}
@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod bulletproofs_tests {
+
+    use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+    use merlin::Transcript;
+    use std::io::Error;
+    use zeromt::{BulletproofsProof, BulletproofsProver, BulletproofsVerifier, Utils};
+
+    struct Generators {
+        g: G1Point,
+        h: G1Point,
+        u: G1Point,
+        g_vec: Vec<G1Point>,
+        h_vec: Vec<G1Point>,
+    }
+
+    fn setup(capacity: usize) -> Generators {
+        let mut rng = ark_std::rand::thread_rng();
+
+        Generators {
+            g: Utils::get_n_generators(1, &mut rng)[0],
+            h: Utils::get_n_generators(1, &mut rng)[0],
+            u: Utils::get_n_generators(1, &mut rng)[0],
+            g_vec: Utils::get_n_generators(capacity, &mut rng),
+            h_vec: Utils::get_n_generators(capacity, &mut rng),
+        }
+    }
+
+    fn prove(
+        gens: &Generators,
+        values: &Vec<usize>,
+        n: usize,
+    ) -> Result<(Vec<G1Point>, BulletproofsProof), Error> {
+        let mut rng = ark_std::rand::thread_rng();
+        let blindings: Vec<ScalarField> = Utils::get_n_random_scalars(values.len(), &mut rng);
+
+        let mut prover_trans: Transcript = Transcript::new(b"BulletproofsTest");
+        BulletproofsProver::new(&gens.g, &gens.h, &gens.g_vec, &gens.h_vec, &gens.u, n).prove(
+            values,
+            &blindings,
+            &mut rng,
+            &mut prover_trans,
+        )
+    }
+
+    fn verify(
+        gens: &Generators,
+        commitments: &Vec<G1Point>,
+        proof: &BulletproofsProof,
+        n: usize,
+    ) -> Result<(), Error> {
+        let mut verifier_trans: Transcript = Transcript::new(b"BulletproofsTest");
+        BulletproofsVerifier::new(&gens.g, &gens.h, &gens.g_vec, &gens.h_vec, &gens.u, n).verify(
+            commitments,
+            proof,
+            &mut verifier_trans,
+        )
+    }
+
+    #[test]
+    fn bulletproofs_single_value_test() {
+        let gens: Generators = setup(64);
+
+        for (value, n) in [(0, 8), (255, 8), (40_000, 16), (usize::MAX, 64), (12, 5)] {
+            let (commitments, proof): (Vec<G1Point>, BulletproofsProof) =
+                prove(&gens, &vec![value], n).unwrap();
+            assert!(
+                verify(&gens, &commitments, &proof, n).is_ok(),
+                "Verifier fails with value {} in {} bits",
+                value,
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn bulletproofs_aggregated_test() {
+        // Only the first m * n generators are used
+        let gens: Generators = setup(4 * 32);
+        let n: usize = 32;
+
+        let values: Vec<usize> = vec![7, 1 << 31, 0];
+        let (commitments, proof): (Vec<G1Point>, BulletproofsProof) =
+            prove(&gens, &values, n).unwrap();
+        assert!(verify(&gens, &commitments, &proof, n).is_ok());
+
+        // The commitments are bound to the proof
+        let mut swapped: Vec<G1Point> = commitments.clone();
+        swapped.swap(0, 1);
+        assert!(verify(&gens, &swapped, &proof, n).is_err());
+        assert!(verify(&gens, &commitments[..2].to_vec(), &proof, n).is_err());
+        assert!(verify(&gens, &commitments, &proof, 16).is_err());
+
+        let shifted: Vec<G1Point> = vec![commitments[0] + gens.g, commitments[1], commitments[2]];
+        assert!(verify(&gens, &shifted, &proof, n).is_err());
+    }
+
+    #[test]
+    fn bulletproofs_error_test() {
+        let gens: Generators = setup(32);
+
+        // Value out of range
+        assert!(prove(&gens, &vec![256], 8).is_err());
+        // Not enough generators for 3 * 16 bits
+        assert!(prove(&gens, &vec![1, 2, 3], 16).is_err());
+        // No values, too many bits
+        assert!(prove(&gens, &vec![], 8).is_err());
+        assert!(prove(&setup(65), &vec![1], 65).is_err());
+    }
+}
@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod burn_tests {
+    use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use merlin::Transcript;
+    use std::io::Error;
+    use zeromt::{AssociatedData, BurnProof, BurnProver, BurnVerifier, ElGamal, Utils};
+
+    #[test]
+    fn burn_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let n: usize = 32;
+        let balance: usize = 1_000_000;
+        let amount: usize = 400_000;
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(n, &mut rng);
+        let h_vec: Vec<G1Point> = Utils::get_n_generators(n, &mut rng);
+
+        let sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(&sk, &g);
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let (c_l, c_r): (G1Point, G1Point) = ElGamal::elgamal_encrypt(balance, &y, &g, &r);
+
+        let mut prover_trans: Transcript = Transcript::new(b"BurnTest");
+        let proof: BurnProof =
+            BurnProver::new(&g, &h, &g_vec, &h_vec, &u, n, &c_r, balance, amount, &sk)
+                .generate_proof(&mut rng, &mut prover_trans)
+                .unwrap();
+
+        let mut compressed_bytes: Vec<u8> = Vec::new();
+        proof.serialize(&mut compressed_bytes).unwrap();
+        let proof: BurnProof = BurnProof::deserialize(compressed_bytes.as_slice()).unwrap();
+
+        let verify = |c_l: &G1Point, amount: usize| -> Result<(), Error> {
+            let mut verifier_trans: Transcript = Transcript::new(b"BurnTest");
+            BurnVerifier::new(&g, &h, &g_vec, &h_vec, &u, n, &y, c_l, &c_r, amount)
+                .verify_proof(&proof, &mut verifier_trans)
+        };
+
+        assert!(verify(&c_l, amount).is_ok(), "Verifier fails");
+        assert!(
+            verify(&c_l, amount + 1).is_err(),
+            "Verifier accepts another amount"
+        );
+        assert!(
+            verify(&(c_l + g), amount).is_err(),
+            "Verifier accepts another balance"
+        );
+
+        // The balance cannot be overdrawn
+        let mut prover_trans: Transcript = Transcript::new(b"BurnTest");
+        let result: Result<BurnProof, Error> = BurnProver::new(
+            &g,
+            &h,
+            &g_vec,
+            &h_vec,
+            &u,
+            n,
+            &c_r,
+            balance,
+            balance + 1,
+            &sk,
+        )
+        .generate_proof(&mut rng, &mut prover_trans);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn burn_whole_balance_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let n: usize = 16;
+        let balance: usize = 12345;
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(n, &mut rng);
+        let h_vec: Vec<G1Point> = Utils::get_n_generators(n, &mut rng);
+
+        let sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(&sk, &g);
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let (c_l, c_r): (G1Point, G1Point) = ElGamal::elgamal_encrypt(balance, &y, &g, &r);
+
+        let mut prover_trans: Transcript = Transcript::new(b"BurnTest");
+        let proof: BurnProof =
+            BurnProver::new(&g, &h, &g_vec, &h_vec, &u, n, &c_r, balance, balance, &sk)
+                .generate_proof(&mut rng, &mut prover_trans)
+                .unwrap();
+
+        let mut verifier_trans: Transcript = Transcript::new(b"BurnTest");
+        let result: Result<(), Error> =
+            BurnVerifier::new(&g, &h, &g_vec, &h_vec, &u, n, &y, &c_l, &c_r, balance)
+                .verify_proof(&proof, &mut verifier_trans);
+        assert!(result.is_ok(), "Verifier fails");
+    }
+
+    #[test]
+    fn burn_associated_data_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let n: usize = 16;
+        let balance: usize = 5000;
+        let amount: usize = 1200;
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(n, &mut rng);
+        let h_vec: Vec<G1Point> = Utils::get_n_generators(n, &mut rng);
+
+        let sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(&sk, &g);
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let (c_l, c_r): (G1Point, G1Point) = ElGamal::elgamal_encrypt(balance, &y, &g, &r);
+
+        let associated_data: AssociatedData = AssociatedData::new().with_chain_id(5).with_epoch(7);
+        let mut prover_trans: Transcript = Transcript::new(b"BurnTest");
+        let proof: BurnProof =
+            BurnProver::new(&g, &h, &g_vec, &h_vec, &u, n, &c_r, balance, amount, &sk)
+                .with_associated_data(&associated_data)
+                .generate_proof(&mut rng, &mut prover_trans)
+                .unwrap();
+
+        let verify = |u: &G1Point, associated_data: &AssociatedData| -> Result<(), Error> {
+            let mut verifier_trans: Transcript = Transcript::new(b"BurnTest");
+            BurnVerifier::new(&g, &h, &g_vec, &h_vec, u, n, &y, &c_l, &c_r, amount)
+                .with_associated_data(associated_data)
+                .verify_proof(&proof, &mut verifier_trans)
+        };
+
+        assert!(verify(&u, &associated_data).is_ok(), "Verifier fails");
+        assert!(
+            verify(&u, &AssociatedData::new().with_chain_id(5).with_epoch(8)).is_err(),
+            "Verifier accepts another epoch"
+        );
+        assert!(
+            verify(&u, &AssociatedData::new().with_chain_id(6).with_epoch(7)).is_err(),
+            "Verifier accepts another chain id"
+        );
+        assert!(
+            verify(&h, &associated_data).is_err(),
+            "Verifier accepts another generator u"
+        );
+    }
+}
@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod extractor_tests {
+    use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+    use ark_ff::Field;
+    use std::io::Error;
+    use zeromt::{
+        ElGamal, Extractor, SigmaABProver, SigmaABVerifier, SigmaRProver, SigmaRVerifier,
+        SigmaSKProver, SigmaSKVerifier, Utils,
+    };
+
+    #[test]
+    fn extract_sigma_sk_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(&sk, &g);
+        let challenges: Vec<ScalarField> = Utils::get_n_random_scalars_not_zero(2, &mut rng);
+
+        let extracted: ScalarField = Extractor::extract_sigma_sk(
+            &mut SigmaSKProver::new(&g, &sk),
+            &mut SigmaSKVerifier::new(&g, &y),
+            42,
+            &challenges[0],
+            &challenges[1],
+        )
+        .unwrap();
+
+        assert_eq!(extracted, sk, "Extracted sk does not match");
+    }
+
+    #[test]
+    fn extract_sigma_r_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let d: G1Point = ElGamal::elgamal_d(&g, &r);
+        let challenges: Vec<ScalarField> = Utils::get_n_random_scalars_not_zero(2, &mut rng);
+
+        let extracted: ScalarField = Extractor::extract_sigma_r(
+            &mut SigmaRProver::new(&g, &r),
+            &mut SigmaRVerifier::new(&g, &d),
+            42,
+            &challenges[0],
+            &challenges[1],
+        )
+        .unwrap();
+
+        assert_eq!(extracted, r, "Extracted r does not match");
+    }
+
+    #[test]
+    fn extract_sigma_ab_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        let n_increases: usize = 2;
+        let m_increases: usize = 5;
+        let mut n: usize = 16;
+        for _ in 0..=n_increases {
+            let mut m: usize = 2;
+            for _ in 0..=m_increases {
+                let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+                let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+
+                let (balance, amounts, balance_remaining) =
+                    Utils::get_mock_balances(m, n, &mut rng);
+
+                let sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+                let y: G1Point = ElGamal::elgamal_calculate_pub_key(&sk, &g);
+
+                let (c_l, c_r): (G1Point, G1Point) = ElGamal::elgamal_encrypt(balance, &y, &g, &r);
+                let d: G1Point = ElGamal::elgamal_d(&g, &r);
+                let c_vec: Vec<G1Point> = amounts
+                    .iter()
+                    .map(|a: &usize| ElGamal::elgamal_encrypt(*a, &y, &g, &r).0)
+                    .collect();
+
+                let challenges: Vec<ScalarField> =
+                    Utils::get_n_random_scalars_not_zero(3, &mut rng);
+                let z: ScalarField = challenges[0];
+
+                let (extracted_sk, extracted_ab): (ScalarField, ScalarField) =
+                    Extractor::extract_sigma_ab(
+                        &mut SigmaABProver::new(&g, &d, &c_r, balance_remaining, &amounts, &sk),
+                        &mut SigmaABVerifier::new(&g, &d, &c_r, &c_l, &c_vec),
+                        42,
+                        &z,
+                        &challenges[1],
+                        &challenges[2],
+                    )
+                    .unwrap();
+
+                let ab: ScalarField = ScalarField::from(balance_remaining as i128) * z.pow([2])
+                    + (1..=amounts.len())
+                        .map(|i: usize| {
+                            ScalarField::from(amounts[i - 1] as i128) * z.pow([2 + i as u64])
+                        })
+                        .sum::<ScalarField>();
+
+                assert_eq!(extracted_sk, sk, "Extracted sk does not match");
+                assert_eq!(
+                    extracted_ab, ab,
+                    "Extracted b' * z^2 + sum(a_i * z^(2+i)) does not match"
+                );
+
+                m *= 2;
+            }
+            n *= 2;
+        }
+    }
+
+    #[test]
+    fn extract_same_challenge_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(&sk, &g);
+        let c: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+
+        let result: Result<ScalarField, Error> = Extractor::extract_sigma_sk(
+            &mut SigmaSKProver::new(&g, &sk),
+            &mut SigmaSKVerifier::new(&g, &y),
+            42,
+            &c,
+            &c,
+        );
+
+        assert!(
+            result.is_err(),
+            "Extraction must fail with equal challenges"
+        );
+    }
+
+    #[test]
+    fn extract_wrong_statement_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let other_y: G1Point = Utils::get_n_random_points(1, &mut rng)[0];
+        let challenges: Vec<ScalarField> = Utils::get_n_random_scalars_not_zero(2, &mut rng);
+
+        // The transcripts are not accepting for a public key the prover has no key for
+        let result: Result<ScalarField, Error> = Extractor::extract_sigma_sk(
+            &mut SigmaSKProver::new(&g, &sk),
+            &mut SigmaSKVerifier::new(&g, &other_y),
+            42,
+            &challenges[0],
+            &challenges[1],
+        );
+
+        assert!(
+            result.is_err(),
+            "Extraction must fail on rejecting transcripts"
+        );
+    }
+}
@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod halo_accumulator_tests {
+    use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_ff::PrimeField;
+    use merlin::Transcript;
+    use serial_test::serial;
+    use std::io::Error;
+    use zeromt::{HaloAccumulator, InnerHaloProof, InnerHaloProver, InnerHaloVerifier, Utils};
+
+    /// Proves and verifies an evaluation with deferred generator check
+    fn deferred_proof(
+        g_vec: &Vec<G1Point>,
+        h: &G1Point,
+        u: &G1Point,
+    ) -> Result<HaloAccumulator, Error> {
+        let mut rng = ark_std::rand::thread_rng();
+        let mut prover_trans: Transcript = Transcript::new(b"HaloAccumulatorTest");
+        let mut verifier_trans: Transcript = Transcript::new(b"HaloAccumulatorTest");
+
+        let t_vec: Vec<ScalarField> = Utils::get_n_random_scalars_not_zero(g_vec.len(), &mut rng);
+        let rand_r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let t_comm: G1Point = Utils::inner_product_point_scalar(g_vec, &t_vec).unwrap()
+            + h.mul(rand_r.into_repr()).into_affine();
+
+        let rand_x: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let b_vec: Vec<ScalarField> = Utils::generate_scalar_exp_vector(g_vec.len(), &rand_x);
+        let t_hat: ScalarField = Utils::inner_product_scalar_scalar(&t_vec, &b_vec).unwrap();
+
+        let proof: InnerHaloProof =
+            InnerHaloProver::new(g_vec, h, &t_comm, &rand_r, &t_hat, &t_vec, &b_vec, u)
+                .generate_proof(&mut prover_trans);
+
+        InnerHaloVerifier::new(g_vec, &b_vec, h, &t_comm, &t_hat, u)
+            .verify_deferred(&proof, &mut verifier_trans)
+    }
+
+    #[test]
+    #[serial]
+    fn halo_accumulator_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        for poly_degree in [1, 20, 63] {
+            let g_vec: Vec<G1Point> = Utils::get_n_generators(poly_degree + 1, &mut rng);
+            let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+            let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+
+            let mut acc_trans: Transcript = Transcript::new(b"HaloAccumulatorTest");
+            let mut accumulator: HaloAccumulator = deferred_proof(&g_vec, &h, &u).unwrap();
+            assert!(accumulator.decide(&g_vec).is_ok(), "Single decide fails");
+
+            for _ in 0..7 {
+                let next: HaloAccumulator = deferred_proof(&g_vec, &h, &u).unwrap();
+                accumulator.merge(&next, &mut acc_trans);
+            }
+
+            assert_eq!(accumulator.get_challenges().len(), 8);
+            let decide_result: Result<(), Error> = accumulator.decide(&g_vec);
+            assert!(
+                decide_result.is_ok(),
+                "Decide fails for degree {}",
+                poly_degree
+            );
+
+            // The same accumulator does not hold for other generators
+            let other_g_vec: Vec<G1Point> = Utils::get_n_generators(poly_degree + 1, &mut rng);
+            assert!(
+                accumulator.decide(&other_g_vec).is_err(),
+                "Decide must fail"
+            );
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn halo_accumulator_wrong_claim_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(16, &mut rng);
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+
+        let mut acc_trans: Transcript = Transcript::new(b"HaloAccumulatorTest");
+        let mut accumulator: HaloAccumulator = deferred_proof(&g_vec, &h, &u).unwrap();
+
+        // An accumulator with a folded generator that does not match its challenges
+        let wrong: HaloAccumulator = HaloAccumulator::new(
+            Utils::get_n_random_scalars_not_zero(4, &mut rng),
+            Utils::get_n_random_points(1, &mut rng)[0],
+        );
+        accumulator.merge(&wrong, &mut acc_trans);
+
+        for _ in 0..3 {
+            let next: HaloAccumulator = deferred_proof(&g_vec, &h, &u).unwrap();
+            accumulator.merge(&next, &mut acc_trans);
+        }
+
+        assert!(accumulator.decide(&g_vec).is_err(), "Decide must fail");
+    }
+}
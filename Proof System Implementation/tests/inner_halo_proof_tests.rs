@@ -4,6 +4,7 @@ mod tests {
     use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
     use ark_ec::{AffineCurve, ProjectiveCurve};
     use ark_ff::{Field, One, PrimeField};
+    use ark_std::rand::Rng;
     use merlin::Transcript;
     use serial_test::serial;
     use std::io::Error;
@@ -45,4 +46,91 @@ mod tests {
         let proof_check: bool = inner_halo_result.is_ok();
         assert!(proof_check, "Verifier fails");
     }
+
+    fn prove_and_verify(poly_degree: usize, wrong_evaluation: bool) -> Result<(), Error> {
+        let mut prover_trans: Transcript = Transcript::new(b"InnerHaloTest");
+        let mut verifier_trans: Transcript = Transcript::new(b"InnerHaloTest");
+
+        let mut rng = ark_std::rand::thread_rng();
+        let t_vec: Vec<ScalarField> =
+            Utils::get_n_random_scalars_not_zero(poly_degree + 1, &mut rng);
+
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(poly_degree + 1, &mut rng);
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+
+        let rand_r = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let t_comm: G1Point = Utils::inner_product_point_scalar(&g_vec, &t_vec).unwrap()
+            + h.mul(rand_r.into_repr()).into_affine();
+
+        let rand_x = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let b_vec: Vec<ScalarField> = Utils::generate_scalar_exp_vector(poly_degree + 1, &rand_x);
+        let t_hat: ScalarField = Utils::inner_product_scalar_scalar(&t_vec, &b_vec).unwrap();
+
+        let inner_halo_proof: InnerHaloProof =
+            InnerHaloProver::new(&g_vec, &h, &t_comm, &rand_r, &t_hat, &t_vec, &b_vec, &u)
+                .generate_proof(&mut prover_trans);
+
+        assert_eq!(
+            inner_halo_proof.get_l_vec().len(),
+            (poly_degree + 1).next_power_of_two().trailing_zeros() as usize
+        );
+
+        let verifier_t_hat: ScalarField = if wrong_evaluation {
+            t_hat + ScalarField::one()
+        } else {
+            t_hat
+        };
+
+        let mut multiscalar_trans: Transcript = Transcript::new(b"InnerHaloTest");
+        let multiscalar_result: Result<(), Error> =
+            InnerHaloVerifier::new(&g_vec, &b_vec, &h, &t_comm, &verifier_t_hat, &u)
+                .verify_proof_multiscalar(&inner_halo_proof, &mut multiscalar_trans);
+
+        let result: Result<(), Error> =
+            InnerHaloVerifier::new(&g_vec, &b_vec, &h, &t_comm, &verifier_t_hat, &u)
+                .verify_proof(&inner_halo_proof, &mut verifier_trans);
+
+        // Both verification paths agree
+        assert_eq!(result.is_ok(), multiscalar_result.is_ok());
+        result
+    }
+
+    #[test]
+    #[serial]
+    fn inner_halo_degree_tests() {
+        // Every degree up to 16, the degrees around each power of two up to 1023, where the
+        // padding changes, and a fresh random sample of the degrees in between on every run
+        let mut rng = ark_std::rand::thread_rng();
+        let mut degrees: Vec<usize> = (1..=16).collect();
+        let mut power: usize = 32;
+        while power <= 1024 {
+            degrees.append(&mut vec![power - 2, power - 1, power]);
+            power *= 2;
+        }
+        degrees.retain(|d: &usize| *d <= 1023);
+        degrees.extend((0..16).map(|_| rng.gen_range(17..1024)));
+
+        for poly_degree in degrees {
+            let inner_halo_result: Result<(), Error> = prove_and_verify(poly_degree, false);
+            assert!(
+                inner_halo_result.is_ok(),
+                "Verifier fails for degree {}",
+                poly_degree
+            );
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn inner_halo_wrong_evaluation_tests() {
+        for poly_degree in [1, 2, 5, 8] {
+            let inner_halo_result: Result<(), Error> = prove_and_verify(poly_degree, true);
+            assert!(
+                inner_halo_result.is_err(),
+                "Verifier must fail for degree {}",
+                poly_degree
+            );
+        }
+    }
 }
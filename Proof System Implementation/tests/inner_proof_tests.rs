@@ -0,0 +1,177 @@
+#[cfg(test)]
+mod inner_proof_tests {
+
+    use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+    use merlin::Transcript;
+    use std::io::Error;
+    use zeromt::{
+        InnerProof, InnerProver, InnerSigmaProof, InnerSigmaProver, InnerSigmaVerifier,
+        InnerVerifier, Utils,
+    };
+
+    /// Random statement P = <a, g_vec> + <b, h_vec> with c = <a, b>
+    fn setup(
+        n: usize,
+    ) -> (
+        Vec<G1Point>,
+        Vec<G1Point>,
+        G1Point,
+        ScalarField,
+        Vec<ScalarField>,
+        Vec<ScalarField>,
+        G1Point,
+    ) {
+        let mut rng = ark_std::rand::thread_rng();
+
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(n, &mut rng);
+        let h_vec: Vec<G1Point> = Utils::get_n_generators(n, &mut rng);
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let a_vec: Vec<ScalarField> = Utils::get_n_random_scalars(n, &mut rng);
+        let b_vec: Vec<ScalarField> = Utils::get_n_random_scalars(n, &mut rng);
+
+        let c: ScalarField = Utils::inner_product_scalar_scalar(&a_vec, &b_vec).unwrap();
+        let p: G1Point = Utils::inner_product_point_scalar(&g_vec, &a_vec).unwrap()
+            + Utils::inner_product_point_scalar(&h_vec, &b_vec).unwrap();
+
+        (g_vec, h_vec, p, c, a_vec, b_vec, u)
+    }
+
+    #[test]
+    fn inner_arbitrary_length_test() {
+        for n in (1..=33).chain([63, 100]) {
+            let (g_vec, h_vec, p, c, a_vec, b_vec, u) = setup(n);
+
+            let mut prover_trans: Transcript = Transcript::new(b"InnerProofTest");
+            let proof: InnerProof = InnerProver::new(&g_vec, &h_vec, &p, &c, &a_vec, &b_vec, &u)
+                .generate_proof(&mut prover_trans)
+                .unwrap();
+
+            assert_eq!(
+                proof.get_l_vec().len(),
+                n.next_power_of_two().trailing_zeros() as usize
+            );
+
+            let mut verifier_trans: Transcript = Transcript::new(b"InnerProofTest");
+            let result: Result<(), Error> = InnerVerifier::new(&g_vec, &h_vec, &p, &c, &u)
+                .verify_proof(&proof, &mut verifier_trans);
+            assert!(result.is_ok(), "Inner verifier fails with n = {}", n);
+
+            let mut verifier_trans: Transcript = Transcript::new(b"InnerProofTest");
+            let result: Result<(), Error> = InnerVerifier::new(&g_vec, &h_vec, &p, &c, &u)
+                .verify_proof_multiscalar(&proof, &mut verifier_trans);
+            assert!(
+                result.is_ok(),
+                "Inner multiscalar verifier fails with n = {}",
+                n
+            );
+
+            // A wrong inner product must still be rejected on the padded vectors
+            let wrong_c: ScalarField = c + ScalarField::from(1u8);
+            let mut verifier_trans: Transcript = Transcript::new(b"InnerProofTest");
+            let result: Result<(), Error> = InnerVerifier::new(&g_vec, &h_vec, &p, &wrong_c, &u)
+                .verify_proof_multiscalar(&proof, &mut verifier_trans);
+            assert!(
+                result.is_err(),
+                "Inner verifier accepts a wrong c with n = {}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn inner_sigma_arbitrary_length_test() {
+        for n in (1..=33).chain([63, 100]) {
+            let (g_vec, h_vec, t, c, a_vec, b_vec, u) = setup(n);
+
+            let mut prover_trans: Transcript = Transcript::new(b"InnerProofTest");
+            let proof: InnerSigmaProof =
+                InnerSigmaProver::new(&g_vec, &h_vec, &t, &c, &a_vec, &b_vec, &u)
+                    .generate_proof(&mut prover_trans)
+                    .unwrap();
+
+            let mut verifier_trans: Transcript = Transcript::new(b"InnerProofTest");
+            let result: Result<(), Error> = InnerSigmaVerifier::new(&g_vec, &h_vec, &t, &c, &u)
+                .verify_proof(&proof, &mut verifier_trans);
+            assert!(result.is_ok(), "InnerSigma verifier fails with n = {}", n);
+
+            let mut verifier_trans: Transcript = Transcript::new(b"InnerProofTest");
+            let result: Result<(), Error> = InnerSigmaVerifier::new(&g_vec, &h_vec, &t, &c, &u)
+                .verify_proof_multiscalar(&proof, &mut verifier_trans);
+            assert!(
+                result.is_ok(),
+                "InnerSigma multiscalar verifier fails with n = {}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn inner_large_length_test() {
+        // m * n = 4096, e.g. 64 values of 64 bits, in twelve rounds
+        let n: usize = 4096;
+        let (g_vec, h_vec, p, c, a_vec, b_vec, u) = setup(n);
+
+        let mut prover_trans: Transcript = Transcript::new(b"InnerProofTest");
+        let proof: InnerProof = InnerProver::new(&g_vec, &h_vec, &p, &c, &a_vec, &b_vec, &u)
+            .generate_proof(&mut prover_trans)
+            .unwrap();
+        assert_eq!(proof.get_l_vec().len(), 12);
+
+        let mut verifier_trans: Transcript = Transcript::new(b"InnerProofTest");
+        let result: Result<(), Error> = InnerVerifier::new(&g_vec, &h_vec, &p, &c, &u)
+            .verify_proof_multiscalar(&proof, &mut verifier_trans);
+        assert!(result.is_ok(), "Inner verifier fails with n = {}", n);
+
+        let mut prover_trans: Transcript = Transcript::new(b"InnerProofTest");
+        let proof: InnerSigmaProof =
+            InnerSigmaProver::new(&g_vec, &h_vec, &p, &c, &a_vec, &b_vec, &u)
+                .generate_proof(&mut prover_trans)
+                .unwrap();
+
+        let mut verifier_trans: Transcript = Transcript::new(b"InnerProofTest");
+        let result: Result<(), Error> = InnerSigmaVerifier::new(&g_vec, &h_vec, &p, &c, &u)
+            .verify_proof_multiscalar(&proof, &mut verifier_trans);
+        assert!(result.is_ok(), "InnerSigma verifier fails with n = {}", n);
+    }
+
+    #[test]
+    fn inner_length_error_test() {
+        let (g_vec, h_vec, p, c, a_vec, b_vec, u) = setup(12);
+
+        // Witness shorter than the generators
+        let short_a_vec: Vec<ScalarField> = a_vec[..11].to_vec();
+        let mut prover_trans: Transcript = Transcript::new(b"InnerProofTest");
+        assert!(
+            InnerProver::new(&g_vec, &h_vec, &p, &c, &short_a_vec, &b_vec, &u)
+                .generate_proof(&mut prover_trans)
+                .is_err()
+        );
+        let mut prover_trans: Transcript = Transcript::new(b"InnerProofTest");
+        assert!(
+            InnerSigmaProver::new(&g_vec, &h_vec, &p, &c, &short_a_vec, &b_vec, &u)
+                .generate_proof(&mut prover_trans)
+                .is_err()
+        );
+
+        let mut prover_trans: Transcript = Transcript::new(b"InnerProofTest");
+        let proof: InnerProof = InnerProver::new(&g_vec, &h_vec, &p, &c, &a_vec, &b_vec, &u)
+            .generate_proof(&mut prover_trans)
+            .unwrap();
+
+        // 17 generators need five rounds, the proof has four
+        let mut rng = ark_std::rand::thread_rng();
+        let long_g_vec: Vec<G1Point> = Utils::get_n_generators(17, &mut rng);
+        let long_h_vec: Vec<G1Point> = Utils::get_n_generators(17, &mut rng);
+        let mut verifier_trans: Transcript = Transcript::new(b"InnerProofTest");
+        assert!(InnerVerifier::new(&long_g_vec, &long_h_vec, &p, &c, &u)
+            .verify_proof_multiscalar(&proof, &mut verifier_trans)
+            .is_err());
+
+        // Generator vectors of different lengths
+        let short_h_vec: Vec<G1Point> = h_vec[..11].to_vec();
+        let mut verifier_trans: Transcript = Transcript::new(b"InnerProofTest");
+        assert!(InnerVerifier::new(&g_vec, &short_h_vec, &p, &c, &u)
+            .verify_proof(&proof, &mut verifier_trans)
+            .is_err());
+    }
+}
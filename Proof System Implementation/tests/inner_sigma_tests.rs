@@ -1,17 +1,19 @@
 #[cfg(test)]
 mod tests {
-    use std::{
-        io::Error,
-        time::{Duration, Instant},
-    };
     use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
     use ark_ec::{AffineCurve, ProjectiveCurve};
     use ark_ff::{Field, One, PrimeField};
     use merlin::Transcript;
     use num_format::{Locale, ToFormattedString};
     use serial_test::serial;
-    use zeromt::{CsvUtils, InnerProof, InnerProver, InnerVerifier, InnerSigmaProof, InnerSigmaProver, InnerSigmaVerifier, PolyCoefficients, RangeProof, RangeProver,
-        RangeVerifier, Utils};
+    use std::{
+        io::Error,
+        time::{Duration, Instant},
+    };
+    use zeromt::{
+        CsvUtils, InnerProof, InnerProver, InnerSigmaProof, InnerSigmaProver, InnerSigmaVerifier,
+        InnerVerifier, PolyCoefficients, RangeProof, RangeProver, RangeVerifier, Utils,
+    };
 
     #[test]
     #[serial]
@@ -104,7 +106,8 @@ mod tests {
                     &r_poly_vec,
                     &u,
                 )
-                .generate_proof(&mut prover_trans);
+                .generate_proof(&mut prover_trans)
+                .unwrap();
 
                 let inner_sigma_result: Result<(), Error> = InnerSigmaVerifier::new(
                     &g_vec,
@@ -216,7 +219,8 @@ mod tests {
                     &r_poly_vec,
                     &u,
                 )
-                .generate_proof(&mut prover_trans);
+                .generate_proof(&mut prover_trans)
+                .unwrap();
 
                 let inner_sigma_result: Result<(), Error> = InnerSigmaVerifier::new(
                     &g_vec,
@@ -332,7 +336,6 @@ mod tests {
                         &g_vec,
                         &h_vec,
                     );
-                
 
                 // Inner Product Bulletproofs
                 let start = Instant::now();
@@ -345,7 +348,8 @@ mod tests {
                     &r_poly_vec,
                     &u,
                 )
-                .generate_proof(&mut prover_trans);
+                .generate_proof(&mut prover_trans)
+                .unwrap();
                 let ipa_bp_prover_duration: Duration = start.elapsed();
 
                 let start = Instant::now();
@@ -370,10 +374,10 @@ mod tests {
                     &r_poly_vec,
                     &u,
                 )
-                .generate_proof(&mut prover_trans);
+                .generate_proof(&mut prover_trans)
+                .unwrap();
                 let ipa_sigma_prover_duration: Duration = start.elapsed();
 
-
                 let start = Instant::now();
                 let inner_sigma_result: Result<(), Error> = InnerSigmaVerifier::new(
                     &g_vec,
@@ -395,7 +399,8 @@ mod tests {
                     &r_poly_vec,
                     &u,
                 )
-                .generate_proof(&mut prover_trans);
+                .generate_proof(&mut prover_trans)
+                .unwrap();
 
                 let start = Instant::now();
                 let inner_sigma_multiexp_result: Result<(), Error> = InnerSigmaVerifier::new(
@@ -408,9 +413,8 @@ mod tests {
                 .verify_proof_multiscalar(&inner_sigma_multiexp_proof, &mut verifier_trans);
                 let ipa_sigma_verifier_multiexp_duration: Duration = start.elapsed();
 
-
-                let proof_check: bool = range_proof_result.is_ok() 
-                    && inner_result.is_ok() 
+                let proof_check: bool = range_proof_result.is_ok()
+                    && inner_result.is_ok()
                     && inner_sigma_result.is_ok()
                     && inner_sigma_multiexp_result.is_ok();
 
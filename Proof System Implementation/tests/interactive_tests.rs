@@ -0,0 +1,308 @@
+#[cfg(test)]
+mod interactive_tests {
+    use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_ff::{Field, One, PrimeField};
+    use std::io::Error;
+    use zeromt::{
+        ElGamal, InnerHaloProof, InnerHaloProver, InnerHaloVerifier, InnerProof, InnerProver,
+        InnerSigmaProof, InnerSigmaProver, InnerSigmaVerifier, InnerVerifier, InteractiveChannel,
+        InteractiveMessage, PolyCoefficients, ProverChannel, RangeProof, RangeProver,
+        RangeVerifier, ReplayTranscript, SigmaABProof, SigmaABProver, SigmaABVerifier, SigmaRProof,
+        SigmaRProver, SigmaRVerifier, SigmaSKProof, SigmaSKProver, SigmaSKVerifier, SigmaYProof,
+        SigmaYProver, SigmaYVerifier, Utils, VerifierChannel,
+    };
+
+    /// Runs the prover in its own thread, with the verifier sampling the challenges in this one.
+    fn run_interactive<P, F>(prove: F) -> (P, Vec<InteractiveMessage>)
+    where
+        P: Send,
+        F: FnOnce(&mut ProverChannel) -> P + Send,
+    {
+        let (mut prover_channel, mut verifier_channel): (ProverChannel, VerifierChannel) =
+            InteractiveChannel::create();
+
+        std::thread::scope(|scope| {
+            let prover = scope.spawn(move || {
+                let proof: P = prove(&mut prover_channel);
+                prover_channel.finish().unwrap();
+                proof
+            });
+
+            let mut rng = ark_std::rand::thread_rng();
+            let messages: Vec<InteractiveMessage> = verifier_channel.run(&mut rng).unwrap();
+
+            (prover.join().unwrap(), messages)
+        })
+    }
+
+    #[test]
+    fn interactive_sigma_sk_r_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(&sk, &g);
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let d: G1Point = ElGamal::elgamal_d(&g, &r);
+
+        let ((sk_proof, r_proof), messages): (
+            (SigmaSKProof, SigmaRProof),
+            Vec<InteractiveMessage>,
+        ) = run_interactive(|channel: &mut ProverChannel| {
+            let mut rng = ark_std::rand::thread_rng();
+            let sk_proof: SigmaSKProof =
+                SigmaSKProver::new(&g, &sk).generate_proof(&mut rng, channel);
+            let r_proof: SigmaRProof = SigmaRProver::new(&g, &r).generate_proof(&mut rng, channel);
+            (sk_proof, r_proof)
+        });
+
+        let result: Result<(), Error> =
+            ReplayTranscript::verify(&messages, |replay: &mut ReplayTranscript| {
+                SigmaSKVerifier::new(&g, &y).verify_proof(&sk_proof, replay)?;
+                SigmaRVerifier::new(&g, &d).verify_proof(&r_proof, replay)
+            });
+
+        assert!(result.is_ok(), "Verifier fails");
+    }
+
+    #[test]
+    fn interactive_sigma_y_ab_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let m: usize = 4;
+        let n: usize = 16;
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let (balance, amounts, balance_remaining) = Utils::get_mock_balances(m, n, &mut rng);
+
+        let sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let recipients_priv_keys: Vec<ScalarField> =
+            Utils::get_n_random_scalars_not_zero(amounts.len(), &mut rng);
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(&sk, &g);
+        let y_bar: Vec<G1Point> = recipients_priv_keys
+            .iter()
+            .map(|key: &ScalarField| ElGamal::elgamal_calculate_pub_key(key, &g))
+            .collect();
+
+        let (c_l, c_r): (G1Point, G1Point) = ElGamal::elgamal_encrypt(balance, &y, &g, &r);
+        let d: G1Point = ElGamal::elgamal_d(&g, &r);
+        let c_vec: Vec<G1Point> = amounts
+            .iter()
+            .map(|a: &usize| ElGamal::elgamal_encrypt(*a, &y, &g, &r).0)
+            .collect();
+        let c_bar_vec: Vec<G1Point> = amounts
+            .iter()
+            .zip(y_bar.iter())
+            .map(|(a, k)| ElGamal::elgamal_encrypt(*a, k, &g, &r).0)
+            .collect();
+
+        let ((ab_proof, y_proof), messages): (
+            (SigmaABProof, SigmaYProof),
+            Vec<InteractiveMessage>,
+        ) = run_interactive(|channel: &mut ProverChannel| {
+            let mut rng = ark_std::rand::thread_rng();
+            let ab_proof: SigmaABProof =
+                SigmaABProver::new(&g, &d, &c_r, balance_remaining, &amounts, &sk)
+                    .generate_proof(&mut rng, channel);
+            let y_proof: SigmaYProof =
+                SigmaYProver::new(&r, &y, &y_bar).generate_proof(&mut rng, channel);
+            (ab_proof, y_proof)
+        });
+
+        let result: Result<(), Error> =
+            ReplayTranscript::verify(&messages, |replay: &mut ReplayTranscript| {
+                SigmaABVerifier::new(&g, &d, &c_r, &c_l, &c_vec).verify_proof(&ab_proof, replay)?;
+                SigmaYVerifier::new(&y, &y_bar, &c_vec, &c_bar_vec).verify_proof(&y_proof, replay)
+            });
+
+        assert!(result.is_ok(), "Verifier fails");
+    }
+
+    #[test]
+    fn interactive_range_inner_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let m: usize = 4;
+        let n: usize = 16;
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+        let h_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+        let (_balance, amounts, balance_remaining) = Utils::get_mock_balances(m, n, &mut rng);
+
+        let ((range_proof, inner_proof, inner_sigma_proof), messages): (
+            (RangeProof, InnerProof, InnerSigmaProof),
+            Vec<InteractiveMessage>,
+        ) = run_interactive(|channel: &mut ProverChannel| {
+            let mut rng = ark_std::rand::thread_rng();
+            let mut range_prover: RangeProver =
+                RangeProver::new(&g, &h, balance_remaining, &amounts, &g_vec, &h_vec, n);
+
+            let (range_proof, l_poly_vec, r_poly_vec, x, y, z, _t_coefficients): (
+                RangeProof,
+                Vec<ScalarField>,
+                Vec<ScalarField>,
+                ScalarField,
+                ScalarField,
+                ScalarField,
+                PolyCoefficients,
+            ) = range_prover.generate_proof(&mut rng, channel);
+
+            let (h_first_vec, phu): (Vec<G1Point>, G1Point) = range_prover.get_ipa_arguments(
+                &x,
+                &y,
+                &z,
+                range_proof.get_mu(),
+                range_proof.get_a(),
+                range_proof.get_s(),
+                &h,
+                &g_vec,
+                &h_vec,
+            );
+
+            let inner_proof: InnerProof = InnerProver::new(
+                &g_vec,
+                &h_first_vec,
+                &phu,
+                range_proof.get_t_hat(),
+                &l_poly_vec,
+                &r_poly_vec,
+                &u,
+            )
+            .generate_proof(channel)
+            .unwrap();
+
+            let inner_sigma_proof: InnerSigmaProof = InnerSigmaProver::new(
+                &g_vec,
+                &h_first_vec,
+                &phu,
+                range_proof.get_t_hat(),
+                &l_poly_vec,
+                &r_poly_vec,
+                &u,
+            )
+            .generate_proof(channel)
+            .unwrap();
+
+            (range_proof, inner_proof, inner_sigma_proof)
+        });
+
+        let result: Result<(), Error> =
+            ReplayTranscript::verify(&messages, |replay: &mut ReplayTranscript| {
+                let mut range_verifier: RangeVerifier = RangeVerifier::new(&g, &h, m, n);
+                let (range_result, x, y, z): (
+                    Result<(), Error>,
+                    ScalarField,
+                    ScalarField,
+                    ScalarField,
+                ) = range_verifier.verify_proof(&range_proof, replay);
+                range_result?;
+
+                let (h_first_vec, phu): (Vec<G1Point>, G1Point) = range_verifier.get_ipa_arguments(
+                    &x,
+                    &y,
+                    &z,
+                    range_proof.get_mu(),
+                    range_proof.get_a(),
+                    range_proof.get_s(),
+                    &h,
+                    &g_vec,
+                    &h_vec,
+                );
+
+                InnerVerifier::new(&g_vec, &h_first_vec, &phu, range_proof.get_t_hat(), &u)
+                    .verify_proof(&inner_proof, replay)?;
+                InnerSigmaVerifier::new(&g_vec, &h_first_vec, &phu, range_proof.get_t_hat(), &u)
+                    .verify_proof(&inner_sigma_proof, replay)
+            });
+
+        assert!(result.is_ok(), "Verifier fails");
+    }
+
+    #[test]
+    fn interactive_inner_halo_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        let t_vec: Vec<ScalarField> = Utils::get_n_random_scalars_not_zero(3, &mut rng);
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(3, &mut rng);
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+
+        let rand_r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let t_comm: G1Point = Utils::inner_product_point_scalar(&g_vec, &t_vec).unwrap()
+            + h.mul(rand_r.into_repr()).into_affine();
+
+        let rand_x: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let b_vec: Vec<ScalarField> = vec![ScalarField::one(), rand_x, rand_x.pow([2])];
+        let t_hat: ScalarField = Utils::inner_product_scalar_scalar(&t_vec, &b_vec).unwrap();
+
+        let (proof, messages): (InnerHaloProof, Vec<InteractiveMessage>) =
+            run_interactive(|channel: &mut ProverChannel| {
+                InnerHaloProver::new(&g_vec, &h, &t_comm, &rand_r, &t_hat, &t_vec, &b_vec, &u)
+                    .generate_proof(channel)
+            });
+
+        let result: Result<(), Error> =
+            ReplayTranscript::verify(&messages, |replay: &mut ReplayTranscript| {
+                InnerHaloVerifier::new(&g_vec, &b_vec, &h, &t_comm, &t_hat, &u)
+                    .verify_proof(&proof, replay)
+            });
+
+        assert!(result.is_ok(), "Verifier fails");
+    }
+
+    #[test]
+    fn interactive_replay_other_proof_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(&sk, &g);
+
+        let (_proof, messages): (SigmaSKProof, Vec<InteractiveMessage>) =
+            run_interactive(|channel: &mut ProverChannel| {
+                SigmaSKProver::new(&g, &sk)
+                    .generate_proof(&mut ark_std::rand::thread_rng(), channel)
+            });
+
+        let (other_proof, _other_messages): (SigmaSKProof, Vec<InteractiveMessage>) =
+            run_interactive(|channel: &mut ProverChannel| {
+                SigmaSKProver::new(&g, &sk)
+                    .generate_proof(&mut ark_std::rand::thread_rng(), channel)
+            });
+
+        // A proof from another execution does not match the recorded messages and challenges
+        let result: Result<(), Error> =
+            ReplayTranscript::verify(&messages, |replay: &mut ReplayTranscript| {
+                SigmaSKVerifier::new(&g, &y).verify_proof(&other_proof, replay)
+            });
+
+        assert!(
+            result.is_err(),
+            "Replay must reject a proof from another execution"
+        );
+    }
+
+    #[test]
+    fn interactive_verifier_hang_up_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+
+        // The verifier hangs up before answering: the prover gets no challenge, without panicking
+        let (mut prover_channel, verifier_channel): (ProverChannel, VerifierChannel) =
+            InteractiveChannel::create();
+        drop(verifier_channel);
+
+        let _proof: SigmaSKProof =
+            SigmaSKProver::new(&g, &sk).generate_proof(&mut rng, &mut prover_channel);
+
+        assert!(
+            prover_channel.finish().is_err(),
+            "Prover channel must report the closed channel"
+        );
+    }
+}
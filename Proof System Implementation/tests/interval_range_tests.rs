@@ -0,0 +1,309 @@
+#[cfg(test)]
+mod interval_range_tests {
+
+    use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_ff::PrimeField;
+    use merlin::Transcript;
+    use serial_test::serial;
+    use std::io::Error;
+    use zeromt::{
+        ElGamal, InnerProof, InnerProver, InnerVerifier, IntervalCommitmentProof,
+        IntervalRangeProver, IntervalRangeVerifier, LinkedRangeProof, RangeBounds, RangeProof,
+        Utils, ZeroMTParams, ZeroMTProof, ZeroMTProver, ZeroMTStatement, ZeroMTVerifier,
+        ZeroMTWitness,
+    };
+
+    fn interval_range_run(values: &Vec<usize>, bounds: &RangeBounds, n: usize) -> bool {
+        interval_range_commitments_run(values, values, bounds, n)
+    }
+
+    /// The verifier gets the commitments to `committed_values`, the prover proves `values`.
+    fn interval_range_commitments_run(
+        committed_values: &Vec<usize>,
+        values: &Vec<usize>,
+        bounds: &RangeBounds,
+        n: usize,
+    ) -> bool {
+        let mut rng = ark_std::rand::thread_rng();
+        let m: usize = values.len();
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(2 * m * n, &mut rng);
+        let h_vec: Vec<G1Point> = Utils::get_n_generators(2 * m * n, &mut rng);
+
+        let gamma_vec: Vec<ScalarField> = Utils::get_n_random_scalars(m, &mut rng);
+        let v_vec: Vec<G1Point> = committed_values
+            .iter()
+            .zip(gamma_vec.iter())
+            .map(|(v, gamma): (&usize, &ScalarField)| {
+                g.mul(ScalarField::from(*v as u64).into_repr())
+                    .into_affine()
+                    + h.mul(gamma.into_repr()).into_affine()
+            })
+            .collect();
+
+        let mut prover_trans: Transcript = Transcript::new(b"IntervalRangeTest");
+        let mut verifier_trans: Transcript = Transcript::new(b"IntervalRangeTest");
+
+        let mut range_prover: IntervalRangeProver = IntervalRangeProver::new(
+            &g, &h, values, &gamma_vec, &v_vec, bounds, &g_vec, &h_vec, n,
+        );
+        let linked_proof: LinkedRangeProof<IntervalCommitmentProof> = range_prover
+            .generate_proof(&mut rng, &mut prover_trans)
+            .unwrap();
+        let range_proof: &RangeProof = linked_proof.get_range_proof();
+
+        let (h_first_vec_prover, phu_prover): (Vec<G1Point>, G1Point) = range_prover
+            .get_ipa_arguments(
+                linked_proof.get_x(),
+                linked_proof.get_y(),
+                linked_proof.get_z(),
+                range_proof.get_mu(),
+                range_proof.get_a(),
+                range_proof.get_s(),
+                &h,
+                &g_vec,
+                &h_vec,
+            );
+
+        let inner_proof: InnerProof = InnerProver::new(
+            &g_vec,
+            &h_first_vec_prover,
+            &phu_prover,
+            range_proof.get_t_hat(),
+            linked_proof.get_l_poly_vec(),
+            linked_proof.get_r_poly_vec(),
+            &u,
+        )
+        .generate_proof(&mut prover_trans)
+        .unwrap();
+
+        let mut range_verifier: IntervalRangeVerifier =
+            IntervalRangeVerifier::new(&g, &h, &v_vec, bounds, n);
+        let (range_result, x_verifier, y_verifier, z_verifier): (
+            Result<(), Error>,
+            ScalarField,
+            ScalarField,
+            ScalarField,
+        ) = range_verifier.verify_proof(
+            range_proof,
+            linked_proof.get_link_proof(),
+            &mut verifier_trans,
+        );
+
+        let (h_first_vec_verifier, phu_verifier): (Vec<G1Point>, G1Point) = range_verifier
+            .get_ipa_arguments(
+                &x_verifier,
+                &y_verifier,
+                &z_verifier,
+                range_proof.get_mu(),
+                range_proof.get_a(),
+                range_proof.get_s(),
+                &h,
+                &g_vec,
+                &h_vec,
+            );
+
+        let inner_result: Result<(), Error> = InnerVerifier::new(
+            &g_vec,
+            &h_first_vec_verifier,
+            &phu_verifier,
+            range_proof.get_t_hat(),
+            &u,
+        )
+        .verify_proof_multiscalar(&inner_proof, &mut verifier_trans);
+
+        range_result.is_ok() && inner_result.is_ok()
+    }
+
+    #[test]
+    fn interval_range_proof_test() {
+        let n: usize = 16;
+
+        // Balance floor of 100, transfers between 1 and 10 000 units
+        let bounds: RangeBounds =
+            RangeBounds::new((100, 60_000), vec![(1, 10_000), (1, 10_000), (1, 10_000)]);
+        assert!(interval_range_run(&vec![100, 1, 10_000, 4_321], &bounds, n));
+        assert!(interval_range_run(
+            &vec![60_000, 5_000, 2, 9_999],
+            &bounds,
+            n
+        ));
+
+        // Bounds far from zero
+        let bounds: RangeBounds = RangeBounds::new((1_000_000, 1_050_000), vec![(70_000, 80_000)]);
+        assert!(interval_range_run(&vec![1_020_304, 75_000], &bounds, n));
+    }
+
+    #[test]
+    fn interval_range_out_of_range_commitment_test() {
+        let n: usize = 16;
+        let bounds: RangeBounds = RangeBounds::new((100, 200), vec![(1, 10_000)]);
+
+        // The committed balance 5 is below the floor: shifting the in-range 150 instead gives
+        // valid range proofs over 50 and 50, which must not verify against V = 5 * g + gamma * h
+        assert!(!interval_range_commitments_run(
+            &vec![5, 700],
+            &vec![150, 700],
+            &bounds,
+            n
+        ));
+        assert!(!interval_range_commitments_run(
+            &vec![150, 20_000],
+            &vec![150, 700],
+            &bounds,
+            n
+        ));
+        assert!(bounds.shift_values(&vec![5, 700], n).is_err());
+    }
+
+    #[test]
+    fn interval_range_bounds_error_test() {
+        let n: usize = 16;
+        let bounds: RangeBounds = RangeBounds::per_amount(2, 1, 10_000, 60_000);
+
+        // Amount above the cap, amount below the minimum
+        assert!(bounds.shift_values(&vec![500, 10_001, 5], n).is_err());
+        assert!(bounds.shift_values(&vec![500, 0, 5], n).is_err());
+        // One bound per value
+        assert!(bounds.shift_values(&vec![500, 5], n).is_err());
+        // Intervals wider than 2^n - 1
+        let wide_bounds: RangeBounds = RangeBounds::new((0, 1 << n), vec![]);
+        assert!(wide_bounds.shift_values(&vec![5], n).is_err());
+
+        assert_eq!(
+            bounds.shift_values(&vec![500, 1, 10_000], n).unwrap(),
+            vec![500, 0, 9_999, 59_500, 9_999, 0]
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn zeromt_bounds_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let n: usize = 16;
+        let m: usize = 4;
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+
+        // Twice the generators of an unbounded transfer
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(2 * m * n, &mut rng);
+        let h_vec: Vec<G1Point> = Utils::get_n_generators(2 * m * n, &mut rng);
+
+        let amounts: Vec<usize> = vec![2_500, 10_000, 1];
+        let remaining_balance: usize = 40_000;
+        let balance: usize = remaining_balance + amounts.iter().sum::<usize>();
+        let bounds: RangeBounds = RangeBounds::per_amount(m - 1, 1, 10_000, 65_535);
+
+        let sender_priv_key: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let sender_pub_key: G1Point = ElGamal::elgamal_calculate_pub_key(&sender_priv_key, &g);
+        let recipients_pub_keys: Vec<G1Point> =
+            Utils::get_n_random_scalars_not_zero(m - 1, &mut rng)
+                .iter()
+                .map(|key: &ScalarField| ElGamal::elgamal_calculate_pub_key(key, &g))
+                .collect();
+
+        let (c_l, c_r): (G1Point, G1Point) =
+            ElGamal::elgamal_encrypt(balance, &sender_pub_key, &g, &r);
+        let d: G1Point = ElGamal::elgamal_d(&g, &r);
+        let c_vec: Vec<G1Point> = amounts
+            .iter()
+            .map(|a: &usize| ElGamal::elgamal_encrypt(*a, &sender_pub_key, &g, &r).0)
+            .collect();
+        let c_bar_vec: Vec<G1Point> = amounts
+            .iter()
+            .zip(recipients_pub_keys.iter())
+            .map(|(a, k)| ElGamal::elgamal_encrypt(*a, k, &g, &r).0)
+            .collect();
+
+        let mut prover_trans: Transcript = Transcript::new(b"ZeroMTTest");
+        let proof: ZeroMTProof = ZeroMTProver::<InnerProver>::new(
+            &ZeroMTParams::new(&g, &h, &g_vec, &h_vec, &u, n),
+            &ZeroMTStatement::new(
+                &d,
+                &c_r,
+                &c_l,
+                &c_vec,
+                &c_bar_vec,
+                &sender_pub_key,
+                &recipients_pub_keys,
+            ),
+            &ZeroMTWitness::new(remaining_balance, &amounts, &sender_priv_key, &r),
+        )
+        .with_bounds(&bounds)
+        .generate_proof(&mut rng, &mut prover_trans)
+        .unwrap();
+
+        let verifier = || {
+            ZeroMTVerifier::<InnerProver>::new(
+                &ZeroMTParams::new(&g, &h, &g_vec, &h_vec, &u, n),
+                &ZeroMTStatement::new(
+                    &d,
+                    &c_r,
+                    &c_l,
+                    &c_vec,
+                    &c_bar_vec,
+                    &sender_pub_key,
+                    &recipients_pub_keys,
+                ),
+            )
+        };
+
+        let mut verifier_trans: Transcript = Transcript::new(b"ZeroMTTest");
+        let verification_result: Result<(), Error> = verifier()
+            .with_bounds(&bounds)
+            .verify_proof(&proof, &mut verifier_trans);
+        assert!(verification_result.is_ok(), "Verifier fails");
+
+        // The bounds are part of the statement
+        let other_bounds_vec: Vec<RangeBounds> = vec![
+            RangeBounds::per_amount(m - 1, 1, 20_000, 65_535),
+            RangeBounds::per_amount(m - 1, 1, 10_000, 60_000),
+            RangeBounds::new((1, 65_535), vec![(1, 10_000); m - 1]),
+        ];
+        for other_bounds in other_bounds_vec.iter() {
+            let mut verifier_trans: Transcript = Transcript::new(b"ZeroMTTest");
+            let verification_result: Result<(), Error> = verifier()
+                .with_bounds(other_bounds)
+                .verify_proof(&proof, &mut verifier_trans);
+            assert!(
+                verification_result.is_err(),
+                "Verifier accepts other bounds"
+            );
+        }
+
+        let mut verifier_trans: Transcript = Transcript::new(b"ZeroMTTest");
+        let verification_result: Result<(), Error> =
+            verifier().verify_proof(&proof, &mut verifier_trans);
+        assert!(
+            verification_result.is_err(),
+            "Verifier accepts without bounds"
+        );
+
+        // An amount above the cap cannot be proved
+        let capped_bounds: RangeBounds = RangeBounds::per_amount(m - 1, 1, 9_999, 65_535);
+        let mut prover_trans: Transcript = Transcript::new(b"ZeroMTTest");
+        let proof_result: Result<ZeroMTProof, Error> = ZeroMTProver::<InnerProver>::new(
+            &ZeroMTParams::new(&g, &h, &g_vec, &h_vec, &u, n),
+            &ZeroMTStatement::new(
+                &d,
+                &c_r,
+                &c_l,
+                &c_vec,
+                &c_bar_vec,
+                &sender_pub_key,
+                &recipients_pub_keys,
+            ),
+            &ZeroMTWitness::new(remaining_balance, &amounts, &sender_priv_key, &r),
+        )
+        .with_bounds(&capped_bounds)
+        .generate_proof(&mut rng, &mut prover_trans);
+        assert!(proof_result.is_err());
+    }
+}
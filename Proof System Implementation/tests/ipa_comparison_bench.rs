@@ -112,7 +112,8 @@ mod zeromt_proof_tests {
                     &r_poly_vec,
                     &u,
                 )
-                .generate_proof(&mut prover_trans);
+                .generate_proof(&mut prover_trans)
+                .unwrap();
                 let old_ipa_prover_duration: Duration = start.elapsed();
 
                 let start = Instant::now();
@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod key_rotation_tests {
+    use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use merlin::Transcript;
+    use std::io::Error;
+    use zeromt::{
+        AssociatedData, ElGamal, KeyRotationProof, KeyRotationProver, KeyRotationVerifier, Utils,
+    };
+
+    #[test]
+    fn key_rotation_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let balance: usize = 123_456;
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(&sk, &g);
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let (c_l, c_r): (G1Point, G1Point) = ElGamal::elgamal_encrypt(balance, &y, &g, &r);
+
+        let sk_new: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let y_new: G1Point = ElGamal::elgamal_calculate_pub_key(&sk_new, &g);
+        let r_new: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let (c_l_new, c_r_new): (G1Point, G1Point) =
+            ElGamal::elgamal_encrypt(balance, &y_new, &g, &r_new);
+
+        let mut prover_trans: Transcript = Transcript::new(b"KeyRotationTest");
+        let mut prover: KeyRotationProver =
+            KeyRotationProver::new(&g, &c_r, balance, &sk, &sk_new, &r_new);
+        assert_eq!(prover.get_new_balance(), (c_l_new, c_r_new));
+        let proof: KeyRotationProof = prover.generate_proof(&mut rng, &mut prover_trans);
+
+        let mut compressed_bytes: Vec<u8> = Vec::new();
+        proof.serialize(&mut compressed_bytes).unwrap();
+        let proof: KeyRotationProof =
+            KeyRotationProof::deserialize(compressed_bytes.as_slice()).unwrap();
+
+        let verify = |y_new: &G1Point, c_l_new: &G1Point, c_r_new: &G1Point| -> Result<(), Error> {
+            let mut verifier_trans: Transcript = Transcript::new(b"KeyRotationTest");
+            KeyRotationVerifier::new(&g, &y, &c_l, &c_r, y_new, c_l_new, c_r_new)
+                .verify_proof(&proof, &mut verifier_trans)
+        };
+
+        assert!(verify(&y_new, &c_l_new, &c_r_new).is_ok(), "Verifier fails");
+
+        // Another balance, key or randomness under the new key
+        let (c_l_other, _c_r): (G1Point, G1Point) =
+            ElGamal::elgamal_encrypt(balance + 1, &y_new, &g, &r_new);
+        assert!(verify(&y_new, &c_l_other, &c_r_new).is_err());
+        assert!(verify(&y, &c_l_new, &c_r_new).is_err());
+        assert!(verify(&y_new, &c_l_new, &c_r).is_err());
+    }
+
+    #[test]
+    fn key_rotation_wrong_balance_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let balance: usize = 5000;
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(&sk, &g);
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let (c_l, c_r): (G1Point, G1Point) = ElGamal::elgamal_encrypt(balance, &y, &g, &r);
+
+        let sk_new: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let y_new: G1Point = ElGamal::elgamal_calculate_pub_key(&sk_new, &g);
+        let r_new: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let associated_data: AssociatedData = AssociatedData::new().with_chain_id(1);
+
+        // The prover claims a larger balance than the one encrypted under y
+        let mut prover: KeyRotationProver =
+            KeyRotationProver::new(&g, &c_r, balance + 1000, &sk, &sk_new, &r_new)
+                .with_associated_data(&associated_data);
+        let (c_l_new, c_r_new): (G1Point, G1Point) = prover.get_new_balance();
+        let mut prover_trans: Transcript = Transcript::new(b"KeyRotationTest");
+        let proof: KeyRotationProof = prover.generate_proof(&mut rng, &mut prover_trans);
+
+        let mut verifier_trans: Transcript = Transcript::new(b"KeyRotationTest");
+        let result: Result<(), Error> =
+            KeyRotationVerifier::new(&g, &y, &c_l, &c_r, &y_new, &c_l_new, &c_r_new)
+                .with_associated_data(&associated_data)
+                .verify_proof(&proof, &mut verifier_trans);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,450 @@
+#[cfg(test)]
+mod ledger_tests {
+    use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+    use serial_test::serial;
+    use zeromt::{
+        AssociatedData, Auditor, BurnProof, BurnProver, ElGamal, InnerProof, InnerProver,
+        KeyRotationProof, KeyRotationProver, Ledger, RegistrationProver, SigmaSKProof, Utils,
+        ZeroMTParams, ZeroMTProof, ZeroMTProver, ZeroMTStatement, ZeroMTWitness,
+    };
+
+    struct Transfer {
+        d: G1Point,
+        c_vec: Vec<G1Point>,
+        c_bar_vec: Vec<G1Point>,
+        proof: ZeroMTProof<InnerProof>,
+    }
+
+    fn register(ledger: &mut Ledger, g: &G1Point, sk: &ScalarField, address: &[u8]) {
+        let mut rng = ark_std::rand::thread_rng();
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(sk, g);
+        let proof: SigmaSKProof =
+            RegistrationProver::new(g, sk, address, &ledger.get_associated_data())
+                .generate_proof(&mut rng);
+        ledger.register(&y, address, &proof).unwrap();
+    }
+
+    fn get_balance(ledger: &Ledger, g: &G1Point, sk: &ScalarField, n: usize) -> usize {
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(sk, g);
+        let (c_l, c_r): (G1Point, G1Point) = *ledger.get_balance(&y).unwrap();
+        Auditor::new(g, sk, n, n / 2)
+            .unwrap()
+            .decrypt_amounts(&c_r, &vec![c_l])
+            .unwrap()[0]
+    }
+
+    fn prove_transfer(
+        ledger: &Ledger,
+        params: &ZeroMTParams,
+        sk: &ScalarField,
+        balance: usize,
+        amounts: &Vec<usize>,
+        y_bar: &Vec<G1Point>,
+        fee: usize,
+    ) -> Transfer {
+        let mut rng = ark_std::rand::thread_rng();
+        let g: &G1Point = params.get_g();
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(sk, g);
+        let (c_l, c_r): (G1Point, G1Point) = *ledger.get_balance(&y).unwrap();
+
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let d: G1Point = ElGamal::elgamal_d(g, &r);
+        let c_vec: Vec<G1Point> = amounts
+            .iter()
+            .map(|a: &usize| ElGamal::elgamal_encrypt(*a, &y, g, &r).0)
+            .collect();
+        let c_bar_vec: Vec<G1Point> = amounts
+            .iter()
+            .zip(y_bar.iter())
+            .map(|(a, k)| ElGamal::elgamal_encrypt(*a, k, g, &r).0)
+            .collect();
+        let remaining_balance: usize = balance - amounts.iter().sum::<usize>() - fee;
+
+        let proof: ZeroMTProof<InnerProof> = ZeroMTProver::<InnerProver>::new(
+            params,
+            &ZeroMTStatement::new(&d, &c_r, &c_l, &c_vec, &c_bar_vec, &y, y_bar),
+            &ZeroMTWitness::new(remaining_balance, amounts, sk, &r),
+        )
+        .with_fee(fee)
+        .with_associated_data(&ledger.get_associated_data())
+        .generate_proof(&mut rng, &mut Ledger::new_transcript())
+        .unwrap();
+
+        Transfer {
+            d,
+            c_vec,
+            c_bar_vec,
+            proof,
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn ledger_transfer_fee_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let m: usize = 4;
+        let n: usize = 16;
+        let fee: usize = 7;
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+        let h_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+        let params: ZeroMTParams = ZeroMTParams::new(&g, &h, &g_vec, &h_vec, &u, n);
+
+        let mut ledger: Ledger = Ledger::new(&g, &h, &g_vec, &h_vec, &u, n).with_fee(fee);
+
+        let sks: Vec<ScalarField> = Utils::get_n_random_scalars_not_zero(m, &mut rng);
+        let keys: Vec<G1Point> = sks
+            .iter()
+            .map(|sk: &ScalarField| ElGamal::elgamal_calculate_pub_key(sk, &g))
+            .collect();
+        for (i, sk) in sks.iter().enumerate() {
+            register(&mut ledger, &g, sk, &[i as u8; 20]);
+        }
+        let proof: SigmaSKProof = RegistrationProver::new(
+            &g,
+            &sks[0],
+            &[0; 20],
+            &AssociatedData::new().with_chain_id(0),
+        )
+        .generate_proof(&mut rng);
+        assert!(ledger.register(&keys[0], &[0; 20], &proof).is_err());
+        ledger.deposit(&keys[0], 1000).unwrap();
+
+        let y_bar: Vec<G1Point> = keys[1..].to_vec();
+        let amounts: Vec<usize> = vec![100, 200, 300];
+        let transfer: Transfer =
+            prove_transfer(&ledger, &params, &sks[0], 1000, &amounts, &y_bar, fee);
+
+        // A transfer proven without the fee does not pass
+        let unpaid: Transfer = prove_transfer(&ledger, &params, &sks[0], 1000, &amounts, &y_bar, 0);
+        assert!(ledger
+            .transfer(
+                &keys[0],
+                &y_bar,
+                &unpaid.d,
+                &unpaid.c_vec,
+                &unpaid.c_bar_vec,
+                &unpaid.proof
+            )
+            .is_err());
+
+        ledger
+            .transfer(
+                &keys[0],
+                &y_bar,
+                &transfer.d,
+                &transfer.c_vec,
+                &transfer.c_bar_vec,
+                &transfer.proof,
+            )
+            .unwrap();
+        assert_eq!(ledger.get_collected_fees(), fee);
+        assert_eq!(get_balance(&ledger, &g, &sks[0], n), 1000 - 600 - fee);
+        for (sk, a) in sks[1..].iter().zip(amounts.iter()) {
+            assert_eq!(get_balance(&ledger, &g, sk, n), *a);
+        }
+
+        // The same proof does not apply to the updated balance
+        assert!(ledger
+            .transfer(
+                &keys[0],
+                &y_bar,
+                &transfer.d,
+                &transfer.c_vec,
+                &transfer.c_bar_vec,
+                &transfer.proof,
+            )
+            .is_err());
+        assert_eq!(get_balance(&ledger, &g, &sks[0], n), 1000 - 600 - fee);
+
+        // A recipient spends the received amount
+        let y_bar: Vec<G1Point> = vec![keys[0], keys[1], keys[3]];
+        let amounts: Vec<usize> = vec![150, 0, 40];
+        let transfer: Transfer =
+            prove_transfer(&ledger, &params, &sks[2], 200, &amounts, &y_bar, fee);
+        ledger
+            .transfer(
+                &keys[2],
+                &y_bar,
+                &transfer.d,
+                &transfer.c_vec,
+                &transfer.c_bar_vec,
+                &transfer.proof,
+            )
+            .unwrap();
+        assert_eq!(ledger.get_collected_fees(), 2 * fee);
+        assert_eq!(get_balance(&ledger, &g, &sks[0], n), 1000 - 600 - fee + 150);
+        assert_eq!(get_balance(&ledger, &g, &sks[1], n), 100);
+        assert_eq!(get_balance(&ledger, &g, &sks[2], n), 200 - 190 - fee);
+        assert_eq!(get_balance(&ledger, &g, &sks[3], n), 300 + 40);
+
+        // The sender cannot be a recipient, and recipients must be registered
+        let to_self: Vec<G1Point> = vec![keys[0], keys[2], keys[3]];
+        let unknown: Vec<G1Point> = Utils::get_n_generators(3, &mut rng);
+        assert!(ledger
+            .transfer(
+                &keys[2],
+                &to_self,
+                &transfer.d,
+                &transfer.c_vec,
+                &transfer.c_bar_vec,
+                &transfer.proof
+            )
+            .is_err());
+        assert!(ledger
+            .transfer(
+                &keys[0],
+                &unknown,
+                &transfer.d,
+                &transfer.c_vec,
+                &transfer.c_bar_vec,
+                &transfer.proof
+            )
+            .is_err());
+
+        // Recipients must be distinct, even with a valid proof
+        let duplicated: Vec<G1Point> = vec![keys[0], keys[0], keys[3]];
+        let amounts: Vec<usize> = vec![1, 1, 1];
+        let transfer: Transfer =
+            prove_transfer(&ledger, &params, &sks[1], 100, &amounts, &duplicated, fee);
+        assert!(ledger
+            .transfer(
+                &keys[1],
+                &duplicated,
+                &transfer.d,
+                &transfer.c_vec,
+                &transfer.c_bar_vec,
+                &transfer.proof
+            )
+            .is_err());
+        assert_eq!(get_balance(&ledger, &g, &sks[1], n), 100);
+    }
+
+    #[test]
+    #[serial]
+    fn ledger_padded_transfer_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let m: usize = 4;
+        let n: usize = 16;
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+        let h_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+        let params: ZeroMTParams = ZeroMTParams::new(&g, &h, &g_vec, &h_vec, &u, n);
+
+        let mut ledger: Ledger = Ledger::new(&g, &h, &g_vec, &h_vec, &u, n);
+
+        let sks: Vec<ScalarField> = Utils::get_n_random_scalars_not_zero(5, &mut rng);
+        let keys: Vec<G1Point> = sks
+            .iter()
+            .map(|sk: &ScalarField| ElGamal::elgamal_calculate_pub_key(sk, &g))
+            .collect();
+        for (i, sk) in sks.iter().enumerate() {
+            register(&mut ledger, &g, sk, &[i as u8; 20]);
+        }
+        ledger.deposit(&keys[0], 1000).unwrap();
+        ledger.deposit(&keys[3], 50).unwrap();
+
+        // Two recipients, padded with one of the other registered accounts so that m = 4
+        let y_bar: Vec<G1Point> = keys[1..3].to_vec();
+        let amounts: Vec<usize> = vec![100, 200];
+        let (padded_amounts, padded_y_bar): (Vec<usize>, Vec<G1Point>) = ledger
+            .pad_recipients(&keys[0], &amounts, &y_bar, &mut rng)
+            .unwrap();
+        assert_eq!(padded_amounts.len(), 3);
+        assert!(padded_y_bar.contains(&keys[3]) || padded_y_bar.contains(&keys[4]));
+
+        // The padded transfer is a standard one for the ledger
+        let transfer: Transfer = prove_transfer(
+            &ledger,
+            &params,
+            &sks[0],
+            1000,
+            &padded_amounts,
+            &padded_y_bar,
+            0,
+        );
+        ledger
+            .transfer(
+                &keys[0],
+                &padded_y_bar,
+                &transfer.d,
+                &transfer.c_vec,
+                &transfer.c_bar_vec,
+                &transfer.proof,
+            )
+            .unwrap();
+        assert_eq!(get_balance(&ledger, &g, &sks[0], n), 1000 - 300);
+        assert_eq!(get_balance(&ledger, &g, &sks[1], n), 100);
+        assert_eq!(get_balance(&ledger, &g, &sks[2], n), 200);
+        assert_eq!(get_balance(&ledger, &g, &sks[3], n), 50);
+        assert_eq!(get_balance(&ledger, &g, &sks[4], n), 0);
+
+        // Four recipients need three dummy recipients, and no other account is registered
+        let amounts: Vec<usize> = vec![1, 2, 3, 4];
+        assert!(ledger
+            .pad_recipients(&keys[0], &amounts, &keys[1..].to_vec(), &mut rng)
+            .is_err());
+    }
+
+    #[test]
+    fn ledger_burn_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let m: usize = 2;
+        let n: usize = 16;
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+        let h_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+        let mut ledger: Ledger = Ledger::new(&g, &h, &g_vec, &h_vec, &u, n);
+
+        let sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(&sk, &g);
+        register(&mut ledger, &g, &sk, b"burner");
+        ledger.deposit(&y, 5000).unwrap();
+
+        // Burn proofs use the first n generators
+        let burn_g_vec: Vec<G1Point> = g_vec[..n].to_vec();
+        let burn_h_vec: Vec<G1Point> = h_vec[..n].to_vec();
+        let prove_burn = |ledger: &Ledger, balance: usize, amount: usize| -> BurnProof {
+            let mut rng = ark_std::rand::thread_rng();
+            let (_c_l, c_r): (G1Point, G1Point) = *ledger.get_balance(&y).unwrap();
+            BurnProver::new(
+                &g,
+                &h,
+                &burn_g_vec,
+                &burn_h_vec,
+                &u,
+                n,
+                &c_r,
+                balance,
+                amount,
+                &sk,
+            )
+            .with_associated_data(&ledger.get_associated_data())
+            .generate_proof(&mut rng, &mut Ledger::new_transcript())
+            .unwrap()
+        };
+
+        let proof: BurnProof = prove_burn(&ledger, 5000, 1200);
+        assert!(ledger.burn(&y, 1300, &proof).is_err());
+        ledger.burn(&y, 1200, &proof).unwrap();
+        assert_eq!(get_balance(&ledger, &g, &sk, n), 3800);
+
+        // Replaying the proof on the updated balance fails
+        assert!(ledger.burn(&y, 1200, &proof).is_err());
+        assert_eq!(get_balance(&ledger, &g, &sk, n), 3800);
+
+        let proof: BurnProof = prove_burn(&ledger, 3800, 3800);
+        ledger.burn(&y, 3800, &proof).unwrap();
+        assert_eq!(get_balance(&ledger, &g, &sk, n), 0);
+    }
+
+    #[test]
+    fn ledger_registration_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let n: usize = 16;
+        let chain_id: u64 = 11155111;
+        let address: [u8; 20] = [0xab; 20];
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(n, &mut rng);
+        let h_vec: Vec<G1Point> = Utils::get_n_generators(n, &mut rng);
+        let mut ledger: Ledger = Ledger::new(&g, &h, &g_vec, &h_vec, &u, n).with_chain_id(chain_id);
+
+        let sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(&sk, &g);
+        let proof: SigmaSKProof =
+            RegistrationProver::new(&g, &sk, &address, &ledger.get_associated_data())
+                .generate_proof(&mut rng);
+
+        // The proof is bound to the address, the chain and the key
+        assert!(ledger.register(&y, &[0xcd; 20], &proof).is_err());
+        let other_chain_proof: SigmaSKProof = RegistrationProver::new(
+            &g,
+            &sk,
+            &address,
+            &AssociatedData::new().with_chain_id(chain_id + 1),
+        )
+        .generate_proof(&mut rng);
+        assert!(ledger.register(&y, &address, &other_chain_proof).is_err());
+        let rogue_key: G1Point = y + Utils::get_n_generators(1, &mut rng)[0];
+        assert!(ledger.register(&rogue_key, &address, &proof).is_err());
+        assert!(ledger.get_balance(&y).is_none());
+
+        ledger.register(&y, &address, &proof).unwrap();
+        assert!(ledger.get_balance(&y).is_some());
+        assert!(ledger.get_balance(&rogue_key).is_none());
+    }
+
+    #[test]
+    fn ledger_rotation_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let m: usize = 2;
+        let n: usize = 16;
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+        let h_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+        let mut ledger: Ledger = Ledger::new(&g, &h, &g_vec, &h_vec, &u, n).with_chain_id(10);
+
+        let sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(&sk, &g);
+        let other_sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let other_y: G1Point = ElGamal::elgamal_calculate_pub_key(&other_sk, &g);
+        register(&mut ledger, &g, &sk, b"owner");
+        register(&mut ledger, &g, &other_sk, b"other");
+        ledger.deposit(&y, 3000).unwrap();
+
+        let sk_new: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let y_new: G1Point = ElGamal::elgamal_calculate_pub_key(&sk_new, &g);
+        let r_new: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+
+        let prove_rotation =
+            |ledger: &Ledger, sk_new: &ScalarField| -> (G1Point, G1Point, KeyRotationProof) {
+                let mut rng = ark_std::rand::thread_rng();
+                let (_c_l, c_r): (G1Point, G1Point) = *ledger.get_balance(&y).unwrap();
+                let prover: KeyRotationProver =
+                    KeyRotationProver::new(&g, &c_r, 3000, &sk, sk_new, &r_new);
+                let (c_l_new, c_r_new): (G1Point, G1Point) = prover.get_new_balance();
+                let proof: KeyRotationProof = prover
+                    .with_associated_data(&ledger.get_associated_data())
+                    .generate_proof(&mut rng, &mut Ledger::new_transcript());
+                (c_l_new, c_r_new, proof)
+            };
+
+        // The new key cannot be an existing account
+        let (c_l_new, c_r_new, proof) = prove_rotation(&ledger, &other_sk);
+        assert!(ledger
+            .rotate(&y, &other_y, &c_l_new, &c_r_new, &proof)
+            .is_err());
+
+        let (c_l_new, c_r_new, proof) = prove_rotation(&ledger, &sk_new);
+        assert!(ledger
+            .rotate(&other_y, &y_new, &c_l_new, &c_r_new, &proof)
+            .is_err());
+        ledger
+            .rotate(&y, &y_new, &c_l_new, &c_r_new, &proof)
+            .unwrap();
+        assert!(ledger.get_balance(&y).is_none());
+        assert_eq!(get_balance(&ledger, &g, &sk_new, n), 3000);
+
+        // The old key is closed, so the proof cannot be replayed
+        assert!(ledger
+            .rotate(&y, &y_new, &c_l_new, &c_r_new, &proof)
+            .is_err());
+        ledger.deposit(&y_new, 500).unwrap();
+        assert_eq!(get_balance(&ledger, &g, &sk_new, n), 3500);
+    }
+}
@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod one_out_of_many_tests {
+    use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_ff::PrimeField;
+    use merlin::Transcript;
+    use std::io::Error;
+    use zeromt::{OneOutOfManyProof, OneOutOfManyProver, OneOutOfManyVerifier, Utils};
+
+    #[test]
+    fn verify_one_out_of_many_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        let ring_increases: usize = 4;
+        let mut ring_size: usize = 4;
+        for _ in 0..=ring_increases {
+            let mut prover_trans: Transcript = Transcript::new(b"OneOutOfManyTest");
+            let mut verifier_trans: Transcript = Transcript::new(b"OneOutOfManyTest");
+
+            let g_vec: Vec<G1Point> = Utils::get_n_random_points(ring_size, &mut rng);
+            let h: G1Point = Utils::get_n_random_points(1, &mut rng)[0];
+            let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+            let index: usize = ring_size / 2 + 1;
+
+            let mut commitments: Vec<G1Point> = Utils::get_n_random_points(ring_size, &mut rng);
+            commitments[index] = h.mul(r.into_repr()).into_affine();
+
+            let proof: OneOutOfManyProof =
+                OneOutOfManyProver::new(&g_vec, &h, &commitments, index, &r)
+                    .generate_proof(&mut rng, &mut prover_trans)
+                    .unwrap();
+
+            let result: Result<(), Error> = OneOutOfManyVerifier::new(&g_vec, &h, &commitments)
+                .verify_proof(&proof, &mut verifier_trans);
+
+            assert!(result.is_ok(), "Verifier fails");
+
+            ring_size *= 2;
+        }
+    }
+
+    #[test]
+    fn verify_one_out_of_many_wrong_opening_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        let mut prover_trans: Transcript = Transcript::new(b"OneOutOfManyTest");
+        let mut verifier_trans: Transcript = Transcript::new(b"OneOutOfManyTest");
+
+        let ring_size: usize = 16;
+        let g_vec: Vec<G1Point> = Utils::get_n_random_points(ring_size, &mut rng);
+        let h: G1Point = Utils::get_n_random_points(1, &mut rng)[0];
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+
+        let mut commitments: Vec<G1Point> = Utils::get_n_random_points(ring_size, &mut rng);
+        commitments[3] = h.mul(r.into_repr()).into_affine();
+
+        // The prover claims an index whose commitment it cannot open
+        let proof: OneOutOfManyProof = OneOutOfManyProver::new(&g_vec, &h, &commitments, 5, &r)
+            .generate_proof(&mut rng, &mut prover_trans)
+            .unwrap();
+
+        let result: Result<(), Error> = OneOutOfManyVerifier::new(&g_vec, &h, &commitments)
+            .verify_proof(&proof, &mut verifier_trans);
+
+        assert!(result.is_err(), "Verifier must fail");
+    }
+
+    #[test]
+    fn one_out_of_many_short_generators_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        let mut prover_trans: Transcript = Transcript::new(b"OneOutOfManyTest");
+
+        let ring_size: usize = 16;
+        let g_vec: Vec<G1Point> = Utils::get_n_random_points(2, &mut rng);
+        let h: G1Point = Utils::get_n_random_points(1, &mut rng)[0];
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+
+        let mut commitments: Vec<G1Point> = Utils::get_n_random_points(ring_size, &mut rng);
+        commitments[3] = h.mul(r.into_repr()).into_affine();
+
+        // log2(16) = 4 generators are needed, the prover must refuse instead of panicking
+        let result: Result<OneOutOfManyProof, Error> =
+            OneOutOfManyProver::new(&g_vec, &h, &commitments, 3, &r)
+                .generate_proof(&mut rng, &mut prover_trans);
+
+        assert!(result.is_err(), "Prover must fail");
+    }
+}
@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod poly_commitment_tests {
+    use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+    use ark_ff::One;
+    use merlin::Transcript;
+    use std::io::Error;
+    use zeromt::{InnerHaloProof, PolyCommitment, Utils};
+
+    #[test]
+    fn poly_commitment_open_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        let max_degree: usize = 15;
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(max_degree + 1, &mut rng);
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let pc: PolyCommitment = PolyCommitment::new(&g_vec, &h, &u);
+
+        // Polynomials of lower degree are committed with the same key
+        for degree in [0, 3, 10, max_degree] {
+            let mut prover_trans: Transcript = Transcript::new(b"PolyCommitmentTest");
+            let mut verifier_trans: Transcript = Transcript::new(b"PolyCommitmentTest");
+
+            let coeffs: Vec<ScalarField> =
+                Utils::get_n_random_scalars_not_zero(degree + 1, &mut rng);
+            let blind: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+            let point: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+
+            let commitment: G1Point = pc.commit(&coeffs, &blind).unwrap();
+            let (value, proof): (ScalarField, InnerHaloProof) = pc
+                .open(&coeffs, &blind, &commitment, &point, &mut prover_trans)
+                .unwrap();
+
+            let expected: ScalarField = Utils::inner_product_scalar_scalar(
+                &coeffs,
+                &Utils::generate_scalar_exp_vector(degree + 1, &point),
+            )
+            .unwrap();
+            assert_eq!(value, expected, "Wrong evaluation for degree {}", degree);
+
+            let result: Result<(), Error> =
+                pc.verify(&commitment, &point, &value, &proof, &mut verifier_trans);
+            assert!(result.is_ok(), "Verifier fails for degree {}", degree);
+        }
+    }
+
+    #[test]
+    fn poly_commitment_wrong_value_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let mut prover_trans: Transcript = Transcript::new(b"PolyCommitmentTest");
+        let mut verifier_trans: Transcript = Transcript::new(b"PolyCommitmentTest");
+
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(8, &mut rng);
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let pc: PolyCommitment = PolyCommitment::new(&g_vec, &h, &u);
+
+        let coeffs: Vec<ScalarField> = Utils::get_n_random_scalars_not_zero(8, &mut rng);
+        let blind: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let point: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+
+        let commitment: G1Point = pc.commit(&coeffs, &blind).unwrap();
+        let (value, proof): (ScalarField, InnerHaloProof) = pc
+            .open(&coeffs, &blind, &commitment, &point, &mut prover_trans)
+            .unwrap();
+
+        let result: Result<(), Error> = pc.verify(
+            &commitment,
+            &point,
+            &(value + ScalarField::one()),
+            &proof,
+            &mut verifier_trans,
+        );
+        assert!(result.is_err(), "Verifier must fail");
+
+        // The key does not support polynomials of higher degree
+        let too_long: Vec<ScalarField> = Utils::get_n_random_scalars_not_zero(9, &mut rng);
+        assert!(pc.commit(&too_long, &blind).is_err());
+    }
+
+    #[test]
+    fn poly_commitment_batch_open_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(32, &mut rng);
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let pc: PolyCommitment = PolyCommitment::new(&g_vec, &h, &u);
+
+        let coeffs_vec: Vec<Vec<ScalarField>> = [2, 31, 17, 32]
+            .iter()
+            .map(|degree: &usize| Utils::get_n_random_scalars_not_zero(*degree, &mut rng))
+            .collect();
+        let blinds: Vec<ScalarField> =
+            Utils::get_n_random_scalars_not_zero(coeffs_vec.len(), &mut rng);
+        let commitments: Vec<G1Point> = coeffs_vec
+            .iter()
+            .zip(blinds.iter())
+            .map(|(coeffs, blind): (&Vec<ScalarField>, &ScalarField)| {
+                pc.commit(coeffs, blind).unwrap()
+            })
+            .collect();
+        let point: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+
+        let mut prover_trans: Transcript = Transcript::new(b"PolyCommitmentTest");
+        let (values, proof): (Vec<ScalarField>, InnerHaloProof) = pc
+            .batch_open(
+                &coeffs_vec,
+                &blinds,
+                &commitments,
+                &point,
+                &mut prover_trans,
+            )
+            .unwrap();
+
+        let mut verifier_trans: Transcript = Transcript::new(b"PolyCommitmentTest");
+        let result: Result<(), Error> =
+            pc.batch_verify(&commitments, &point, &values, &proof, &mut verifier_trans);
+        assert!(result.is_ok(), "Verifier fails");
+
+        // A single wrong evaluation invalidates the batch
+        let mut wrong_values: Vec<ScalarField> = values.clone();
+        wrong_values[2] += ScalarField::one();
+        let mut verifier_trans: Transcript = Transcript::new(b"PolyCommitmentTest");
+        let result: Result<(), Error> = pc.batch_verify(
+            &commitments,
+            &point,
+            &wrong_values,
+            &proof,
+            &mut verifier_trans,
+        );
+        assert!(result.is_err(), "Verifier must fail");
+    }
+}
@@ -0,0 +1,286 @@
+#[cfg(test)]
+mod r1cs_tests {
+
+    use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+    use merlin::Transcript;
+    use std::io::Error;
+    use zeromt::{
+        ConstraintSystem, ElGamal, LinearCombination, R1CSProof, R1CSProver, R1CSVerifier,
+        SigmaCommitProof, SigmaCommitProver, SigmaCommitVerifier, Utils, Variable,
+    };
+
+    struct Generators {
+        g: G1Point,
+        h: G1Point,
+        u: G1Point,
+        g_vec: Vec<G1Point>,
+        h_vec: Vec<G1Point>,
+    }
+
+    fn setup(capacity: usize) -> Generators {
+        let mut rng = ark_std::rand::thread_rng();
+
+        Generators {
+            g: Utils::get_n_generators(1, &mut rng)[0],
+            h: Utils::get_n_generators(1, &mut rng)[0],
+            u: Utils::get_n_generators(1, &mut rng)[0],
+            g_vec: Utils::get_n_generators(capacity, &mut rng),
+            h_vec: Utils::get_n_generators(capacity, &mut rng),
+        }
+    }
+
+    /// amount_1 + amount_2 <= limit, and amount_1 is a multiple of 100
+    fn business_gadget<CS: ConstraintSystem>(
+        cs: &mut CS,
+        amount_1: Variable,
+        amount_2: Variable,
+        limit: u64,
+        assignment: Option<(u64, u64)>,
+        n: usize,
+    ) -> Result<(), Error> {
+        let headroom: LinearCombination =
+            LinearCombination::from(ScalarField::from(limit)) - amount_1 - amount_2;
+        let headroom_value: Option<u64> = match assignment {
+            Some((a_1, a_2)) => Some(
+                limit
+                    .checked_sub(a_1 + a_2)
+                    .ok_or_else(|| Error::from(std::io::ErrorKind::InvalidInput))?,
+            ),
+            None => None,
+        };
+        cs.constrain_range(headroom, headroom_value, n)?;
+
+        // amount_1 = 100 * k with k in [0, 2^n), k being a free wire of a multiplier
+        let k_value: Option<u64> = assignment.map(|(a_1, _a_2)| a_1 / 100);
+        let (k, _right, _output): (Variable, Variable, Variable) = cs.allocate_multiplier(
+            k_value.map(|k: u64| (ScalarField::from(k), ScalarField::from(0u8))),
+        )?;
+        cs.constrain(amount_1 - k * ScalarField::from(100u8));
+        cs.constrain_range(k.into(), k_value, n)
+    }
+
+    #[test]
+    fn r1cs_multiplication_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let gens: Generators = setup(8);
+        let gammas: Vec<ScalarField> = Utils::get_n_random_scalars(3, &mut rng);
+
+        // a * b = c and a + b + c = 23 over committed a = 3, b = 5, c = 15
+        let values: Vec<ScalarField> = vec![3u8, 5, 15]
+            .into_iter()
+            .map(ScalarField::from)
+            .collect();
+
+        let mut prover: R1CSProver =
+            R1CSProver::new(&gens.g, &gens.h, &gens.g_vec, &gens.h_vec, &gens.u);
+        let variables: Vec<Variable> = values
+            .iter()
+            .zip(gammas.iter())
+            .map(|(v, gamma)| prover.commit(*v, *gamma).1)
+            .collect();
+        let (_l, _r, o) = prover
+            .multiply(variables[0].into(), variables[1].into())
+            .unwrap();
+        prover.constrain(o - variables[2]);
+        prover.constrain(
+            variables[0] + variables[1] + variables[2]
+                - LinearCombination::from(ScalarField::from(23u8)),
+        );
+        let commitments: Vec<G1Point> = prover.get_commitments().clone();
+
+        let mut prover_trans: Transcript = Transcript::new(b"R1CSTest");
+        let proof: R1CSProof = prover.generate_proof(&mut rng, &mut prover_trans).unwrap();
+
+        let mut verifier: R1CSVerifier =
+            R1CSVerifier::new(&gens.g, &gens.h, &gens.g_vec, &gens.h_vec, &gens.u);
+        let variables: Vec<Variable> = commitments.iter().map(|v| verifier.commit(*v)).collect();
+        let (_l, _r, o) = verifier
+            .multiply(variables[0].into(), variables[1].into())
+            .unwrap();
+        verifier.constrain(o - variables[2]);
+        verifier.constrain(
+            variables[0] + variables[1] + variables[2]
+                - LinearCombination::from(ScalarField::from(23u8)),
+        );
+
+        let mut verifier_trans: Transcript = Transcript::new(b"R1CSTest");
+        let result: Result<(), Error> = verifier.verify_proof(&proof, &mut verifier_trans);
+        assert!(result.is_ok(), "R1CS verifier fails");
+
+        // The same proof does not verify against a different constraint
+        let mut verifier: R1CSVerifier =
+            R1CSVerifier::new(&gens.g, &gens.h, &gens.g_vec, &gens.h_vec, &gens.u);
+        let variables: Vec<Variable> = commitments.iter().map(|v| verifier.commit(*v)).collect();
+        let (_l, _r, o) = verifier
+            .multiply(variables[0].into(), variables[1].into())
+            .unwrap();
+        verifier.constrain(o - variables[2]);
+        verifier.constrain(
+            variables[0] + variables[1] + variables[2]
+                - LinearCombination::from(ScalarField::from(24u8)),
+        );
+
+        let mut verifier_trans: Transcript = Transcript::new(b"R1CSTest");
+        let result: Result<(), Error> = verifier.verify_proof(&proof, &mut verifier_trans);
+        assert!(result.is_err(), "R1CS verifier accepts a different circuit");
+
+        // An unsatisfied circuit is refused by the prover
+        let mut prover: R1CSProver =
+            R1CSProver::new(&gens.g, &gens.h, &gens.g_vec, &gens.h_vec, &gens.u);
+        let (_v, a) = prover.commit(ScalarField::from(3u8), gammas[0]);
+        let (_v, b) = prover.commit(ScalarField::from(5u8), gammas[1]);
+        let (_l, _r, o) = prover.multiply(a.into(), b.into()).unwrap();
+        prover.constrain(o - LinearCombination::from(ScalarField::from(16u8)));
+
+        let mut prover_trans: Transcript = Transcript::new(b"R1CSTest");
+        assert!(prover.generate_proof(&mut rng, &mut prover_trans).is_err());
+    }
+
+    #[test]
+    fn r1cs_linear_only_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let gens: Generators = setup(1);
+        let gammas: Vec<ScalarField> = Utils::get_n_random_scalars(2, &mut rng);
+
+        // Without multiplication gates the proof still goes through a dummy multiplier
+        let mut prover: R1CSProver =
+            R1CSProver::new(&gens.g, &gens.h, &gens.g_vec, &gens.h_vec, &gens.u);
+        let (v_1, a) = prover.commit(ScalarField::from(40u8), gammas[0]);
+        let (v_2, b) = prover.commit(ScalarField::from(40u8), gammas[1]);
+        prover.constrain(a - b);
+
+        let mut prover_trans: Transcript = Transcript::new(b"R1CSTest");
+        let proof: R1CSProof = prover.generate_proof(&mut rng, &mut prover_trans).unwrap();
+
+        let mut verifier: R1CSVerifier =
+            R1CSVerifier::new(&gens.g, &gens.h, &gens.g_vec, &gens.h_vec, &gens.u);
+        let a: Variable = verifier.commit(v_1);
+        let b: Variable = verifier.commit(v_2);
+        verifier.constrain(a - b);
+
+        let mut verifier_trans: Transcript = Transcript::new(b"R1CSTest");
+        let result: Result<(), Error> = verifier.verify_proof(&proof, &mut verifier_trans);
+        assert!(result.is_ok(), "R1CS verifier fails");
+    }
+
+    #[test]
+    fn r1cs_business_constraints_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let n: usize = 16;
+        let limit: u64 = 1000;
+        let gens: Generators = setup(64);
+        let gammas: Vec<ScalarField> = Utils::get_n_random_scalars(2, &mut rng);
+
+        // 2n + 1 multipliers, padded to 64 by the inner-product argument
+        let amounts: (u64, u64) = (600, 350);
+        let mut prover: R1CSProver =
+            R1CSProver::new(&gens.g, &gens.h, &gens.g_vec, &gens.h_vec, &gens.u);
+        let (v_1, a_1) = prover.commit(ScalarField::from(amounts.0), gammas[0]);
+        let (v_2, a_2) = prover.commit(ScalarField::from(amounts.1), gammas[1]);
+        business_gadget(&mut prover, a_1, a_2, limit, Some(amounts), n).unwrap();
+
+        let mut prover_trans: Transcript = Transcript::new(b"R1CSTest");
+        let proof: R1CSProof = prover.generate_proof(&mut rng, &mut prover_trans).unwrap();
+
+        let mut verifier: R1CSVerifier =
+            R1CSVerifier::new(&gens.g, &gens.h, &gens.g_vec, &gens.h_vec, &gens.u);
+        let a_1: Variable = verifier.commit(v_1);
+        let a_2: Variable = verifier.commit(v_2);
+        business_gadget(&mut verifier, a_1, a_2, limit, None, n).unwrap();
+
+        let mut verifier_trans: Transcript = Transcript::new(b"R1CSTest");
+        let result: Result<(), Error> = verifier.verify_proof(&proof, &mut verifier_trans);
+        assert!(result.is_ok(), "R1CS verifier fails");
+
+        // Over the limit, or not a multiple of 100
+        for amounts in [(700u64, 350u64), (650, 300)] {
+            let mut prover: R1CSProver =
+                R1CSProver::new(&gens.g, &gens.h, &gens.g_vec, &gens.h_vec, &gens.u);
+            let (_v, a_1) = prover.commit(ScalarField::from(amounts.0), gammas[0]);
+            let (_v, a_2) = prover.commit(ScalarField::from(amounts.1), gammas[1]);
+
+            let mut prover_trans: Transcript = Transcript::new(b"R1CSTest");
+            let result: Result<R1CSProof, Error> =
+                business_gadget(&mut prover, a_1, a_2, limit, Some(amounts), n)
+                    .and_then(|_| prover.generate_proof(&mut rng, &mut prover_trans));
+            assert!(
+                result.is_err(),
+                "Unsatisfied business constraints are proved"
+            );
+        }
+    }
+
+    #[test]
+    fn r1cs_linked_transfer_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let n: usize = 16;
+        let limit: u64 = 1000;
+        let gens: Generators = setup(64);
+
+        // ZeroMT ciphertexts of the transferred amounts under the sender public key
+        let amounts: Vec<u64> = vec![500, 480];
+        let sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(&sk, &gens.g);
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let d: G1Point = ElGamal::elgamal_d(&gens.g, &r);
+        let c_vec: Vec<G1Point> = amounts
+            .iter()
+            .map(|a: &u64| ElGamal::elgamal_encrypt(*a as usize, &y, &gens.g, &r).0)
+            .collect();
+
+        // The amounts enter the circuit as committed variables
+        let gammas: Vec<ScalarField> = Utils::get_n_random_scalars(2, &mut rng);
+        let mut prover: R1CSProver =
+            R1CSProver::new(&gens.g, &gens.h, &gens.g_vec, &gens.h_vec, &gens.u);
+        let (_v, a_1) = prover.commit(ScalarField::from(amounts[0]), gammas[0]);
+        let (_v, a_2) = prover.commit(ScalarField::from(amounts[1]), gammas[1]);
+        business_gadget(
+            &mut prover,
+            a_1,
+            a_2,
+            limit,
+            Some((amounts[0], amounts[1])),
+            n,
+        )
+        .unwrap();
+        let v_vec: Vec<G1Point> = prover.get_commitments().clone();
+
+        let mut prover_trans: Transcript = Transcript::new(b"R1CSLinkTest");
+        let link_proof: SigmaCommitProof =
+            SigmaCommitProver::new(&gens.g, &gens.h, &d, &c_vec, &v_vec, &sk, &gammas)
+                .generate_proof(&mut rng, &mut prover_trans)
+                .unwrap();
+        let proof: R1CSProof = prover.generate_proof(&mut rng, &mut prover_trans).unwrap();
+
+        let mut verifier_trans: Transcript = Transcript::new(b"R1CSLinkTest");
+        let result: Result<(), Error> =
+            SigmaCommitVerifier::new(&gens.g, &gens.h, &d, &y, &c_vec, &v_vec)
+                .verify_proof(&link_proof, &mut verifier_trans);
+        assert!(result.is_ok(), "SigmaCommit verifier fails");
+
+        let mut verifier: R1CSVerifier =
+            R1CSVerifier::new(&gens.g, &gens.h, &gens.g_vec, &gens.h_vec, &gens.u);
+        let a_1: Variable = verifier.commit(v_vec[0]);
+        let a_2: Variable = verifier.commit(v_vec[1]);
+        business_gadget(&mut verifier, a_1, a_2, limit, None, n).unwrap();
+        let result: Result<(), Error> = verifier.verify_proof(&proof, &mut verifier_trans);
+        assert!(result.is_ok(), "R1CS verifier fails");
+
+        // Commitments to other amounts cannot be linked to the ciphertexts
+        let other_v_vec: Vec<G1Point> = vec![
+            Utils::pedersen_commitment(&ScalarField::from(400u16), &gens.g, &gammas[0], &gens.h),
+            v_vec[1],
+        ];
+        let mut prover_trans: Transcript = Transcript::new(b"R1CSLinkTest");
+        let link_proof: SigmaCommitProof =
+            SigmaCommitProver::new(&gens.g, &gens.h, &d, &c_vec, &other_v_vec, &sk, &gammas)
+                .generate_proof(&mut rng, &mut prover_trans)
+                .unwrap();
+
+        let mut verifier_trans: Transcript = Transcript::new(b"R1CSLinkTest");
+        let result: Result<(), Error> =
+            SigmaCommitVerifier::new(&gens.g, &gens.h, &d, &y, &c_vec, &other_v_vec)
+                .verify_proof(&link_proof, &mut verifier_trans);
+        assert!(result.is_err(), "SigmaCommit links a different amount");
+    }
+}
@@ -0,0 +1,301 @@
+#[cfg(test)]
+mod range_mpc_tests {
+
+    use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use merlin::Transcript;
+    use serial_test::serial;
+    use std::io::Error;
+    use std::sync::mpsc::{channel, Receiver, Sender};
+    use std::thread;
+    use zeromt::{
+        BitChallenge, BitCommitment, InnerProof, InnerVerifier, IntervalCommitmentProof,
+        PolyChallenge, ProofShare, RangeDealer, RangeParty, RangeProof, RangeVerifier,
+        ResponseChallenge, ResponseShare, TCommitment, Utils,
+    };
+
+    #[derive(Clone)]
+    struct Generators {
+        g: G1Point,
+        h: G1Point,
+        u: G1Point,
+        g_vec: Vec<G1Point>,
+        h_vec: Vec<G1Point>,
+    }
+
+    fn setup(total_bits: usize) -> Generators {
+        let mut rng = ark_std::rand::thread_rng();
+
+        Generators {
+            g: Utils::get_n_generators(1, &mut rng)[0],
+            h: Utils::get_n_generators(1, &mut rng)[0],
+            u: Utils::get_n_generators(1, &mut rng)[0],
+            g_vec: Utils::get_n_generators(total_bits, &mut rng),
+            h_vec: Utils::get_n_generators(total_bits, &mut rng),
+        }
+    }
+
+    fn send<M: CanonicalSerialize>(sender: &Sender<Vec<u8>>, message: &M) {
+        let mut bytes: Vec<u8> = Vec::new();
+        message.serialize(&mut bytes).unwrap();
+        sender.send(bytes).unwrap();
+    }
+
+    fn receive<M: CanonicalDeserialize>(receiver: &Receiver<Vec<u8>>) -> M {
+        M::deserialize(&receiver.recv().unwrap()[..]).unwrap()
+    }
+
+    fn verify(
+        gens: &Generators,
+        m: usize,
+        n: usize,
+        range_proof: &RangeProof,
+        commitment_proof: &IntervalCommitmentProof,
+        inner_proof: &InnerProof,
+        v_vec: &Vec<G1Point>,
+    ) -> Result<(), Error> {
+        let mut verifier_trans: Transcript = Transcript::new(b"RangeMPCTest");
+        let mut range_verifier: RangeVerifier = RangeVerifier::new(&gens.g, &gens.h, m, n);
+        let (range_result, x, y, z) = range_verifier.verify_committed_proof(
+            range_proof,
+            commitment_proof,
+            v_vec,
+            &mut verifier_trans,
+        );
+        range_result?;
+
+        let (h_first_vec, phu): (Vec<G1Point>, G1Point) = range_verifier.get_ipa_arguments(
+            &x,
+            &y,
+            &z,
+            range_proof.get_mu(),
+            range_proof.get_a(),
+            range_proof.get_s(),
+            &gens.h,
+            &gens.g_vec,
+            &gens.h_vec,
+        );
+
+        InnerVerifier::new(
+            &gens.g_vec,
+            &h_first_vec,
+            &phu,
+            range_proof.get_t_hat(),
+            &gens.u,
+        )
+        .verify_proof(inner_proof, &mut verifier_trans)
+    }
+
+    #[test]
+    #[serial]
+    fn range_mpc_multi_thread_test() {
+        let m: usize = 4;
+        let n: usize = 32;
+        let gens: Generators = setup(m * n);
+        let values: Vec<usize> = vec![0, 1, 1_000_000, (1 << n) - 1];
+
+        // Every party runs in its own thread and only exchanges serialized messages
+        let mut to_parties: Vec<Sender<Vec<u8>>> = Vec::new();
+        let mut from_parties: Vec<Receiver<Vec<u8>>> = Vec::new();
+        let mut handles: Vec<thread::JoinHandle<()>> = Vec::new();
+        for (j, value) in values.iter().enumerate() {
+            let (to_party, party_receiver): (Sender<Vec<u8>>, Receiver<Vec<u8>>) = channel();
+            let (party_sender, from_party): (Sender<Vec<u8>>, Receiver<Vec<u8>>) = channel();
+            to_parties.push(to_party);
+            from_parties.push(from_party);
+
+            let gens: Generators = gens.clone();
+            let value: usize = *value;
+            handles.push(thread::spawn(move || {
+                let mut rng = ark_std::rand::thread_rng();
+                let gamma: ScalarField = Utils::get_n_random_scalars(1, &mut rng)[0];
+                let party: RangeParty =
+                    RangeParty::new(&gens.g, &gens.h, &gens.g_vec, &gens.h_vec, n, value, gamma);
+
+                let (party, bit_commitment) = party.commit_bits(j, &mut rng).unwrap();
+                send(&party_sender, &bit_commitment);
+
+                let bit_challenge: BitChallenge = receive(&party_receiver);
+                let (party, t_commitment) = party.commit_poly(&bit_challenge, &mut rng).unwrap();
+                send(&party_sender, &t_commitment);
+
+                let poly_challenge: PolyChallenge = receive(&party_receiver);
+                let (party, proof_share) = party.share_proof(&poly_challenge, &mut rng).unwrap();
+                send(&party_sender, &proof_share);
+
+                let response_challenge: ResponseChallenge = receive(&party_receiver);
+                send(&party_sender, &party.respond(&response_challenge));
+            }));
+        }
+
+        let mut prover_trans: Transcript = Transcript::new(b"RangeMPCTest");
+        let dealer = RangeDealer::new(
+            &gens.g,
+            &gens.h,
+            &gens.g_vec,
+            &gens.h_vec,
+            &gens.u,
+            m,
+            n,
+            &mut prover_trans,
+        );
+
+        let bit_commitments: Vec<BitCommitment> = from_parties.iter().map(receive).collect();
+        let v_vec: Vec<G1Point> = bit_commitments
+            .iter()
+            .map(|commitment: &BitCommitment| *commitment.get_v())
+            .collect();
+        let (dealer, bit_challenge) = dealer.receive_bit_commitments(&bit_commitments).unwrap();
+        to_parties
+            .iter()
+            .for_each(|to_party| send(to_party, &bit_challenge));
+
+        let t_commitments: Vec<TCommitment> = from_parties.iter().map(receive).collect();
+        let (dealer, poly_challenge) = dealer.receive_t_commitments(&t_commitments).unwrap();
+        to_parties
+            .iter()
+            .for_each(|to_party| send(to_party, &poly_challenge));
+
+        let proof_shares: Vec<ProofShare> = from_parties.iter().map(receive).collect();
+        let (dealer, response_challenge) = dealer.receive_proof_shares(&proof_shares).unwrap();
+        to_parties
+            .iter()
+            .for_each(|to_party| send(to_party, &response_challenge));
+
+        let response_shares: Vec<ResponseShare> = from_parties.iter().map(receive).collect();
+        let (range_proof, commitment_proof, inner_proof): (
+            RangeProof,
+            IntervalCommitmentProof,
+            InnerProof,
+        ) = dealer.receive_response_shares(&response_shares).unwrap();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let result: Result<(), Error> = verify(
+            &gens,
+            m,
+            n,
+            &range_proof,
+            &commitment_proof,
+            &inner_proof,
+            &v_vec,
+        );
+        assert!(result.is_ok(), "Aggregated range proof fails");
+
+        // The proof holds only for the commitments of the parties
+        let mut swapped_v_vec: Vec<G1Point> = v_vec.clone();
+        swapped_v_vec.swap(0, 1);
+        let result: Result<(), Error> = verify(
+            &gens,
+            m,
+            n,
+            &range_proof,
+            &commitment_proof,
+            &inner_proof,
+            &swapped_v_vec,
+        );
+        assert!(result.is_err(), "Aggregated range proof with swapped V_j");
+
+        let mut other_v_vec: Vec<G1Point> = v_vec.clone();
+        other_v_vec[2] = Utils::pedersen_commitment(
+            &ScalarField::from(2_000_000u64),
+            &gens.g,
+            &ScalarField::from(1u8),
+            &gens.h,
+        );
+        let result: Result<(), Error> = verify(
+            &gens,
+            m,
+            n,
+            &range_proof,
+            &commitment_proof,
+            &inner_proof,
+            &other_v_vec,
+        );
+        assert!(result.is_err(), "Aggregated range proof with another V_j");
+    }
+
+    #[test]
+    fn range_mpc_bad_party_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let m: usize = 2;
+        let n: usize = 16;
+        let gens: Generators = setup(m * n);
+
+        let gamma: ScalarField = ScalarField::from(7u8);
+
+        // A value out of range is refused by its own party
+        let party: RangeParty =
+            RangeParty::new(&gens.g, &gens.h, &gens.g_vec, &gens.h_vec, n, 1 << n, gamma);
+        assert!(party.commit_bits(0, &mut rng).is_err());
+
+        let run = |tamper_v: bool, tamper_response: bool| -> bool {
+            let mut rng = ark_std::rand::thread_rng();
+            let mut prover_trans: Transcript = Transcript::new(b"RangeMPCTest");
+            let dealer = RangeDealer::new(
+                &gens.g,
+                &gens.h,
+                &gens.g_vec,
+                &gens.h_vec,
+                &gens.u,
+                m,
+                n,
+                &mut prover_trans,
+            );
+
+            let (parties, mut bit_commitments): (Vec<_>, Vec<BitCommitment>) = [10usize, 20]
+                .iter()
+                .enumerate()
+                .map(|(j, value)| {
+                    RangeParty::new(&gens.g, &gens.h, &gens.g_vec, &gens.h_vec, n, *value, gamma)
+                        .commit_bits(j, &mut rng)
+                        .unwrap()
+                })
+                .unzip();
+            if tamper_v {
+                bit_commitments[1] = BitCommitment::new(
+                    *bit_commitments[1].get_a(),
+                    *bit_commitments[1].get_s(),
+                    *bit_commitments[0].get_v(),
+                );
+            }
+            let (dealer, bit_challenge) = dealer.receive_bit_commitments(&bit_commitments).unwrap();
+
+            let (parties, t_commitments): (Vec<_>, Vec<TCommitment>) = parties
+                .into_iter()
+                .map(|party| party.commit_poly(&bit_challenge, &mut rng).unwrap())
+                .unzip();
+            let (dealer, poly_challenge) = dealer.receive_t_commitments(&t_commitments).unwrap();
+
+            let (parties, proof_shares): (Vec<_>, Vec<ProofShare>) = parties
+                .into_iter()
+                .map(|party| party.share_proof(&poly_challenge, &mut rng).unwrap())
+                .unzip();
+            let (dealer, response_challenge) = dealer.receive_proof_shares(&proof_shares).unwrap();
+
+            let mut response_shares: Vec<ResponseShare> = parties
+                .into_iter()
+                .map(|party| party.respond(&response_challenge))
+                .collect();
+            if tamper_response {
+                response_shares[1] = ResponseShare::new(
+                    *response_shares[1].get_s_ab() + ScalarField::from(1u8),
+                    *response_shares[1].get_s_tau(),
+                    *response_shares[1].get_s_gamma(),
+                );
+            }
+
+            dealer.receive_response_shares(&response_shares).is_ok()
+        };
+
+        assert!(run(false, false), "Honest parties are refused");
+
+        // A tampered response is caught by the dealer
+        assert!(!run(false, true), "Tampered response is accepted");
+
+        // So is a party whose V_j does not commit to its value
+        assert!(!run(true, false), "Tampered V_j is accepted");
+    }
+}
@@ -0,0 +1,249 @@
+#[cfg(test)]
+mod range_plus_tests {
+
+    use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_ff::{Field, PrimeField};
+    use merlin::Transcript;
+    use serial_test::serial;
+    use std::io::Error;
+    use zeromt::{
+        ElGamal, InnerProver, RangePlusProof, RangePlusProver, RangePlusVerifier, SigmaABProof,
+        SigmaABProver, SigmaABVerifier, Utils, WeightedInnerProof, WeightedInnerProver,
+        WeightedInnerVerifier, ZeroMTParams, ZeroMTPlusProof, ZeroMTProver, ZeroMTStatement,
+        ZeroMTVerifier, ZeroMTWitness,
+    };
+
+    #[test]
+    fn weighted_inner_arbitrary_length_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        for n in (1..=17).chain([48]) {
+            let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+            let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+            let g_vec: Vec<G1Point> = Utils::get_n_generators(n, &mut rng);
+            let h_vec: Vec<G1Point> = Utils::get_n_generators(n, &mut rng);
+            let a_vec: Vec<ScalarField> = Utils::get_n_random_scalars(n, &mut rng);
+            let b_vec: Vec<ScalarField> = Utils::get_n_random_scalars(n, &mut rng);
+            let alpha: ScalarField = Utils::get_n_random_scalars(1, &mut rng)[0];
+            let y: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+
+            // P = <a, g_vec> + <b, h_vec> + (sum_i a_i * b_i * y^(i + 1)) * g + alpha * h
+            let c: ScalarField = (0..n)
+                .map(|i: usize| a_vec[i] * b_vec[i] * y.pow([(i + 1) as u64]))
+                .sum();
+            let p: G1Point =
+                Utils::pedersen_vector_commitment(&alpha, &h, &a_vec, &g_vec, &b_vec, &h_vec)
+                    .unwrap()
+                    + g.mul(c.into_repr()).into_affine();
+
+            let mut prover_trans: Transcript = Transcript::new(b"WeightedInnerTest");
+            let proof: WeightedInnerProof =
+                WeightedInnerProver::new(&g_vec, &h_vec, &g, &h, &p, &a_vec, &b_vec, &alpha, &y)
+                    .generate_proof(&mut rng, &mut prover_trans)
+                    .unwrap();
+
+            let mut verifier_trans: Transcript = Transcript::new(b"WeightedInnerTest");
+            let result: Result<(), Error> =
+                WeightedInnerVerifier::new(&g_vec, &h_vec, &g, &h, &p, &y)
+                    .verify_proof(&proof, &mut verifier_trans);
+            assert!(
+                result.is_ok(),
+                "Weighted inner verifier fails with n = {}",
+                n
+            );
+
+            let wrong_p: G1Point = p + g;
+            let mut verifier_trans: Transcript = Transcript::new(b"WeightedInnerTest");
+            let result: Result<(), Error> =
+                WeightedInnerVerifier::new(&g_vec, &h_vec, &g, &h, &wrong_p, &y)
+                    .verify_proof(&proof, &mut verifier_trans);
+            assert!(
+                result.is_err(),
+                "Weighted inner verifier accepts a wrong P with n = {}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn range_plus_proof_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        for (m, n) in [(2, 16), (3, 16), (4, 32), (8, 64)] {
+            let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+            let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+            let g_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+            let h_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+
+            let (_balance, amounts, remaining_balance) = Utils::get_mock_balances(m, n, &mut rng);
+
+            let mut prover_trans: Transcript = Transcript::new(b"RangePlusTest");
+            let proof: RangePlusProof =
+                RangePlusProver::new(&g, &h, remaining_balance, &amounts, &g_vec, &h_vec, n)
+                    .generate_proof(&mut rng, &mut prover_trans)
+                    .unwrap();
+
+            let mut verifier_trans: Transcript = Transcript::new(b"RangePlusTest");
+            let result: Result<(), Error> = RangePlusVerifier::new(&g, &h, &g_vec, &h_vec, m, n)
+                .verify_proof(&proof, &mut verifier_trans);
+            assert!(
+                result.is_ok(),
+                "RangePlus verifier fails with m = {}, n = {}",
+                m,
+                n
+            );
+        }
+
+        // Values out of range cannot be proved
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(32, &mut rng);
+        let h_vec: Vec<G1Point> = Utils::get_n_generators(32, &mut rng);
+        let mut prover_trans: Transcript = Transcript::new(b"RangePlusTest");
+        assert!(
+            RangePlusProver::new(&g, &h, 1 << 16, &vec![5], &g_vec, &h_vec, 16)
+                .generate_proof(&mut rng, &mut prover_trans)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn range_plus_link_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let n: usize = 16;
+        let m: usize = 4;
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+        let h_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+
+        let amounts: Vec<usize> = vec![100, 200, 300];
+        let remaining_balance: usize = 400;
+        let balance: usize = remaining_balance + amounts.iter().sum::<usize>();
+
+        let sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(&sk, &g);
+        let (c_l, c_r): (G1Point, G1Point) = ElGamal::elgamal_encrypt(balance, &y, &g, &r);
+        let d: G1Point = ElGamal::elgamal_d(&g, &r);
+        let c_vec: Vec<G1Point> = amounts
+            .iter()
+            .map(|a: &usize| ElGamal::elgamal_encrypt(*a, &y, &g, &r).0)
+            .collect();
+
+        let run = |range_amounts: &Vec<usize>| -> Result<(), Error> {
+            let mut rng = ark_std::rand::thread_rng();
+
+            let mut prover_trans: Transcript = Transcript::new(b"RangePlusTest");
+            let (proof, sigma_ab_proof): (RangePlusProof, SigmaABProof) =
+                RangePlusProver::new(&g, &h, remaining_balance, range_amounts, &g_vec, &h_vec, n)
+                    .generate_linked_proof(
+                        &mut rng,
+                        &mut prover_trans,
+                        &mut SigmaABProver::new(&g, &d, &c_r, remaining_balance, &amounts, &sk),
+                    )
+                    .unwrap();
+
+            let mut verifier_trans: Transcript = Transcript::new(b"RangePlusTest");
+            RangePlusVerifier::new(&g, &h, &g_vec, &h_vec, m, n).verify_linked_proof(
+                &proof,
+                &sigma_ab_proof,
+                &mut SigmaABVerifier::new(&g, &d, &c_r, &c_l, &c_vec),
+                &mut verifier_trans,
+            )
+        };
+
+        assert!(run(&amounts).is_ok(), "Linked verifier fails");
+        // Range-proved amounts other than the encrypted ones
+        assert!(
+            run(&vec![200, 100, 300]).is_err(),
+            "Linked verifier accepts other amounts"
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn zeromt_plus_proof_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        for (m, n) in [(2, 16), (4, 32), (5, 16)] {
+            let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+            let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+            let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+            let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+            let g_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+            let h_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+
+            let (balance, amounts, remaining_balance) = Utils::get_mock_balances(m, n, &mut rng);
+
+            let sender_priv_key: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+            let sender_pub_key: G1Point = ElGamal::elgamal_calculate_pub_key(&sender_priv_key, &g);
+            let recipients_pub_keys: Vec<G1Point> =
+                Utils::get_n_random_scalars_not_zero(amounts.len(), &mut rng)
+                    .iter()
+                    .map(|key: &ScalarField| ElGamal::elgamal_calculate_pub_key(key, &g))
+                    .collect();
+
+            let (c_l, c_r): (G1Point, G1Point) =
+                ElGamal::elgamal_encrypt(balance, &sender_pub_key, &g, &r);
+            let d: G1Point = ElGamal::elgamal_d(&g, &r);
+            let c_vec: Vec<G1Point> = amounts
+                .iter()
+                .map(|a: &usize| ElGamal::elgamal_encrypt(*a, &sender_pub_key, &g, &r).0)
+                .collect();
+            let c_bar_vec: Vec<G1Point> = amounts
+                .iter()
+                .zip(recipients_pub_keys.iter())
+                .map(|(a, k)| ElGamal::elgamal_encrypt(*a, k, &g, &r).0)
+                .collect();
+
+            let mut prover_trans: Transcript = Transcript::new(b"ZeroMTTest");
+            let proof: ZeroMTPlusProof = ZeroMTProver::<InnerProver>::new(
+                &ZeroMTParams::new(&g, &h, &g_vec, &h_vec, &u, n),
+                &ZeroMTStatement::new(
+                    &d,
+                    &c_r,
+                    &c_l,
+                    &c_vec,
+                    &c_bar_vec,
+                    &sender_pub_key,
+                    &recipients_pub_keys,
+                ),
+                &ZeroMTWitness::new(remaining_balance, &amounts, &sender_priv_key, &r),
+            )
+            .generate_plus_proof(&mut rng, &mut prover_trans)
+            .unwrap();
+
+            let verifier = |c_l: &G1Point| -> Result<(), Error> {
+                let mut verifier_trans: Transcript = Transcript::new(b"ZeroMTTest");
+                ZeroMTVerifier::<InnerProver>::new(
+                    &ZeroMTParams::new(&g, &h, &g_vec, &h_vec, &u, n),
+                    &ZeroMTStatement::new(
+                        &d,
+                        &c_r,
+                        c_l,
+                        &c_vec,
+                        &c_bar_vec,
+                        &sender_pub_key,
+                        &recipients_pub_keys,
+                    ),
+                )
+                .verify_plus_proof(&proof, &mut verifier_trans)
+            };
+
+            assert!(
+                verifier(&c_l).is_ok(),
+                "Verifier fails with m = {}, n = {}",
+                m,
+                n
+            );
+            // A different balance is caught through the linked Sigma-protocol ab
+            assert!(
+                verifier(&(c_l + g)).is_err(),
+                "Verifier accepts another balance"
+            );
+        }
+    }
+}
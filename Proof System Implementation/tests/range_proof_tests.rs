@@ -146,7 +146,8 @@ mod range_proof_tests {
                     &r_poly_vec,
                     &u,
                 )
-                .generate_proof(&mut prover_trans);
+                .generate_proof(&mut prover_trans)
+                .unwrap();
 
                 let inner_result: Result<(), Error> = InnerVerifier::new(
                     &g_vec,
@@ -256,7 +257,8 @@ mod range_proof_tests {
                     &r_poly_vec,
                     &u,
                 )
-                .generate_proof(&mut prover_trans);
+                .generate_proof(&mut prover_trans)
+                .unwrap();
 
                 let inner_result: Result<(), Error> = InnerVerifier::new(
                     &g_vec,
@@ -276,4 +278,149 @@ mod range_proof_tests {
             n *= 2;
         }
     }
+
+    #[test]
+    fn verify_range_proof_bit_lengths_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        // Remaining balance in 64 bits, amounts in 32 bits and in 8 bits
+        let bit_lengths: Vec<usize> = vec![64, 32, 32, 8];
+        let total_bits: usize = bit_lengths.iter().sum();
+        let balance_remaining: usize = usize::MAX - 12_345;
+        let amounts: Vec<usize> = vec![(1 << 32) - 1, 1_000_000, 255];
+
+        let mut prover_trans: Transcript = Transcript::new(b"RangeProofTest");
+        let mut verifier_trans: Transcript = Transcript::new(b"RangeProofTest");
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(total_bits, &mut rng);
+        let h_vec: Vec<G1Point> = Utils::get_n_generators(total_bits, &mut rng);
+
+        let mut range_prover: RangeProver =
+            RangeProver::new(&g, &h, balance_remaining, &amounts, &g_vec, &h_vec, 64)
+                .with_bit_lengths(bit_lengths.clone())
+                .unwrap();
+        let mut range_verifier: RangeVerifier = RangeVerifier::new(&g, &h, amounts.len() + 1, 64)
+            .with_bit_lengths(bit_lengths, &g_vec, &h_vec)
+            .unwrap();
+
+        let (range_proof, l_poly_vec, r_poly_vec, x_prover, y_prover, z_prover, _t_coefficients): (
+            RangeProof,
+            Vec<ScalarField>,
+            Vec<ScalarField>,
+            ScalarField,
+            ScalarField,
+            ScalarField,
+            PolyCoefficients,
+        ) = range_prover.generate_proof(&mut rng, &mut prover_trans);
+
+        assert_eq!(l_poly_vec.len(), total_bits);
+
+        let (range_proof_result, x_verifier, y_verifier, z_verifier): (
+            Result<(), Error>,
+            ScalarField,
+            ScalarField,
+            ScalarField,
+        ) = range_verifier.verify_proof(&range_proof, &mut verifier_trans);
+
+        let (h_first_vec_prover, phu_prover): (Vec<G1Point>, G1Point) = range_prover
+            .get_ipa_arguments(
+                &x_prover,
+                &y_prover,
+                &z_prover,
+                range_proof.get_mu(),
+                range_proof.get_a(),
+                range_proof.get_s(),
+                &h,
+                &g_vec,
+                &h_vec,
+            );
+
+        let (h_first_vec_verifier, phu_verifier): (Vec<G1Point>, G1Point) = range_verifier
+            .get_ipa_arguments(
+                &x_verifier,
+                &y_verifier,
+                &z_verifier,
+                range_proof.get_mu(),
+                range_proof.get_a(),
+                range_proof.get_s(),
+                &h,
+                &g_vec,
+                &h_vec,
+            );
+
+        // 136 entries, padded to 256 by the inner-product argument
+        let inner_proof: InnerProof = InnerProver::new(
+            &g_vec,
+            &h_first_vec_prover,
+            &phu_prover,
+            range_proof.get_t_hat(),
+            &l_poly_vec,
+            &r_poly_vec,
+            &u,
+        )
+        .generate_proof(&mut prover_trans)
+        .unwrap();
+
+        let inner_result: Result<(), Error> = InnerVerifier::new(
+            &g_vec,
+            &h_first_vec_verifier,
+            &phu_verifier,
+            range_proof.get_t_hat(),
+            &u,
+        )
+        .verify_proof_multiscalar(&inner_proof, &mut verifier_trans);
+
+        assert!(
+            range_proof_result.is_ok() && inner_result.is_ok(),
+            "Verifier fails"
+        );
+    }
+
+    #[test]
+    fn range_proof_invalid_bit_lengths_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        let balance_remaining: usize = 1 << 20;
+        let amounts: Vec<usize> = vec![256, 255];
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(48, &mut rng);
+        let h_vec: Vec<G1Point> = Utils::get_n_generators(48, &mut rng);
+
+        let with_bit_lengths = |bit_lengths: Vec<usize>| -> Result<RangeProver, Error> {
+            RangeProver::new(&g, &h, balance_remaining, &amounts, &g_vec, &h_vec, 16)
+                .with_bit_lengths(bit_lengths)
+        };
+
+        // One bit length per value, summing up to the number of generators
+        assert!(with_bit_lengths(vec![32, 16]).is_err());
+        assert!(with_bit_lengths(vec![24, 16, 8, 0]).is_err());
+        assert!(with_bit_lengths(vec![32, 16, 16]).is_err());
+        // 256 does not fit in 8 bits
+        assert!(with_bit_lengths(vec![24, 8, 16]).is_err());
+        assert!(with_bit_lengths(vec![24, 16, 8]).is_ok());
+
+        // Every value has at least one bit
+        assert!(with_bit_lengths(vec![32, 16, 0]).is_err());
+
+        let verifier_with_bit_lengths =
+            |bit_lengths: Vec<usize>, generators: usize| -> Result<RangeVerifier, Error> {
+                RangeVerifier::new(&g, &h, 3, 16).with_bit_lengths(
+                    bit_lengths,
+                    &g_vec[..generators].to_vec(),
+                    &h_vec,
+                )
+            };
+        assert!(verifier_with_bit_lengths(vec![32, 16], 48).is_err());
+        assert!(verifier_with_bit_lengths(vec![24, 16, 128], 48).is_err());
+        assert!(verifier_with_bit_lengths(vec![32, 16, 16], 48).is_err());
+        assert!(verifier_with_bit_lengths(vec![24, 16, 8], 40).is_err());
+        assert!(verifier_with_bit_lengths(vec![40, 8, 0], 48).is_err());
+        assert!(verifier_with_bit_lengths(vec![24, 16, 8], 48).is_ok());
+    }
 }
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod sigma_decrypt_tests {
+    use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+    use merlin::Transcript;
+    use std::io::Error;
+    use zeromt::{
+        DecryptThresholdProof, DecryptThresholdProver, DecryptThresholdVerifier, ElGamal,
+        SigmaDecryptProof, SigmaDecryptProver, SigmaDecryptVerifier, Utils,
+    };
+
+    #[test]
+    fn verify_sigma_decrypt_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        let balance: usize = 1500;
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(&sk, &g);
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let (c_l, c_r): (G1Point, G1Point) = ElGamal::elgamal_encrypt(balance, &y, &g, &r);
+
+        let mut prover_trans: Transcript = Transcript::new(b"SigmaDecryptTest");
+        let proof: SigmaDecryptProof = SigmaDecryptProver::new(&g, &c_r, balance, &sk)
+            .generate_proof(&mut rng, &mut prover_trans);
+
+        let mut verifier_trans: Transcript = Transcript::new(b"SigmaDecryptTest");
+        let result: Result<(), Error> = SigmaDecryptVerifier::new(&g, &y, &c_l, &c_r, balance)
+            .verify_proof(&proof, &mut verifier_trans);
+        assert!(result.is_ok(), "Verifier fails");
+
+        // The proof does not disclose any other value
+        let mut verifier_trans: Transcript = Transcript::new(b"SigmaDecryptTest");
+        let result: Result<(), Error> = SigmaDecryptVerifier::new(&g, &y, &c_l, &c_r, balance + 1)
+            .verify_proof(&proof, &mut verifier_trans);
+        assert!(result.is_err(), "Verifier accepts a wrong value");
+
+        // Nor does it verify against any other statement
+        let point: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let other_statements: Vec<(&str, G1Point, G1Point, G1Point, G1Point)> = vec![
+            ("g", point, y, c_l, c_r),
+            ("y", g, point, c_l, c_r),
+            ("C_L", g, y, point, c_r),
+            ("C_R", g, y, c_l, point),
+        ];
+        for (label, g, y, c_l, c_r) in other_statements.iter() {
+            let mut verifier_trans: Transcript = Transcript::new(b"SigmaDecryptTest");
+            let result: Result<(), Error> = SigmaDecryptVerifier::new(g, y, c_l, c_r, balance)
+                .verify_proof(&proof, &mut verifier_trans);
+            assert!(result.is_err(), "Verifier accepts another {}", label);
+        }
+
+        // Nor can it be produced without the private key
+        let other_sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let mut prover_trans: Transcript = Transcript::new(b"SigmaDecryptTest");
+        let proof: SigmaDecryptProof = SigmaDecryptProver::new(&g, &c_r, balance, &other_sk)
+            .generate_proof(&mut rng, &mut prover_trans);
+
+        let mut verifier_trans: Transcript = Transcript::new(b"SigmaDecryptTest");
+        let result: Result<(), Error> = SigmaDecryptVerifier::new(&g, &y, &c_l, &c_r, balance)
+            .verify_proof(&proof, &mut verifier_trans);
+        assert!(result.is_err(), "Verifier accepts a wrong private key");
+    }
+
+    #[test]
+    fn decrypt_threshold_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        let n: usize = 16;
+        let balance: usize = 1000;
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(n, &mut rng);
+        let h_vec: Vec<G1Point> = Utils::get_n_generators(n, &mut rng);
+        let sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(&sk, &g);
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let (c_l, c_r): (G1Point, G1Point) = ElGamal::elgamal_encrypt(balance, &y, &g, &r);
+
+        for threshold in [0usize, 600, balance] {
+            let mut prover_trans: Transcript = Transcript::new(b"DecryptThresholdTest");
+            let proof: DecryptThresholdProof = DecryptThresholdProver::new(
+                &g, &h, &g_vec, &h_vec, n, &c_r, balance, threshold, &sk,
+            )
+            .generate_proof(&mut rng, &mut prover_trans)
+            .unwrap();
+
+            let mut verifier_trans: Transcript = Transcript::new(b"DecryptThresholdTest");
+            let result: Result<(), Error> =
+                DecryptThresholdVerifier::new(&g, &h, &g_vec, &h_vec, n, &y, &c_l, &c_r, threshold)
+                    .verify_proof(&proof, &mut verifier_trans);
+            assert!(result.is_ok(), "Verifier fails");
+
+            // The same proof does not hold for a higher threshold
+            let mut verifier_trans: Transcript = Transcript::new(b"DecryptThresholdTest");
+            let result: Result<(), Error> = DecryptThresholdVerifier::new(
+                &g,
+                &h,
+                &g_vec,
+                &h_vec,
+                n,
+                &y,
+                &c_l,
+                &c_r,
+                threshold + 100,
+            )
+            .verify_proof(&proof, &mut verifier_trans);
+            assert!(result.is_err(), "Verifier accepts a higher threshold");
+        }
+
+        // A balance below the threshold cannot be proved
+        let mut prover_trans: Transcript = Transcript::new(b"DecryptThresholdTest");
+        let result: Result<DecryptThresholdProof, Error> =
+            DecryptThresholdProver::new(&g, &h, &g_vec, &h_vec, n, &c_r, balance, balance + 1, &sk)
+                .generate_proof(&mut rng, &mut prover_trans);
+        assert!(
+            result.is_err(),
+            "Prover accepts a balance below the threshold"
+        );
+    }
+}
@@ -1,9 +1,11 @@
 #[cfg(test)]
 mod sigma_r_tests {
     use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_ff::{Field, PrimeField};
     use merlin::Transcript;
     use std::io::Error;
-    use zeromt::{ElGamal, SigmaRProof, SigmaRProver, SigmaRVerifier, Utils};
+    use zeromt::{ElGamal, SigmaRProof, SigmaRProver, SigmaRVerifier, TranscriptProtocol, Utils};
     #[test]
     fn verify_sigma_r_test() {
         let mut rng = ark_std::rand::thread_rng();
@@ -34,4 +36,39 @@ mod sigma_r_tests {
             _n *= 2;
         }
     }
+
+    #[test]
+    fn sigma_r_statement_binding_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+
+        // Without g and D in the transcript the challenge only depends on A_D, so a proof can
+        // be forged by choosing D after the challenge: D = c^-1 * (s_r * g - A_D)
+        let a_d: G1Point = Utils::get_n_random_points(1, &mut rng)[0];
+        let s_r: ScalarField = Utils::get_n_random_scalars(1, &mut rng)[0];
+        let mut weak_trans: Transcript = Transcript::new(b"SigmaRTest");
+        weak_trans.domain_sep(b"SigmaR");
+        let _result = weak_trans.append_point(b"A_D", &a_d);
+        let c: ScalarField = weak_trans.challenge_scalar(b"c");
+        let d: G1Point = (g.mul(s_r.into_repr()).into_affine() + -a_d)
+            .mul(c.inverse().unwrap().into_repr())
+            .into_affine();
+        let forged: SigmaRProof = SigmaRProof::new(a_d, s_r);
+
+        assert!(
+            SigmaRVerifier::new(&g, &d)
+                .verify_with_challenge(&forged, &c)
+                .is_ok(),
+            "Forged proof must satisfy the verification equation"
+        );
+
+        // The challenge derived from the statement rejects it
+        let mut verifier_trans: Transcript = Transcript::new(b"SigmaRTest");
+        assert!(
+            SigmaRVerifier::new(&g, &d)
+                .verify_proof(&forged, &mut verifier_trans)
+                .is_err(),
+            "Forged proof must not verify"
+        );
+    }
 }
@@ -3,7 +3,10 @@ mod sigma_sk_tests {
     use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
     use merlin::Transcript;
     use std::io::Error;
-    use zeromt::{ElGamal, SigmaSKProof, SigmaSKProver, SigmaSKVerifier, Utils};
+    use zeromt::{
+        AssociatedData, ElGamal, RegistrationProver, RegistrationVerifier, SigmaSKProof,
+        SigmaSKProver, SigmaSKVerifier, Utils,
+    };
     #[test]
     fn verify_sigma_sk_test() {
         let mut rng = ark_std::rand::thread_rng();
@@ -34,4 +37,37 @@ mod sigma_sk_tests {
             _n *= 2;
         }
     }
+
+    #[test]
+    fn registration_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let address: &[u8] = b"0x5FbDB2315678afecb367f032d93F642f64180aa3";
+        let associated_data: AssociatedData = AssociatedData::new().with_chain_id(1);
+        let other_chain: AssociatedData = AssociatedData::new().with_chain_id(2);
+
+        let sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(&sk, &g);
+
+        let proof: SigmaSKProof =
+            RegistrationProver::new(&g, &sk, address, &associated_data).generate_proof(&mut rng);
+
+        assert!(RegistrationVerifier::new(&g, &y, address, &associated_data)
+            .verify_proof(&proof)
+            .is_ok());
+        assert!(RegistrationVerifier::new(&g, &y, b"0x0", &associated_data)
+            .verify_proof(&proof)
+            .is_err());
+        assert!(RegistrationVerifier::new(&g, &y, address, &other_chain)
+            .verify_proof(&proof)
+            .is_err());
+
+        // A plain Sigma-protocol `sk` proof is not a registration proof
+        let mut prover_trans: Transcript = Transcript::new(b"ZeroMTRegistration");
+        let proof: SigmaSKProof =
+            SigmaSKProver::new(&g, &sk).generate_proof(&mut rng, &mut prover_trans);
+        assert!(RegistrationVerifier::new(&g, &y, address, &associated_data)
+            .verify_proof(&proof)
+            .is_err());
+    }
 }
@@ -0,0 +1,404 @@
+#[cfg(test)]
+mod simulator_tests {
+    use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+    use ark_ff::PrimeField;
+    use merlin::Transcript;
+    use zeromt::{
+        ElGamal, InnerSigmaProof, InnerSigmaProver, InnerSigmaVerifier, SigmaABProof,
+        SigmaABProver, SigmaABVerifier, SigmaRProof, SigmaRProver, SigmaRVerifier, SigmaSKProof,
+        SigmaSKProver, SigmaSKVerifier, SigmaYProof, SigmaYProver, SigmaYVerifier, Utils,
+    };
+
+    const SAMPLES: usize = 512;
+    const BUCKETS: usize = 16;
+    // Two-sample chi-square bound with BUCKETS - 1 = 15 degrees of freedom, p-value around 1e-5
+    const CHI_SQUARE_BOUND: f64 = 51.0;
+
+    // Top four bits of the third 64-bit limb, far from both the low bits and the modulus
+    fn limb_bucket(limb: u64) -> usize {
+        (limb >> 60) as usize
+    }
+
+    fn scalar_bucket(s: &ScalarField) -> usize {
+        limb_bucket(s.into_repr().0[2])
+    }
+
+    fn point_bucket(p: &G1Point) -> usize {
+        limb_bucket(p.x.into_repr().0[2])
+    }
+
+    fn chi_square_two_samples(real: &Vec<usize>, simulated: &Vec<usize>) -> f64 {
+        let mut real_counts: Vec<f64> = vec![0.0; BUCKETS];
+        let mut simulated_counts: Vec<f64> = vec![0.0; BUCKETS];
+        real.iter().for_each(|b: &usize| real_counts[*b] += 1.0);
+        simulated
+            .iter()
+            .for_each(|b: &usize| simulated_counts[*b] += 1.0);
+
+        real_counts
+            .iter()
+            .zip(simulated_counts.iter())
+            .filter(|(r, s): &(&f64, &f64)| **r + **s > 0.0)
+            .map(|(r, s): (&f64, &f64)| (r - s).powi(2) / (r + s))
+            .sum()
+    }
+
+    fn assert_same_distribution(real: &Vec<usize>, simulated: &Vec<usize>, name: &str) {
+        let chi_square: f64 = chi_square_two_samples(real, simulated);
+        assert!(
+            chi_square < CHI_SQUARE_BOUND,
+            "{} distributions differ, chi-square {}",
+            name,
+            chi_square
+        );
+    }
+
+    #[test]
+    fn sigma_sk_simulator_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(&sk, &g);
+
+        let (mut real_a, mut real_s): (Vec<usize>, Vec<usize>) = (vec![], vec![]);
+        let (mut simulated_a, mut simulated_s): (Vec<usize>, Vec<usize>) = (vec![], vec![]);
+
+        for _ in 0..SAMPLES {
+            let mut prover_trans: Transcript = Transcript::new(b"SigmaSKSimulatorTest");
+            let real: SigmaSKProof =
+                SigmaSKProver::new(&g, &sk).generate_proof(&mut rng, &mut prover_trans);
+
+            let c: ScalarField = Utils::get_n_random_scalars(1, &mut rng)[0];
+            let simulated: SigmaSKProof = SigmaSKProver::simulate(&g, &y, &c, &mut rng);
+
+            assert!(
+                SigmaSKVerifier::new(&g, &y)
+                    .verify_with_challenge(&simulated, &c)
+                    .is_ok(),
+                "Simulated transcript is not accepting"
+            );
+
+            real_a.push(point_bucket(real.get_a_y()));
+            real_s.push(scalar_bucket(real.get_s_sk()));
+            simulated_a.push(point_bucket(simulated.get_a_y()));
+            simulated_s.push(scalar_bucket(simulated.get_s_sk()));
+        }
+
+        assert_same_distribution(&real_a, &simulated_a, "A_y");
+        assert_same_distribution(&real_s, &simulated_s, "s_sk");
+    }
+
+    #[test]
+    fn sigma_r_simulator_test() {
+        let mut rng = ark_std::rand::thread_rng();
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let d: G1Point = ElGamal::elgamal_d(&g, &r);
+
+        let (mut real_a, mut real_s): (Vec<usize>, Vec<usize>) = (vec![], vec![]);
+        let (mut simulated_a, mut simulated_s): (Vec<usize>, Vec<usize>) = (vec![], vec![]);
+
+        for _ in 0..SAMPLES {
+            let mut prover_trans: Transcript = Transcript::new(b"SigmaRSimulatorTest");
+            let real: SigmaRProof =
+                SigmaRProver::new(&g, &r).generate_proof(&mut rng, &mut prover_trans);
+
+            let c: ScalarField = Utils::get_n_random_scalars(1, &mut rng)[0];
+            let simulated: SigmaRProof = SigmaRProver::simulate(&g, &d, &c, &mut rng);
+
+            assert!(
+                SigmaRVerifier::new(&g, &d)
+                    .verify_with_challenge(&simulated, &c)
+                    .is_ok(),
+                "Simulated transcript is not accepting"
+            );
+
+            real_a.push(point_bucket(real.get_a_d()));
+            real_s.push(scalar_bucket(real.get_s_r()));
+            simulated_a.push(point_bucket(simulated.get_a_d()));
+            simulated_s.push(scalar_bucket(simulated.get_s_r()));
+        }
+
+        assert_same_distribution(&real_a, &simulated_a, "A_D");
+        assert_same_distribution(&real_s, &simulated_s, "s_r");
+    }
+
+    #[test]
+    fn sigma_y_simulator_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let m: usize = 4;
+        let n: usize = 16;
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let (_balance, amounts, _remaining_balance) = Utils::get_mock_balances(m, n, &mut rng);
+
+        let sender_priv_key: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let recipients_priv_keys: Vec<ScalarField> =
+            Utils::get_n_random_scalars_not_zero(amounts.len(), &mut rng);
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(&sender_priv_key, &g);
+        let y_bar: Vec<G1Point> = recipients_priv_keys
+            .iter()
+            .map(|key: &ScalarField| ElGamal::elgamal_calculate_pub_key(key, &g))
+            .collect();
+
+        let c_vec: Vec<G1Point> = amounts
+            .iter()
+            .map(|a: &usize| ElGamal::elgamal_encrypt(*a, &y, &g, &r).0)
+            .collect();
+        let c_bar_vec: Vec<G1Point> = amounts
+            .iter()
+            .zip(y_bar.iter())
+            .map(|(a, k)| ElGamal::elgamal_encrypt(*a, k, &g, &r).0)
+            .collect();
+
+        let (mut real_a, mut real_s): (Vec<usize>, Vec<usize>) = (vec![], vec![]);
+        let (mut simulated_a, mut simulated_s): (Vec<usize>, Vec<usize>) = (vec![], vec![]);
+
+        for _ in 0..SAMPLES {
+            let mut prover_trans: Transcript = Transcript::new(b"SigmaYSimulatorTest");
+            let real: SigmaYProof =
+                SigmaYProver::new(&r, &y, &y_bar).generate_proof(&mut rng, &mut prover_trans);
+
+            let c: ScalarField = Utils::get_n_random_scalars(1, &mut rng)[0];
+            let simulated: SigmaYProof =
+                SigmaYProver::simulate(&y, &y_bar, &c_vec, &c_bar_vec, &c, &mut rng);
+
+            assert!(
+                SigmaYVerifier::new(&y, &y_bar, &c_vec, &c_bar_vec)
+                    .verify_with_challenge(&simulated, &c)
+                    .is_ok(),
+                "Simulated transcript is not accepting"
+            );
+
+            real_a.push(point_bucket(real.get_a_y_bar()));
+            real_s.push(scalar_bucket(real.get_s_r()));
+            simulated_a.push(point_bucket(simulated.get_a_y_bar()));
+            simulated_s.push(scalar_bucket(simulated.get_s_r()));
+        }
+
+        assert_same_distribution(&real_a, &simulated_a, "A_y_bar");
+        assert_same_distribution(&real_s, &simulated_s, "s_r");
+    }
+
+    #[test]
+    fn sigma_y_auditor_simulator_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let m: usize = 4;
+        let n: usize = 16;
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let (_balance, amounts, _remaining_balance) = Utils::get_mock_balances(m, n, &mut rng);
+
+        let sender_priv_key: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let recipients_priv_keys: Vec<ScalarField> =
+            Utils::get_n_random_scalars_not_zero(amounts.len(), &mut rng);
+        let auditor_priv_key: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(&sender_priv_key, &g);
+        let y_bar: Vec<G1Point> = recipients_priv_keys
+            .iter()
+            .map(|key: &ScalarField| ElGamal::elgamal_calculate_pub_key(key, &g))
+            .collect();
+        let y_auditor: G1Point = ElGamal::elgamal_calculate_pub_key(&auditor_priv_key, &g);
+
+        let c_vec: Vec<G1Point> = amounts
+            .iter()
+            .map(|a: &usize| ElGamal::elgamal_encrypt(*a, &y, &g, &r).0)
+            .collect();
+        let c_bar_vec: Vec<G1Point> = amounts
+            .iter()
+            .zip(y_bar.iter())
+            .map(|(a, k)| ElGamal::elgamal_encrypt(*a, k, &g, &r).0)
+            .collect();
+        let c_auditor_vec: Vec<G1Point> = amounts
+            .iter()
+            .map(|a: &usize| ElGamal::elgamal_encrypt(*a, &y_auditor, &g, &r).0)
+            .collect();
+
+        let (mut real_a, mut real_s): (Vec<usize>, Vec<usize>) = (vec![], vec![]);
+        let (mut simulated_a, mut simulated_s): (Vec<usize>, Vec<usize>) = (vec![], vec![]);
+
+        for _ in 0..SAMPLES {
+            let mut prover_trans: Transcript = Transcript::new(b"SigmaYAuditorSimulatorTest");
+            let real: SigmaYProof = SigmaYProver::new(&r, &y, &y_bar)
+                .with_auditor(&y_auditor, &c_auditor_vec)
+                .generate_proof(&mut rng, &mut prover_trans);
+
+            let challenges: Vec<ScalarField> = Utils::get_n_random_scalars(2, &mut rng);
+            let simulated: SigmaYProof = SigmaYProver::simulate_with_auditor(
+                &y,
+                &y_bar,
+                &c_vec,
+                &c_bar_vec,
+                &y_auditor,
+                &c_auditor_vec,
+                &challenges[0],
+                &challenges[1],
+                &mut rng,
+            );
+
+            assert!(
+                SigmaYVerifier::new(&y, &y_bar, &c_vec, &c_bar_vec)
+                    .with_auditor(&y_auditor, &c_auditor_vec)
+                    .verify_with_challenges(&simulated, &challenges[0], &challenges[1])
+                    .is_ok(),
+                "Simulated transcript is not accepting"
+            );
+
+            real_a.push(point_bucket(real.get_a_auditor().unwrap()));
+            real_s.push(scalar_bucket(real.get_s_r()));
+            simulated_a.push(point_bucket(simulated.get_a_auditor().unwrap()));
+            simulated_s.push(scalar_bucket(simulated.get_s_r()));
+        }
+
+        assert_same_distribution(&real_a, &simulated_a, "A_auditor");
+        assert_same_distribution(&real_s, &simulated_s, "s_r");
+    }
+
+    #[test]
+    fn sigma_ab_simulator_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let m: usize = 4;
+        let n: usize = 16;
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let (balance, amounts, balance_remaining) = Utils::get_mock_balances(m, n, &mut rng);
+
+        let sk: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let y: G1Point = ElGamal::elgamal_calculate_pub_key(&sk, &g);
+        let (c_l, c_r): (G1Point, G1Point) = ElGamal::elgamal_encrypt(balance, &y, &g, &r);
+        let d: G1Point = ElGamal::elgamal_d(&g, &r);
+        let c_vec: Vec<G1Point> = amounts
+            .iter()
+            .map(|a: &usize| ElGamal::elgamal_encrypt(*a, &y, &g, &r).0)
+            .collect();
+
+        let (mut real_a, mut real_s): (Vec<usize>, Vec<usize>) = (vec![], vec![]);
+        let (mut simulated_a, mut simulated_s): (Vec<usize>, Vec<usize>) = (vec![], vec![]);
+
+        for _ in 0..SAMPLES {
+            let mut prover_trans: Transcript = Transcript::new(b"SigmaABSimulatorTest");
+            let real: SigmaABProof =
+                SigmaABProver::new(&g, &d, &c_r, balance_remaining, &amounts, &sk)
+                    .generate_proof(&mut rng, &mut prover_trans);
+
+            let challenges: Vec<ScalarField> = Utils::get_n_random_scalars(2, &mut rng);
+            let simulated: SigmaABProof = SigmaABProver::simulate(
+                &g,
+                &d,
+                &c_r,
+                &c_l,
+                &c_vec,
+                &challenges[0],
+                &challenges[1],
+                &mut rng,
+            );
+
+            assert!(
+                SigmaABVerifier::new(&g, &d, &c_r, &c_l, &c_vec)
+                    .verify_with_challenges(&simulated, &challenges[0], &challenges[1])
+                    .is_ok(),
+                "Simulated transcript is not accepting"
+            );
+
+            real_a.push(point_bucket(real.get_a_ab()));
+            real_s.push(scalar_bucket(real.get_s_ab()));
+            simulated_a.push(point_bucket(simulated.get_a_ab()));
+            simulated_s.push(scalar_bucket(simulated.get_s_ab()));
+        }
+
+        assert_same_distribution(&real_a, &simulated_a, "A_ab");
+        assert_same_distribution(&real_s, &simulated_s, "s_ab");
+    }
+
+    #[test]
+    fn inner_sigma_simulator_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let rounds: usize = 3;
+        let size: usize = 1 << rounds;
+
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(size, &mut rng);
+        let h_vec: Vec<G1Point> = Utils::get_n_generators(size, &mut rng);
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+
+        // The plain argument is deterministic for a fixed statement and witness, so only the
+        // blinded case can be simulated
+        let a_vec: Vec<ScalarField> = Utils::get_n_random_scalars(size, &mut rng);
+        let b_vec: Vec<ScalarField> = Utils::get_n_random_scalars(size, &mut rng);
+        let c: ScalarField = Utils::inner_product_scalar_scalar(&a_vec, &b_vec).unwrap();
+        let t: G1Point = Utils::inner_product_point_scalar(&g_vec, &a_vec).unwrap()
+            + Utils::inner_product_point_scalar(&h_vec, &b_vec).unwrap();
+        let proofs: Vec<InnerSigmaProof> = (0..2)
+            .map(|_| {
+                let mut prover_trans: Transcript = Transcript::new(b"InnerSigmaSimulatorTest");
+                InnerSigmaProver::new(&g_vec, &h_vec, &t, &c, &a_vec, &b_vec, &u)
+                    .generate_proof(&mut prover_trans)
+                    .unwrap()
+            })
+            .collect();
+        assert_eq!(proofs[0].get_a(), proofs[1].get_a());
+        assert_eq!(proofs[0].get_b(), proofs[1].get_b());
+
+        // Fixed witness a_L, a_R as in the range proof, blinded by fresh s_L, s_R and x
+        let a_l: Vec<ScalarField> = Utils::get_n_random_scalars(size, &mut rng);
+        let a_r: Vec<ScalarField> = Utils::get_n_random_scalars(size, &mut rng);
+
+        let (mut real_a, mut real_c, mut real_r): (Vec<usize>, Vec<usize>, Vec<usize>) =
+            (vec![], vec![], vec![]);
+        let (mut simulated_a, mut simulated_c, mut simulated_r): (
+            Vec<usize>,
+            Vec<usize>,
+            Vec<usize>,
+        ) = (vec![], vec![], vec![]);
+
+        for _ in 0..SAMPLES {
+            let x: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+            let s_l: Vec<ScalarField> = Utils::get_n_random_scalars(size, &mut rng);
+            let s_r: Vec<ScalarField> = Utils::get_n_random_scalars(size, &mut rng);
+            let l_vec: Vec<ScalarField> =
+                Utils::sum_scalar_scalar(&a_l, &Utils::product_scalar(&x, &s_l)).unwrap();
+            let r_vec: Vec<ScalarField> =
+                Utils::sum_scalar_scalar(&a_r, &Utils::product_scalar(&x, &s_r)).unwrap();
+            let real_c_value: ScalarField =
+                Utils::inner_product_scalar_scalar(&l_vec, &r_vec).unwrap();
+            let real_t: G1Point = Utils::inner_product_point_scalar(&g_vec, &l_vec).unwrap()
+                + Utils::inner_product_point_scalar(&h_vec, &r_vec).unwrap();
+
+            let mut prover_trans: Transcript = Transcript::new(b"InnerSigmaSimulatorTest");
+            let real: InnerSigmaProof =
+                InnerSigmaProver::new(&g_vec, &h_vec, &real_t, &real_c_value, &l_vec, &r_vec, &u)
+                    .generate_proof(&mut prover_trans)
+                    .unwrap();
+
+            let y: ScalarField = Utils::get_n_random_scalars(1, &mut rng)[0];
+            let x_vec: Vec<ScalarField> = Utils::get_n_random_scalars(rounds, &mut rng);
+            let (simulated_t, simulated_c_value, simulated): (
+                G1Point,
+                ScalarField,
+                InnerSigmaProof,
+            ) = InnerSigmaProver::simulate(&g_vec, &h_vec, &u, &y, &x_vec, &mut rng).unwrap();
+
+            assert!(
+                InnerSigmaVerifier::new(&g_vec, &h_vec, &simulated_t, &simulated_c_value, &u)
+                    .verify_with_challenges(&simulated, &y, &x_vec)
+                    .is_ok(),
+                "Simulated transcript is not accepting"
+            );
+
+            real_a.push(scalar_bucket(real.get_a()));
+            real_c.push(scalar_bucket(&real_c_value));
+            real_r.push(point_bucket(&real.get_r_vec()[rounds - 1]));
+            simulated_a.push(scalar_bucket(simulated.get_a()));
+            simulated_c.push(scalar_bucket(&simulated_c_value));
+            simulated_r.push(point_bucket(&simulated.get_r_vec()[rounds - 1]));
+        }
+
+        assert_same_distribution(&real_a, &simulated_a, "a");
+        assert_same_distribution(&real_c, &simulated_c, "c");
+        assert_same_distribution(&real_r, &simulated_r, "r");
+    }
+}
@@ -2,7 +2,7 @@
 mod utils_tests {
     use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
     use ark_ec::short_weierstrass_jacobian::GroupAffine;
-    use ark_ff::Zero;
+    use ark_ff::{Field, One, Zero};
     use zeromt::Utils;
     #[test]
     pub fn number_to_be_bits_test() {
@@ -149,4 +149,55 @@ mod utils_tests {
             -test_points[0] + test_points[1]
         );
     }
+
+    #[test]
+    pub fn get_s_vector_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let rounds: usize = 5;
+        let x_vec: Vec<ScalarField> = Utils::get_n_random_scalars_not_zero(rounds, &mut rng);
+        let x_inv_vec: Vec<ScalarField> = Utils::batch_inverse(&x_vec);
+        let x_sq_vec: Vec<ScalarField> = x_vec.iter().map(|x: &ScalarField| x.square()).collect();
+
+        for (x, x_inv) in x_vec.iter().zip(x_inv_vec.iter()) {
+            assert_eq!(*x * x_inv, ScalarField::one());
+        }
+
+        // s_i = prod_j x_j^(+1 or -1), the first challenge on the most significant bit of i
+        let expected: Vec<ScalarField> = (0..1 << rounds)
+            .map(|i: usize| {
+                (0..rounds)
+                    .map(|j: usize| {
+                        if (i >> (rounds - 1 - j)) & 1 == 1 {
+                            x_vec[j]
+                        } else {
+                            x_inv_vec[j]
+                        }
+                    })
+                    .product()
+            })
+            .collect();
+
+        let s: Vec<ScalarField> =
+            Utils::get_s_vector(&x_inv_vec.iter().product(), &x_sq_vec, 1 << rounds).unwrap();
+        assert_eq!(s, expected);
+
+        let s_truncated: Vec<ScalarField> =
+            Utils::get_s_vector(&x_inv_vec.iter().product(), &x_sq_vec, 21).unwrap();
+        assert_eq!(s_truncated, expected[..21].to_vec());
+
+        assert!(Utils::get_s_vector(&ScalarField::one(), &x_sq_vec, (1 << rounds) + 1).is_err());
+    }
+
+    #[test]
+    pub fn multiscalar_mul_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let points: Vec<G1Point> = Utils::get_n_generators(33, &mut rng);
+        let scalars: Vec<ScalarField> = Utils::get_n_random_scalars(33, &mut rng);
+
+        assert_eq!(
+            Utils::multiscalar_mul(&points, &scalars).unwrap(),
+            Utils::inner_product_point_scalar(&points, &scalars).unwrap()
+        );
+        assert!(Utils::multiscalar_mul(&points, &scalars[1..].to_vec()).is_err());
+    }
 }
@@ -11,7 +11,8 @@ mod zeromt_proof_tests {
         CsvUtils, ElGamal, InnerProof, InnerProver, InnerVerifier, PolyCoefficients, RangeProof,
         RangeProver, RangeVerifier, SigmaABProof, SigmaABProver, SigmaABVerifier, SigmaRProof,
         SigmaRProver, SigmaRVerifier, SigmaSKProof, SigmaSKProver, SigmaSKVerifier, SigmaYProof,
-        SigmaYProver, SigmaYVerifier, Utils,
+        SigmaYProver, SigmaYVerifier, Utils, ZeroMTParams, ZeroMTPlusProof, ZeroMTProof,
+        ZeroMTProver, ZeroMTStatement, ZeroMTVerifier, ZeroMTWitness,
     };
 
     #[test]
@@ -203,7 +204,8 @@ mod zeromt_proof_tests {
                     &r_poly_vec,
                     &u,
                 )
-                .generate_proof(&mut prover_trans);
+                .generate_proof(&mut prover_trans)
+                .unwrap();
                 let inner_prover_duration: Duration = start.elapsed();
 
                 let start = Instant::now();
@@ -331,4 +333,153 @@ mod zeromt_proof_tests {
             n *= 2;
         }
     }
+
+    /// Full ZeroMT proofs with RangeProver and InnerProver against RangePlusProver, side by side
+    #[test]
+    #[serial]
+    fn zeromt_range_plus_bench() {
+        let mut bench: CsvUtils = CsvUtils::new(
+            "./benchmark/zeromt_range_plus.csv".to_string(),
+            [
+                "n".to_string(),
+                "m".to_string(),
+                "zeromt_prover_time_ms".to_string(),
+                "zeromt_verifier_time_ms".to_string(),
+                "zeromt_proof_size_bytes".to_string(),
+                "zeromt_plus_prover_time_ms".to_string(),
+                "zeromt_plus_verifier_time_ms".to_string(),
+                "zeromt_plus_proof_size_bytes".to_string(),
+            ]
+            .to_vec(),
+        );
+
+        let n_increases: usize = 2;
+        let m_increases: usize = 5;
+
+        let mut rng = ark_std::rand::thread_rng();
+
+        let mut n: usize = 16;
+        for _ in 0..=n_increases {
+            let mut m: usize = 2;
+            for _ in 0..=m_increases {
+                let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+                let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+                let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+
+                let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+
+                let g_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+                let h_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+
+                let (balance, amounts, remaining_balance) =
+                    Utils::get_mock_balances(m, n, &mut rng);
+
+                let sender_priv_key: ScalarField =
+                    Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+                let sender_pub_key: G1Point =
+                    ElGamal::elgamal_calculate_pub_key(&sender_priv_key, &g);
+                let recipients_pub_keys: Vec<G1Point> =
+                    Utils::get_n_random_scalars_not_zero(amounts.len(), &mut rng)
+                        .iter()
+                        .map(|key: &ScalarField| ElGamal::elgamal_calculate_pub_key(key, &g))
+                        .collect();
+
+                let (c_l, c_r): (G1Point, G1Point) =
+                    ElGamal::elgamal_encrypt(balance, &sender_pub_key, &g, &r);
+                let d: G1Point = ElGamal::elgamal_d(&g, &r);
+                let c_vec: Vec<G1Point> = amounts
+                    .iter()
+                    .map(|a: &usize| ElGamal::elgamal_encrypt(*a, &sender_pub_key, &g, &r).0)
+                    .collect();
+                let c_bar_vec: Vec<G1Point> = amounts
+                    .iter()
+                    .zip(recipients_pub_keys.iter())
+                    .map(|(a, k)| ElGamal::elgamal_encrypt(*a, k, &g, &r).0)
+                    .collect();
+
+                let prover = || {
+                    ZeroMTProver::<InnerProver>::new(
+                        &ZeroMTParams::new(&g, &h, &g_vec, &h_vec, &u, n),
+                        &ZeroMTStatement::new(
+                            &d,
+                            &c_r,
+                            &c_l,
+                            &c_vec,
+                            &c_bar_vec,
+                            &sender_pub_key,
+                            &recipients_pub_keys,
+                        ),
+                        &ZeroMTWitness::new(remaining_balance, &amounts, &sender_priv_key, &r),
+                    )
+                };
+                let verifier = || {
+                    ZeroMTVerifier::<InnerProver>::new(
+                        &ZeroMTParams::new(&g, &h, &g_vec, &h_vec, &u, n),
+                        &ZeroMTStatement::new(
+                            &d,
+                            &c_r,
+                            &c_l,
+                            &c_vec,
+                            &c_bar_vec,
+                            &sender_pub_key,
+                            &recipients_pub_keys,
+                        ),
+                    )
+                };
+
+                let mut prover_trans: Transcript = Transcript::new(b"ZeroMTTest");
+                let start = Instant::now();
+                let proof: ZeroMTProof = prover()
+                    .generate_proof(&mut rng, &mut prover_trans)
+                    .unwrap();
+                let prover_duration: Duration = start.elapsed();
+
+                let mut verifier_trans: Transcript = Transcript::new(b"ZeroMTTest");
+                let start = Instant::now();
+                let result = verifier().verify_proof(&proof, &mut verifier_trans);
+                let verifier_duration: Duration = start.elapsed();
+                assert!(result.is_ok(), "Verifier fails");
+
+                let mut prover_trans: Transcript = Transcript::new(b"ZeroMTTest");
+                let start = Instant::now();
+                let plus_proof: ZeroMTPlusProof = prover()
+                    .generate_plus_proof(&mut rng, &mut prover_trans)
+                    .unwrap();
+                let plus_prover_duration: Duration = start.elapsed();
+
+                let mut verifier_trans: Transcript = Transcript::new(b"ZeroMTTest");
+                let start = Instant::now();
+                let plus_result = verifier().verify_plus_proof(&plus_proof, &mut verifier_trans);
+                let plus_verifier_duration: Duration = start.elapsed();
+                assert!(plus_result.is_ok(), "Plus verifier fails");
+
+                bench.write_content(
+                    [
+                        n.to_string(),
+                        m.to_string(),
+                        prover_duration.as_millis().to_formatted_string(&Locale::en),
+                        verifier_duration
+                            .as_millis()
+                            .to_formatted_string(&Locale::en),
+                        proof.uncompressed_size().to_formatted_string(&Locale::en),
+                        plus_prover_duration
+                            .as_millis()
+                            .to_formatted_string(&Locale::en),
+                        plus_verifier_duration
+                            .as_millis()
+                            .to_formatted_string(&Locale::en),
+                        plus_proof
+                            .uncompressed_size()
+                            .to_formatted_string(&Locale::en),
+                    ]
+                    .to_vec(),
+                );
+
+                bench.next_line();
+
+                m *= 2;
+            }
+            n *= 2;
+        }
+    }
 }
@@ -2,22 +2,420 @@
 mod zeromt_proof_tests {
 
     use ark_bn254::{Fr as ScalarField, G1Affine as G1Point};
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
     use merlin::Transcript;
     use serial_test::serial;
     use std::io::Error;
-    use zeromt::{ElGamal, Utils, ZeroMTProof, ZeroMTProver, ZeroMTVerifier};
+    use zeromt::{
+        AssociatedData, ElGamal, InnerProductArgument, InnerProof, InnerProver, InnerSigmaProver,
+        IpaStatement, IpaWitness, Utils, ZeroMTParams, ZeroMTPlusProof, ZeroMTProof, ZeroMTProver,
+        ZeroMTStatement, ZeroMTVerifier, ZeroMTWitness,
+    };
 
     #[test]
     #[serial]
     fn zeromt_proof_test() {
-        let n_increases: usize = 2;
-        let m_increases: usize = 5;
+        zeromt_proof_run::<InnerProver>(2, 2, 5);
+    }
+
+    #[test]
+    #[serial]
+    fn zeromt_inner_sigma_proof_test() {
+        zeromt_proof_run::<InnerSigmaProver>(1, 2, 3);
+    }
+
+    #[test]
+    #[serial]
+    fn zeromt_non_power_of_two_test() {
+        // m * n = 48 and 96, padded to 64 and 128 by the inner-product arguments
+        zeromt_proof_run::<InnerProver>(0, 3, 1);
+        zeromt_proof_run::<InnerSigmaProver>(0, 3, 1);
+    }
 
+    #[test]
+    #[serial]
+    fn zeromt_dummy_recipients_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let n: usize = 16;
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+
+        let sender_priv_key: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let sender_pub_key: G1Point = ElGamal::elgamal_calculate_pub_key(&sender_priv_key, &g);
+        // Accounts known to the ledger, the first five of them are the real recipients
+        let accounts: Vec<G1Point> = Utils::get_n_random_scalars_not_zero(8, &mut rng)
+            .iter()
+            .map(|key: &ScalarField| ElGamal::elgamal_calculate_pub_key(key, &g))
+            .chain(std::iter::once(sender_pub_key))
+            .collect();
+        let real_pub_keys: Vec<G1Point> = accounts[..5].to_vec();
+
+        // Five recipients, padded to seven so that m = 8
+        let (balance, real_amounts, remaining_balance) = Utils::get_mock_balances(6, n, &mut rng);
+        let (amounts, recipients_pub_keys): (Vec<usize>, Vec<G1Point>) =
+            ZeroMTProver::<InnerProver>::pad_recipients(
+                &sender_pub_key,
+                &real_amounts,
+                &real_pub_keys,
+                &accounts,
+                &mut rng,
+            )
+            .unwrap();
+        assert_eq!(amounts.len(), 7);
+        assert_eq!(
+            amounts.iter().sum::<usize>(),
+            real_amounts.iter().sum::<usize>()
+        );
+        for (a, k) in real_amounts.iter().zip(real_pub_keys.iter()) {
+            let i: usize = recipients_pub_keys.iter().position(|y| y == k).unwrap();
+            assert_eq!(amounts[i], *a);
+        }
+        // The dummy recipients are distinct accounts, other than the sender
+        for (i, k) in recipients_pub_keys.iter().enumerate() {
+            assert!(accounts[..8].contains(k));
+            assert!(!recipients_pub_keys[(i + 1)..].contains(k));
+        }
+
+        // Too few accounts to pad the transfer
+        assert!(ZeroMTProver::<InnerProver>::pad_recipients(
+            &sender_pub_key,
+            &real_amounts,
+            &real_pub_keys,
+            &accounts[..6].to_vec(),
+            &mut rng,
+        )
+        .is_err());
+
+        let m: usize = amounts.len() + 1;
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+        let h_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+
+        let (c_l, c_r): (G1Point, G1Point) =
+            ElGamal::elgamal_encrypt(balance, &sender_pub_key, &g, &r);
+        let d: G1Point = ElGamal::elgamal_d(&g, &r);
+
+        // The dummy transfers are encrypted exactly as the real ones
+        let c_vec: Vec<G1Point> = amounts
+            .iter()
+            .map(|a: &usize| ElGamal::elgamal_encrypt(*a, &sender_pub_key, &g, &r).0)
+            .collect();
+        let c_bar_vec: Vec<G1Point> = amounts
+            .iter()
+            .zip(recipients_pub_keys.iter())
+            .map(|(a, k)| ElGamal::elgamal_encrypt(*a, k, &g, &r).0)
+            .collect();
+        for (i, c_bar) in c_bar_vec.iter().enumerate() {
+            assert!(!c_bar_vec[(i + 1)..].contains(c_bar));
+        }
+
+        let mut prover_trans: Transcript = Transcript::new(b"ZeroMTTest");
+        let proof: ZeroMTProof = ZeroMTProver::<InnerProver>::new(
+            &ZeroMTParams::new(&g, &h, &g_vec, &h_vec, &u, n),
+            &ZeroMTStatement::new(
+                &d,
+                &c_r,
+                &c_l,
+                &c_vec,
+                &c_bar_vec,
+                &sender_pub_key,
+                &recipients_pub_keys,
+            ),
+            &ZeroMTWitness::new(remaining_balance, &amounts, &sender_priv_key, &r),
+        )
+        .generate_proof(&mut rng, &mut prover_trans)
+        .unwrap();
+
+        // The standard verifier accepts the padded transfer
+        let mut verifier_trans: Transcript = Transcript::new(b"ZeroMTTest");
+        let verification_result: Result<(), Error> = ZeroMTVerifier::<InnerProver>::new(
+            &ZeroMTParams::new(&g, &h, &g_vec, &h_vec, &u, n),
+            &ZeroMTStatement::new(
+                &d,
+                &c_r,
+                &c_l,
+                &c_vec,
+                &c_bar_vec,
+                &sender_pub_key,
+                &recipients_pub_keys,
+            ),
+        )
+        .verify_proof(&proof, &mut verifier_trans);
+
+        assert!(verification_result.is_ok(), "Verifier fails");
+    }
+
+    #[test]
+    #[serial]
+    fn zeromt_fee_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let m: usize = 4;
+        let n: usize = 16;
+        let fee: usize = 25;
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+        let h_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+
+        let (balance, amounts, remaining_balance) = Utils::get_mock_balances(m, n, &mut rng);
+
+        let sender_priv_key: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let sender_pub_key: G1Point = ElGamal::elgamal_calculate_pub_key(&sender_priv_key, &g);
+        let recipients_pub_keys: Vec<G1Point> = Utils::get_n_generators(m - 1, &mut rng);
+
+        let (c_l, c_r): (G1Point, G1Point) =
+            ElGamal::elgamal_encrypt(balance + fee, &sender_pub_key, &g, &r);
+        let d: G1Point = ElGamal::elgamal_d(&g, &r);
+        let c_vec: Vec<G1Point> = amounts
+            .iter()
+            .map(|a: &usize| ElGamal::elgamal_encrypt(*a, &sender_pub_key, &g, &r).0)
+            .collect();
+        let c_bar_vec: Vec<G1Point> = amounts
+            .iter()
+            .zip(recipients_pub_keys.iter())
+            .map(|(a, k)| ElGamal::elgamal_encrypt(*a, k, &g, &r).0)
+            .collect();
+
+        // The sender balance covers the fee on top of the amounts
+        let prover = || {
+            ZeroMTProver::<InnerProver>::new(
+                &ZeroMTParams::new(&g, &h, &g_vec, &h_vec, &u, n),
+                &ZeroMTStatement::new(
+                    &d,
+                    &c_r,
+                    &c_l,
+                    &c_vec,
+                    &c_bar_vec,
+                    &sender_pub_key,
+                    &recipients_pub_keys,
+                ),
+                &ZeroMTWitness::new(remaining_balance, &amounts, &sender_priv_key, &r),
+            )
+            .with_fee(fee)
+        };
+        let verifier = |fee: Option<usize>| {
+            let verifier: ZeroMTVerifier<InnerProver> = ZeroMTVerifier::<InnerProver>::new(
+                &ZeroMTParams::new(&g, &h, &g_vec, &h_vec, &u, n),
+                &ZeroMTStatement::new(
+                    &d,
+                    &c_r,
+                    &c_l,
+                    &c_vec,
+                    &c_bar_vec,
+                    &sender_pub_key,
+                    &recipients_pub_keys,
+                ),
+            );
+            match fee {
+                Some(fee) => verifier.with_fee(fee),
+                None => verifier,
+            }
+        };
+
+        let proof: ZeroMTProof<InnerProof> = prover()
+            .generate_proof(&mut rng, &mut Transcript::new(b"ZeroMTTest"))
+            .unwrap();
+        let plus_proof: ZeroMTPlusProof = prover()
+            .generate_plus_proof(&mut rng, &mut Transcript::new(b"ZeroMTTest"))
+            .unwrap();
+
+        for verifier_fee in [Some(fee), Some(fee + 1), Some(0), None] {
+            let expected: bool = verifier_fee == Some(fee);
+            let result: Result<(), Error> =
+                verifier(verifier_fee).verify_proof(&proof, &mut Transcript::new(b"ZeroMTTest"));
+            assert_eq!(result.is_ok(), expected, "Fee {:?}", verifier_fee);
+
+            let result: Result<(), Error> = verifier(verifier_fee)
+                .verify_plus_proof(&plus_proof, &mut Transcript::new(b"ZeroMTTest"));
+            assert_eq!(result.is_ok(), expected, "Plus fee {:?}", verifier_fee);
+        }
+    }
+
+    /// Public statement of a transfer, cloned and altered one element at a time
+    #[derive(Clone)]
+    struct TransferStatement {
+        g: G1Point,
+        h: G1Point,
+        y: G1Point,
+        y_bar: Vec<G1Point>,
+        c_l: G1Point,
+        c_r: G1Point,
+        d: G1Point,
+        c_vec: Vec<G1Point>,
+        c_bar_vec: Vec<G1Point>,
+    }
+
+    #[test]
+    #[serial]
+    fn zeromt_associated_data_test() {
+        let mut rng = ark_std::rand::thread_rng();
+        let m: usize = 4;
+        let n: usize = 16;
+
+        let g: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let h: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let u: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let g_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+        let h_vec: Vec<G1Point> = Utils::get_n_generators(m * n, &mut rng);
+        let r: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+
+        let (balance, amounts, remaining_balance) = Utils::get_mock_balances(m, n, &mut rng);
+
+        let sender_priv_key: ScalarField = Utils::get_n_random_scalars_not_zero(1, &mut rng)[0];
+        let sender_pub_key: G1Point = ElGamal::elgamal_calculate_pub_key(&sender_priv_key, &g);
+        let recipients_pub_keys: Vec<G1Point> = Utils::get_n_generators(m - 1, &mut rng);
+
+        let (c_l, c_r): (G1Point, G1Point) =
+            ElGamal::elgamal_encrypt(balance, &sender_pub_key, &g, &r);
+        let d: G1Point = ElGamal::elgamal_d(&g, &r);
+        let statement: TransferStatement = TransferStatement {
+            g,
+            h,
+            y: sender_pub_key,
+            y_bar: recipients_pub_keys.clone(),
+            c_l,
+            c_r,
+            d,
+            c_vec: amounts
+                .iter()
+                .map(|a: &usize| ElGamal::elgamal_encrypt(*a, &sender_pub_key, &g, &r).0)
+                .collect(),
+            c_bar_vec: amounts
+                .iter()
+                .zip(recipients_pub_keys.iter())
+                .map(|(a, k)| ElGamal::elgamal_encrypt(*a, k, &g, &r).0)
+                .collect(),
+        };
+
+        let address: &[u8] = b"0x5FbDB2315678afecb367f032d93F642f64180aa3";
+        let associated_data: AssociatedData = AssociatedData::new()
+            .with_chain_id(11155111)
+            .with_contract_address(address)
+            .with_epoch(42);
+
+        let proof: ZeroMTProof<InnerProof> = ZeroMTProver::<InnerProver>::new(
+            &ZeroMTParams::new(&g, &h, &g_vec, &h_vec, &u, n),
+            &ZeroMTStatement::new(
+                &d,
+                &c_r,
+                &c_l,
+                &statement.c_vec,
+                &statement.c_bar_vec,
+                &sender_pub_key,
+                &recipients_pub_keys,
+            ),
+            &ZeroMTWitness::new(remaining_balance, &amounts, &sender_priv_key, &r),
+        )
+        .with_associated_data(&associated_data)
+        .generate_proof(&mut rng, &mut Transcript::new(b"ZeroMTTest"))
+        .unwrap();
+
+        let verify = |statement: &TransferStatement,
+                      associated_data: Option<&AssociatedData>|
+         -> Result<(), Error> {
+            let verifier: ZeroMTVerifier<InnerProver> = ZeroMTVerifier::<InnerProver>::new(
+                &ZeroMTParams::new(&statement.g, &statement.h, &g_vec, &h_vec, &u, n),
+                &ZeroMTStatement::new(
+                    &statement.d,
+                    &statement.c_r,
+                    &statement.c_l,
+                    &statement.c_vec,
+                    &statement.c_bar_vec,
+                    &statement.y,
+                    &statement.y_bar,
+                ),
+            );
+            match associated_data {
+                Some(associated_data) => verifier.with_associated_data(associated_data),
+                None => verifier,
+            }
+            .verify_proof(&proof, &mut Transcript::new(b"ZeroMTTest"))
+        };
+
+        assert!(
+            verify(&statement, Some(&associated_data)).is_ok(),
+            "Verifier fails"
+        );
+
+        // Any other transaction context
+        let other_contexts: Vec<AssociatedData> = vec![
+            AssociatedData::new()
+                .with_chain_id(1)
+                .with_contract_address(address)
+                .with_epoch(42),
+            AssociatedData::new()
+                .with_chain_id(11155111)
+                .with_contract_address(b"0xe7f1725E7734CE288F8367e1Bb143E90bb3F0512")
+                .with_epoch(42),
+            AssociatedData::new()
+                .with_chain_id(11155111)
+                .with_contract_address(address)
+                .with_epoch(43),
+            AssociatedData::new()
+                .with_chain_id(11155111)
+                .with_contract_address(address),
+            AssociatedData::new(),
+        ];
+        for other_context in other_contexts.iter() {
+            assert!(
+                verify(&statement, Some(other_context)).is_err(),
+                "{:?}",
+                other_context
+            );
+        }
+        assert!(verify(&statement, None).is_err(), "No associated data");
+
+        // Any other element of the statement is rejected as well, already by the verification
+        // equations: the binding of the challenges to the statement is shown on SigmaR, see
+        // sigma_r_statement_binding_test
+        let point: G1Point = Utils::get_n_generators(1, &mut rng)[0];
+        let mut tampered: Vec<(&str, TransferStatement)> = Vec::new();
+        let mut alter = |label: &'static str, f: &dyn Fn(&mut TransferStatement)| {
+            let mut other: TransferStatement = statement.clone();
+            f(&mut other);
+            tampered.push((label, other));
+        };
+        alter("g", &|s: &mut TransferStatement| s.g = point);
+        alter("h", &|s: &mut TransferStatement| s.h = point);
+        alter("y", &|s: &mut TransferStatement| s.y = point);
+        alter("y_bar", &|s: &mut TransferStatement| s.y_bar[0] = point);
+        alter("C_L", &|s: &mut TransferStatement| s.c_l = point);
+        alter("C_R", &|s: &mut TransferStatement| s.c_r = point);
+        alter("D", &|s: &mut TransferStatement| s.d = point);
+        alter("C", &|s: &mut TransferStatement| s.c_vec[0] = point);
+        alter("C_bar", &|s: &mut TransferStatement| s.c_bar_vec[0] = point);
+        alter("y_bar length", &|s: &mut TransferStatement| {
+            s.y_bar.push(point)
+        });
+        alter("C length", &|s: &mut TransferStatement| {
+            let _popped = s.c_vec.pop();
+        });
+        alter("C_bar length", &|s: &mut TransferStatement| {
+            s.c_bar_vec.push(point)
+        });
+        for (label, other) in tampered.iter() {
+            assert!(
+                verify(other, Some(&associated_data)).is_err(),
+                "Statement {}",
+                label
+            );
+        }
+    }
+
+    fn zeromt_proof_run<I>(n_increases: usize, m_start: usize, m_increases: usize)
+    where
+        I: InnerProductArgument<Statement = IpaStatement, Witness = IpaWitness>,
+    {
         let mut rng = ark_std::rand::thread_rng();
 
         let mut n: usize = 16;
         for _ in 0..=n_increases {
-            let mut m: usize = 2;
+            let mut m: usize = m_start;
             for _ in 0..=m_increases {
                 let mut prover_trans: Transcript = Transcript::new(b"ZeroMTTest");
                 let mut verifier_trans: Transcript = Transcript::new(b"ZeroMTTest");
@@ -64,43 +462,46 @@ mod zeromt_proof_tests {
                     .map(|(a, k)| ElGamal::elgamal_encrypt(*a, k, &g, &r).0)
                     .collect();
 
-                let proof: ZeroMTProof = ZeroMTProver::new(
-                    &g,
-                    &h,
-                    remaining_balance,
-                    &amounts,
-                    &g_vec,
-                    &h_vec,
-                    &u,
-                    n,
-                    &d,
-                    &c_r,
-                    &sender_priv_key,
-                    &r,
-                    &sender_pub_key,
-                    &recipients_pub_keys,
+                let proof: ZeroMTProof<I::Proof> = ZeroMTProver::<I>::new(
+                    &ZeroMTParams::new(&g, &h, &g_vec, &h_vec, &u, n),
+                    &ZeroMTStatement::new(
+                        &d,
+                        &c_r,
+                        &c_l,
+                        &c_vec,
+                        &c_bar_vec,
+                        &sender_pub_key,
+                        &recipients_pub_keys,
+                    ),
+                    &ZeroMTWitness::new(remaining_balance, &amounts, &sender_priv_key, &r),
                 )
-                .generate_proof(&mut rng, &mut prover_trans);
-
-                let verification_result: Result<(), Error> = ZeroMTVerifier::new(
-                    &g,
-                    &h,
-                    n,
-                    &g_vec,
-                    &h_vec,
-                    &u,
-                    &d,
-                    &c_r,
-                    &c_l,
-                    &c_vec,
-                    &c_bar_vec,
-                    &sender_pub_key,
-                    &recipients_pub_keys,
+                .generate_proof(&mut rng, &mut prover_trans)
+                .unwrap();
+
+                let verification_result: Result<(), Error> = ZeroMTVerifier::<I>::new(
+                    &ZeroMTParams::new(&g, &h, &g_vec, &h_vec, &u, n),
+                    &ZeroMTStatement::new(
+                        &d,
+                        &c_r,
+                        &c_l,
+                        &c_vec,
+                        &c_bar_vec,
+                        &sender_pub_key,
+                        &recipients_pub_keys,
+                    ),
                 )
                 .verify_proof(&proof, &mut verifier_trans);
 
                 assert!(verification_result.is_ok(), "Verifier fails");
 
+                // The selected argument is the first byte of the encoding
+                let mut proof_bytes: Vec<u8> = Vec::new();
+                proof.serialize(&mut proof_bytes).unwrap();
+                assert_eq!(proof_bytes[0], I::ID);
+                let decoded: ZeroMTProof<I::Proof> =
+                    ZeroMTProof::deserialize(&proof_bytes[..]).unwrap();
+                assert_eq!(decoded.get_ipa_id(), I::ID);
+
                 m *= 2;
             }
             n *= 2;